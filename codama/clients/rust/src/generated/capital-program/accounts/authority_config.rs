@@ -19,10 +19,25 @@ pub nft_program: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub admin: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pending_admin: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub agent: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub guardian: Pubkey,
+pub arbiters: [Pubkey; 7],
+pub arbiter_count: u8,
+pub arbiter_threshold: u8,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub treasury: Pubkey,
 pub early_unlock_fee: u64,
+pub protocol_fee_bps: u16,
+pub min_operator_bond_bps: u16,
+pub insurance_fee_bps: u16,
+pub insurance_coverage_bps: u16,
 pub min_lock_duration: i64,
 pub max_lock_duration: i64,
+pub paused: bool,
+pub treasury_bump: u8,
 pub bump: u8,
 }
 
@@ -30,7 +45,7 @@ pub bump: u8,
 pub const AUTHORITY_CONFIG_DISCRIMINATOR: [u8; 8] = [70, 68, 21, 32, 12, 21, 85, 159];
 
 impl AuthorityConfig {
-      pub const LEN: usize = 129;
+      pub const LEN: usize = 461;
   
   
   
@@ -5,15 +5,31 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#attestation;
+  pub(crate) mod r#attester;
   pub(crate) mod r#authority_config;
   pub(crate) mod r#base_asset_v1;
+  pub(crate) mod r#beneficiary_account;
+  pub(crate) mod r#challenge;
+  pub(crate) mod r#epoch_commitment;
+  pub(crate) mod r#insurance_fund;
   pub(crate) mod r#n_f_t_config;
+  pub(crate) mod r#operator_registry;
   pub(crate) mod r#position;
+  pub(crate) mod r#slash_request;
   pub(crate) mod r#vault;
 
+  pub use self::r#attestation::*;
+  pub use self::r#attester::*;
   pub use self::r#authority_config::*;
   pub use self::r#base_asset_v1::*;
+  pub use self::r#beneficiary_account::*;
+  pub use self::r#challenge::*;
+  pub use self::r#epoch_commitment::*;
+  pub use self::r#insurance_fund::*;
   pub use self::r#n_f_t_config::*;
+  pub use self::r#operator_registry::*;
   pub use self::r#position::*;
+  pub use self::r#slash_request::*;
   pub use self::r#vault::*;
 
@@ -20,6 +20,10 @@ pub capital_program: Pubkey,
 pub authority: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub admin: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub pending_admin: Pubkey,
+pub paused: bool,
+pub capital_paused: bool,
 pub bump: u8,
 }
 
@@ -27,7 +31,7 @@ pub bump: u8,
 pub const N_F_T_CONFIG_DISCRIMINATOR: [u8; 8] = [92, 106, 11, 162, 250, 188, 6, 90];
 
 impl NFTConfig {
-      pub const LEN: usize = 105;
+      pub const LEN: usize = 139;
   
   
   
@@ -19,7 +19,8 @@ pub vault: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub asset: Pubkey,
 pub total_value_locked: u64,
-pub total_rewards_claimed: u64,
+pub rewards_claimed: [u64; 4],
+pub reward_debts: [u128; 4],
 pub bump: u8,
 }
 
@@ -27,7 +28,7 @@ pub bump: u8,
 pub const POSITION_DISCRIMINATOR: [u8; 8] = [170, 188, 143, 228, 122, 64, 247, 208];
 
 impl Position {
-      pub const LEN: usize = 89;
+      pub const LEN: usize = 177;
   
   
   
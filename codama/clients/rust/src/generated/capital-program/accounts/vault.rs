@@ -6,7 +6,8 @@
 //!
 
 use solana_pubkey::Pubkey;
-use crate::generated::types::Beneficiary;
+use crate::generated::types::RewardStream;
+use crate::generated::types::VaultPhase;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -17,16 +18,20 @@ pub struct Vault {
 pub discriminator: [u8; 8],
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub locking_token_mint: Pubkey,
-#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub reward_token_mint: Pubkey,
+pub reward_streams: Vec<RewardStream>,
 pub min_cap: u64,
 pub max_cap: u64,
 pub min_lock_amount: u64,
-pub total_rewards_deposited: u64,
 pub total_capital_collected: u64,
 pub capital_after_slashing: u64,
-pub beneficiaries: Vec<Beneficiary>,
+pub operator_bond: u64,
+pub insurance_claimed: u64,
+pub capital_withdrawn: u64,
+pub challenge_slashed: u64,
+pub beneficiary_count: u32,
+pub total_beneficiary_bps: u16,
 pub investor_bps: u16,
+pub operator_commission_bps: u16,
 pub max_slash_bps: u16,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub nft_collection: Pubkey,
@@ -36,11 +41,11 @@ pub reward_distributor: Pubkey,
 pub node_operator: Pubkey,
 pub lock_phase_start_at: i64,
 pub lock_phase_duration: i64,
-pub is_dispute_active: bool,
-pub dispute_start_time: i64,
-pub pending_slash_amount: u64,
-#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub slash_claimant: Pubkey,
+pub vault_index: u64,
+pub phase: VaultPhase,
+pub paused: bool,
+pub swept: bool,
+pub slash_request_count: u64,
 pub bump: u8,
 }
 
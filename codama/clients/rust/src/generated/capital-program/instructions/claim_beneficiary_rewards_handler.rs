@@ -10,6 +10,7 @@ use borsh::BorshDeserialize;
 
 pub const CLAIM_BENEFICIARY_REWARDS_HANDLER_DISCRIMINATOR: [u8; 8] = [105, 195, 177, 247, 219, 87, 16, 160];
 
+/// Claims the named reward stream plus every stream passed in remaining accounts
 /// Accounts.
 #[derive(Debug)]
 pub struct ClaimBeneficiaryRewardsHandler {
@@ -21,6 +22,16 @@ pub struct ClaimBeneficiaryRewardsHandler {
           pub vault: solana_pubkey::Pubkey,
           
               
+          pub config: solana_pubkey::Pubkey,
+                /// The caller's share of the vault rewards
+
+    
+              
+          pub beneficiary_account: solana_pubkey::Pubkey,
+                /// Mint of the reward stream to claim, further streams go in remaining accounts
+
+    
+              
           pub reward_mint: solana_pubkey::Pubkey,
           
               
@@ -40,13 +51,13 @@ pub struct ClaimBeneficiaryRewardsHandler {
       }
 
 impl ClaimBeneficiaryRewardsHandler {
-  pub fn instruction(&self, args: ClaimBeneficiaryRewardsHandlerInstructionArgs) -> solana_instruction::Instruction {
-    self.instruction_with_remaining_accounts(args, &[])
+  pub fn instruction(&self) -> solana_instruction::Instruction {
+    self.instruction_with_remaining_accounts(&[])
   }
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
-  pub fn instruction_with_remaining_accounts(&self, args: ClaimBeneficiaryRewardsHandlerInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(8+ remaining_accounts.len());
+  pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
+    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.beneficiary,
             true
@@ -54,6 +65,14 @@ impl ClaimBeneficiaryRewardsHandler {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.vault,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.config,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.beneficiary_account,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.reward_mint,
@@ -80,9 +99,7 @@ impl ClaimBeneficiaryRewardsHandler {
             false
           ));
                       accounts.extend_from_slice(remaining_accounts);
-    let mut data = ClaimBeneficiaryRewardsHandlerInstructionData::new().try_to_vec().unwrap();
-          let mut args = args.try_to_vec().unwrap();
-      data.append(&mut args);
+    let data = ClaimBeneficiaryRewardsHandlerInstructionData::new().try_to_vec().unwrap();
     
     solana_instruction::Instruction {
       program_id: crate::CAPITAL_PROGRAM_ID,
@@ -96,13 +113,13 @@ impl ClaimBeneficiaryRewardsHandler {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct ClaimBeneficiaryRewardsHandlerInstructionData {
             discriminator: [u8; 8],
-            }
+      }
 
 impl ClaimBeneficiaryRewardsHandlerInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [105, 195, 177, 247, 219, 87, 16, 160],
-                                }
+                  }
   }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -116,17 +133,6 @@ impl Default for ClaimBeneficiaryRewardsHandlerInstructionData {
   }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
- pub struct ClaimBeneficiaryRewardsHandlerInstructionArgs {
-                  pub beneficiary_index: u8,
-      }
-
-impl ClaimBeneficiaryRewardsHandlerInstructionArgs {
-  pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
-    borsh::to_vec(self)
-  }
-}
 
 
 /// Instruction builder for `ClaimBeneficiaryRewardsHandler`.
@@ -135,24 +141,27 @@ impl ClaimBeneficiaryRewardsHandlerInstructionArgs {
 ///
                       ///   0. `[writable, signer]` beneficiary
                 ///   1. `[writable]` vault
-          ///   2. `[]` reward_mint
-                ///   3. `[writable]` vault_ata
-                ///   4. `[writable]` beneficiary_ata
-                ///   5. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   6. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   2. `[]` config
+                ///   3. `[writable]` beneficiary_account
+          ///   4. `[]` reward_mint
+                ///   5. `[writable]` vault_ata
+                ///   6. `[writable]` beneficiary_ata
+                ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ClaimBeneficiaryRewardsHandlerBuilder {
             beneficiary: Option<solana_pubkey::Pubkey>,
                 vault: Option<solana_pubkey::Pubkey>,
+                config: Option<solana_pubkey::Pubkey>,
+                beneficiary_account: Option<solana_pubkey::Pubkey>,
                 reward_mint: Option<solana_pubkey::Pubkey>,
                 vault_ata: Option<solana_pubkey::Pubkey>,
                 beneficiary_ata: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
-                        beneficiary_index: Option<u8>,
-        __remaining_accounts: Vec<solana_instruction::AccountMeta>,
+                __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl ClaimBeneficiaryRewardsHandlerBuilder {
@@ -170,6 +179,18 @@ impl ClaimBeneficiaryRewardsHandlerBuilder {
                     self
     }
             #[inline(always)]
+    pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
+                        self.config = Some(config);
+                    self
+    }
+            /// The caller's share of the vault rewards
+#[inline(always)]
+    pub fn beneficiary_account(&mut self, beneficiary_account: solana_pubkey::Pubkey) -> &mut Self {
+                        self.beneficiary_account = Some(beneficiary_account);
+                    self
+    }
+            /// Mint of the reward stream to claim, further streams go in remaining accounts
+#[inline(always)]
     pub fn reward_mint(&mut self, reward_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.reward_mint = Some(reward_mint);
                     self
@@ -202,12 +223,7 @@ impl ClaimBeneficiaryRewardsHandlerBuilder {
                         self.system_program = Some(system_program);
                     self
     }
-                    #[inline(always)]
-      pub fn beneficiary_index(&mut self, beneficiary_index: u8) -> &mut Self {
-        self.beneficiary_index = Some(beneficiary_index);
-        self
-      }
-        /// Add an additional account to the instruction.
+            /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
     self.__remaining_accounts.push(account);
@@ -224,6 +240,8 @@ impl ClaimBeneficiaryRewardsHandlerBuilder {
     let accounts = ClaimBeneficiaryRewardsHandler {
                               beneficiary: self.beneficiary.expect("beneficiary is not set"),
                                         vault: self.vault.expect("vault is not set"),
+                                        config: self.config.expect("config is not set"),
+                                        beneficiary_account: self.beneficiary_account.expect("beneficiary_account is not set"),
                                         reward_mint: self.reward_mint.expect("reward_mint is not set"),
                                         vault_ata: self.vault_ata.expect("vault_ata is not set"),
                                         beneficiary_ata: self.beneficiary_ata.expect("beneficiary_ata is not set"),
@@ -231,11 +249,8 @@ impl ClaimBeneficiaryRewardsHandlerBuilder {
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                       };
-          let args = ClaimBeneficiaryRewardsHandlerInstructionArgs {
-                                                              beneficiary_index: self.beneficiary_index.clone().expect("beneficiary_index is not set"),
-                                    };
     
-    accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
   }
 }
 
@@ -249,6 +264,16 @@ impl ClaimBeneficiaryRewardsHandlerBuilder {
               pub vault: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub config: &'b solana_account_info::AccountInfo<'a>,
+                        /// The caller's share of the vault rewards
+
+      
+                    
+              pub beneficiary_account: &'b solana_account_info::AccountInfo<'a>,
+                        /// Mint of the reward stream to claim, further streams go in remaining accounts
+
+      
+                    
               pub reward_mint: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -279,6 +304,16 @@ pub struct ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
           pub vault: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub config: &'b solana_account_info::AccountInfo<'a>,
+                /// The caller's share of the vault rewards
+
+    
+              
+          pub beneficiary_account: &'b solana_account_info::AccountInfo<'a>,
+                /// Mint of the reward stream to claim, further streams go in remaining accounts
+
+    
+              
           pub reward_mint: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -295,28 +330,26 @@ pub struct ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
-            /// The arguments for the instruction.
-    pub __args: ClaimBeneficiaryRewardsHandlerInstructionArgs,
-  }
+        }
 
 impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
   pub fn new(
     program: &'b solana_account_info::AccountInfo<'a>,
           accounts: ClaimBeneficiaryRewardsHandlerCpiAccounts<'a, 'b>,
-              args: ClaimBeneficiaryRewardsHandlerInstructionArgs,
-      ) -> Self {
+          ) -> Self {
     Self {
       __program: program,
               beneficiary: accounts.beneficiary,
               vault: accounts.vault,
+              config: accounts.config,
+              beneficiary_account: accounts.beneficiary_account,
               reward_mint: accounts.reward_mint,
               vault_ata: accounts.vault_ata,
               beneficiary_ata: accounts.beneficiary_ata,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
-                    __args: args,
-          }
+                }
   }
   #[inline(always)]
   pub fn invoke(&self) -> solana_program_error::ProgramResult {
@@ -338,7 +371,7 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(8+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.beneficiary.key,
             true
@@ -346,6 +379,14 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.vault.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.beneficiary_account.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.reward_mint.key,
@@ -378,19 +419,19 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
           is_writable: remaining_account.2,
       })
     });
-    let mut data = ClaimBeneficiaryRewardsHandlerInstructionData::new().try_to_vec().unwrap();
-          let mut args = self.__args.try_to_vec().unwrap();
-      data.append(&mut args);
+    let data = ClaimBeneficiaryRewardsHandlerInstructionData::new().try_to_vec().unwrap();
     
     let instruction = solana_instruction::Instruction {
       program_id: crate::CAPITAL_PROGRAM_ID,
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.beneficiary.clone());
                         account_infos.push(self.vault.clone());
+                        account_infos.push(self.config.clone());
+                        account_infos.push(self.beneficiary_account.clone());
                         account_infos.push(self.reward_mint.clone());
                         account_infos.push(self.vault_ata.clone());
                         account_infos.push(self.beneficiary_ata.clone());
@@ -413,12 +454,14 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpi<'a, 'b> {
 ///
                       ///   0. `[writable, signer]` beneficiary
                 ///   1. `[writable]` vault
-          ///   2. `[]` reward_mint
-                ///   3. `[writable]` vault_ata
-                ///   4. `[writable]` beneficiary_ata
-          ///   5. `[]` token_program
-          ///   6. `[]` associated_token_program
-          ///   7. `[]` system_program
+          ///   2. `[]` config
+                ///   3. `[writable]` beneficiary_account
+          ///   4. `[]` reward_mint
+                ///   5. `[writable]` vault_ata
+                ///   6. `[writable]` beneficiary_ata
+          ///   7. `[]` token_program
+          ///   8. `[]` associated_token_program
+          ///   9. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
   instruction: Box<ClaimBeneficiaryRewardsHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -430,14 +473,15 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
       __program: program,
               beneficiary: None,
               vault: None,
+              config: None,
+              beneficiary_account: None,
               reward_mint: None,
               vault_ata: None,
               beneficiary_ata: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
-                                            beneficiary_index: None,
-                    __remaining_accounts: Vec::new(),
+                                __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
@@ -452,6 +496,18 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config = Some(config);
+                    self
+    }
+      /// The caller's share of the vault rewards
+#[inline(always)]
+    pub fn beneficiary_account(&mut self, beneficiary_account: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.beneficiary_account = Some(beneficiary_account);
+                    self
+    }
+      /// Mint of the reward stream to claim, further streams go in remaining accounts
+#[inline(always)]
     pub fn reward_mint(&mut self, reward_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.reward_mint = Some(reward_mint);
                     self
@@ -481,12 +537,7 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
                         self.instruction.system_program = Some(system_program);
                     self
     }
-                    #[inline(always)]
-      pub fn beneficiary_index(&mut self, beneficiary_index: u8) -> &mut Self {
-        self.instruction.beneficiary_index = Some(beneficiary_index);
-        self
-      }
-        /// Add an additional account to the instruction.
+            /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: &'b solana_account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
     self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
@@ -508,9 +559,6 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
   #[allow(clippy::clone_on_copy)]
   #[allow(clippy::vec_init_then_push)]
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
-          let args = ClaimBeneficiaryRewardsHandlerInstructionArgs {
-                                                              beneficiary_index: self.instruction.beneficiary_index.clone().expect("beneficiary_index is not set"),
-                                    };
         let instruction = ClaimBeneficiaryRewardsHandlerCpi {
         __program: self.instruction.__program,
                   
@@ -518,6 +566,10 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
                   
           vault: self.instruction.vault.expect("vault is not set"),
                   
+          config: self.instruction.config.expect("config is not set"),
+                  
+          beneficiary_account: self.instruction.beneficiary_account.expect("beneficiary_account is not set"),
+                  
           reward_mint: self.instruction.reward_mint.expect("reward_mint is not set"),
                   
           vault_ata: self.instruction.vault_ata.expect("vault_ata is not set"),
@@ -529,8 +581,7 @@ impl<'a, 'b> ClaimBeneficiaryRewardsHandlerCpiBuilder<'a, 'b> {
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
-                          __args: args,
-            };
+                    };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
 }
@@ -540,14 +591,15 @@ struct ClaimBeneficiaryRewardsHandlerCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             beneficiary: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+                config: Option<&'b solana_account_info::AccountInfo<'a>>,
+                beneficiary_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 reward_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 beneficiary_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-                        beneficiary_index: Option<u8>,
-        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+                /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
 
@@ -10,6 +10,7 @@ use borsh::BorshDeserialize;
 
 pub const CLAIM_INVESTOR_REWARDS_HANDLER_DISCRIMINATOR: [u8; 8] = [6, 82, 210, 8, 150, 27, 95, 90];
 
+/// Claims the named reward stream plus every stream passed in remaining accounts
 /// Accounts.
 #[derive(Debug)]
 pub struct ClaimInvestorRewardsHandler {
@@ -38,7 +39,7 @@ pub struct ClaimInvestorRewardsHandler {
     
               
           pub asset: solana_pubkey::Pubkey,
-                /// The reward token mint
+                /// Mint of the reward stream to claim, further streams go in remaining accounts
 
     
               
@@ -80,7 +81,7 @@ impl ClaimInvestorRewardsHandler {
             self.config,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.vault,
             false
           ));
@@ -159,7 +160,7 @@ impl Default for ClaimInvestorRewardsHandlerInstructionData {
 ///
                 ///   0. `[signer]` holder
           ///   1. `[]` config
-          ///   2. `[]` vault
+                ///   2. `[writable]` vault
                 ///   3. `[writable]` position
           ///   4. `[]` asset
           ///   5. `[]` reward_mint
@@ -218,7 +219,7 @@ impl ClaimInvestorRewardsHandlerBuilder {
                         self.asset = Some(asset);
                     self
     }
-            /// The reward token mint
+            /// Mint of the reward stream to claim, further streams go in remaining accounts
 #[inline(always)]
     pub fn reward_mint(&mut self, reward_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.reward_mint = Some(reward_mint);
@@ -313,7 +314,7 @@ impl ClaimInvestorRewardsHandlerBuilder {
       
                     
               pub asset: &'b solana_account_info::AccountInfo<'a>,
-                        /// The reward token mint
+                        /// Mint of the reward stream to claim, further streams go in remaining accounts
 
       
                     
@@ -368,7 +369,7 @@ pub struct ClaimInvestorRewardsHandlerCpi<'a, 'b> {
     
               
           pub asset: &'b solana_account_info::AccountInfo<'a>,
-                /// The reward token mint
+                /// Mint of the reward stream to claim, further streams go in remaining accounts
 
     
               
@@ -443,7 +444,7 @@ impl<'a, 'b> ClaimInvestorRewardsHandlerCpi<'a, 'b> {
             *self.config.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.vault.key,
             false
           ));
@@ -522,7 +523,7 @@ impl<'a, 'b> ClaimInvestorRewardsHandlerCpi<'a, 'b> {
 ///
                 ///   0. `[signer]` holder
           ///   1. `[]` config
-          ///   2. `[]` vault
+                ///   2. `[writable]` vault
                 ///   3. `[writable]` position
           ///   4. `[]` asset
           ///   5. `[]` reward_mint
@@ -585,7 +586,7 @@ impl<'a, 'b> ClaimInvestorRewardsHandlerCpiBuilder<'a, 'b> {
                         self.instruction.asset = Some(asset);
                     self
     }
-      /// The reward token mint
+      /// Mint of the reward stream to claim, further streams go in remaining accounts
 #[inline(always)]
     pub fn reward_mint(&mut self, reward_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.reward_mint = Some(reward_mint);
@@ -28,7 +28,7 @@ pub struct ClosePositionHandler {
     
               
           pub config: solana_pubkey::Pubkey,
-                /// The position being updated
+                /// The position being closed
 
     
               
@@ -52,6 +52,14 @@ pub struct ClosePositionHandler {
           
               
           pub capital_provider_lock_ata: solana_pubkey::Pubkey,
+                /// Insurance fund of the locking token, pass it to claim coverage of slashed principal
+
+    
+              
+          pub insurance_fund: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub insurance_fund_ata: Option<solana_pubkey::Pubkey>,
           
               
           pub token_program: solana_pubkey::Pubkey,
@@ -76,7 +84,7 @@ impl ClosePositionHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(14+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(16+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.position_holder,
             true
@@ -113,6 +121,28 @@ impl ClosePositionHandler {
             self.capital_provider_lock_ata,
             false
           ));
+                              if let Some(insurance_fund) = self.insurance_fund {
+              accounts.push(solana_instruction::AccountMeta::new(
+                insurance_fund,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                insurance_fund_ata,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false
@@ -183,11 +213,13 @@ impl Default for ClosePositionHandlerInstructionData {
           ///   6. `[]` lock_mint
                 ///   7. `[writable]` vault_lock_ata
                 ///   8. `[writable]` capital_provider_lock_ata
-                ///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   10. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   11. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
-                ///   12. `[optional]` nft_program (default to `AkFAoXys2zhqE15q8XJJJRqXgxLdtJ1kb9ec4fCo1GgH`)
-                ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                      ///   9. `[writable, optional]` insurance_fund
+                      ///   10. `[writable, optional]` insurance_fund_ata
+                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   12. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   13. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+                ///   14. `[optional]` nft_program (default to `AkFAoXys2zhqE15q8XJJJRqXgxLdtJ1kb9ec4fCo1GgH`)
+                ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct ClosePositionHandlerBuilder {
             position_holder: Option<solana_pubkey::Pubkey>,
@@ -199,6 +231,8 @@ pub struct ClosePositionHandlerBuilder {
                 lock_mint: Option<solana_pubkey::Pubkey>,
                 vault_lock_ata: Option<solana_pubkey::Pubkey>,
                 capital_provider_lock_ata: Option<solana_pubkey::Pubkey>,
+                insurance_fund: Option<solana_pubkey::Pubkey>,
+                insurance_fund_ata: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 mpl_core_program: Option<solana_pubkey::Pubkey>,
@@ -229,7 +263,7 @@ impl ClosePositionHandlerBuilder {
                         self.config = Some(config);
                     self
     }
-            /// The position being updated
+            /// The position being closed
 #[inline(always)]
     pub fn position(&mut self, position: solana_pubkey::Pubkey) -> &mut Self {
                         self.position = Some(position);
@@ -261,6 +295,19 @@ impl ClosePositionHandlerBuilder {
     pub fn capital_provider_lock_ata(&mut self, capital_provider_lock_ata: solana_pubkey::Pubkey) -> &mut Self {
                         self.capital_provider_lock_ata = Some(capital_provider_lock_ata);
                     self
+    }
+            /// `[optional account]`
+/// Insurance fund of the locking token, pass it to claim coverage of slashed principal
+#[inline(always)]
+    pub fn insurance_fund(&mut self, insurance_fund: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.insurance_fund = insurance_fund;
+                    self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn insurance_fund_ata(&mut self, insurance_fund_ata: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.insurance_fund_ata = insurance_fund_ata;
+                    self
     }
             /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
 #[inline(always)]
@@ -316,6 +363,8 @@ impl ClosePositionHandlerBuilder {
                                         lock_mint: self.lock_mint.expect("lock_mint is not set"),
                                         vault_lock_ata: self.vault_lock_ata.expect("vault_lock_ata is not set"),
                                         capital_provider_lock_ata: self.capital_provider_lock_ata.expect("capital_provider_lock_ata is not set"),
+                                        insurance_fund: self.insurance_fund,
+                                        insurance_fund_ata: self.insurance_fund_ata,
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         mpl_core_program: self.mpl_core_program.unwrap_or(solana_pubkey::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d")),
@@ -344,7 +393,7 @@ impl ClosePositionHandlerBuilder {
       
                     
               pub config: &'b solana_account_info::AccountInfo<'a>,
-                        /// The position being updated
+                        /// The position being closed
 
       
                     
@@ -368,6 +417,14 @@ impl ClosePositionHandlerBuilder {
                 
                     
               pub capital_provider_lock_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// Insurance fund of the locking token, pass it to claim coverage of slashed principal
+
+      
+                    
+              pub insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -404,7 +461,7 @@ pub struct ClosePositionHandlerCpi<'a, 'b> {
     
               
           pub config: &'b solana_account_info::AccountInfo<'a>,
-                /// The position being updated
+                /// The position being closed
 
     
               
@@ -428,6 +485,14 @@ pub struct ClosePositionHandlerCpi<'a, 'b> {
           
               
           pub capital_provider_lock_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// Insurance fund of the locking token, pass it to claim coverage of slashed principal
+
+    
+              
+          pub insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -461,6 +526,8 @@ impl<'a, 'b> ClosePositionHandlerCpi<'a, 'b> {
               lock_mint: accounts.lock_mint,
               vault_lock_ata: accounts.vault_lock_ata,
               capital_provider_lock_ata: accounts.capital_provider_lock_ata,
+              insurance_fund: accounts.insurance_fund,
+              insurance_fund_ata: accounts.insurance_fund_ata,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               mpl_core_program: accounts.mpl_core_program,
@@ -488,7 +555,7 @@ impl<'a, 'b> ClosePositionHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(14+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(16+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.position_holder.key,
             true
@@ -525,6 +592,28 @@ impl<'a, 'b> ClosePositionHandlerCpi<'a, 'b> {
             *self.capital_provider_lock_ata.key,
             false
           ));
+                              if let Some(insurance_fund) = self.insurance_fund {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *insurance_fund.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *insurance_fund_ata.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false
@@ -559,7 +648,7 @@ impl<'a, 'b> ClosePositionHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(17 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.position_holder.clone());
                         account_infos.push(self.vault.clone());
@@ -570,6 +659,12 @@ impl<'a, 'b> ClosePositionHandlerCpi<'a, 'b> {
                         account_infos.push(self.lock_mint.clone());
                         account_infos.push(self.vault_lock_ata.clone());
                         account_infos.push(self.capital_provider_lock_ata.clone());
+                  if let Some(insurance_fund) = self.insurance_fund {
+          account_infos.push(insurance_fund.clone());
+        }
+                  if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+          account_infos.push(insurance_fund_ata.clone());
+        }
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.mpl_core_program.clone());
@@ -598,11 +693,13 @@ impl<'a, 'b> ClosePositionHandlerCpi<'a, 'b> {
           ///   6. `[]` lock_mint
                 ///   7. `[writable]` vault_lock_ata
                 ///   8. `[writable]` capital_provider_lock_ata
-          ///   9. `[]` token_program
-          ///   10. `[]` associated_token_program
-          ///   11. `[]` mpl_core_program
-          ///   12. `[]` nft_program
-          ///   13. `[]` system_program
+                      ///   9. `[writable, optional]` insurance_fund
+                      ///   10. `[writable, optional]` insurance_fund_ata
+          ///   11. `[]` token_program
+          ///   12. `[]` associated_token_program
+          ///   13. `[]` mpl_core_program
+          ///   14. `[]` nft_program
+          ///   15. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct ClosePositionHandlerCpiBuilder<'a, 'b> {
   instruction: Box<ClosePositionHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -621,6 +718,8 @@ impl<'a, 'b> ClosePositionHandlerCpiBuilder<'a, 'b> {
               lock_mint: None,
               vault_lock_ata: None,
               capital_provider_lock_ata: None,
+              insurance_fund: None,
+              insurance_fund_ata: None,
               token_program: None,
               associated_token_program: None,
               mpl_core_program: None,
@@ -648,7 +747,7 @@ impl<'a, 'b> ClosePositionHandlerCpiBuilder<'a, 'b> {
                         self.instruction.config = Some(config);
                     self
     }
-      /// The position being updated
+      /// The position being closed
 #[inline(always)]
     pub fn position(&mut self, position: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.position = Some(position);
@@ -680,6 +779,19 @@ impl<'a, 'b> ClosePositionHandlerCpiBuilder<'a, 'b> {
     pub fn capital_provider_lock_ata(&mut self, capital_provider_lock_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.capital_provider_lock_ata = Some(capital_provider_lock_ata);
                     self
+    }
+      /// Insurance fund of the locking token, pass it to claim coverage of slashed principal
+/// `[optional account]`
+#[inline(always)]
+    pub fn insurance_fund(&mut self, insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.insurance_fund = insurance_fund;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn insurance_fund_ata(&mut self, insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.insurance_fund_ata = insurance_fund_ata;
+                    self
     }
       #[inline(always)]
     pub fn token_program(&mut self, token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
@@ -749,6 +861,10 @@ impl<'a, 'b> ClosePositionHandlerCpiBuilder<'a, 'b> {
                   
           capital_provider_lock_ata: self.instruction.capital_provider_lock_ata.expect("capital_provider_lock_ata is not set"),
                   
+          insurance_fund: self.instruction.insurance_fund,
+                  
+          insurance_fund_ata: self.instruction.insurance_fund_ata,
+                  
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
@@ -775,6 +891,8 @@ struct ClosePositionHandlerCpiBuilderInstruction<'a, 'b> {
                 lock_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault_lock_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 capital_provider_lock_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 mpl_core_program: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -28,6 +28,11 @@ pub struct CloseVaultHandler {
           
               
           pub vault_lock_ata: solana_pubkey::Pubkey,
+                /// Receives what is left of the operator bond
+
+    
+              
+          pub node_operator_lock_ata: solana_pubkey::Pubkey,
           
               
           pub staking_token_mint: solana_pubkey::Pubkey,
@@ -52,7 +57,7 @@ impl CloseVaultHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.node_operator,
             true
@@ -72,6 +77,10 @@ impl CloseVaultHandler {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.vault_lock_ata,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.node_operator_lock_ata,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.staking_token_mint,
@@ -139,11 +148,12 @@ impl Default for CloseVaultHandlerInstructionData {
                 ///   2. `[writable]` vault
                 ///   3. `[writable]` vault_reward_ata
                 ///   4. `[writable]` vault_lock_ata
-          ///   5. `[]` staking_token_mint
-          ///   6. `[]` reward_token_mint
-                ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   5. `[writable]` node_operator_lock_ata
+          ///   6. `[]` staking_token_mint
+          ///   7. `[]` reward_token_mint
+                ///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   9. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CloseVaultHandlerBuilder {
             node_operator: Option<solana_pubkey::Pubkey>,
@@ -151,6 +161,7 @@ pub struct CloseVaultHandlerBuilder {
                 vault: Option<solana_pubkey::Pubkey>,
                 vault_reward_ata: Option<solana_pubkey::Pubkey>,
                 vault_lock_ata: Option<solana_pubkey::Pubkey>,
+                node_operator_lock_ata: Option<solana_pubkey::Pubkey>,
                 staking_token_mint: Option<solana_pubkey::Pubkey>,
                 reward_token_mint: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
@@ -187,6 +198,12 @@ impl CloseVaultHandlerBuilder {
     pub fn vault_lock_ata(&mut self, vault_lock_ata: solana_pubkey::Pubkey) -> &mut Self {
                         self.vault_lock_ata = Some(vault_lock_ata);
                     self
+    }
+            /// Receives what is left of the operator bond
+#[inline(always)]
+    pub fn node_operator_lock_ata(&mut self, node_operator_lock_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.node_operator_lock_ata = Some(node_operator_lock_ata);
+                    self
     }
             #[inline(always)]
     pub fn staking_token_mint(&mut self, staking_token_mint: solana_pubkey::Pubkey) -> &mut Self {
@@ -236,6 +253,7 @@ impl CloseVaultHandlerBuilder {
                                         vault: self.vault.expect("vault is not set"),
                                         vault_reward_ata: self.vault_reward_ata.expect("vault_reward_ata is not set"),
                                         vault_lock_ata: self.vault_lock_ata.expect("vault_lock_ata is not set"),
+                                        node_operator_lock_ata: self.node_operator_lock_ata.expect("node_operator_lock_ata is not set"),
                                         staking_token_mint: self.staking_token_mint.expect("staking_token_mint is not set"),
                                         reward_token_mint: self.reward_token_mint.expect("reward_token_mint is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
@@ -264,6 +282,11 @@ impl CloseVaultHandlerBuilder {
                 
                     
               pub vault_lock_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// Receives what is left of the operator bond
+
+      
+                    
+              pub node_operator_lock_ata: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub staking_token_mint: &'b solana_account_info::AccountInfo<'a>,
@@ -300,6 +323,11 @@ pub struct CloseVaultHandlerCpi<'a, 'b> {
           
               
           pub vault_lock_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// Receives what is left of the operator bond
+
+    
+              
+          pub node_operator_lock_ata: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub staking_token_mint: &'b solana_account_info::AccountInfo<'a>,
@@ -329,6 +357,7 @@ impl<'a, 'b> CloseVaultHandlerCpi<'a, 'b> {
               vault: accounts.vault,
               vault_reward_ata: accounts.vault_reward_ata,
               vault_lock_ata: accounts.vault_lock_ata,
+              node_operator_lock_ata: accounts.node_operator_lock_ata,
               staking_token_mint: accounts.staking_token_mint,
               reward_token_mint: accounts.reward_token_mint,
               token_program: accounts.token_program,
@@ -356,7 +385,7 @@ impl<'a, 'b> CloseVaultHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(10+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.node_operator.key,
             true
@@ -376,6 +405,10 @@ impl<'a, 'b> CloseVaultHandlerCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.vault_lock_ata.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.node_operator_lock_ata.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.staking_token_mint.key,
@@ -411,13 +444,14 @@ impl<'a, 'b> CloseVaultHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.node_operator.clone());
                         account_infos.push(self.nft.clone());
                         account_infos.push(self.vault.clone());
                         account_infos.push(self.vault_reward_ata.clone());
                         account_infos.push(self.vault_lock_ata.clone());
+                        account_infos.push(self.node_operator_lock_ata.clone());
                         account_infos.push(self.staking_token_mint.clone());
                         account_infos.push(self.reward_token_mint.clone());
                         account_infos.push(self.token_program.clone());
@@ -442,11 +476,12 @@ impl<'a, 'b> CloseVaultHandlerCpi<'a, 'b> {
                 ///   2. `[writable]` vault
                 ///   3. `[writable]` vault_reward_ata
                 ///   4. `[writable]` vault_lock_ata
-          ///   5. `[]` staking_token_mint
-          ///   6. `[]` reward_token_mint
-          ///   7. `[]` token_program
-          ///   8. `[]` associated_token_program
-          ///   9. `[]` system_program
+                ///   5. `[writable]` node_operator_lock_ata
+          ///   6. `[]` staking_token_mint
+          ///   7. `[]` reward_token_mint
+          ///   8. `[]` token_program
+          ///   9. `[]` associated_token_program
+          ///   10. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct CloseVaultHandlerCpiBuilder<'a, 'b> {
   instruction: Box<CloseVaultHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -461,6 +496,7 @@ impl<'a, 'b> CloseVaultHandlerCpiBuilder<'a, 'b> {
               vault: None,
               vault_reward_ata: None,
               vault_lock_ata: None,
+              node_operator_lock_ata: None,
               staking_token_mint: None,
               reward_token_mint: None,
               token_program: None,
@@ -494,6 +530,12 @@ impl<'a, 'b> CloseVaultHandlerCpiBuilder<'a, 'b> {
     pub fn vault_lock_ata(&mut self, vault_lock_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.vault_lock_ata = Some(vault_lock_ata);
                     self
+    }
+      /// Receives what is left of the operator bond
+#[inline(always)]
+    pub fn node_operator_lock_ata(&mut self, node_operator_lock_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.node_operator_lock_ata = Some(node_operator_lock_ata);
+                    self
     }
       #[inline(always)]
     pub fn staking_token_mint(&mut self, staking_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
@@ -555,6 +597,8 @@ impl<'a, 'b> CloseVaultHandlerCpiBuilder<'a, 'b> {
                   
           vault_lock_ata: self.instruction.vault_lock_ata.expect("vault_lock_ata is not set"),
                   
+          node_operator_lock_ata: self.instruction.node_operator_lock_ata.expect("node_operator_lock_ata is not set"),
+                  
           staking_token_mint: self.instruction.staking_token_mint.expect("staking_token_mint is not set"),
                   
           reward_token_mint: self.instruction.reward_token_mint.expect("reward_token_mint is not set"),
@@ -577,6 +621,7 @@ struct CloseVaultHandlerCpiBuilderInstruction<'a, 'b> {
                 vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault_lock_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                node_operator_lock_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 staking_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 reward_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -5,7 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
-use solana_pubkey::Pubkey;
+use crate::generated::types::SlashShare;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -14,8 +14,8 @@ pub const CREATE_SLAS_REQ_HANDLER_DISCRIMINATOR: [u8; 8] = [105, 0, 66, 75, 41,
 /// Accounts.
 #[derive(Debug)]
 pub struct CreateSlasReqHandler {
-            /// and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-/// STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+            /// and can be anyone, either one of benificiaries or some DePIN network outside solana.
+/// A failing attestation can back the request instead of the agent's word alone
 
     
               
@@ -23,9 +23,22 @@ pub struct CreateSlasReqHandler {
           
               
           pub vault: solana_pubkey::Pubkey,
+                /// The new request, numbered by the vault's slash request count
+
+    
+              
+          pub slash_request: solana_pubkey::Pubkey,
+                /// Optional failing attestation of the vault backing the request
+
+    
+              
+          pub attestation: Option<solana_pubkey::Pubkey>,
           
               
           pub config: solana_pubkey::Pubkey,
+          
+              
+          pub system_program: solana_pubkey::Pubkey,
       }
 
 impl CreateSlasReqHandler {
@@ -35,7 +48,7 @@ impl CreateSlasReqHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: CreateSlasReqHandlerInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(3+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.agent,
             true
@@ -44,9 +57,28 @@ impl CreateSlasReqHandler {
             self.vault,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.slash_request,
+            false
+          ));
+                              if let Some(attestation) = self.attestation {
+              accounts.push(solana_instruction::AccountMeta::new(
+                attestation,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.config,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = CreateSlasReqHandlerInstructionData::new().try_to_vec().unwrap();
@@ -65,13 +97,13 @@ impl CreateSlasReqHandler {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct CreateSlasReqHandlerInstructionData {
             discriminator: [u8; 8],
-                  }
+                                          }
 
 impl CreateSlasReqHandlerInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [105, 0, 66, 75, 41, 216, 177, 167],
-                                              }
+                                                                                                      }
   }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -89,7 +121,11 @@ impl Default for CreateSlasReqHandlerInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct CreateSlasReqHandlerInstructionArgs {
                   pub slash_bps: u16,
-                pub slash_claimant: Pubkey,
+                pub claimants: Vec<SlashShare>,
+                pub insurance_bps: u16,
+                pub burn_bps: u16,
+                pub evidence_hash: [u8; 32],
+                pub evidence_uri: String,
       }
 
 impl CreateSlasReqHandlerInstructionArgs {
@@ -105,14 +141,24 @@ impl CreateSlasReqHandlerInstructionArgs {
 ///
                       ///   0. `[writable, signer]` agent
                 ///   1. `[writable]` vault
-          ///   2. `[]` config
+                ///   2. `[writable]` slash_request
+                      ///   3. `[writable, optional]` attestation
+          ///   4. `[]` config
+                ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateSlasReqHandlerBuilder {
             agent: Option<solana_pubkey::Pubkey>,
                 vault: Option<solana_pubkey::Pubkey>,
+                slash_request: Option<solana_pubkey::Pubkey>,
+                attestation: Option<solana_pubkey::Pubkey>,
                 config: Option<solana_pubkey::Pubkey>,
+                system_program: Option<solana_pubkey::Pubkey>,
                         slash_bps: Option<u16>,
-                slash_claimant: Option<Pubkey>,
+                claimants: Option<Vec<SlashShare>>,
+                insurance_bps: Option<u16>,
+                burn_bps: Option<u16>,
+                evidence_hash: Option<[u8; 32]>,
+                evidence_uri: Option<String>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -120,8 +166,8 @@ impl CreateSlasReqHandlerBuilder {
   pub fn new() -> Self {
     Self::default()
   }
-            /// and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-/// STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+            /// and can be anyone, either one of benificiaries or some DePIN network outside solana.
+/// A failing attestation can back the request instead of the agent's word alone
 #[inline(always)]
     pub fn agent(&mut self, agent: solana_pubkey::Pubkey) -> &mut Self {
                         self.agent = Some(agent);
@@ -131,11 +177,30 @@ impl CreateSlasReqHandlerBuilder {
     pub fn vault(&mut self, vault: solana_pubkey::Pubkey) -> &mut Self {
                         self.vault = Some(vault);
                     self
+    }
+            /// The new request, numbered by the vault's slash request count
+#[inline(always)]
+    pub fn slash_request(&mut self, slash_request: solana_pubkey::Pubkey) -> &mut Self {
+                        self.slash_request = Some(slash_request);
+                    self
+    }
+            /// `[optional account]`
+/// Optional failing attestation of the vault backing the request
+#[inline(always)]
+    pub fn attestation(&mut self, attestation: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.attestation = attestation;
+                    self
     }
             #[inline(always)]
     pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
                         self.config = Some(config);
                     self
+    }
+            /// `[optional account, default to '11111111111111111111111111111111']`
+#[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
+                        self.system_program = Some(system_program);
+                    self
     }
                     #[inline(always)]
       pub fn slash_bps(&mut self, slash_bps: u16) -> &mut Self {
@@ -143,8 +208,28 @@ impl CreateSlasReqHandlerBuilder {
         self
       }
                 #[inline(always)]
-      pub fn slash_claimant(&mut self, slash_claimant: Pubkey) -> &mut Self {
-        self.slash_claimant = Some(slash_claimant);
+      pub fn claimants(&mut self, claimants: Vec<SlashShare>) -> &mut Self {
+        self.claimants = Some(claimants);
+        self
+      }
+                #[inline(always)]
+      pub fn insurance_bps(&mut self, insurance_bps: u16) -> &mut Self {
+        self.insurance_bps = Some(insurance_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn burn_bps(&mut self, burn_bps: u16) -> &mut Self {
+        self.burn_bps = Some(burn_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn evidence_hash(&mut self, evidence_hash: [u8; 32]) -> &mut Self {
+        self.evidence_hash = Some(evidence_hash);
+        self
+      }
+                #[inline(always)]
+      pub fn evidence_uri(&mut self, evidence_uri: String) -> &mut Self {
+        self.evidence_uri = Some(evidence_uri);
         self
       }
         /// Add an additional account to the instruction.
@@ -164,11 +249,18 @@ impl CreateSlasReqHandlerBuilder {
     let accounts = CreateSlasReqHandler {
                               agent: self.agent.expect("agent is not set"),
                                         vault: self.vault.expect("vault is not set"),
+                                        slash_request: self.slash_request.expect("slash_request is not set"),
+                                        attestation: self.attestation,
                                         config: self.config.expect("config is not set"),
+                                        system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                       };
           let args = CreateSlasReqHandlerInstructionArgs {
                                                               slash_bps: self.slash_bps.clone().expect("slash_bps is not set"),
-                                                                  slash_claimant: self.slash_claimant.clone().expect("slash_claimant is not set"),
+                                                                  claimants: self.claimants.clone().expect("claimants is not set"),
+                                                                  insurance_bps: self.insurance_bps.clone().expect("insurance_bps is not set"),
+                                                                  burn_bps: self.burn_bps.clone().expect("burn_bps is not set"),
+                                                                  evidence_hash: self.evidence_hash.clone().expect("evidence_hash is not set"),
+                                                                  evidence_uri: self.evidence_uri.clone().expect("evidence_uri is not set"),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -177,8 +269,8 @@ impl CreateSlasReqHandlerBuilder {
 
   /// `create_slas_req_handler` CPI accounts.
   pub struct CreateSlasReqHandlerCpiAccounts<'a, 'b> {
-                  /// and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-/// STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+                  /// and can be anyone, either one of benificiaries or some DePIN network outside solana.
+/// A failing attestation can back the request instead of the agent's word alone
 
       
                     
@@ -186,17 +278,30 @@ impl CreateSlasReqHandlerBuilder {
                 
                     
               pub vault: &'b solana_account_info::AccountInfo<'a>,
+                        /// The new request, numbered by the vault's slash request count
+
+      
+                    
+              pub slash_request: &'b solana_account_info::AccountInfo<'a>,
+                        /// Optional failing attestation of the vault backing the request
+
+      
+                    
+              pub attestation: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub config: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub system_program: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `create_slas_req_handler` CPI instruction.
 pub struct CreateSlasReqHandlerCpi<'a, 'b> {
   /// The program to invoke.
   pub __program: &'b solana_account_info::AccountInfo<'a>,
-            /// and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-/// STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+            /// and can be anyone, either one of benificiaries or some DePIN network outside solana.
+/// A failing attestation can back the request instead of the agent's word alone
 
     
               
@@ -204,9 +309,22 @@ pub struct CreateSlasReqHandlerCpi<'a, 'b> {
           
               
           pub vault: &'b solana_account_info::AccountInfo<'a>,
+                /// The new request, numbered by the vault's slash request count
+
+    
+              
+          pub slash_request: &'b solana_account_info::AccountInfo<'a>,
+                /// Optional failing attestation of the vault backing the request
+
+    
+              
+          pub attestation: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub config: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub system_program: &'b solana_account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: CreateSlasReqHandlerInstructionArgs,
   }
@@ -221,7 +339,10 @@ impl<'a, 'b> CreateSlasReqHandlerCpi<'a, 'b> {
       __program: program,
               agent: accounts.agent,
               vault: accounts.vault,
+              slash_request: accounts.slash_request,
+              attestation: accounts.attestation,
               config: accounts.config,
+              system_program: accounts.system_program,
                     __args: args,
           }
   }
@@ -245,7 +366,7 @@ impl<'a, 'b> CreateSlasReqHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(3+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.agent.key,
             true
@@ -254,9 +375,28 @@ impl<'a, 'b> CreateSlasReqHandlerCpi<'a, 'b> {
             *self.vault.key,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.slash_request.key,
+            false
+          ));
+                              if let Some(attestation) = self.attestation {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *attestation.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.config.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -274,11 +414,16 @@ impl<'a, 'b> CreateSlasReqHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.agent.clone());
                         account_infos.push(self.vault.clone());
+                        account_infos.push(self.slash_request.clone());
+                  if let Some(attestation) = self.attestation {
+          account_infos.push(attestation.clone());
+        }
                         account_infos.push(self.config.clone());
+                        account_infos.push(self.system_program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -295,7 +440,10 @@ impl<'a, 'b> CreateSlasReqHandlerCpi<'a, 'b> {
 ///
                       ///   0. `[writable, signer]` agent
                 ///   1. `[writable]` vault
-          ///   2. `[]` config
+                ///   2. `[writable]` slash_request
+                      ///   3. `[writable, optional]` attestation
+          ///   4. `[]` config
+          ///   5. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct CreateSlasReqHandlerCpiBuilder<'a, 'b> {
   instruction: Box<CreateSlasReqHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -307,15 +455,22 @@ impl<'a, 'b> CreateSlasReqHandlerCpiBuilder<'a, 'b> {
       __program: program,
               agent: None,
               vault: None,
+              slash_request: None,
+              attestation: None,
               config: None,
+              system_program: None,
                                             slash_bps: None,
-                                slash_claimant: None,
+                                claimants: None,
+                                insurance_bps: None,
+                                burn_bps: None,
+                                evidence_hash: None,
+                                evidence_uri: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
-      /// and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-/// STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+      /// and can be anyone, either one of benificiaries or some DePIN network outside solana.
+/// A failing attestation can back the request instead of the agent's word alone
 #[inline(always)]
     pub fn agent(&mut self, agent: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.agent = Some(agent);
@@ -325,11 +480,29 @@ impl<'a, 'b> CreateSlasReqHandlerCpiBuilder<'a, 'b> {
     pub fn vault(&mut self, vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.vault = Some(vault);
                     self
+    }
+      /// The new request, numbered by the vault's slash request count
+#[inline(always)]
+    pub fn slash_request(&mut self, slash_request: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.slash_request = Some(slash_request);
+                    self
+    }
+      /// Optional failing attestation of the vault backing the request
+/// `[optional account]`
+#[inline(always)]
+    pub fn attestation(&mut self, attestation: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.attestation = attestation;
+                    self
     }
       #[inline(always)]
     pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.config = Some(config);
                     self
+    }
+      #[inline(always)]
+    pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.system_program = Some(system_program);
+                    self
     }
                     #[inline(always)]
       pub fn slash_bps(&mut self, slash_bps: u16) -> &mut Self {
@@ -337,8 +510,28 @@ impl<'a, 'b> CreateSlasReqHandlerCpiBuilder<'a, 'b> {
         self
       }
                 #[inline(always)]
-      pub fn slash_claimant(&mut self, slash_claimant: Pubkey) -> &mut Self {
-        self.instruction.slash_claimant = Some(slash_claimant);
+      pub fn claimants(&mut self, claimants: Vec<SlashShare>) -> &mut Self {
+        self.instruction.claimants = Some(claimants);
+        self
+      }
+                #[inline(always)]
+      pub fn insurance_bps(&mut self, insurance_bps: u16) -> &mut Self {
+        self.instruction.insurance_bps = Some(insurance_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn burn_bps(&mut self, burn_bps: u16) -> &mut Self {
+        self.instruction.burn_bps = Some(burn_bps);
+        self
+      }
+                #[inline(always)]
+      pub fn evidence_hash(&mut self, evidence_hash: [u8; 32]) -> &mut Self {
+        self.instruction.evidence_hash = Some(evidence_hash);
+        self
+      }
+                #[inline(always)]
+      pub fn evidence_uri(&mut self, evidence_uri: String) -> &mut Self {
+        self.instruction.evidence_uri = Some(evidence_uri);
         self
       }
         /// Add an additional account to the instruction.
@@ -365,7 +558,11 @@ impl<'a, 'b> CreateSlasReqHandlerCpiBuilder<'a, 'b> {
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
           let args = CreateSlasReqHandlerInstructionArgs {
                                                               slash_bps: self.instruction.slash_bps.clone().expect("slash_bps is not set"),
-                                                                  slash_claimant: self.instruction.slash_claimant.clone().expect("slash_claimant is not set"),
+                                                                  claimants: self.instruction.claimants.clone().expect("claimants is not set"),
+                                                                  insurance_bps: self.instruction.insurance_bps.clone().expect("insurance_bps is not set"),
+                                                                  burn_bps: self.instruction.burn_bps.clone().expect("burn_bps is not set"),
+                                                                  evidence_hash: self.instruction.evidence_hash.clone().expect("evidence_hash is not set"),
+                                                                  evidence_uri: self.instruction.evidence_uri.clone().expect("evidence_uri is not set"),
                                     };
         let instruction = CreateSlasReqHandlerCpi {
         __program: self.instruction.__program,
@@ -374,7 +571,13 @@ impl<'a, 'b> CreateSlasReqHandlerCpiBuilder<'a, 'b> {
                   
           vault: self.instruction.vault.expect("vault is not set"),
                   
+          slash_request: self.instruction.slash_request.expect("slash_request is not set"),
+                  
+          attestation: self.instruction.attestation,
+                  
           config: self.instruction.config.expect("config is not set"),
+                  
+          system_program: self.instruction.system_program.expect("system_program is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -386,9 +589,16 @@ struct CreateSlasReqHandlerCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             agent: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+                slash_request: Option<&'b solana_account_info::AccountInfo<'a>>,
+                attestation: Option<&'b solana_account_info::AccountInfo<'a>>,
                 config: Option<&'b solana_account_info::AccountInfo<'a>>,
+                system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         slash_bps: Option<u16>,
-                slash_claimant: Option<Pubkey>,
+                claimants: Option<Vec<SlashShare>>,
+                insurance_bps: Option<u16>,
+                burn_bps: Option<u16>,
+                evidence_hash: Option<[u8; 32]>,
+                evidence_uri: Option<String>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,7 +5,6 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
-use crate::generated::types::Beneficiary;
 use solana_pubkey::Pubkey;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
@@ -20,6 +19,11 @@ pub struct CreateVaultHandler {
     
               
           pub provider: solana_pubkey::Pubkey,
+                /// Per-operator vault counter, created with the operator's first vault
+
+    
+              
+          pub operator_registry: solana_pubkey::Pubkey,
                 /// The vault account to be created
 
     
@@ -35,7 +39,7 @@ pub struct CreateVaultHandler {
     
               
           pub nft_config: solana_pubkey::Pubkey,
-                /// Reward token mint - tokens distributed as rewards
+                /// Reward token mint - first reward stream of the vault
 
     
               
@@ -45,6 +49,16 @@ pub struct CreateVaultHandler {
     
               
           pub lock_mint: solana_pubkey::Pubkey,
+                /// Vault's locking token account, holds the operator bond and investor capital
+
+    
+              
+          pub vault_lock_ata: solana_pubkey::Pubkey,
+                /// Provider's locking token account funding the operator bond
+
+    
+              
+          pub provider_lock_ata: solana_pubkey::Pubkey,
                 /// NFT collection for vault positions
 
     
@@ -74,10 +88,14 @@ impl CreateVaultHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: CreateVaultHandlerInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.provider,
             true
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.operator_registry,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.vault,
@@ -98,6 +116,14 @@ impl CreateVaultHandler {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.lock_mint,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.vault_lock_ata,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.provider_lock_ata,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.nft_collection,
@@ -166,10 +192,10 @@ impl Default for CreateVaultHandlerInstructionData {
                   pub min_cap: u64,
                 pub max_cap: u64,
                 pub min_lock_amount: u64,
-                pub beneficiaries: Vec<Beneficiary>,
+                pub operator_bond: u64,
                 pub investor_bps: u16,
+                pub operator_commission_bps: u16,
                 pub max_slash_bps: u16,
-                pub slash_claimant: Pubkey,
                 pub reward_distributor: Pubkey,
                 pub node_operator: Pubkey,
                 pub lock_phase_duration: i64,
@@ -188,25 +214,31 @@ impl CreateVaultHandlerInstructionArgs {
 /// ### Accounts:
 ///
                       ///   0. `[writable, signer]` provider
-                ///   1. `[writable]` vault
-          ///   2. `[]` config_account
-          ///   3. `[]` nft_config
-          ///   4. `[]` reward_token_mint
-          ///   5. `[]` lock_mint
-                      ///   6. `[writable, signer]` nft_collection
-                ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   9. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
-                ///   10. `[optional]` nft_marketplace (default to `AkFAoXys2zhqE15q8XJJJRqXgxLdtJ1kb9ec4fCo1GgH`)
-                ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   1. `[writable]` operator_registry
+                ///   2. `[writable]` vault
+          ///   3. `[]` config_account
+          ///   4. `[]` nft_config
+          ///   5. `[]` reward_token_mint
+          ///   6. `[]` lock_mint
+                ///   7. `[writable]` vault_lock_ata
+                ///   8. `[writable]` provider_lock_ata
+                      ///   9. `[writable, signer]` nft_collection
+                ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   12. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+                ///   13. `[optional]` nft_marketplace (default to `AkFAoXys2zhqE15q8XJJJRqXgxLdtJ1kb9ec4fCo1GgH`)
+                ///   14. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct CreateVaultHandlerBuilder {
             provider: Option<solana_pubkey::Pubkey>,
+                operator_registry: Option<solana_pubkey::Pubkey>,
                 vault: Option<solana_pubkey::Pubkey>,
                 config_account: Option<solana_pubkey::Pubkey>,
                 nft_config: Option<solana_pubkey::Pubkey>,
                 reward_token_mint: Option<solana_pubkey::Pubkey>,
                 lock_mint: Option<solana_pubkey::Pubkey>,
+                vault_lock_ata: Option<solana_pubkey::Pubkey>,
+                provider_lock_ata: Option<solana_pubkey::Pubkey>,
                 nft_collection: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
@@ -216,10 +248,10 @@ pub struct CreateVaultHandlerBuilder {
                         min_cap: Option<u64>,
                 max_cap: Option<u64>,
                 min_lock_amount: Option<u64>,
-                beneficiaries: Option<Vec<Beneficiary>>,
+                operator_bond: Option<u64>,
                 investor_bps: Option<u16>,
+                operator_commission_bps: Option<u16>,
                 max_slash_bps: Option<u16>,
-                slash_claimant: Option<Pubkey>,
                 reward_distributor: Option<Pubkey>,
                 node_operator: Option<Pubkey>,
                 lock_phase_duration: Option<i64>,
@@ -236,6 +268,12 @@ impl CreateVaultHandlerBuilder {
     pub fn provider(&mut self, provider: solana_pubkey::Pubkey) -> &mut Self {
                         self.provider = Some(provider);
                     self
+    }
+            /// Per-operator vault counter, created with the operator's first vault
+#[inline(always)]
+    pub fn operator_registry(&mut self, operator_registry: solana_pubkey::Pubkey) -> &mut Self {
+                        self.operator_registry = Some(operator_registry);
+                    self
     }
             /// The vault account to be created
 #[inline(always)]
@@ -255,7 +293,7 @@ impl CreateVaultHandlerBuilder {
                         self.nft_config = Some(nft_config);
                     self
     }
-            /// Reward token mint - tokens distributed as rewards
+            /// Reward token mint - first reward stream of the vault
 #[inline(always)]
     pub fn reward_token_mint(&mut self, reward_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.reward_token_mint = Some(reward_token_mint);
@@ -266,6 +304,18 @@ impl CreateVaultHandlerBuilder {
     pub fn lock_mint(&mut self, lock_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.lock_mint = Some(lock_mint);
                     self
+    }
+            /// Vault's locking token account, holds the operator bond and investor capital
+#[inline(always)]
+    pub fn vault_lock_ata(&mut self, vault_lock_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.vault_lock_ata = Some(vault_lock_ata);
+                    self
+    }
+            /// Provider's locking token account funding the operator bond
+#[inline(always)]
+    pub fn provider_lock_ata(&mut self, provider_lock_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.provider_lock_ata = Some(provider_lock_ata);
+                    self
     }
             /// NFT collection for vault positions
 #[inline(always)]
@@ -319,8 +369,8 @@ impl CreateVaultHandlerBuilder {
         self
       }
                 #[inline(always)]
-      pub fn beneficiaries(&mut self, beneficiaries: Vec<Beneficiary>) -> &mut Self {
-        self.beneficiaries = Some(beneficiaries);
+      pub fn operator_bond(&mut self, operator_bond: u64) -> &mut Self {
+        self.operator_bond = Some(operator_bond);
         self
       }
                 #[inline(always)]
@@ -329,13 +379,13 @@ impl CreateVaultHandlerBuilder {
         self
       }
                 #[inline(always)]
-      pub fn max_slash_bps(&mut self, max_slash_bps: u16) -> &mut Self {
-        self.max_slash_bps = Some(max_slash_bps);
+      pub fn operator_commission_bps(&mut self, operator_commission_bps: u16) -> &mut Self {
+        self.operator_commission_bps = Some(operator_commission_bps);
         self
       }
                 #[inline(always)]
-      pub fn slash_claimant(&mut self, slash_claimant: Pubkey) -> &mut Self {
-        self.slash_claimant = Some(slash_claimant);
+      pub fn max_slash_bps(&mut self, max_slash_bps: u16) -> &mut Self {
+        self.max_slash_bps = Some(max_slash_bps);
         self
       }
                 #[inline(always)]
@@ -374,11 +424,14 @@ impl CreateVaultHandlerBuilder {
   pub fn instruction(&self) -> solana_instruction::Instruction {
     let accounts = CreateVaultHandler {
                               provider: self.provider.expect("provider is not set"),
+                                        operator_registry: self.operator_registry.expect("operator_registry is not set"),
                                         vault: self.vault.expect("vault is not set"),
                                         config_account: self.config_account.expect("config_account is not set"),
                                         nft_config: self.nft_config.expect("nft_config is not set"),
                                         reward_token_mint: self.reward_token_mint.expect("reward_token_mint is not set"),
                                         lock_mint: self.lock_mint.expect("lock_mint is not set"),
+                                        vault_lock_ata: self.vault_lock_ata.expect("vault_lock_ata is not set"),
+                                        provider_lock_ata: self.provider_lock_ata.expect("provider_lock_ata is not set"),
                                         nft_collection: self.nft_collection.expect("nft_collection is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
@@ -390,10 +443,10 @@ impl CreateVaultHandlerBuilder {
                                                               min_cap: self.min_cap.clone().expect("min_cap is not set"),
                                                                   max_cap: self.max_cap.clone().expect("max_cap is not set"),
                                                                   min_lock_amount: self.min_lock_amount.clone().expect("min_lock_amount is not set"),
-                                                                  beneficiaries: self.beneficiaries.clone().expect("beneficiaries is not set"),
+                                                                  operator_bond: self.operator_bond.clone().expect("operator_bond is not set"),
                                                                   investor_bps: self.investor_bps.clone().expect("investor_bps is not set"),
+                                                                  operator_commission_bps: self.operator_commission_bps.clone().expect("operator_commission_bps is not set"),
                                                                   max_slash_bps: self.max_slash_bps.clone().expect("max_slash_bps is not set"),
-                                                                  slash_claimant: self.slash_claimant.clone().expect("slash_claimant is not set"),
                                                                   reward_distributor: self.reward_distributor.clone().expect("reward_distributor is not set"),
                                                                   node_operator: self.node_operator.clone().expect("node_operator is not set"),
                                                                   lock_phase_duration: self.lock_phase_duration.clone().expect("lock_phase_duration is not set"),
@@ -411,6 +464,11 @@ impl CreateVaultHandlerBuilder {
       
                     
               pub provider: &'b solana_account_info::AccountInfo<'a>,
+                        /// Per-operator vault counter, created with the operator's first vault
+
+      
+                    
+              pub operator_registry: &'b solana_account_info::AccountInfo<'a>,
                         /// The vault account to be created
 
       
@@ -426,7 +484,7 @@ impl CreateVaultHandlerBuilder {
       
                     
               pub nft_config: &'b solana_account_info::AccountInfo<'a>,
-                        /// Reward token mint - tokens distributed as rewards
+                        /// Reward token mint - first reward stream of the vault
 
       
                     
@@ -436,6 +494,16 @@ impl CreateVaultHandlerBuilder {
       
                     
               pub lock_mint: &'b solana_account_info::AccountInfo<'a>,
+                        /// Vault's locking token account, holds the operator bond and investor capital
+
+      
+                    
+              pub vault_lock_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// Provider's locking token account funding the operator bond
+
+      
+                    
+              pub provider_lock_ata: &'b solana_account_info::AccountInfo<'a>,
                         /// NFT collection for vault positions
 
       
@@ -467,6 +535,11 @@ pub struct CreateVaultHandlerCpi<'a, 'b> {
     
               
           pub provider: &'b solana_account_info::AccountInfo<'a>,
+                /// Per-operator vault counter, created with the operator's first vault
+
+    
+              
+          pub operator_registry: &'b solana_account_info::AccountInfo<'a>,
                 /// The vault account to be created
 
     
@@ -482,7 +555,7 @@ pub struct CreateVaultHandlerCpi<'a, 'b> {
     
               
           pub nft_config: &'b solana_account_info::AccountInfo<'a>,
-                /// Reward token mint - tokens distributed as rewards
+                /// Reward token mint - first reward stream of the vault
 
     
               
@@ -492,6 +565,16 @@ pub struct CreateVaultHandlerCpi<'a, 'b> {
     
               
           pub lock_mint: &'b solana_account_info::AccountInfo<'a>,
+                /// Vault's locking token account, holds the operator bond and investor capital
+
+    
+              
+          pub vault_lock_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// Provider's locking token account funding the operator bond
+
+    
+              
+          pub provider_lock_ata: &'b solana_account_info::AccountInfo<'a>,
                 /// NFT collection for vault positions
 
     
@@ -525,11 +608,14 @@ impl<'a, 'b> CreateVaultHandlerCpi<'a, 'b> {
     Self {
       __program: program,
               provider: accounts.provider,
+              operator_registry: accounts.operator_registry,
               vault: accounts.vault,
               config_account: accounts.config_account,
               nft_config: accounts.nft_config,
               reward_token_mint: accounts.reward_token_mint,
               lock_mint: accounts.lock_mint,
+              vault_lock_ata: accounts.vault_lock_ata,
+              provider_lock_ata: accounts.provider_lock_ata,
               nft_collection: accounts.nft_collection,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
@@ -559,10 +645,14 @@ impl<'a, 'b> CreateVaultHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(15+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.provider.key,
             true
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.operator_registry.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.vault.key,
@@ -583,6 +673,14 @@ impl<'a, 'b> CreateVaultHandlerCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.lock_mint.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.vault_lock_ata.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.provider_lock_ata.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.nft_collection.key,
@@ -624,14 +722,17 @@ impl<'a, 'b> CreateVaultHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(16 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.provider.clone());
+                        account_infos.push(self.operator_registry.clone());
                         account_infos.push(self.vault.clone());
                         account_infos.push(self.config_account.clone());
                         account_infos.push(self.nft_config.clone());
                         account_infos.push(self.reward_token_mint.clone());
                         account_infos.push(self.lock_mint.clone());
+                        account_infos.push(self.vault_lock_ata.clone());
+                        account_infos.push(self.provider_lock_ata.clone());
                         account_infos.push(self.nft_collection.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
@@ -653,17 +754,20 @@ impl<'a, 'b> CreateVaultHandlerCpi<'a, 'b> {
 /// ### Accounts:
 ///
                       ///   0. `[writable, signer]` provider
-                ///   1. `[writable]` vault
-          ///   2. `[]` config_account
-          ///   3. `[]` nft_config
-          ///   4. `[]` reward_token_mint
-          ///   5. `[]` lock_mint
-                      ///   6. `[writable, signer]` nft_collection
-          ///   7. `[]` token_program
-          ///   8. `[]` associated_token_program
-          ///   9. `[]` mpl_core_program
-          ///   10. `[]` nft_marketplace
-          ///   11. `[]` system_program
+                ///   1. `[writable]` operator_registry
+                ///   2. `[writable]` vault
+          ///   3. `[]` config_account
+          ///   4. `[]` nft_config
+          ///   5. `[]` reward_token_mint
+          ///   6. `[]` lock_mint
+                ///   7. `[writable]` vault_lock_ata
+                ///   8. `[writable]` provider_lock_ata
+                      ///   9. `[writable, signer]` nft_collection
+          ///   10. `[]` token_program
+          ///   11. `[]` associated_token_program
+          ///   12. `[]` mpl_core_program
+          ///   13. `[]` nft_marketplace
+          ///   14. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct CreateVaultHandlerCpiBuilder<'a, 'b> {
   instruction: Box<CreateVaultHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -674,11 +778,14 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
     let instruction = Box::new(CreateVaultHandlerCpiBuilderInstruction {
       __program: program,
               provider: None,
+              operator_registry: None,
               vault: None,
               config_account: None,
               nft_config: None,
               reward_token_mint: None,
               lock_mint: None,
+              vault_lock_ata: None,
+              provider_lock_ata: None,
               nft_collection: None,
               token_program: None,
               associated_token_program: None,
@@ -688,10 +795,10 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
                                             min_cap: None,
                                 max_cap: None,
                                 min_lock_amount: None,
-                                beneficiaries: None,
+                                operator_bond: None,
                                 investor_bps: None,
+                                operator_commission_bps: None,
                                 max_slash_bps: None,
-                                slash_claimant: None,
                                 reward_distributor: None,
                                 node_operator: None,
                                 lock_phase_duration: None,
@@ -705,6 +812,12 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
     pub fn provider(&mut self, provider: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.provider = Some(provider);
                     self
+    }
+      /// Per-operator vault counter, created with the operator's first vault
+#[inline(always)]
+    pub fn operator_registry(&mut self, operator_registry: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.operator_registry = Some(operator_registry);
+                    self
     }
       /// The vault account to be created
 #[inline(always)]
@@ -724,7 +837,7 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
                         self.instruction.nft_config = Some(nft_config);
                     self
     }
-      /// Reward token mint - tokens distributed as rewards
+      /// Reward token mint - first reward stream of the vault
 #[inline(always)]
     pub fn reward_token_mint(&mut self, reward_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.reward_token_mint = Some(reward_token_mint);
@@ -735,6 +848,18 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
     pub fn lock_mint(&mut self, lock_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.lock_mint = Some(lock_mint);
                     self
+    }
+      /// Vault's locking token account, holds the operator bond and investor capital
+#[inline(always)]
+    pub fn vault_lock_ata(&mut self, vault_lock_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.vault_lock_ata = Some(vault_lock_ata);
+                    self
+    }
+      /// Provider's locking token account funding the operator bond
+#[inline(always)]
+    pub fn provider_lock_ata(&mut self, provider_lock_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.provider_lock_ata = Some(provider_lock_ata);
+                    self
     }
       /// NFT collection for vault positions
 #[inline(always)]
@@ -783,8 +908,8 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
         self
       }
                 #[inline(always)]
-      pub fn beneficiaries(&mut self, beneficiaries: Vec<Beneficiary>) -> &mut Self {
-        self.instruction.beneficiaries = Some(beneficiaries);
+      pub fn operator_bond(&mut self, operator_bond: u64) -> &mut Self {
+        self.instruction.operator_bond = Some(operator_bond);
         self
       }
                 #[inline(always)]
@@ -793,13 +918,13 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
         self
       }
                 #[inline(always)]
-      pub fn max_slash_bps(&mut self, max_slash_bps: u16) -> &mut Self {
-        self.instruction.max_slash_bps = Some(max_slash_bps);
+      pub fn operator_commission_bps(&mut self, operator_commission_bps: u16) -> &mut Self {
+        self.instruction.operator_commission_bps = Some(operator_commission_bps);
         self
       }
                 #[inline(always)]
-      pub fn slash_claimant(&mut self, slash_claimant: Pubkey) -> &mut Self {
-        self.instruction.slash_claimant = Some(slash_claimant);
+      pub fn max_slash_bps(&mut self, max_slash_bps: u16) -> &mut Self {
+        self.instruction.max_slash_bps = Some(max_slash_bps);
         self
       }
                 #[inline(always)]
@@ -848,10 +973,10 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
                                                               min_cap: self.instruction.min_cap.clone().expect("min_cap is not set"),
                                                                   max_cap: self.instruction.max_cap.clone().expect("max_cap is not set"),
                                                                   min_lock_amount: self.instruction.min_lock_amount.clone().expect("min_lock_amount is not set"),
-                                                                  beneficiaries: self.instruction.beneficiaries.clone().expect("beneficiaries is not set"),
+                                                                  operator_bond: self.instruction.operator_bond.clone().expect("operator_bond is not set"),
                                                                   investor_bps: self.instruction.investor_bps.clone().expect("investor_bps is not set"),
+                                                                  operator_commission_bps: self.instruction.operator_commission_bps.clone().expect("operator_commission_bps is not set"),
                                                                   max_slash_bps: self.instruction.max_slash_bps.clone().expect("max_slash_bps is not set"),
-                                                                  slash_claimant: self.instruction.slash_claimant.clone().expect("slash_claimant is not set"),
                                                                   reward_distributor: self.instruction.reward_distributor.clone().expect("reward_distributor is not set"),
                                                                   node_operator: self.instruction.node_operator.clone().expect("node_operator is not set"),
                                                                   lock_phase_duration: self.instruction.lock_phase_duration.clone().expect("lock_phase_duration is not set"),
@@ -862,6 +987,8 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
                   
           provider: self.instruction.provider.expect("provider is not set"),
                   
+          operator_registry: self.instruction.operator_registry.expect("operator_registry is not set"),
+                  
           vault: self.instruction.vault.expect("vault is not set"),
                   
           config_account: self.instruction.config_account.expect("config_account is not set"),
@@ -872,6 +999,10 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
                   
           lock_mint: self.instruction.lock_mint.expect("lock_mint is not set"),
                   
+          vault_lock_ata: self.instruction.vault_lock_ata.expect("vault_lock_ata is not set"),
+                  
+          provider_lock_ata: self.instruction.provider_lock_ata.expect("provider_lock_ata is not set"),
+                  
           nft_collection: self.instruction.nft_collection.expect("nft_collection is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
@@ -893,11 +1024,14 @@ impl<'a, 'b> CreateVaultHandlerCpiBuilder<'a, 'b> {
 struct CreateVaultHandlerCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             provider: Option<&'b solana_account_info::AccountInfo<'a>>,
+                operator_registry: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 config_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 nft_config: Option<&'b solana_account_info::AccountInfo<'a>>,
                 reward_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lock_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+                vault_lock_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                provider_lock_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 nft_collection: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -907,10 +1041,10 @@ struct CreateVaultHandlerCpiBuilderInstruction<'a, 'b> {
                         min_cap: Option<u64>,
                 max_cap: Option<u64>,
                 min_lock_amount: Option<u64>,
-                beneficiaries: Option<Vec<Beneficiary>>,
+                operator_bond: Option<u64>,
                 investor_bps: Option<u16>,
+                operator_commission_bps: Option<u16>,
                 max_slash_bps: Option<u16>,
-                slash_claimant: Option<Pubkey>,
                 reward_distributor: Option<Pubkey>,
                 node_operator: Option<Pubkey>,
                 lock_phase_duration: Option<i64>,
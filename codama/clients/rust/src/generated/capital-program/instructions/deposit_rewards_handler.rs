@@ -13,11 +13,11 @@ pub const DEPOSIT_REWARDS_HANDLER_DISCRIMINATOR: [u8; 8] = [186, 68, 103, 29, 15
 /// Accounts.
 #[derive(Debug)]
 pub struct DepositRewardsHandler {
-            /// The authorized agent depositing rewards
+            /// The vault's reward distributor or the global agent
 
     
               
-          pub agent: solana_pubkey::Pubkey,
+          pub depositor: solana_pubkey::Pubkey,
                 /// The vault receiving rewards
 
     
@@ -28,7 +28,7 @@ pub struct DepositRewardsHandler {
     
               
           pub config: solana_pubkey::Pubkey,
-                /// Reward token mint
+                /// Mint of the reward stream being funded
 
     
               
@@ -38,11 +38,33 @@ pub struct DepositRewardsHandler {
     
               
           pub vault_reward_ata: solana_pubkey::Pubkey,
-                /// Agent's reward token account
+          
+              
+          pub treasury: solana_pubkey::Pubkey,
+                /// Treasury's token account collecting the protocol fee for this mint,
+/// created by the admin through init_fee_accounts. Only required while the
+/// treasury's share of the protocol fee is positive
+
+    
+              
+          pub treasury_reward_ata: Option<solana_pubkey::Pubkey>,
+                /// Insurance fund of the reward mint, receives its slice of the protocol fee.
+/// The admin moves it into the locking token's fund with withdraw_insurance.
+/// Only required while the insurance share of the protocol fee is positive
+
+    
+              
+          pub insurance_fund: Option<solana_pubkey::Pubkey>,
+                /// Insurance fund's token account for the reward mint
+
+    
+              
+          pub insurance_fund_ata: Option<solana_pubkey::Pubkey>,
+                /// Depositor's reward token account
 
     
               
-          pub agent_reward_ata: solana_pubkey::Pubkey,
+          pub depositor_reward_ata: solana_pubkey::Pubkey,
           
               
           pub token_program: solana_pubkey::Pubkey,
@@ -61,9 +83,9 @@ impl DepositRewardsHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: DepositRewardsHandlerInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(9+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(13+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
-            self.agent,
+            self.depositor,
             true
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
@@ -82,8 +104,45 @@ impl DepositRewardsHandler {
             self.vault_reward_ata,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.treasury,
+            false
+          ));
+                              if let Some(treasury_reward_ata) = self.treasury_reward_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                treasury_reward_ata,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund) = self.insurance_fund {
+              accounts.push(solana_instruction::AccountMeta::new(
+                insurance_fund,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                insurance_fund_ata,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.agent_reward_ata,
+            self.depositor_reward_ata,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -115,13 +174,13 @@ impl DepositRewardsHandler {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct DepositRewardsHandlerInstructionData {
             discriminator: [u8; 8],
-            }
+                  }
 
 impl DepositRewardsHandlerInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [186, 68, 103, 29, 158, 215, 213, 14],
-                                }
+                                              }
   }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -139,6 +198,7 @@ impl Default for DepositRewardsHandlerInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct DepositRewardsHandlerInstructionArgs {
                   pub amount: u64,
+                pub stream_duration: Option<i64>,
       }
 
 impl DepositRewardsHandlerInstructionArgs {
@@ -152,27 +212,36 @@ impl DepositRewardsHandlerInstructionArgs {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` agent
+                      ///   0. `[writable, signer]` depositor
                 ///   1. `[writable]` vault
           ///   2. `[]` config
           ///   3. `[]` reward_token_mint
                 ///   4. `[writable]` vault_reward_ata
-                ///   5. `[writable]` agent_reward_ata
-                ///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   7. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   5. `[]` treasury
+                      ///   6. `[writable, optional]` treasury_reward_ata
+                      ///   7. `[writable, optional]` insurance_fund
+                      ///   8. `[writable, optional]` insurance_fund_ata
+                ///   9. `[writable]` depositor_reward_ata
+                ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   11. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct DepositRewardsHandlerBuilder {
-            agent: Option<solana_pubkey::Pubkey>,
+            depositor: Option<solana_pubkey::Pubkey>,
                 vault: Option<solana_pubkey::Pubkey>,
                 config: Option<solana_pubkey::Pubkey>,
                 reward_token_mint: Option<solana_pubkey::Pubkey>,
                 vault_reward_ata: Option<solana_pubkey::Pubkey>,
-                agent_reward_ata: Option<solana_pubkey::Pubkey>,
+                treasury: Option<solana_pubkey::Pubkey>,
+                treasury_reward_ata: Option<solana_pubkey::Pubkey>,
+                insurance_fund: Option<solana_pubkey::Pubkey>,
+                insurance_fund_ata: Option<solana_pubkey::Pubkey>,
+                depositor_reward_ata: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
                         amount: Option<u64>,
+                stream_duration: Option<i64>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -180,10 +249,10 @@ impl DepositRewardsHandlerBuilder {
   pub fn new() -> Self {
     Self::default()
   }
-            /// The authorized agent depositing rewards
+            /// The vault's reward distributor or the global agent
 #[inline(always)]
-    pub fn agent(&mut self, agent: solana_pubkey::Pubkey) -> &mut Self {
-                        self.agent = Some(agent);
+    pub fn depositor(&mut self, depositor: solana_pubkey::Pubkey) -> &mut Self {
+                        self.depositor = Some(depositor);
                     self
     }
             /// The vault receiving rewards
@@ -198,7 +267,7 @@ impl DepositRewardsHandlerBuilder {
                         self.config = Some(config);
                     self
     }
-            /// Reward token mint
+            /// Mint of the reward stream being funded
 #[inline(always)]
     pub fn reward_token_mint(&mut self, reward_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.reward_token_mint = Some(reward_token_mint);
@@ -210,10 +279,40 @@ impl DepositRewardsHandlerBuilder {
                         self.vault_reward_ata = Some(vault_reward_ata);
                     self
     }
-            /// Agent's reward token account
+            #[inline(always)]
+    pub fn treasury(&mut self, treasury: solana_pubkey::Pubkey) -> &mut Self {
+                        self.treasury = Some(treasury);
+                    self
+    }
+            /// `[optional account]`
+/// Treasury's token account collecting the protocol fee for this mint,
+/// created by the admin through init_fee_accounts. Only required while the
+/// treasury's share of the protocol fee is positive
 #[inline(always)]
-    pub fn agent_reward_ata(&mut self, agent_reward_ata: solana_pubkey::Pubkey) -> &mut Self {
-                        self.agent_reward_ata = Some(agent_reward_ata);
+    pub fn treasury_reward_ata(&mut self, treasury_reward_ata: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.treasury_reward_ata = treasury_reward_ata;
+                    self
+    }
+            /// `[optional account]`
+/// Insurance fund of the reward mint, receives its slice of the protocol fee.
+/// The admin moves it into the locking token's fund with withdraw_insurance.
+/// Only required while the insurance share of the protocol fee is positive
+#[inline(always)]
+    pub fn insurance_fund(&mut self, insurance_fund: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.insurance_fund = insurance_fund;
+                    self
+    }
+            /// `[optional account]`
+/// Insurance fund's token account for the reward mint
+#[inline(always)]
+    pub fn insurance_fund_ata(&mut self, insurance_fund_ata: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.insurance_fund_ata = insurance_fund_ata;
+                    self
+    }
+            /// Depositor's reward token account
+#[inline(always)]
+    pub fn depositor_reward_ata(&mut self, depositor_reward_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.depositor_reward_ata = Some(depositor_reward_ata);
                     self
     }
             /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
@@ -238,6 +337,12 @@ impl DepositRewardsHandlerBuilder {
       pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
         self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn stream_duration(&mut self, stream_duration: i64) -> &mut Self {
+        self.stream_duration = Some(stream_duration);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -254,18 +359,23 @@ impl DepositRewardsHandlerBuilder {
   #[allow(clippy::clone_on_copy)]
   pub fn instruction(&self) -> solana_instruction::Instruction {
     let accounts = DepositRewardsHandler {
-                              agent: self.agent.expect("agent is not set"),
+                              depositor: self.depositor.expect("depositor is not set"),
                                         vault: self.vault.expect("vault is not set"),
                                         config: self.config.expect("config is not set"),
                                         reward_token_mint: self.reward_token_mint.expect("reward_token_mint is not set"),
                                         vault_reward_ata: self.vault_reward_ata.expect("vault_reward_ata is not set"),
-                                        agent_reward_ata: self.agent_reward_ata.expect("agent_reward_ata is not set"),
+                                        treasury: self.treasury.expect("treasury is not set"),
+                                        treasury_reward_ata: self.treasury_reward_ata,
+                                        insurance_fund: self.insurance_fund,
+                                        insurance_fund_ata: self.insurance_fund_ata,
+                                        depositor_reward_ata: self.depositor_reward_ata.expect("depositor_reward_ata is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                       };
           let args = DepositRewardsHandlerInstructionArgs {
                                                               amount: self.amount.clone().expect("amount is not set"),
+                                                                  stream_duration: self.stream_duration.clone(),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -274,11 +384,11 @@ impl DepositRewardsHandlerBuilder {
 
   /// `deposit_rewards_handler` CPI accounts.
   pub struct DepositRewardsHandlerCpiAccounts<'a, 'b> {
-                  /// The authorized agent depositing rewards
+                  /// The vault's reward distributor or the global agent
 
       
                     
-              pub agent: &'b solana_account_info::AccountInfo<'a>,
+              pub depositor: &'b solana_account_info::AccountInfo<'a>,
                         /// The vault receiving rewards
 
       
@@ -289,7 +399,7 @@ impl DepositRewardsHandlerBuilder {
       
                     
               pub config: &'b solana_account_info::AccountInfo<'a>,
-                        /// Reward token mint
+                        /// Mint of the reward stream being funded
 
       
                     
@@ -299,11 +409,33 @@ impl DepositRewardsHandlerBuilder {
       
                     
               pub vault_reward_ata: &'b solana_account_info::AccountInfo<'a>,
-                        /// Agent's reward token account
+                
+                    
+              pub treasury: &'b solana_account_info::AccountInfo<'a>,
+                        /// Treasury's token account collecting the protocol fee for this mint,
+/// created by the admin through init_fee_accounts. Only required while the
+/// treasury's share of the protocol fee is positive
+
+      
+                    
+              pub treasury_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// Insurance fund of the reward mint, receives its slice of the protocol fee.
+/// The admin moves it into the locking token's fund with withdraw_insurance.
+/// Only required while the insurance share of the protocol fee is positive
+
+      
+                    
+              pub insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// Insurance fund's token account for the reward mint
 
       
                     
-              pub agent_reward_ata: &'b solana_account_info::AccountInfo<'a>,
+              pub insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// Depositor's reward token account
+
+      
+                    
+              pub depositor_reward_ata: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -319,11 +451,11 @@ impl DepositRewardsHandlerBuilder {
 pub struct DepositRewardsHandlerCpi<'a, 'b> {
   /// The program to invoke.
   pub __program: &'b solana_account_info::AccountInfo<'a>,
-            /// The authorized agent depositing rewards
+            /// The vault's reward distributor or the global agent
 
     
               
-          pub agent: &'b solana_account_info::AccountInfo<'a>,
+          pub depositor: &'b solana_account_info::AccountInfo<'a>,
                 /// The vault receiving rewards
 
     
@@ -334,7 +466,7 @@ pub struct DepositRewardsHandlerCpi<'a, 'b> {
     
               
           pub config: &'b solana_account_info::AccountInfo<'a>,
-                /// Reward token mint
+                /// Mint of the reward stream being funded
 
     
               
@@ -344,11 +476,33 @@ pub struct DepositRewardsHandlerCpi<'a, 'b> {
     
               
           pub vault_reward_ata: &'b solana_account_info::AccountInfo<'a>,
-                /// Agent's reward token account
+          
+              
+          pub treasury: &'b solana_account_info::AccountInfo<'a>,
+                /// Treasury's token account collecting the protocol fee for this mint,
+/// created by the admin through init_fee_accounts. Only required while the
+/// treasury's share of the protocol fee is positive
+
+    
+              
+          pub treasury_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// Insurance fund of the reward mint, receives its slice of the protocol fee.
+/// The admin moves it into the locking token's fund with withdraw_insurance.
+/// Only required while the insurance share of the protocol fee is positive
+
+    
+              
+          pub insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// Insurance fund's token account for the reward mint
 
     
               
-          pub agent_reward_ata: &'b solana_account_info::AccountInfo<'a>,
+          pub insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// Depositor's reward token account
+
+    
+              
+          pub depositor_reward_ata: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -370,12 +524,16 @@ impl<'a, 'b> DepositRewardsHandlerCpi<'a, 'b> {
       ) -> Self {
     Self {
       __program: program,
-              agent: accounts.agent,
+              depositor: accounts.depositor,
               vault: accounts.vault,
               config: accounts.config,
               reward_token_mint: accounts.reward_token_mint,
               vault_reward_ata: accounts.vault_reward_ata,
-              agent_reward_ata: accounts.agent_reward_ata,
+              treasury: accounts.treasury,
+              treasury_reward_ata: accounts.treasury_reward_ata,
+              insurance_fund: accounts.insurance_fund,
+              insurance_fund_ata: accounts.insurance_fund_ata,
+              depositor_reward_ata: accounts.depositor_reward_ata,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
@@ -402,9 +560,9 @@ impl<'a, 'b> DepositRewardsHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(9+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(13+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
-            *self.agent.key,
+            *self.depositor.key,
             true
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
@@ -423,8 +581,45 @@ impl<'a, 'b> DepositRewardsHandlerCpi<'a, 'b> {
             *self.vault_reward_ata.key,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.treasury.key,
+            false
+          ));
+                              if let Some(treasury_reward_ata) = self.treasury_reward_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *treasury_reward_ata.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund) = self.insurance_fund {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *insurance_fund.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *insurance_fund_ata.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.agent_reward_ata.key,
+            *self.depositor_reward_ata.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
@@ -455,14 +650,24 @@ impl<'a, 'b> DepositRewardsHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
-                  account_infos.push(self.agent.clone());
+                  account_infos.push(self.depositor.clone());
                         account_infos.push(self.vault.clone());
                         account_infos.push(self.config.clone());
                         account_infos.push(self.reward_token_mint.clone());
                         account_infos.push(self.vault_reward_ata.clone());
-                        account_infos.push(self.agent_reward_ata.clone());
+                        account_infos.push(self.treasury.clone());
+                  if let Some(treasury_reward_ata) = self.treasury_reward_ata {
+          account_infos.push(treasury_reward_ata.clone());
+        }
+                  if let Some(insurance_fund) = self.insurance_fund {
+          account_infos.push(insurance_fund.clone());
+        }
+                  if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+          account_infos.push(insurance_fund_ata.clone());
+        }
+                        account_infos.push(self.depositor_reward_ata.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
@@ -480,15 +685,19 @@ impl<'a, 'b> DepositRewardsHandlerCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` agent
+                      ///   0. `[writable, signer]` depositor
                 ///   1. `[writable]` vault
           ///   2. `[]` config
           ///   3. `[]` reward_token_mint
                 ///   4. `[writable]` vault_reward_ata
-                ///   5. `[writable]` agent_reward_ata
-          ///   6. `[]` token_program
-          ///   7. `[]` associated_token_program
-          ///   8. `[]` system_program
+          ///   5. `[]` treasury
+                      ///   6. `[writable, optional]` treasury_reward_ata
+                      ///   7. `[writable, optional]` insurance_fund
+                      ///   8. `[writable, optional]` insurance_fund_ata
+                ///   9. `[writable]` depositor_reward_ata
+          ///   10. `[]` token_program
+          ///   11. `[]` associated_token_program
+          ///   12. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct DepositRewardsHandlerCpiBuilder<'a, 'b> {
   instruction: Box<DepositRewardsHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -498,24 +707,29 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
   pub fn new(program: &'b solana_account_info::AccountInfo<'a>) -> Self {
     let instruction = Box::new(DepositRewardsHandlerCpiBuilderInstruction {
       __program: program,
-              agent: None,
+              depositor: None,
               vault: None,
               config: None,
               reward_token_mint: None,
               vault_reward_ata: None,
-              agent_reward_ata: None,
+              treasury: None,
+              treasury_reward_ata: None,
+              insurance_fund: None,
+              insurance_fund_ata: None,
+              depositor_reward_ata: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
                                             amount: None,
+                                stream_duration: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
-      /// The authorized agent depositing rewards
+      /// The vault's reward distributor or the global agent
 #[inline(always)]
-    pub fn agent(&mut self, agent: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.agent = Some(agent);
+    pub fn depositor(&mut self, depositor: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.depositor = Some(depositor);
                     self
     }
       /// The vault receiving rewards
@@ -530,7 +744,7 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
                         self.instruction.config = Some(config);
                     self
     }
-      /// Reward token mint
+      /// Mint of the reward stream being funded
 #[inline(always)]
     pub fn reward_token_mint(&mut self, reward_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.reward_token_mint = Some(reward_token_mint);
@@ -542,10 +756,40 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
                         self.instruction.vault_reward_ata = Some(vault_reward_ata);
                     self
     }
-      /// Agent's reward token account
+      #[inline(always)]
+    pub fn treasury(&mut self, treasury: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.treasury = Some(treasury);
+                    self
+    }
+      /// Treasury's token account collecting the protocol fee for this mint,
+/// created by the admin through init_fee_accounts. Only required while the
+/// treasury's share of the protocol fee is positive
+/// `[optional account]`
+#[inline(always)]
+    pub fn treasury_reward_ata(&mut self, treasury_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.treasury_reward_ata = treasury_reward_ata;
+                    self
+    }
+      /// Insurance fund of the reward mint, receives its slice of the protocol fee.
+/// The admin moves it into the locking token's fund with withdraw_insurance.
+/// Only required while the insurance share of the protocol fee is positive
+/// `[optional account]`
 #[inline(always)]
-    pub fn agent_reward_ata(&mut self, agent_reward_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.agent_reward_ata = Some(agent_reward_ata);
+    pub fn insurance_fund(&mut self, insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.insurance_fund = insurance_fund;
+                    self
+    }
+      /// Insurance fund's token account for the reward mint
+/// `[optional account]`
+#[inline(always)]
+    pub fn insurance_fund_ata(&mut self, insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.insurance_fund_ata = insurance_fund_ata;
+                    self
+    }
+      /// Depositor's reward token account
+#[inline(always)]
+    pub fn depositor_reward_ata(&mut self, depositor_reward_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.depositor_reward_ata = Some(depositor_reward_ata);
                     self
     }
       #[inline(always)]
@@ -567,6 +811,12 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
       pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
         self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn stream_duration(&mut self, stream_duration: i64) -> &mut Self {
+        self.instruction.stream_duration = Some(stream_duration);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -592,11 +842,12 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
           let args = DepositRewardsHandlerInstructionArgs {
                                                               amount: self.instruction.amount.clone().expect("amount is not set"),
+                                                                  stream_duration: self.instruction.stream_duration.clone(),
                                     };
         let instruction = DepositRewardsHandlerCpi {
         __program: self.instruction.__program,
                   
-          agent: self.instruction.agent.expect("agent is not set"),
+          depositor: self.instruction.depositor.expect("depositor is not set"),
                   
           vault: self.instruction.vault.expect("vault is not set"),
                   
@@ -606,7 +857,15 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
                   
           vault_reward_ata: self.instruction.vault_reward_ata.expect("vault_reward_ata is not set"),
                   
-          agent_reward_ata: self.instruction.agent_reward_ata.expect("agent_reward_ata is not set"),
+          treasury: self.instruction.treasury.expect("treasury is not set"),
+                  
+          treasury_reward_ata: self.instruction.treasury_reward_ata,
+                  
+          insurance_fund: self.instruction.insurance_fund,
+                  
+          insurance_fund_ata: self.instruction.insurance_fund_ata,
+                  
+          depositor_reward_ata: self.instruction.depositor_reward_ata.expect("depositor_reward_ata is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
@@ -622,16 +881,21 @@ impl<'a, 'b> DepositRewardsHandlerCpiBuilder<'a, 'b> {
 #[derive(Clone, Debug)]
 struct DepositRewardsHandlerCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
-            agent: Option<&'b solana_account_info::AccountInfo<'a>>,
+            depositor: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault: Option<&'b solana_account_info::AccountInfo<'a>>,
                 config: Option<&'b solana_account_info::AccountInfo<'a>>,
                 reward_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                agent_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                treasury: Option<&'b solana_account_info::AccountInfo<'a>>,
+                treasury_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                depositor_reward_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         amount: Option<u64>,
+                stream_duration: Option<i64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -13,12 +13,19 @@ pub const FINALIZE_SLASH_REQ_HANDLER_DISCRIMINATOR: [u8; 8] = [41, 86, 186, 238,
 /// Accounts.
 #[derive(Debug)]
 pub struct FinalizeSlashReqHandler {
-      
+            /// The agent, or the admin once the node operator appealed
+
+    
               
-          pub agent: solana_pubkey::Pubkey,
+          pub resolver: solana_pubkey::Pubkey,
           
               
           pub vault: solana_pubkey::Pubkey,
+                /// The vault's open slash request, kept as a record once resolved
+
+    
+              
+          pub slash_request: solana_pubkey::Pubkey,
           
               
           pub config: solana_pubkey::Pubkey,
@@ -32,11 +39,19 @@ pub struct FinalizeSlashReqHandler {
     
               
           pub vault_token_ata: solana_pubkey::Pubkey,
-                /// Capital provider's token account
+                /// Holds the claimants' part of the slash until each of them claims it
+
+    
+              
+          pub slash_escrow_ata: solana_pubkey::Pubkey,
+                /// Insurance fund of the locking token, required when part of the slash goes there
 
     
               
-          pub slash_claimant_ata: solana_pubkey::Pubkey,
+          pub insurance_fund: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub insurance_fund_ata: Option<solana_pubkey::Pubkey>,
           
               
           pub token_program: solana_pubkey::Pubkey,
@@ -55,20 +70,24 @@ impl FinalizeSlashReqHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: FinalizeSlashReqHandlerInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(9+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
-            self.agent,
+            self.resolver,
             true
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.vault,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.slash_request,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.config,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.locking_token_mint,
             false
           ));
@@ -77,9 +96,31 @@ impl FinalizeSlashReqHandler {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.slash_claimant_ata,
+            self.slash_escrow_ata,
             false
           ));
+                              if let Some(insurance_fund) = self.insurance_fund {
+              accounts.push(solana_instruction::AccountMeta::new(
+                insurance_fund,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                insurance_fund_ata,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false
@@ -147,23 +188,29 @@ impl FinalizeSlashReqHandlerInstructionArgs {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` agent
+                      ///   0. `[writable, signer]` resolver
                 ///   1. `[writable]` vault
-          ///   2. `[]` config
-          ///   3. `[]` locking_token_mint
-                ///   4. `[writable]` vault_token_ata
-                ///   5. `[writable]` slash_claimant_ata
-                ///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   7. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   2. `[writable]` slash_request
+          ///   3. `[]` config
+                ///   4. `[writable]` locking_token_mint
+                ///   5. `[writable]` vault_token_ata
+                ///   6. `[writable]` slash_escrow_ata
+                      ///   7. `[writable, optional]` insurance_fund
+                      ///   8. `[writable, optional]` insurance_fund_ata
+                ///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   10. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   11. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct FinalizeSlashReqHandlerBuilder {
-            agent: Option<solana_pubkey::Pubkey>,
+            resolver: Option<solana_pubkey::Pubkey>,
                 vault: Option<solana_pubkey::Pubkey>,
+                slash_request: Option<solana_pubkey::Pubkey>,
                 config: Option<solana_pubkey::Pubkey>,
                 locking_token_mint: Option<solana_pubkey::Pubkey>,
                 vault_token_ata: Option<solana_pubkey::Pubkey>,
-                slash_claimant_ata: Option<solana_pubkey::Pubkey>,
+                slash_escrow_ata: Option<solana_pubkey::Pubkey>,
+                insurance_fund: Option<solana_pubkey::Pubkey>,
+                insurance_fund_ata: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
@@ -176,15 +223,22 @@ impl FinalizeSlashReqHandlerBuilder {
   pub fn new() -> Self {
     Self::default()
   }
-            #[inline(always)]
-    pub fn agent(&mut self, agent: solana_pubkey::Pubkey) -> &mut Self {
-                        self.agent = Some(agent);
+            /// The agent, or the admin once the node operator appealed
+#[inline(always)]
+    pub fn resolver(&mut self, resolver: solana_pubkey::Pubkey) -> &mut Self {
+                        self.resolver = Some(resolver);
                     self
     }
             #[inline(always)]
     pub fn vault(&mut self, vault: solana_pubkey::Pubkey) -> &mut Self {
                         self.vault = Some(vault);
                     self
+    }
+            /// The vault's open slash request, kept as a record once resolved
+#[inline(always)]
+    pub fn slash_request(&mut self, slash_request: solana_pubkey::Pubkey) -> &mut Self {
+                        self.slash_request = Some(slash_request);
+                    self
     }
             #[inline(always)]
     pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
@@ -203,10 +257,23 @@ impl FinalizeSlashReqHandlerBuilder {
                         self.vault_token_ata = Some(vault_token_ata);
                     self
     }
-            /// Capital provider's token account
+            /// Holds the claimants' part of the slash until each of them claims it
+#[inline(always)]
+    pub fn slash_escrow_ata(&mut self, slash_escrow_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.slash_escrow_ata = Some(slash_escrow_ata);
+                    self
+    }
+            /// `[optional account]`
+/// Insurance fund of the locking token, required when part of the slash goes there
+#[inline(always)]
+    pub fn insurance_fund(&mut self, insurance_fund: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.insurance_fund = insurance_fund;
+                    self
+    }
+            /// `[optional account]`
 #[inline(always)]
-    pub fn slash_claimant_ata(&mut self, slash_claimant_ata: solana_pubkey::Pubkey) -> &mut Self {
-                        self.slash_claimant_ata = Some(slash_claimant_ata);
+    pub fn insurance_fund_ata(&mut self, insurance_fund_ata: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.insurance_fund_ata = insurance_fund_ata;
                     self
     }
             /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
@@ -252,12 +319,15 @@ impl FinalizeSlashReqHandlerBuilder {
   #[allow(clippy::clone_on_copy)]
   pub fn instruction(&self) -> solana_instruction::Instruction {
     let accounts = FinalizeSlashReqHandler {
-                              agent: self.agent.expect("agent is not set"),
+                              resolver: self.resolver.expect("resolver is not set"),
                                         vault: self.vault.expect("vault is not set"),
+                                        slash_request: self.slash_request.expect("slash_request is not set"),
                                         config: self.config.expect("config is not set"),
                                         locking_token_mint: self.locking_token_mint.expect("locking_token_mint is not set"),
                                         vault_token_ata: self.vault_token_ata.expect("vault_token_ata is not set"),
-                                        slash_claimant_ata: self.slash_claimant_ata.expect("slash_claimant_ata is not set"),
+                                        slash_escrow_ata: self.slash_escrow_ata.expect("slash_escrow_ata is not set"),
+                                        insurance_fund: self.insurance_fund,
+                                        insurance_fund_ata: self.insurance_fund_ata,
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -273,12 +343,19 @@ impl FinalizeSlashReqHandlerBuilder {
 
   /// `finalize_slash_req_handler` CPI accounts.
   pub struct FinalizeSlashReqHandlerCpiAccounts<'a, 'b> {
-          
+                  /// The agent, or the admin once the node operator appealed
+
+      
                     
-              pub agent: &'b solana_account_info::AccountInfo<'a>,
+              pub resolver: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub vault: &'b solana_account_info::AccountInfo<'a>,
+                        /// The vault's open slash request, kept as a record once resolved
+
+      
+                    
+              pub slash_request: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub config: &'b solana_account_info::AccountInfo<'a>,
@@ -292,11 +369,19 @@ impl FinalizeSlashReqHandlerBuilder {
       
                     
               pub vault_token_ata: &'b solana_account_info::AccountInfo<'a>,
-                        /// Capital provider's token account
+                        /// Holds the claimants' part of the slash until each of them claims it
+
+      
+                    
+              pub slash_escrow_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// Insurance fund of the locking token, required when part of the slash goes there
 
       
                     
-              pub slash_claimant_ata: &'b solana_account_info::AccountInfo<'a>,
+              pub insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -312,12 +397,19 @@ impl FinalizeSlashReqHandlerBuilder {
 pub struct FinalizeSlashReqHandlerCpi<'a, 'b> {
   /// The program to invoke.
   pub __program: &'b solana_account_info::AccountInfo<'a>,
-      
+            /// The agent, or the admin once the node operator appealed
+
+    
               
-          pub agent: &'b solana_account_info::AccountInfo<'a>,
+          pub resolver: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub vault: &'b solana_account_info::AccountInfo<'a>,
+                /// The vault's open slash request, kept as a record once resolved
+
+    
+              
+          pub slash_request: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub config: &'b solana_account_info::AccountInfo<'a>,
@@ -331,11 +423,19 @@ pub struct FinalizeSlashReqHandlerCpi<'a, 'b> {
     
               
           pub vault_token_ata: &'b solana_account_info::AccountInfo<'a>,
-                /// Capital provider's token account
+                /// Holds the claimants' part of the slash until each of them claims it
 
     
               
-          pub slash_claimant_ata: &'b solana_account_info::AccountInfo<'a>,
+          pub slash_escrow_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// Insurance fund of the locking token, required when part of the slash goes there
+
+    
+              
+          pub insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -357,12 +457,15 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpi<'a, 'b> {
       ) -> Self {
     Self {
       __program: program,
-              agent: accounts.agent,
+              resolver: accounts.resolver,
               vault: accounts.vault,
+              slash_request: accounts.slash_request,
               config: accounts.config,
               locking_token_mint: accounts.locking_token_mint,
               vault_token_ata: accounts.vault_token_ata,
-              slash_claimant_ata: accounts.slash_claimant_ata,
+              slash_escrow_ata: accounts.slash_escrow_ata,
+              insurance_fund: accounts.insurance_fund,
+              insurance_fund_ata: accounts.insurance_fund_ata,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
@@ -389,20 +492,24 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(9+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(12+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
-            *self.agent.key,
+            *self.resolver.key,
             true
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.vault.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.slash_request.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.config.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.locking_token_mint.key,
             false
           ));
@@ -411,9 +518,31 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpi<'a, 'b> {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.slash_claimant_ata.key,
+            *self.slash_escrow_ata.key,
             false
           ));
+                              if let Some(insurance_fund) = self.insurance_fund {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *insurance_fund.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
+                              if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *insurance_fund_ata.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::CAPITAL_PROGRAM_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false
@@ -442,14 +571,21 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
-                  account_infos.push(self.agent.clone());
+                  account_infos.push(self.resolver.clone());
                         account_infos.push(self.vault.clone());
+                        account_infos.push(self.slash_request.clone());
                         account_infos.push(self.config.clone());
                         account_infos.push(self.locking_token_mint.clone());
                         account_infos.push(self.vault_token_ata.clone());
-                        account_infos.push(self.slash_claimant_ata.clone());
+                        account_infos.push(self.slash_escrow_ata.clone());
+                  if let Some(insurance_fund) = self.insurance_fund {
+          account_infos.push(insurance_fund.clone());
+        }
+                  if let Some(insurance_fund_ata) = self.insurance_fund_ata {
+          account_infos.push(insurance_fund_ata.clone());
+        }
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
@@ -467,15 +603,18 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-                      ///   0. `[writable, signer]` agent
+                      ///   0. `[writable, signer]` resolver
                 ///   1. `[writable]` vault
-          ///   2. `[]` config
-          ///   3. `[]` locking_token_mint
-                ///   4. `[writable]` vault_token_ata
-                ///   5. `[writable]` slash_claimant_ata
-          ///   6. `[]` token_program
-          ///   7. `[]` associated_token_program
-          ///   8. `[]` system_program
+                ///   2. `[writable]` slash_request
+          ///   3. `[]` config
+                ///   4. `[writable]` locking_token_mint
+                ///   5. `[writable]` vault_token_ata
+                ///   6. `[writable]` slash_escrow_ata
+                      ///   7. `[writable, optional]` insurance_fund
+                      ///   8. `[writable, optional]` insurance_fund_ata
+          ///   9. `[]` token_program
+          ///   10. `[]` associated_token_program
+          ///   11. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct FinalizeSlashReqHandlerCpiBuilder<'a, 'b> {
   instruction: Box<FinalizeSlashReqHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -485,12 +624,15 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpiBuilder<'a, 'b> {
   pub fn new(program: &'b solana_account_info::AccountInfo<'a>) -> Self {
     let instruction = Box::new(FinalizeSlashReqHandlerCpiBuilderInstruction {
       __program: program,
-              agent: None,
+              resolver: None,
               vault: None,
+              slash_request: None,
               config: None,
               locking_token_mint: None,
               vault_token_ata: None,
-              slash_claimant_ata: None,
+              slash_escrow_ata: None,
+              insurance_fund: None,
+              insurance_fund_ata: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
@@ -500,15 +642,22 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpiBuilder<'a, 'b> {
     });
     Self { instruction }
   }
-      #[inline(always)]
-    pub fn agent(&mut self, agent: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.agent = Some(agent);
+      /// The agent, or the admin once the node operator appealed
+#[inline(always)]
+    pub fn resolver(&mut self, resolver: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.resolver = Some(resolver);
                     self
     }
       #[inline(always)]
     pub fn vault(&mut self, vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.vault = Some(vault);
                     self
+    }
+      /// The vault's open slash request, kept as a record once resolved
+#[inline(always)]
+    pub fn slash_request(&mut self, slash_request: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.slash_request = Some(slash_request);
+                    self
     }
       #[inline(always)]
     pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
@@ -527,10 +676,23 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpiBuilder<'a, 'b> {
                         self.instruction.vault_token_ata = Some(vault_token_ata);
                     self
     }
-      /// Capital provider's token account
+      /// Holds the claimants' part of the slash until each of them claims it
 #[inline(always)]
-    pub fn slash_claimant_ata(&mut self, slash_claimant_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.slash_claimant_ata = Some(slash_claimant_ata);
+    pub fn slash_escrow_ata(&mut self, slash_escrow_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.slash_escrow_ata = Some(slash_escrow_ata);
+                    self
+    }
+      /// Insurance fund of the locking token, required when part of the slash goes there
+/// `[optional account]`
+#[inline(always)]
+    pub fn insurance_fund(&mut self, insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.insurance_fund = insurance_fund;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn insurance_fund_ata(&mut self, insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.insurance_fund_ata = insurance_fund_ata;
                     self
     }
       #[inline(always)]
@@ -587,17 +749,23 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpiBuilder<'a, 'b> {
         let instruction = FinalizeSlashReqHandlerCpi {
         __program: self.instruction.__program,
                   
-          agent: self.instruction.agent.expect("agent is not set"),
+          resolver: self.instruction.resolver.expect("resolver is not set"),
                   
           vault: self.instruction.vault.expect("vault is not set"),
                   
+          slash_request: self.instruction.slash_request.expect("slash_request is not set"),
+                  
           config: self.instruction.config.expect("config is not set"),
                   
           locking_token_mint: self.instruction.locking_token_mint.expect("locking_token_mint is not set"),
                   
           vault_token_ata: self.instruction.vault_token_ata.expect("vault_token_ata is not set"),
                   
-          slash_claimant_ata: self.instruction.slash_claimant_ata.expect("slash_claimant_ata is not set"),
+          slash_escrow_ata: self.instruction.slash_escrow_ata.expect("slash_escrow_ata is not set"),
+                  
+          insurance_fund: self.instruction.insurance_fund,
+                  
+          insurance_fund_ata: self.instruction.insurance_fund_ata,
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
@@ -613,12 +781,15 @@ impl<'a, 'b> FinalizeSlashReqHandlerCpiBuilder<'a, 'b> {
 #[derive(Clone, Debug)]
 struct FinalizeSlashReqHandlerCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
-            agent: Option<&'b solana_account_info::AccountInfo<'a>>,
+            resolver: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+                slash_request: Option<&'b solana_account_info::AccountInfo<'a>>,
                 config: Option<&'b solana_account_info::AccountInfo<'a>>,
                 locking_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 vault_token_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                slash_claimant_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                slash_escrow_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                insurance_fund: Option<&'b solana_account_info::AccountInfo<'a>>,
+                insurance_fund_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -15,6 +15,7 @@ pub const INIT_CAPITAL_PROGRAM_HANDLER_DISCRIMINATOR: [u8; 8] = [248, 187, 57, 8
 #[derive(Debug)]
 pub struct InitCapitalProgramHandler {
             /// Global authority configuration account
+/// re-initialization is not possible because of the init constraint
 
     
               
@@ -84,13 +85,13 @@ impl InitCapitalProgramHandler {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct InitCapitalProgramHandlerInstructionData {
             discriminator: [u8; 8],
-                                    }
+                              }
 
 impl InitCapitalProgramHandlerInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: [248, 187, 57, 89, 38, 219, 66, 222],
-                                                                                        }
+                                                                          }
   }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -109,7 +110,6 @@ impl Default for InitCapitalProgramHandlerInstructionData {
  pub struct InitCapitalProgramHandlerInstructionArgs {
                   pub agent: Pubkey,
                 pub early_unlock_fee: u64,
-                pub dispute_window: i64,
                 pub max_lock_duration: i64,
                 pub min_lock_duration: i64,
       }
@@ -139,7 +139,6 @@ pub struct InitCapitalProgramHandlerBuilder {
                 system_program: Option<solana_pubkey::Pubkey>,
                         agent: Option<Pubkey>,
                 early_unlock_fee: Option<u64>,
-                dispute_window: Option<i64>,
                 max_lock_duration: Option<i64>,
                 min_lock_duration: Option<i64>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -150,6 +149,7 @@ impl InitCapitalProgramHandlerBuilder {
     Self::default()
   }
             /// Global authority configuration account
+/// re-initialization is not possible because of the init constraint
 #[inline(always)]
     pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
                         self.config = Some(config);
@@ -191,11 +191,6 @@ impl InitCapitalProgramHandlerBuilder {
         self
       }
                 #[inline(always)]
-      pub fn dispute_window(&mut self, dispute_window: i64) -> &mut Self {
-        self.dispute_window = Some(dispute_window);
-        self
-      }
-                #[inline(always)]
       pub fn max_lock_duration(&mut self, max_lock_duration: i64) -> &mut Self {
         self.max_lock_duration = Some(max_lock_duration);
         self
@@ -229,7 +224,6 @@ impl InitCapitalProgramHandlerBuilder {
           let args = InitCapitalProgramHandlerInstructionArgs {
                                                               agent: self.agent.clone().expect("agent is not set"),
                                                                   early_unlock_fee: self.early_unlock_fee.clone().expect("early_unlock_fee is not set"),
-                                                                  dispute_window: self.dispute_window.clone().expect("dispute_window is not set"),
                                                                   max_lock_duration: self.max_lock_duration.clone().expect("max_lock_duration is not set"),
                                                                   min_lock_duration: self.min_lock_duration.clone().expect("min_lock_duration is not set"),
                                     };
@@ -241,6 +235,7 @@ impl InitCapitalProgramHandlerBuilder {
   /// `init_capital_program_handler` CPI accounts.
   pub struct InitCapitalProgramHandlerCpiAccounts<'a, 'b> {
                   /// Global authority configuration account
+/// re-initialization is not possible because of the init constraint
 
       
                     
@@ -270,6 +265,7 @@ pub struct InitCapitalProgramHandlerCpi<'a, 'b> {
   /// The program to invoke.
   pub __program: &'b solana_account_info::AccountInfo<'a>,
             /// Global authority configuration account
+/// re-initialization is not possible because of the init constraint
 
     
               
@@ -411,7 +407,6 @@ impl<'a, 'b> InitCapitalProgramHandlerCpiBuilder<'a, 'b> {
               system_program: None,
                                             agent: None,
                                 early_unlock_fee: None,
-                                dispute_window: None,
                                 max_lock_duration: None,
                                 min_lock_duration: None,
                     __remaining_accounts: Vec::new(),
@@ -419,6 +414,7 @@ impl<'a, 'b> InitCapitalProgramHandlerCpiBuilder<'a, 'b> {
     Self { instruction }
   }
       /// Global authority configuration account
+/// re-initialization is not possible because of the init constraint
 #[inline(always)]
     pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.config = Some(config);
@@ -458,11 +454,6 @@ impl<'a, 'b> InitCapitalProgramHandlerCpiBuilder<'a, 'b> {
         self
       }
                 #[inline(always)]
-      pub fn dispute_window(&mut self, dispute_window: i64) -> &mut Self {
-        self.instruction.dispute_window = Some(dispute_window);
-        self
-      }
-                #[inline(always)]
       pub fn max_lock_duration(&mut self, max_lock_duration: i64) -> &mut Self {
         self.instruction.max_lock_duration = Some(max_lock_duration);
         self
@@ -497,7 +488,6 @@ impl<'a, 'b> InitCapitalProgramHandlerCpiBuilder<'a, 'b> {
           let args = InitCapitalProgramHandlerInstructionArgs {
                                                               agent: self.instruction.agent.clone().expect("agent is not set"),
                                                                   early_unlock_fee: self.instruction.early_unlock_fee.clone().expect("early_unlock_fee is not set"),
-                                                                  dispute_window: self.instruction.dispute_window.clone().expect("dispute_window is not set"),
                                                                   max_lock_duration: self.instruction.max_lock_duration.clone().expect("max_lock_duration is not set"),
                                                                   min_lock_duration: self.instruction.min_lock_duration.clone().expect("min_lock_duration is not set"),
                                     };
@@ -529,7 +519,6 @@ struct InitCapitalProgramHandlerCpiBuilderInstruction<'a, 'b> {
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         agent: Option<Pubkey>,
                 early_unlock_fee: Option<u64>,
-                dispute_window: Option<i64>,
                 max_lock_duration: Option<i64>,
                 min_lock_duration: Option<i64>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -5,27 +5,97 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#accept_admin_handler;
+  pub(crate) mod r#add_beneficiary_handler;
+  pub(crate) mod r#add_reward_stream_handler;
+  pub(crate) mod r#advance_phase_handler;
+  pub(crate) mod r#appeal_slash_req_handler;
+  pub(crate) mod r#challenge_missed_epoch_handler;
   pub(crate) mod r#claim_beneficiary_rewards_handler;
   pub(crate) mod r#claim_investor_rewards_handler;
+  pub(crate) mod r#claim_operator_commission_handler;
+  pub(crate) mod r#claim_slash_payout_handler;
   pub(crate) mod r#close_position_handler;
   pub(crate) mod r#close_vault_handler;
+  pub(crate) mod r#commit_epoch_handler;
   pub(crate) mod r#create_slas_req_handler;
   pub(crate) mod r#create_vault_handler;
+  pub(crate) mod r#deposit_insurance_handler;
   pub(crate) mod r#deposit_rewards_handler;
+  pub(crate) mod r#dismiss_slash_req_handler;
+  pub(crate) mod r#donate_rewards_handler;
+  pub(crate) mod r#early_unlock_position_handler;
   pub(crate) mod r#finalize_slash_req_handler;
   pub(crate) mod r#init_capital_program_handler;
+  pub(crate) mod r#init_fee_accounts_handler;
+  pub(crate) mod r#issue_challenge_handler;
   pub(crate) mod r#open_position_handler;
+  pub(crate) mod r#propose_admin_handler;
+  pub(crate) mod r#register_attester_handler;
+  pub(crate) mod r#remove_attester_handler;
+  pub(crate) mod r#remove_beneficiary_handler;
+  pub(crate) mod r#resize_beneficiary_handler;
+  pub(crate) mod r#respond_challenge_handler;
+  pub(crate) mod r#set_protocol_pause_handler;
+  pub(crate) mod r#set_vault_pause_handler;
+  pub(crate) mod r#slash_failed_challenge_handler;
+  pub(crate) mod r#submit_attestation_handler;
+  pub(crate) mod r#sweep_vault_handler;
+  pub(crate) mod r#transfer_beneficiary_handler;
+  pub(crate) mod r#update_agent_handler;
+  pub(crate) mod r#update_arbiters_handler;
+  pub(crate) mod r#update_guardian_handler;
   pub(crate) mod r#update_position_handler;
+  pub(crate) mod r#update_program_config_handler;
+  pub(crate) mod r#update_reward_distributor_handler;
+  pub(crate) mod r#vote_slash_handler;
+  pub(crate) mod r#withdraw_insurance_handler;
+  pub(crate) mod r#withdraw_treasury_handler;
 
+  pub use self::r#accept_admin_handler::*;
+  pub use self::r#add_beneficiary_handler::*;
+  pub use self::r#add_reward_stream_handler::*;
+  pub use self::r#advance_phase_handler::*;
+  pub use self::r#appeal_slash_req_handler::*;
+  pub use self::r#challenge_missed_epoch_handler::*;
   pub use self::r#claim_beneficiary_rewards_handler::*;
   pub use self::r#claim_investor_rewards_handler::*;
+  pub use self::r#claim_operator_commission_handler::*;
+  pub use self::r#claim_slash_payout_handler::*;
   pub use self::r#close_position_handler::*;
   pub use self::r#close_vault_handler::*;
+  pub use self::r#commit_epoch_handler::*;
   pub use self::r#create_slas_req_handler::*;
   pub use self::r#create_vault_handler::*;
+  pub use self::r#deposit_insurance_handler::*;
   pub use self::r#deposit_rewards_handler::*;
+  pub use self::r#dismiss_slash_req_handler::*;
+  pub use self::r#donate_rewards_handler::*;
+  pub use self::r#early_unlock_position_handler::*;
   pub use self::r#finalize_slash_req_handler::*;
   pub use self::r#init_capital_program_handler::*;
+  pub use self::r#init_fee_accounts_handler::*;
+  pub use self::r#issue_challenge_handler::*;
   pub use self::r#open_position_handler::*;
+  pub use self::r#propose_admin_handler::*;
+  pub use self::r#register_attester_handler::*;
+  pub use self::r#remove_attester_handler::*;
+  pub use self::r#remove_beneficiary_handler::*;
+  pub use self::r#resize_beneficiary_handler::*;
+  pub use self::r#respond_challenge_handler::*;
+  pub use self::r#set_protocol_pause_handler::*;
+  pub use self::r#set_vault_pause_handler::*;
+  pub use self::r#slash_failed_challenge_handler::*;
+  pub use self::r#submit_attestation_handler::*;
+  pub use self::r#sweep_vault_handler::*;
+  pub use self::r#transfer_beneficiary_handler::*;
+  pub use self::r#update_agent_handler::*;
+  pub use self::r#update_arbiters_handler::*;
+  pub use self::r#update_guardian_handler::*;
   pub use self::r#update_position_handler::*;
+  pub use self::r#update_program_config_handler::*;
+  pub use self::r#update_reward_distributor_handler::*;
+  pub use self::r#vote_slash_handler::*;
+  pub use self::r#withdraw_insurance_handler::*;
+  pub use self::r#withdraw_treasury_handler::*;
 
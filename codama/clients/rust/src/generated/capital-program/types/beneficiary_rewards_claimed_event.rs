@@ -16,7 +16,10 @@ pub struct BeneficiaryRewardsClaimedEvent {
 pub beneficiary: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub vault: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub reward_mint: Pubkey,
 pub amount: u64,
+pub unvested_amount: u64,
 pub timestamp: i64,
 }
 
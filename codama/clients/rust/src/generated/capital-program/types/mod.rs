@@ -5,33 +5,121 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
-  pub(crate) mod r#beneficiary;
+  pub(crate) mod r#admin_transfer_proposed_event;
+  pub(crate) mod r#admin_transferred_event;
+  pub(crate) mod r#agent_updated_event;
+  pub(crate) mod r#arbiter_approval;
+  pub(crate) mod r#arbiters_updated_event;
+  pub(crate) mod r#attestation_submitted_event;
+  pub(crate) mod r#attester_registered_event;
+  pub(crate) mod r#attester_removed_event;
+  pub(crate) mod r#beneficiary_added_event;
+  pub(crate) mod r#beneficiary_removed_event;
+  pub(crate) mod r#beneficiary_resized_event;
   pub(crate) mod r#beneficiary_rewards_claimed_event;
+  pub(crate) mod r#beneficiary_transferred_event;
+  pub(crate) mod r#beneficiary_vesting;
+  pub(crate) mod r#challenge_answered_event;
+  pub(crate) mod r#challenge_issued_event;
+  pub(crate) mod r#challenge_slash_terms;
+  pub(crate) mod r#challenge_slashed_event;
+  pub(crate) mod r#challenge_status;
+  pub(crate) mod r#depositor_role;
+  pub(crate) mod r#epoch_committed_event;
+  pub(crate) mod r#epoch_missed_event;
+  pub(crate) mod r#guardian_updated_event;
+  pub(crate) mod r#insurance_claimed_event;
+  pub(crate) mod r#insurance_deposited_event;
+  pub(crate) mod r#insurance_withdrawn_event;
   pub(crate) mod r#key;
+  pub(crate) mod r#operator_commission_claimed_event;
+  pub(crate) mod r#paused_event;
   pub(crate) mod r#position_closed_event;
+  pub(crate) mod r#position_early_unlocked_event;
   pub(crate) mod r#position_opened_event;
   pub(crate) mod r#position_updated_event;
+  pub(crate) mod r#program_config_updated_event;
   pub(crate) mod r#program_initialized_event;
+  pub(crate) mod r#reward_distributor_updated_event;
+  pub(crate) mod r#reward_stream;
+  pub(crate) mod r#reward_stream_added_event;
   pub(crate) mod r#rewards_claimed_event;
   pub(crate) mod r#rewards_deposited_event;
+  pub(crate) mod r#rewards_donated_event;
+  pub(crate) mod r#slash_appealed_event;
+  pub(crate) mod r#slash_claimant;
+  pub(crate) mod r#slash_payout_claimed_event;
+  pub(crate) mod r#slash_req_dismissed_event;
   pub(crate) mod r#slash_req_finalized_event;
   pub(crate) mod r#slash_request_created_event;
+  pub(crate) mod r#slash_share;
+  pub(crate) mod r#slash_status;
+  pub(crate) mod r#slash_vote_cast_event;
+  pub(crate) mod r#treasury_withdrawn_event;
+  pub(crate) mod r#unpaused_event;
   pub(crate) mod r#update_authority;
   pub(crate) mod r#vault_closed_event;
   pub(crate) mod r#vault_created_event;
+  pub(crate) mod r#vault_phase;
+  pub(crate) mod r#vault_phase_changed_event;
+  pub(crate) mod r#vault_swept_event;
 
-  pub use self::r#beneficiary::*;
+  pub use self::r#admin_transfer_proposed_event::*;
+  pub use self::r#admin_transferred_event::*;
+  pub use self::r#agent_updated_event::*;
+  pub use self::r#arbiter_approval::*;
+  pub use self::r#arbiters_updated_event::*;
+  pub use self::r#attestation_submitted_event::*;
+  pub use self::r#attester_registered_event::*;
+  pub use self::r#attester_removed_event::*;
+  pub use self::r#beneficiary_added_event::*;
+  pub use self::r#beneficiary_removed_event::*;
+  pub use self::r#beneficiary_resized_event::*;
   pub use self::r#beneficiary_rewards_claimed_event::*;
+  pub use self::r#beneficiary_transferred_event::*;
+  pub use self::r#beneficiary_vesting::*;
+  pub use self::r#challenge_answered_event::*;
+  pub use self::r#challenge_issued_event::*;
+  pub use self::r#challenge_slash_terms::*;
+  pub use self::r#challenge_slashed_event::*;
+  pub use self::r#challenge_status::*;
+  pub use self::r#depositor_role::*;
+  pub use self::r#epoch_committed_event::*;
+  pub use self::r#epoch_missed_event::*;
+  pub use self::r#guardian_updated_event::*;
+  pub use self::r#insurance_claimed_event::*;
+  pub use self::r#insurance_deposited_event::*;
+  pub use self::r#insurance_withdrawn_event::*;
   pub use self::r#key::*;
+  pub use self::r#operator_commission_claimed_event::*;
+  pub use self::r#paused_event::*;
   pub use self::r#position_closed_event::*;
+  pub use self::r#position_early_unlocked_event::*;
   pub use self::r#position_opened_event::*;
   pub use self::r#position_updated_event::*;
+  pub use self::r#program_config_updated_event::*;
   pub use self::r#program_initialized_event::*;
+  pub use self::r#reward_distributor_updated_event::*;
+  pub use self::r#reward_stream::*;
+  pub use self::r#reward_stream_added_event::*;
   pub use self::r#rewards_claimed_event::*;
   pub use self::r#rewards_deposited_event::*;
+  pub use self::r#rewards_donated_event::*;
+  pub use self::r#slash_appealed_event::*;
+  pub use self::r#slash_claimant::*;
+  pub use self::r#slash_payout_claimed_event::*;
+  pub use self::r#slash_req_dismissed_event::*;
   pub use self::r#slash_req_finalized_event::*;
   pub use self::r#slash_request_created_event::*;
+  pub use self::r#slash_share::*;
+  pub use self::r#slash_status::*;
+  pub use self::r#slash_vote_cast_event::*;
+  pub use self::r#treasury_withdrawn_event::*;
+  pub use self::r#unpaused_event::*;
   pub use self::r#update_authority::*;
   pub use self::r#vault_closed_event::*;
   pub use self::r#vault_created_event::*;
+  pub use self::r#vault_phase::*;
+  pub use self::r#vault_phase_changed_event::*;
+  pub use self::r#vault_swept_event::*;
 
@@ -23,7 +23,6 @@ pub nft_program: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub capital_program: Pubkey,
 pub early_unlock_fee: u64,
-pub dispute_window: i64,
 pub min_lock_duration: i64,
 pub max_lock_duration: i64,
 pub timestamp: i64,
@@ -18,6 +18,8 @@ pub holder: Pubkey,
 pub vault: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub position: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub reward_mint: Pubkey,
 pub amount: u64,
 pub timestamp: i64,
 }
@@ -6,6 +6,7 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::DepositorRole;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -15,8 +16,14 @@ pub struct RewardsDepositedEvent {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub vault: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub agent: Pubkey,
+pub depositor: Pubkey,
+pub depositor_role: DepositorRole,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub reward_mint: Pubkey,
 pub amount: u64,
+pub protocol_fee: u64,
+pub insurance_fee: u64,
+pub stream_duration: i64,
 pub total_rewards: u64,
 pub timestamp: i64,
 }
@@ -11,11 +11,11 @@ use borsh::BorshDeserialize;
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Beneficiary {
+pub struct SlashClaimant {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub address: Pubkey,
 pub share_bps: u16,
-pub total_claimed: u64,
+pub claimed: bool,
 }
 
 
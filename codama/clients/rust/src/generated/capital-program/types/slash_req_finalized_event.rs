@@ -6,6 +6,7 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::SlashStatus;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -13,10 +14,17 @@ use borsh::BorshDeserialize;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlashReqFinalizedEvent {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub claimant: Pubkey,
-#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub vault: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub slash_request: Pubkey,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub resolver: Pubkey,
+pub appealed: bool,
+pub status: SlashStatus,
 pub amount: u64,
+pub bond_slashed: u64,
+pub insurance_amount: u64,
+pub burned_amount: u64,
 pub timestamp: i64,
 }
 
@@ -15,10 +15,18 @@ pub struct SlashRequestCreatedEvent {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub vault: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub agent: Pubkey,
+pub slash_request: Pubkey,
+pub sequence: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
-pub slash_claimant: Pubkey,
+pub agent: Pubkey,
+pub claimant_count: u8,
+pub insurance_bps: u16,
+pub burn_bps: u16,
 pub slash_bps: u16,
+pub requested_amount: u64,
+pub evidence_hash: [u8; 32],
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub attestation: Pubkey,
 pub dispute_start_time: i64,
 pub timestamp: i64,
 }
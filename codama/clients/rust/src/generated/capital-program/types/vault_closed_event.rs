@@ -14,6 +14,7 @@ use borsh::BorshDeserialize;
 pub struct VaultClosedEvent {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub node_operator: Pubkey,
+pub bond_returned: u64,
 pub timestamp: i64,
 }
 
@@ -18,6 +18,7 @@ pub vault: Pubkey,
 pub provider: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub node_operator: Pubkey,
+pub vault_index: u64,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub staking_token: Pubkey,
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
@@ -26,6 +27,7 @@ pub reward_token: Pubkey,
 pub nft_collection: Pubkey,
 pub min_cap: u64,
 pub max_cap: u64,
+pub operator_bond: u64,
 pub lock_phase_start_time: i64,
 pub timestamp: i64,
 }
@@ -34,6 +34,11 @@ pub struct ListAssetHandler {
           
               
           pub system_program: solana_pubkey::Pubkey,
+                /// Appended last to keep the account order of existing clients
+
+    
+              
+          pub config: solana_pubkey::Pubkey,
       }
 
 impl ListAssetHandler {
@@ -43,7 +48,7 @@ impl ListAssetHandler {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: ListAssetHandlerInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(7+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.seller,
             true
@@ -67,6 +72,10 @@ impl ListAssetHandler {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.config,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = ListAssetHandlerInstructionData::new().try_to_vec().unwrap();
@@ -129,6 +138,7 @@ impl ListAssetHandlerInstructionArgs {
                 ///   3. `[writable]` collection
                 ///   4. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
                 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   6. `[]` config
 #[derive(Clone, Debug, Default)]
 pub struct ListAssetHandlerBuilder {
             seller: Option<solana_pubkey::Pubkey>,
@@ -137,6 +147,7 @@ pub struct ListAssetHandlerBuilder {
                 collection: Option<solana_pubkey::Pubkey>,
                 mpl_core_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
+                config: Option<solana_pubkey::Pubkey>,
                         price: Option<u64>,
                 paying_token_mint: Option<Pubkey>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
@@ -178,6 +189,12 @@ impl ListAssetHandlerBuilder {
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            /// Appended last to keep the account order of existing clients
+#[inline(always)]
+    pub fn config(&mut self, config: solana_pubkey::Pubkey) -> &mut Self {
+                        self.config = Some(config);
+                    self
     }
                     #[inline(always)]
       pub fn price(&mut self, price: u64) -> &mut Self {
@@ -210,6 +227,7 @@ impl ListAssetHandlerBuilder {
                                         collection: self.collection.expect("collection is not set"),
                                         mpl_core_program: self.mpl_core_program.unwrap_or(solana_pubkey::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                                        config: self.config.expect("config is not set"),
                       };
           let args = ListAssetHandlerInstructionArgs {
                                                               price: self.price.clone().expect("price is not set"),
@@ -242,6 +260,11 @@ impl ListAssetHandlerBuilder {
                 
                     
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                        /// Appended last to keep the account order of existing clients
+
+      
+                    
+              pub config: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `list_asset_handler` CPI instruction.
@@ -268,6 +291,11 @@ pub struct ListAssetHandlerCpi<'a, 'b> {
           
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                /// Appended last to keep the account order of existing clients
+
+    
+              
+          pub config: &'b solana_account_info::AccountInfo<'a>,
             /// The arguments for the instruction.
     pub __args: ListAssetHandlerInstructionArgs,
   }
@@ -286,6 +314,7 @@ impl<'a, 'b> ListAssetHandlerCpi<'a, 'b> {
               collection: accounts.collection,
               mpl_core_program: accounts.mpl_core_program,
               system_program: accounts.system_program,
+              config: accounts.config,
                     __args: args,
           }
   }
@@ -309,7 +338,7 @@ impl<'a, 'b> ListAssetHandlerCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(6+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(7+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.seller.key,
             true
@@ -333,6 +362,10 @@ impl<'a, 'b> ListAssetHandlerCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.config.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -350,7 +383,7 @@ impl<'a, 'b> ListAssetHandlerCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.seller.clone());
                         account_infos.push(self.asset.clone());
@@ -358,6 +391,7 @@ impl<'a, 'b> ListAssetHandlerCpi<'a, 'b> {
                         account_infos.push(self.collection.clone());
                         account_infos.push(self.mpl_core_program.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.config.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -378,6 +412,7 @@ impl<'a, 'b> ListAssetHandlerCpi<'a, 'b> {
                 ///   3. `[writable]` collection
           ///   4. `[]` mpl_core_program
           ///   5. `[]` system_program
+          ///   6. `[]` config
 #[derive(Clone, Debug)]
 pub struct ListAssetHandlerCpiBuilder<'a, 'b> {
   instruction: Box<ListAssetHandlerCpiBuilderInstruction<'a, 'b>>,
@@ -393,6 +428,7 @@ impl<'a, 'b> ListAssetHandlerCpiBuilder<'a, 'b> {
               collection: None,
               mpl_core_program: None,
               system_program: None,
+              config: None,
                                             price: None,
                                 paying_token_mint: None,
                     __remaining_accounts: Vec::new(),
@@ -429,6 +465,12 @@ impl<'a, 'b> ListAssetHandlerCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+      /// Appended last to keep the account order of existing clients
+#[inline(always)]
+    pub fn config(&mut self, config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.config = Some(config);
+                    self
     }
                     #[inline(always)]
       pub fn price(&mut self, price: u64) -> &mut Self {
@@ -480,6 +522,8 @@ impl<'a, 'b> ListAssetHandlerCpiBuilder<'a, 'b> {
           mpl_core_program: self.instruction.mpl_core_program.expect("mpl_core_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+                  
+          config: self.instruction.config.expect("config is not set"),
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -495,6 +539,7 @@ struct ListAssetHandlerCpiBuilderInstruction<'a, 'b> {
                 collection: Option<&'b solana_account_info::AccountInfo<'a>>,
                 mpl_core_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                config: Option<&'b solana_account_info::AccountInfo<'a>>,
                         price: Option<u64>,
                 paying_token_mint: Option<Pubkey>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -5,17 +5,23 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#accept_admin_handler;
   pub(crate) mod r#burn_asset_handler;
   pub(crate) mod r#create_core_asset_handler;
   pub(crate) mod r#create_vault_collection_handler;
   pub(crate) mod r#init_nft_program_handler;
   pub(crate) mod r#list_asset_handler;
+  pub(crate) mod r#propose_admin_handler;
+  pub(crate) mod r#set_marketplace_pause_handler;
   pub(crate) mod r#unlist_asset_handler;
 
+  pub use self::r#accept_admin_handler::*;
   pub use self::r#burn_asset_handler::*;
   pub use self::r#create_core_asset_handler::*;
   pub use self::r#create_vault_collection_handler::*;
   pub use self::r#init_nft_program_handler::*;
   pub use self::r#list_asset_handler::*;
+  pub use self::r#propose_admin_handler::*;
+  pub use self::r#set_marketplace_pause_handler::*;
   pub use self::r#unlist_asset_handler::*;
 
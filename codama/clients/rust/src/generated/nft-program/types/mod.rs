@@ -5,20 +5,26 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#admin_transfer_proposed_event;
+  pub(crate) mod r#admin_transferred_event;
   pub(crate) mod r#asset_burned_event;
   pub(crate) mod r#asset_minted_event;
   pub(crate) mod r#collection_created_event;
   pub(crate) mod r#key;
+  pub(crate) mod r#marketplace_pause_updated_event;
   pub(crate) mod r#offer_cancelled_event;
   pub(crate) mod r#offer_created_event;
   pub(crate) mod r#offer_purchased_event;
   pub(crate) mod r#program_initialized_event;
   pub(crate) mod r#update_authority;
 
+  pub use self::r#admin_transfer_proposed_event::*;
+  pub use self::r#admin_transferred_event::*;
   pub use self::r#asset_burned_event::*;
   pub use self::r#asset_minted_event::*;
   pub use self::r#collection_created_event::*;
   pub use self::r#key::*;
+  pub use self::r#marketplace_pause_updated_event::*;
   pub use self::r#offer_cancelled_event::*;
   pub use self::r#offer_created_event::*;
   pub use self::r#offer_purchased_event::*;
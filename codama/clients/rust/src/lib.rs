@@ -1,6 +1,7 @@
 #![allow(unused_imports)]
 
 pub(crate) mod generated;
+pub mod pdas;
 
 pub use generated::capital_program::*;
 
@@ -0,0 +1,90 @@
+//! Hand-written PDA helpers for the capital program. Codama does not emit
+//! seeds for these accounts, so they are kept in sync with the program here.
+
+use solana_pubkey::Pubkey;
+
+use crate::CAPITAL_PROGRAM_ID;
+
+pub fn find_authority_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"Config"], &CAPITAL_PROGRAM_ID)
+}
+
+pub fn find_treasury_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"Treasury"], &CAPITAL_PROGRAM_ID)
+}
+
+pub fn find_insurance_fund_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"Insurance", mint.as_ref()], &CAPITAL_PROGRAM_ID)
+}
+
+pub fn find_operator_registry_address(node_operator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"Operator", node_operator.as_ref()], &CAPITAL_PROGRAM_ID)
+}
+
+/// `vault_index` is the operator registry's `vault_count` at the time the vault was created.
+pub fn find_vault_address(node_operator: &Pubkey, vault_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"Vault",
+            node_operator.as_ref(),
+            vault_index.to_le_bytes().as_ref(),
+        ],
+        &CAPITAL_PROGRAM_ID,
+    )
+}
+
+pub fn find_position_address(asset: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"Position", asset.as_ref()], &CAPITAL_PROGRAM_ID)
+}
+
+pub fn find_beneficiary_address(vault: &Pubkey, beneficiary: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"Beneficiary", vault.as_ref(), beneficiary.as_ref()],
+        &CAPITAL_PROGRAM_ID,
+    )
+}
+
+pub fn find_slash_request_address(vault: &Pubkey, sequence: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"SlashRequest",
+            vault.as_ref(),
+            sequence.to_le_bytes().as_ref(),
+        ],
+        &CAPITAL_PROGRAM_ID,
+    )
+}
+
+pub fn find_attester_address(key: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"Attester", key.as_ref()], &CAPITAL_PROGRAM_ID)
+}
+
+pub fn find_attestation_address(vault: &Pubkey, attester: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"Attestation",
+            vault.as_ref(),
+            attester.as_ref(),
+            epoch.to_le_bytes().as_ref(),
+        ],
+        &CAPITAL_PROGRAM_ID,
+    )
+}
+
+pub fn find_epoch_commitment_address(vault: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"Commitment", vault.as_ref(), epoch.to_le_bytes().as_ref()],
+        &CAPITAL_PROGRAM_ID,
+    )
+}
+
+pub fn find_challenge_address(commitment: &Pubkey, leaf_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"Challenge",
+            commitment.as_ref(),
+            leaf_index.to_le_bytes().as_ref(),
+        ],
+        &CAPITAL_PROGRAM_ID,
+    )
+}
@@ -17,12 +17,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -55,20 +61,44 @@ export type AuthorityConfig = {
   discriminator: ReadonlyUint8Array;
   nftProgram: Address;
   admin: Address;
+  pendingAdmin: Address;
   agent: Address;
+  guardian: Address;
+  arbiters: Array<Address>;
+  arbiterCount: number;
+  arbiterThreshold: number;
+  treasury: Address;
   earlyUnlockFee: bigint;
+  protocolFeeBps: number;
+  minOperatorBondBps: number;
+  insuranceFeeBps: number;
+  insuranceCoverageBps: number;
   minLockDuration: bigint;
   maxLockDuration: bigint;
+  paused: boolean;
+  treasuryBump: number;
   bump: number;
 };
 
 export type AuthorityConfigArgs = {
   nftProgram: Address;
   admin: Address;
+  pendingAdmin: Address;
   agent: Address;
+  guardian: Address;
+  arbiters: Array<Address>;
+  arbiterCount: number;
+  arbiterThreshold: number;
+  treasury: Address;
   earlyUnlockFee: number | bigint;
+  protocolFeeBps: number;
+  minOperatorBondBps: number;
+  insuranceFeeBps: number;
+  insuranceCoverageBps: number;
   minLockDuration: number | bigint;
   maxLockDuration: number | bigint;
+  paused: boolean;
+  treasuryBump: number;
   bump: number;
 };
 
@@ -79,10 +109,22 @@ export function getAuthorityConfigEncoder(): FixedSizeEncoder<AuthorityConfigArg
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["nftProgram", getAddressEncoder()],
       ["admin", getAddressEncoder()],
+      ["pendingAdmin", getAddressEncoder()],
       ["agent", getAddressEncoder()],
+      ["guardian", getAddressEncoder()],
+      ["arbiters", getArrayEncoder(getAddressEncoder(), { size: 7 })],
+      ["arbiterCount", getU8Encoder()],
+      ["arbiterThreshold", getU8Encoder()],
+      ["treasury", getAddressEncoder()],
       ["earlyUnlockFee", getU64Encoder()],
+      ["protocolFeeBps", getU16Encoder()],
+      ["minOperatorBondBps", getU16Encoder()],
+      ["insuranceFeeBps", getU16Encoder()],
+      ["insuranceCoverageBps", getU16Encoder()],
       ["minLockDuration", getI64Encoder()],
       ["maxLockDuration", getI64Encoder()],
+      ["paused", getBooleanEncoder()],
+      ["treasuryBump", getU8Encoder()],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: AUTHORITY_CONFIG_DISCRIMINATOR }),
@@ -95,10 +137,22 @@ export function getAuthorityConfigDecoder(): FixedSizeDecoder<AuthorityConfig> {
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["nftProgram", getAddressDecoder()],
     ["admin", getAddressDecoder()],
+    ["pendingAdmin", getAddressDecoder()],
     ["agent", getAddressDecoder()],
+    ["guardian", getAddressDecoder()],
+    ["arbiters", getArrayDecoder(getAddressDecoder(), { size: 7 })],
+    ["arbiterCount", getU8Decoder()],
+    ["arbiterThreshold", getU8Decoder()],
+    ["treasury", getAddressDecoder()],
     ["earlyUnlockFee", getU64Decoder()],
+    ["protocolFeeBps", getU16Decoder()],
+    ["minOperatorBondBps", getU16Decoder()],
+    ["insuranceFeeBps", getU16Decoder()],
+    ["insuranceCoverageBps", getU16Decoder()],
     ["minLockDuration", getI64Decoder()],
     ["maxLockDuration", getI64Decoder()],
+    ["paused", getBooleanDecoder()],
+    ["treasuryBump", getU8Decoder()],
     ["bump", getU8Decoder()],
   ]);
 }
@@ -175,5 +229,5 @@ export async function fetchAllMaybeAuthorityConfig(
 }
 
 export function getAuthorityConfigSize(): number {
-  return 129;
+  return 461;
 }
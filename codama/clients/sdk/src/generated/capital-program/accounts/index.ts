@@ -6,8 +6,16 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./attestation";
+export * from "./attester";
 export * from "./authorityConfig";
 export * from "./baseAssetV1";
+export * from "./beneficiaryAccount";
+export * from "./challenge";
+export * from "./epochCommitment";
+export * from "./insuranceFund";
 export * from "./nFTConfig";
+export * from "./operatorRegistry";
 export * from "./position";
+export * from "./slashRequest";
 export * from "./vault";
@@ -17,6 +17,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -52,6 +54,9 @@ export type NFTConfig = {
   capitalProgram: Address;
   authority: Address;
   admin: Address;
+  pendingAdmin: Address;
+  paused: boolean;
+  capitalPaused: boolean;
   bump: number;
 };
 
@@ -59,6 +64,9 @@ export type NFTConfigArgs = {
   capitalProgram: Address;
   authority: Address;
   admin: Address;
+  pendingAdmin: Address;
+  paused: boolean;
+  capitalPaused: boolean;
   bump: number;
 };
 
@@ -70,6 +78,9 @@ export function getNFTConfigEncoder(): FixedSizeEncoder<NFTConfigArgs> {
       ["capitalProgram", getAddressEncoder()],
       ["authority", getAddressEncoder()],
       ["admin", getAddressEncoder()],
+      ["pendingAdmin", getAddressEncoder()],
+      ["paused", getBooleanEncoder()],
+      ["capitalPaused", getBooleanEncoder()],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: N_F_T_CONFIG_DISCRIMINATOR }),
@@ -83,6 +94,9 @@ export function getNFTConfigDecoder(): FixedSizeDecoder<NFTConfig> {
     ["capitalProgram", getAddressDecoder()],
     ["authority", getAddressDecoder()],
     ["admin", getAddressDecoder()],
+    ["pendingAdmin", getAddressDecoder()],
+    ["paused", getBooleanDecoder()],
+    ["capitalPaused", getBooleanDecoder()],
     ["bump", getU8Decoder()],
   ]);
 }
@@ -146,5 +160,5 @@ export async function fetchAllMaybeNFTConfig(
 }
 
 export function getNFTConfigSize(): number {
-  return 105;
+  return 139;
 }
@@ -17,10 +17,14 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -52,7 +56,8 @@ export type Position = {
   vault: Address;
   asset: Address;
   totalValueLocked: bigint;
-  totalRewardsClaimed: bigint;
+  rewardsClaimed: Array<bigint>;
+  rewardDebts: Array<bigint>;
   bump: number;
 };
 
@@ -60,7 +65,8 @@ export type PositionArgs = {
   vault: Address;
   asset: Address;
   totalValueLocked: number | bigint;
-  totalRewardsClaimed: number | bigint;
+  rewardsClaimed: Array<number | bigint>;
+  rewardDebts: Array<number | bigint>;
   bump: number;
 };
 
@@ -72,7 +78,8 @@ export function getPositionEncoder(): FixedSizeEncoder<PositionArgs> {
       ["vault", getAddressEncoder()],
       ["asset", getAddressEncoder()],
       ["totalValueLocked", getU64Encoder()],
-      ["totalRewardsClaimed", getU64Encoder()],
+      ["rewardsClaimed", getArrayEncoder(getU64Encoder(), { size: 4 })],
+      ["rewardDebts", getArrayEncoder(getU128Encoder(), { size: 4 })],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: POSITION_DISCRIMINATOR }),
@@ -86,7 +93,8 @@ export function getPositionDecoder(): FixedSizeDecoder<Position> {
     ["vault", getAddressDecoder()],
     ["asset", getAddressDecoder()],
     ["totalValueLocked", getU64Decoder()],
-    ["totalRewardsClaimed", getU64Decoder()],
+    ["rewardsClaimed", getArrayDecoder(getU64Decoder(), { size: 4 })],
+    ["rewardDebts", getArrayDecoder(getU128Decoder(), { size: 4 })],
     ["bump", getU8Decoder()],
   ]);
 }
@@ -150,5 +158,5 @@ export async function fetchAllMaybePosition(
 }
 
 export function getPositionSize(): number {
-  return 89;
+  return 177;
 }
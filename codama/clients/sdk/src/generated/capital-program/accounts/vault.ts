@@ -29,6 +29,8 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -47,10 +49,14 @@ import {
   type ReadonlyUint8Array,
 } from "gill";
 import {
-  getBeneficiaryDecoder,
-  getBeneficiaryEncoder,
-  type Beneficiary,
-  type BeneficiaryArgs,
+  getRewardStreamDecoder,
+  getRewardStreamEncoder,
+  getVaultPhaseDecoder,
+  getVaultPhaseEncoder,
+  type RewardStream,
+  type RewardStreamArgs,
+  type VaultPhase,
+  type VaultPhaseArgs,
 } from "../types";
 
 export const VAULT_DISCRIMINATOR = new Uint8Array([
@@ -64,49 +70,61 @@ export function getVaultDiscriminatorBytes() {
 export type Vault = {
   discriminator: ReadonlyUint8Array;
   lockingTokenMint: Address;
-  rewardTokenMint: Address;
+  rewardStreams: Array<RewardStream>;
   minCap: bigint;
   maxCap: bigint;
   minLockAmount: bigint;
-  totalRewardsDeposited: bigint;
   totalCapitalCollected: bigint;
   capitalAfterSlashing: bigint;
-  beneficiaries: Array<Beneficiary>;
+  operatorBond: bigint;
+  insuranceClaimed: bigint;
+  capitalWithdrawn: bigint;
+  challengeSlashed: bigint;
+  beneficiaryCount: number;
+  totalBeneficiaryBps: number;
   investorBps: number;
+  operatorCommissionBps: number;
   maxSlashBps: number;
   nftCollection: Address;
   rewardDistributor: Address;
   nodeOperator: Address;
   lockPhaseStartAt: bigint;
   lockPhaseDuration: bigint;
-  isDisputeActive: boolean;
-  disputeStartTime: bigint;
-  pendingSlashAmount: bigint;
-  slashClaimant: Address;
+  vaultIndex: bigint;
+  phase: VaultPhase;
+  paused: boolean;
+  swept: boolean;
+  slashRequestCount: bigint;
   bump: number;
 };
 
 export type VaultArgs = {
   lockingTokenMint: Address;
-  rewardTokenMint: Address;
+  rewardStreams: Array<RewardStreamArgs>;
   minCap: number | bigint;
   maxCap: number | bigint;
   minLockAmount: number | bigint;
-  totalRewardsDeposited: number | bigint;
   totalCapitalCollected: number | bigint;
   capitalAfterSlashing: number | bigint;
-  beneficiaries: Array<BeneficiaryArgs>;
+  operatorBond: number | bigint;
+  insuranceClaimed: number | bigint;
+  capitalWithdrawn: number | bigint;
+  challengeSlashed: number | bigint;
+  beneficiaryCount: number;
+  totalBeneficiaryBps: number;
   investorBps: number;
+  operatorCommissionBps: number;
   maxSlashBps: number;
   nftCollection: Address;
   rewardDistributor: Address;
   nodeOperator: Address;
   lockPhaseStartAt: number | bigint;
   lockPhaseDuration: number | bigint;
-  isDisputeActive: boolean;
-  disputeStartTime: number | bigint;
-  pendingSlashAmount: number | bigint;
-  slashClaimant: Address;
+  vaultIndex: number | bigint;
+  phase: VaultPhaseArgs;
+  paused: boolean;
+  swept: boolean;
+  slashRequestCount: number | bigint;
   bump: number;
 };
 
@@ -116,25 +134,31 @@ export function getVaultEncoder(): Encoder<VaultArgs> {
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["lockingTokenMint", getAddressEncoder()],
-      ["rewardTokenMint", getAddressEncoder()],
+      ["rewardStreams", getArrayEncoder(getRewardStreamEncoder())],
       ["minCap", getU64Encoder()],
       ["maxCap", getU64Encoder()],
       ["minLockAmount", getU64Encoder()],
-      ["totalRewardsDeposited", getU64Encoder()],
       ["totalCapitalCollected", getU64Encoder()],
       ["capitalAfterSlashing", getU64Encoder()],
-      ["beneficiaries", getArrayEncoder(getBeneficiaryEncoder())],
+      ["operatorBond", getU64Encoder()],
+      ["insuranceClaimed", getU64Encoder()],
+      ["capitalWithdrawn", getU64Encoder()],
+      ["challengeSlashed", getU64Encoder()],
+      ["beneficiaryCount", getU32Encoder()],
+      ["totalBeneficiaryBps", getU16Encoder()],
       ["investorBps", getU16Encoder()],
+      ["operatorCommissionBps", getU16Encoder()],
       ["maxSlashBps", getU16Encoder()],
       ["nftCollection", getAddressEncoder()],
       ["rewardDistributor", getAddressEncoder()],
       ["nodeOperator", getAddressEncoder()],
       ["lockPhaseStartAt", getI64Encoder()],
       ["lockPhaseDuration", getI64Encoder()],
-      ["isDisputeActive", getBooleanEncoder()],
-      ["disputeStartTime", getI64Encoder()],
-      ["pendingSlashAmount", getU64Encoder()],
-      ["slashClaimant", getAddressEncoder()],
+      ["vaultIndex", getU64Encoder()],
+      ["phase", getVaultPhaseEncoder()],
+      ["paused", getBooleanEncoder()],
+      ["swept", getBooleanEncoder()],
+      ["slashRequestCount", getU64Encoder()],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: VAULT_DISCRIMINATOR }),
@@ -146,25 +170,31 @@ export function getVaultDecoder(): Decoder<Vault> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["lockingTokenMint", getAddressDecoder()],
-    ["rewardTokenMint", getAddressDecoder()],
+    ["rewardStreams", getArrayDecoder(getRewardStreamDecoder())],
     ["minCap", getU64Decoder()],
     ["maxCap", getU64Decoder()],
     ["minLockAmount", getU64Decoder()],
-    ["totalRewardsDeposited", getU64Decoder()],
     ["totalCapitalCollected", getU64Decoder()],
     ["capitalAfterSlashing", getU64Decoder()],
-    ["beneficiaries", getArrayDecoder(getBeneficiaryDecoder())],
+    ["operatorBond", getU64Decoder()],
+    ["insuranceClaimed", getU64Decoder()],
+    ["capitalWithdrawn", getU64Decoder()],
+    ["challengeSlashed", getU64Decoder()],
+    ["beneficiaryCount", getU32Decoder()],
+    ["totalBeneficiaryBps", getU16Decoder()],
     ["investorBps", getU16Decoder()],
+    ["operatorCommissionBps", getU16Decoder()],
     ["maxSlashBps", getU16Decoder()],
     ["nftCollection", getAddressDecoder()],
     ["rewardDistributor", getAddressDecoder()],
     ["nodeOperator", getAddressDecoder()],
     ["lockPhaseStartAt", getI64Decoder()],
     ["lockPhaseDuration", getI64Decoder()],
-    ["isDisputeActive", getBooleanDecoder()],
-    ["disputeStartTime", getI64Decoder()],
-    ["pendingSlashAmount", getU64Decoder()],
-    ["slashClaimant", getAddressDecoder()],
+    ["vaultIndex", getU64Decoder()],
+    ["phase", getVaultPhaseDecoder()],
+    ["paused", getBooleanDecoder()],
+    ["swept", getBooleanDecoder()],
+    ["slashRequestCount", getU64Decoder()],
     ["bump", getU8Decoder()],
   ]);
 }
@@ -16,8 +16,6 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
-  getU8Decoder,
-  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -55,6 +53,8 @@ export type ClaimBeneficiaryRewardsHandlerInstruction<
   TProgram extends string = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
   TAccountBeneficiary extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountBeneficiaryAccount extends string | AccountMeta<string> = string,
   TAccountRewardMint extends string | AccountMeta<string> = string,
   TAccountVaultAta extends string | AccountMeta<string> = string,
   TAccountBeneficiaryAta extends string | AccountMeta<string> = string,
@@ -76,6 +76,12 @@ export type ClaimBeneficiaryRewardsHandlerInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountBeneficiaryAccount extends string
+        ? WritableAccount<TAccountBeneficiaryAccount>
+        : TAccountBeneficiaryAccount,
       TAccountRewardMint extends string
         ? ReadonlyAccount<TAccountRewardMint>
         : TAccountRewardMint,
@@ -100,19 +106,13 @@ export type ClaimBeneficiaryRewardsHandlerInstruction<
 
 export type ClaimBeneficiaryRewardsHandlerInstructionData = {
   discriminator: ReadonlyUint8Array;
-  beneficiaryIndex: number;
 };
 
-export type ClaimBeneficiaryRewardsHandlerInstructionDataArgs = {
-  beneficiaryIndex: number;
-};
+export type ClaimBeneficiaryRewardsHandlerInstructionDataArgs = {};
 
 export function getClaimBeneficiaryRewardsHandlerInstructionDataEncoder(): FixedSizeEncoder<ClaimBeneficiaryRewardsHandlerInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([
-      ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
-      ["beneficiaryIndex", getU8Encoder()],
-    ]),
+    getStructEncoder([["discriminator", fixEncoderSize(getBytesEncoder(), 8)]]),
     (value) => ({
       ...value,
       discriminator: CLAIM_BENEFICIARY_REWARDS_HANDLER_DISCRIMINATOR,
@@ -123,7 +123,6 @@ export function getClaimBeneficiaryRewardsHandlerInstructionDataEncoder(): Fixed
 export function getClaimBeneficiaryRewardsHandlerInstructionDataDecoder(): FixedSizeDecoder<ClaimBeneficiaryRewardsHandlerInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
-    ["beneficiaryIndex", getU8Decoder()],
   ]);
 }
 
@@ -140,6 +139,8 @@ export function getClaimBeneficiaryRewardsHandlerInstructionDataCodec(): FixedSi
 export type ClaimBeneficiaryRewardsHandlerAsyncInput<
   TAccountBeneficiary extends string = string,
   TAccountVault extends string = string,
+  TAccountConfig extends string = string,
+  TAccountBeneficiaryAccount extends string = string,
   TAccountRewardMint extends string = string,
   TAccountVaultAta extends string = string,
   TAccountBeneficiaryAta extends string = string,
@@ -149,18 +150,23 @@ export type ClaimBeneficiaryRewardsHandlerAsyncInput<
 > = {
   beneficiary: TransactionSigner<TAccountBeneficiary>;
   vault: Address<TAccountVault>;
+  config?: Address<TAccountConfig>;
+  /** The caller's share of the vault rewards */
+  beneficiaryAccount?: Address<TAccountBeneficiaryAccount>;
+  /** Mint of the reward stream to claim, further streams go in remaining accounts */
   rewardMint: Address<TAccountRewardMint>;
   vaultAta?: Address<TAccountVaultAta>;
   beneficiaryAta?: Address<TAccountBeneficiaryAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
-  beneficiaryIndex: ClaimBeneficiaryRewardsHandlerInstructionDataArgs["beneficiaryIndex"];
 };
 
 export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
   TAccountBeneficiary extends string,
   TAccountVault extends string,
+  TAccountConfig extends string,
+  TAccountBeneficiaryAccount extends string,
   TAccountRewardMint extends string,
   TAccountVaultAta extends string,
   TAccountBeneficiaryAta extends string,
@@ -172,6 +178,8 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
   input: ClaimBeneficiaryRewardsHandlerAsyncInput<
     TAccountBeneficiary,
     TAccountVault,
+    TAccountConfig,
+    TAccountBeneficiaryAccount,
     TAccountRewardMint,
     TAccountVaultAta,
     TAccountBeneficiaryAta,
@@ -185,6 +193,8 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
     TProgramAddress,
     TAccountBeneficiary,
     TAccountVault,
+    TAccountConfig,
+    TAccountBeneficiaryAccount,
     TAccountRewardMint,
     TAccountVaultAta,
     TAccountBeneficiaryAta,
@@ -201,6 +211,11 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
   const originalAccounts = {
     beneficiary: { value: input.beneficiary ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    beneficiaryAccount: {
+      value: input.beneficiaryAccount ?? null,
+      isWritable: true,
+    },
     rewardMint: { value: input.rewardMint ?? null, isWritable: false },
     vaultAta: { value: input.vaultAta ?? null, isWritable: true },
     beneficiaryAta: { value: input.beneficiaryAta ?? null, isWritable: true },
@@ -216,10 +231,27 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
     ResolvedAccount
   >;
 
-  // Original args.
-  const args = { ...input };
-
   // Resolve default values.
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([67, 111, 110, 102, 105, 103])),
+      ],
+    });
+  }
+  if (!accounts.beneficiaryAccount.value) {
+    accounts.beneficiaryAccount.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([66, 101, 110, 101, 102, 105, 99, 105, 97, 114, 121]),
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.vault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.beneficiary.value)),
+      ],
+    });
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
@@ -260,6 +292,8 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
     accounts: [
       getAccountMeta(accounts.beneficiary),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.beneficiaryAccount),
       getAccountMeta(accounts.rewardMint),
       getAccountMeta(accounts.vaultAta),
       getAccountMeta(accounts.beneficiaryAta),
@@ -267,14 +301,14 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getClaimBeneficiaryRewardsHandlerInstructionDataEncoder().encode(
-      args as ClaimBeneficiaryRewardsHandlerInstructionDataArgs,
-    ),
+    data: getClaimBeneficiaryRewardsHandlerInstructionDataEncoder().encode({}),
     programAddress,
   } as ClaimBeneficiaryRewardsHandlerInstruction<
     TProgramAddress,
     TAccountBeneficiary,
     TAccountVault,
+    TAccountConfig,
+    TAccountBeneficiaryAccount,
     TAccountRewardMint,
     TAccountVaultAta,
     TAccountBeneficiaryAta,
@@ -287,6 +321,8 @@ export async function getClaimBeneficiaryRewardsHandlerInstructionAsync<
 export type ClaimBeneficiaryRewardsHandlerInput<
   TAccountBeneficiary extends string = string,
   TAccountVault extends string = string,
+  TAccountConfig extends string = string,
+  TAccountBeneficiaryAccount extends string = string,
   TAccountRewardMint extends string = string,
   TAccountVaultAta extends string = string,
   TAccountBeneficiaryAta extends string = string,
@@ -296,18 +332,23 @@ export type ClaimBeneficiaryRewardsHandlerInput<
 > = {
   beneficiary: TransactionSigner<TAccountBeneficiary>;
   vault: Address<TAccountVault>;
+  config: Address<TAccountConfig>;
+  /** The caller's share of the vault rewards */
+  beneficiaryAccount: Address<TAccountBeneficiaryAccount>;
+  /** Mint of the reward stream to claim, further streams go in remaining accounts */
   rewardMint: Address<TAccountRewardMint>;
   vaultAta: Address<TAccountVaultAta>;
   beneficiaryAta: Address<TAccountBeneficiaryAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
-  beneficiaryIndex: ClaimBeneficiaryRewardsHandlerInstructionDataArgs["beneficiaryIndex"];
 };
 
 export function getClaimBeneficiaryRewardsHandlerInstruction<
   TAccountBeneficiary extends string,
   TAccountVault extends string,
+  TAccountConfig extends string,
+  TAccountBeneficiaryAccount extends string,
   TAccountRewardMint extends string,
   TAccountVaultAta extends string,
   TAccountBeneficiaryAta extends string,
@@ -319,6 +360,8 @@ export function getClaimBeneficiaryRewardsHandlerInstruction<
   input: ClaimBeneficiaryRewardsHandlerInput<
     TAccountBeneficiary,
     TAccountVault,
+    TAccountConfig,
+    TAccountBeneficiaryAccount,
     TAccountRewardMint,
     TAccountVaultAta,
     TAccountBeneficiaryAta,
@@ -331,6 +374,8 @@ export function getClaimBeneficiaryRewardsHandlerInstruction<
   TProgramAddress,
   TAccountBeneficiary,
   TAccountVault,
+  TAccountConfig,
+  TAccountBeneficiaryAccount,
   TAccountRewardMint,
   TAccountVaultAta,
   TAccountBeneficiaryAta,
@@ -346,6 +391,11 @@ export function getClaimBeneficiaryRewardsHandlerInstruction<
   const originalAccounts = {
     beneficiary: { value: input.beneficiary ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
+    beneficiaryAccount: {
+      value: input.beneficiaryAccount ?? null,
+      isWritable: true,
+    },
     rewardMint: { value: input.rewardMint ?? null, isWritable: false },
     vaultAta: { value: input.vaultAta ?? null, isWritable: true },
     beneficiaryAta: { value: input.beneficiaryAta ?? null, isWritable: true },
@@ -361,9 +411,6 @@ export function getClaimBeneficiaryRewardsHandlerInstruction<
     ResolvedAccount
   >;
 
-  // Original args.
-  const args = { ...input };
-
   // Resolve default values.
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
@@ -383,6 +430,8 @@ export function getClaimBeneficiaryRewardsHandlerInstruction<
     accounts: [
       getAccountMeta(accounts.beneficiary),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.beneficiaryAccount),
       getAccountMeta(accounts.rewardMint),
       getAccountMeta(accounts.vaultAta),
       getAccountMeta(accounts.beneficiaryAta),
@@ -390,14 +439,14 @@ export function getClaimBeneficiaryRewardsHandlerInstruction<
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getClaimBeneficiaryRewardsHandlerInstructionDataEncoder().encode(
-      args as ClaimBeneficiaryRewardsHandlerInstructionDataArgs,
-    ),
+    data: getClaimBeneficiaryRewardsHandlerInstructionDataEncoder().encode({}),
     programAddress,
   } as ClaimBeneficiaryRewardsHandlerInstruction<
     TProgramAddress,
     TAccountBeneficiary,
     TAccountVault,
+    TAccountConfig,
+    TAccountBeneficiaryAccount,
     TAccountRewardMint,
     TAccountVaultAta,
     TAccountBeneficiaryAta,
@@ -415,12 +464,16 @@ export type ParsedClaimBeneficiaryRewardsHandlerInstruction<
   accounts: {
     beneficiary: TAccountMetas[0];
     vault: TAccountMetas[1];
-    rewardMint: TAccountMetas[2];
-    vaultAta: TAccountMetas[3];
-    beneficiaryAta: TAccountMetas[4];
-    tokenProgram: TAccountMetas[5];
-    associatedTokenProgram: TAccountMetas[6];
-    systemProgram: TAccountMetas[7];
+    config: TAccountMetas[2];
+    /** The caller's share of the vault rewards */
+    beneficiaryAccount: TAccountMetas[3];
+    /** Mint of the reward stream to claim, further streams go in remaining accounts */
+    rewardMint: TAccountMetas[4];
+    vaultAta: TAccountMetas[5];
+    beneficiaryAta: TAccountMetas[6];
+    tokenProgram: TAccountMetas[7];
+    associatedTokenProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[9];
   };
   data: ClaimBeneficiaryRewardsHandlerInstructionData;
 };
@@ -433,7 +486,7 @@ export function parseClaimBeneficiaryRewardsHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedClaimBeneficiaryRewardsHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -448,6 +501,8 @@ export function parseClaimBeneficiaryRewardsHandlerInstruction<
     accounts: {
       beneficiary: getNextAccount(),
       vault: getNextAccount(),
+      config: getNextAccount(),
+      beneficiaryAccount: getNextAccount(),
       rewardMint: getNextAccount(),
       vaultAta: getNextAccount(),
       beneficiaryAta: getNextAccount(),
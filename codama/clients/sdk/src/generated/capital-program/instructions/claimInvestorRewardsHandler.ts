@@ -78,7 +78,7 @@ export type ClaimInvestorRewardsHandlerInstruction<
         ? ReadonlyAccount<TAccountConfig>
         : TAccountConfig,
       TAccountVault extends string
-        ? ReadonlyAccount<TAccountVault>
+        ? WritableAccount<TAccountVault>
         : TAccountVault,
       TAccountPosition extends string
         ? WritableAccount<TAccountPosition>
@@ -163,7 +163,7 @@ export type ClaimInvestorRewardsHandlerAsyncInput<
   position?: Address<TAccountPosition>;
   /** The MPL Core asset (NFT) representing the position */
   asset: Address<TAccountAsset>;
-  /** The reward token mint */
+  /** Mint of the reward stream to claim, further streams go in remaining accounts */
   rewardMint: Address<TAccountRewardMint>;
   /** Vault's token account holding rewards */
   vaultAta?: Address<TAccountVaultAta>;
@@ -226,7 +226,7 @@ export async function getClaimInvestorRewardsHandlerInstructionAsync<
   const originalAccounts = {
     holder: { value: input.holder ?? null, isWritable: false },
     config: { value: input.config ?? null, isWritable: false },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     position: { value: input.position ?? null, isWritable: true },
     asset: { value: input.asset ?? null, isWritable: false },
     rewardMint: { value: input.rewardMint ?? null, isWritable: false },
@@ -249,7 +249,7 @@ export async function getClaimInvestorRewardsHandlerInstructionAsync<
     accounts.config.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+        getBytesEncoder().encode(new Uint8Array([67, 111, 110, 102, 105, 103])),
       ],
     });
   }
@@ -355,7 +355,7 @@ export type ClaimInvestorRewardsHandlerInput<
   position: Address<TAccountPosition>;
   /** The MPL Core asset (NFT) representing the position */
   asset: Address<TAccountAsset>;
-  /** The reward token mint */
+  /** Mint of the reward stream to claim, further streams go in remaining accounts */
   rewardMint: Address<TAccountRewardMint>;
   /** Vault's token account holding rewards */
   vaultAta: Address<TAccountVaultAta>;
@@ -416,7 +416,7 @@ export function getClaimInvestorRewardsHandlerInstruction<
   const originalAccounts = {
     holder: { value: input.holder ?? null, isWritable: false },
     config: { value: input.config ?? null, isWritable: false },
-    vault: { value: input.vault ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
     position: { value: input.position ?? null, isWritable: true },
     asset: { value: input.asset ?? null, isWritable: false },
     rewardMint: { value: input.rewardMint ?? null, isWritable: false },
@@ -497,7 +497,7 @@ export type ParsedClaimInvestorRewardsHandlerInstruction<
     position: TAccountMetas[3];
     /** The MPL Core asset (NFT) representing the position */
     asset: TAccountMetas[4];
-    /** The reward token mint */
+    /** Mint of the reward stream to claim, further streams go in remaining accounts */
     rewardMint: TAccountMetas[5];
     /** Vault's token account holding rewards */
     vaultAta: TAccountMetas[6];
@@ -60,6 +60,8 @@ export type ClosePositionHandlerInstruction<
   TAccountLockMint extends string | AccountMeta<string> = string,
   TAccountVaultLockAta extends string | AccountMeta<string> = string,
   TAccountCapitalProviderLockAta extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountInsuranceFundAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
   TAccountAssociatedTokenProgram extends string | AccountMeta<string> =
@@ -103,6 +105,12 @@ export type ClosePositionHandlerInstruction<
       TAccountCapitalProviderLockAta extends string
         ? WritableAccount<TAccountCapitalProviderLockAta>
         : TAccountCapitalProviderLockAta,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountInsuranceFundAta extends string
+        ? WritableAccount<TAccountInsuranceFundAta>
+        : TAccountInsuranceFundAta,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -164,6 +172,8 @@ export type ClosePositionHandlerAsyncInput<
   TAccountLockMint extends string = string,
   TAccountVaultLockAta extends string = string,
   TAccountCapitalProviderLockAta extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountInsuranceFundAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -176,7 +186,7 @@ export type ClosePositionHandlerAsyncInput<
   vault: Address<TAccountVault>;
   /** Global configuration */
   config?: Address<TAccountConfig>;
-  /** The position being updated */
+  /** The position being closed */
   position?: Address<TAccountPosition>;
   /** The NFT asset representing the position */
   asset: Address<TAccountAsset>;
@@ -185,6 +195,9 @@ export type ClosePositionHandlerAsyncInput<
   lockMint: Address<TAccountLockMint>;
   vaultLockAta?: Address<TAccountVaultLockAta>;
   capitalProviderLockAta?: Address<TAccountCapitalProviderLockAta>;
+  /** Insurance fund of the locking token, pass it to claim coverage of slashed principal */
+  insuranceFund?: Address<TAccountInsuranceFund>;
+  insuranceFundAta?: Address<TAccountInsuranceFundAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
@@ -202,6 +215,8 @@ export async function getClosePositionHandlerInstructionAsync<
   TAccountLockMint extends string,
   TAccountVaultLockAta extends string,
   TAccountCapitalProviderLockAta extends string,
+  TAccountInsuranceFund extends string,
+  TAccountInsuranceFundAta extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountMplCoreProgram extends string,
@@ -219,6 +234,8 @@ export async function getClosePositionHandlerInstructionAsync<
     TAccountLockMint,
     TAccountVaultLockAta,
     TAccountCapitalProviderLockAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountMplCoreProgram,
@@ -238,6 +255,8 @@ export async function getClosePositionHandlerInstructionAsync<
     TAccountLockMint,
     TAccountVaultLockAta,
     TAccountCapitalProviderLockAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountMplCoreProgram,
@@ -263,6 +282,11 @@ export async function getClosePositionHandlerInstructionAsync<
       value: input.capitalProviderLockAta ?? null,
       isWritable: true,
     },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    insuranceFundAta: {
+      value: input.insuranceFundAta ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -354,6 +378,8 @@ export async function getClosePositionHandlerInstructionAsync<
       getAccountMeta(accounts.lockMint),
       getAccountMeta(accounts.vaultLockAta),
       getAccountMeta(accounts.capitalProviderLockAta),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.insuranceFundAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.mplCoreProgram),
@@ -373,6 +399,8 @@ export async function getClosePositionHandlerInstructionAsync<
     TAccountLockMint,
     TAccountVaultLockAta,
     TAccountCapitalProviderLockAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountMplCoreProgram,
@@ -391,6 +419,8 @@ export type ClosePositionHandlerInput<
   TAccountLockMint extends string = string,
   TAccountVaultLockAta extends string = string,
   TAccountCapitalProviderLockAta extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountInsuranceFundAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountMplCoreProgram extends string = string,
@@ -403,7 +433,7 @@ export type ClosePositionHandlerInput<
   vault: Address<TAccountVault>;
   /** Global configuration */
   config: Address<TAccountConfig>;
-  /** The position being updated */
+  /** The position being closed */
   position: Address<TAccountPosition>;
   /** The NFT asset representing the position */
   asset: Address<TAccountAsset>;
@@ -412,6 +442,9 @@ export type ClosePositionHandlerInput<
   lockMint: Address<TAccountLockMint>;
   vaultLockAta: Address<TAccountVaultLockAta>;
   capitalProviderLockAta: Address<TAccountCapitalProviderLockAta>;
+  /** Insurance fund of the locking token, pass it to claim coverage of slashed principal */
+  insuranceFund?: Address<TAccountInsuranceFund>;
+  insuranceFundAta?: Address<TAccountInsuranceFundAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
@@ -429,6 +462,8 @@ export function getClosePositionHandlerInstruction<
   TAccountLockMint extends string,
   TAccountVaultLockAta extends string,
   TAccountCapitalProviderLockAta extends string,
+  TAccountInsuranceFund extends string,
+  TAccountInsuranceFundAta extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountMplCoreProgram extends string,
@@ -446,6 +481,8 @@ export function getClosePositionHandlerInstruction<
     TAccountLockMint,
     TAccountVaultLockAta,
     TAccountCapitalProviderLockAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountMplCoreProgram,
@@ -464,6 +501,8 @@ export function getClosePositionHandlerInstruction<
   TAccountLockMint,
   TAccountVaultLockAta,
   TAccountCapitalProviderLockAta,
+  TAccountInsuranceFund,
+  TAccountInsuranceFundAta,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
   TAccountMplCoreProgram,
@@ -488,6 +527,11 @@ export function getClosePositionHandlerInstruction<
       value: input.capitalProviderLockAta ?? null,
       isWritable: true,
     },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    insuranceFundAta: {
+      value: input.insuranceFundAta ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -536,6 +580,8 @@ export function getClosePositionHandlerInstruction<
       getAccountMeta(accounts.lockMint),
       getAccountMeta(accounts.vaultLockAta),
       getAccountMeta(accounts.capitalProviderLockAta),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.insuranceFundAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.mplCoreProgram),
@@ -555,6 +601,8 @@ export function getClosePositionHandlerInstruction<
     TAccountLockMint,
     TAccountVaultLockAta,
     TAccountCapitalProviderLockAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountMplCoreProgram,
@@ -575,7 +623,7 @@ export type ParsedClosePositionHandlerInstruction<
     vault: TAccountMetas[1];
     /** Global configuration */
     config: TAccountMetas[2];
-    /** The position being updated */
+    /** The position being closed */
     position: TAccountMetas[3];
     /** The NFT asset representing the position */
     asset: TAccountMetas[4];
@@ -584,11 +632,14 @@ export type ParsedClosePositionHandlerInstruction<
     lockMint: TAccountMetas[6];
     vaultLockAta: TAccountMetas[7];
     capitalProviderLockAta: TAccountMetas[8];
-    tokenProgram: TAccountMetas[9];
-    associatedTokenProgram: TAccountMetas[10];
-    mplCoreProgram: TAccountMetas[11];
-    nftProgram: TAccountMetas[12];
-    systemProgram: TAccountMetas[13];
+    /** Insurance fund of the locking token, pass it to claim coverage of slashed principal */
+    insuranceFund?: TAccountMetas[9] | undefined;
+    insuranceFundAta?: TAccountMetas[10] | undefined;
+    tokenProgram: TAccountMetas[11];
+    associatedTokenProgram: TAccountMetas[12];
+    mplCoreProgram: TAccountMetas[13];
+    nftProgram: TAccountMetas[14];
+    systemProgram: TAccountMetas[15];
   };
   data: ClosePositionHandlerInstructionData;
 };
@@ -601,7 +652,7 @@ export function parseClosePositionHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedClosePositionHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 16) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -611,6 +662,12 @@ export function parseClosePositionHandlerInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CAPITAL_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -623,6 +680,8 @@ export function parseClosePositionHandlerInstruction<
       lockMint: getNextAccount(),
       vaultLockAta: getNextAccount(),
       capitalProviderLockAta: getNextAccount(),
+      insuranceFund: getNextOptionalAccount(),
+      insuranceFundAta: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       mplCoreProgram: getNextAccount(),
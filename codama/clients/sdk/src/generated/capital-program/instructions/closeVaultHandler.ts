@@ -56,6 +56,7 @@ export type CloseVaultHandlerInstruction<
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountVaultRewardAta extends string | AccountMeta<string> = string,
   TAccountVaultLockAta extends string | AccountMeta<string> = string,
+  TAccountNodeOperatorLockAta extends string | AccountMeta<string> = string,
   TAccountStakingTokenMint extends string | AccountMeta<string> = string,
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
@@ -83,6 +84,9 @@ export type CloseVaultHandlerInstruction<
       TAccountVaultLockAta extends string
         ? WritableAccount<TAccountVaultLockAta>
         : TAccountVaultLockAta,
+      TAccountNodeOperatorLockAta extends string
+        ? WritableAccount<TAccountNodeOperatorLockAta>
+        : TAccountNodeOperatorLockAta,
       TAccountStakingTokenMint extends string
         ? ReadonlyAccount<TAccountStakingTokenMint>
         : TAccountStakingTokenMint,
@@ -137,6 +141,7 @@ export type CloseVaultHandlerAsyncInput<
   TAccountVault extends string = string,
   TAccountVaultRewardAta extends string = string,
   TAccountVaultLockAta extends string = string,
+  TAccountNodeOperatorLockAta extends string = string,
   TAccountStakingTokenMint extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountTokenProgram extends string = string,
@@ -148,6 +153,8 @@ export type CloseVaultHandlerAsyncInput<
   vault: Address<TAccountVault>;
   vaultRewardAta?: Address<TAccountVaultRewardAta>;
   vaultLockAta?: Address<TAccountVaultLockAta>;
+  /** Receives what is left of the operator bond */
+  nodeOperatorLockAta?: Address<TAccountNodeOperatorLockAta>;
   stakingTokenMint: Address<TAccountStakingTokenMint>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -161,6 +168,7 @@ export async function getCloseVaultHandlerInstructionAsync<
   TAccountVault extends string,
   TAccountVaultRewardAta extends string,
   TAccountVaultLockAta extends string,
+  TAccountNodeOperatorLockAta extends string,
   TAccountStakingTokenMint extends string,
   TAccountRewardTokenMint extends string,
   TAccountTokenProgram extends string,
@@ -174,6 +182,7 @@ export async function getCloseVaultHandlerInstructionAsync<
     TAccountVault,
     TAccountVaultRewardAta,
     TAccountVaultLockAta,
+    TAccountNodeOperatorLockAta,
     TAccountStakingTokenMint,
     TAccountRewardTokenMint,
     TAccountTokenProgram,
@@ -189,6 +198,7 @@ export async function getCloseVaultHandlerInstructionAsync<
     TAccountVault,
     TAccountVaultRewardAta,
     TAccountVaultLockAta,
+    TAccountNodeOperatorLockAta,
     TAccountStakingTokenMint,
     TAccountRewardTokenMint,
     TAccountTokenProgram,
@@ -207,6 +217,10 @@ export async function getCloseVaultHandlerInstructionAsync<
     vault: { value: input.vault ?? null, isWritable: true },
     vaultRewardAta: { value: input.vaultRewardAta ?? null, isWritable: true },
     vaultLockAta: { value: input.vaultLockAta ?? null, isWritable: true },
+    nodeOperatorLockAta: {
+      value: input.nodeOperatorLockAta ?? null,
+      isWritable: true,
+    },
     stakingTokenMint: {
       value: input.stakingTokenMint ?? null,
       isWritable: false,
@@ -258,6 +272,19 @@ export async function getCloseVaultHandlerInstructionAsync<
       ],
     });
   }
+  if (!accounts.nodeOperatorLockAta.value) {
+    accounts.nodeOperatorLockAta.value = await getProgramDerivedAddress({
+      programAddress:
+        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.nodeOperator.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(
+          expectAddress(accounts.stakingTokenMint.value),
+        ),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">;
@@ -275,6 +302,7 @@ export async function getCloseVaultHandlerInstructionAsync<
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.vaultRewardAta),
       getAccountMeta(accounts.vaultLockAta),
+      getAccountMeta(accounts.nodeOperatorLockAta),
       getAccountMeta(accounts.stakingTokenMint),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.tokenProgram),
@@ -290,6 +318,7 @@ export async function getCloseVaultHandlerInstructionAsync<
     TAccountVault,
     TAccountVaultRewardAta,
     TAccountVaultLockAta,
+    TAccountNodeOperatorLockAta,
     TAccountStakingTokenMint,
     TAccountRewardTokenMint,
     TAccountTokenProgram,
@@ -304,6 +333,7 @@ export type CloseVaultHandlerInput<
   TAccountVault extends string = string,
   TAccountVaultRewardAta extends string = string,
   TAccountVaultLockAta extends string = string,
+  TAccountNodeOperatorLockAta extends string = string,
   TAccountStakingTokenMint extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountTokenProgram extends string = string,
@@ -315,6 +345,8 @@ export type CloseVaultHandlerInput<
   vault: Address<TAccountVault>;
   vaultRewardAta: Address<TAccountVaultRewardAta>;
   vaultLockAta: Address<TAccountVaultLockAta>;
+  /** Receives what is left of the operator bond */
+  nodeOperatorLockAta: Address<TAccountNodeOperatorLockAta>;
   stakingTokenMint: Address<TAccountStakingTokenMint>;
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -328,6 +360,7 @@ export function getCloseVaultHandlerInstruction<
   TAccountVault extends string,
   TAccountVaultRewardAta extends string,
   TAccountVaultLockAta extends string,
+  TAccountNodeOperatorLockAta extends string,
   TAccountStakingTokenMint extends string,
   TAccountRewardTokenMint extends string,
   TAccountTokenProgram extends string,
@@ -341,6 +374,7 @@ export function getCloseVaultHandlerInstruction<
     TAccountVault,
     TAccountVaultRewardAta,
     TAccountVaultLockAta,
+    TAccountNodeOperatorLockAta,
     TAccountStakingTokenMint,
     TAccountRewardTokenMint,
     TAccountTokenProgram,
@@ -355,6 +389,7 @@ export function getCloseVaultHandlerInstruction<
   TAccountVault,
   TAccountVaultRewardAta,
   TAccountVaultLockAta,
+  TAccountNodeOperatorLockAta,
   TAccountStakingTokenMint,
   TAccountRewardTokenMint,
   TAccountTokenProgram,
@@ -372,6 +407,10 @@ export function getCloseVaultHandlerInstruction<
     vault: { value: input.vault ?? null, isWritable: true },
     vaultRewardAta: { value: input.vaultRewardAta ?? null, isWritable: true },
     vaultLockAta: { value: input.vaultLockAta ?? null, isWritable: true },
+    nodeOperatorLockAta: {
+      value: input.nodeOperatorLockAta ?? null,
+      isWritable: true,
+    },
     stakingTokenMint: {
       value: input.stakingTokenMint ?? null,
       isWritable: false,
@@ -414,6 +453,7 @@ export function getCloseVaultHandlerInstruction<
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.vaultRewardAta),
       getAccountMeta(accounts.vaultLockAta),
+      getAccountMeta(accounts.nodeOperatorLockAta),
       getAccountMeta(accounts.stakingTokenMint),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.tokenProgram),
@@ -429,6 +469,7 @@ export function getCloseVaultHandlerInstruction<
     TAccountVault,
     TAccountVaultRewardAta,
     TAccountVaultLockAta,
+    TAccountNodeOperatorLockAta,
     TAccountStakingTokenMint,
     TAccountRewardTokenMint,
     TAccountTokenProgram,
@@ -448,11 +489,13 @@ export type ParsedCloseVaultHandlerInstruction<
     vault: TAccountMetas[2];
     vaultRewardAta: TAccountMetas[3];
     vaultLockAta: TAccountMetas[4];
-    stakingTokenMint: TAccountMetas[5];
-    rewardTokenMint: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
+    /** Receives what is left of the operator bond */
+    nodeOperatorLockAta: TAccountMetas[5];
+    stakingTokenMint: TAccountMetas[6];
+    rewardTokenMint: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
+    associatedTokenProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[10];
   };
   data: CloseVaultHandlerInstructionData;
 };
@@ -465,7 +508,7 @@ export function parseCloseVaultHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCloseVaultHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -483,6 +526,7 @@ export function parseCloseVaultHandlerInstruction<
       vault: getNextAccount(),
       vaultRewardAta: getNextAccount(),
       vaultLockAta: getNextAccount(),
+      nodeOperatorLockAta: getNextAccount(),
       stakingTokenMint: getNextAccount(),
       rewardTokenMint: getNextAccount(),
       tokenProgram: getNextAccount(),
@@ -7,11 +7,13 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -19,13 +21,17 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -37,6 +43,12 @@ import {
 } from "gill";
 import { CAPITAL_PROGRAM_PROGRAM_ADDRESS } from "../programs";
 import { getAccountMetaFactory, type ResolvedAccount } from "../shared";
+import {
+  getSlashShareDecoder,
+  getSlashShareEncoder,
+  type SlashShare,
+  type SlashShareArgs,
+} from "../types";
 
 export const CREATE_SLAS_REQ_HANDLER_DISCRIMINATOR = new Uint8Array([
   105, 0, 66, 75, 41, 216, 177, 167,
@@ -52,7 +64,11 @@ export type CreateSlasReqHandlerInstruction<
   TProgram extends string = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
   TAccountAgent extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSlashRequest extends string | AccountMeta<string> = string,
+  TAccountAttestation extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    "11111111111111111111111111111111",
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -65,9 +81,18 @@ export type CreateSlasReqHandlerInstruction<
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountSlashRequest extends string
+        ? WritableAccount<TAccountSlashRequest>
+        : TAccountSlashRequest,
+      TAccountAttestation extends string
+        ? WritableAccount<TAccountAttestation>
+        : TAccountAttestation,
       TAccountConfig extends string
         ? ReadonlyAccount<TAccountConfig>
         : TAccountConfig,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -75,20 +100,32 @@ export type CreateSlasReqHandlerInstruction<
 export type CreateSlasReqHandlerInstructionData = {
   discriminator: ReadonlyUint8Array;
   slashBps: number;
-  slashClaimant: Address;
+  claimants: Array<SlashShare>;
+  insuranceBps: number;
+  burnBps: number;
+  evidenceHash: ReadonlyUint8Array;
+  evidenceUri: string;
 };
 
 export type CreateSlasReqHandlerInstructionDataArgs = {
   slashBps: number;
-  slashClaimant: Address;
+  claimants: Array<SlashShareArgs>;
+  insuranceBps: number;
+  burnBps: number;
+  evidenceHash: ReadonlyUint8Array;
+  evidenceUri: string;
 };
 
-export function getCreateSlasReqHandlerInstructionDataEncoder(): FixedSizeEncoder<CreateSlasReqHandlerInstructionDataArgs> {
+export function getCreateSlasReqHandlerInstructionDataEncoder(): Encoder<CreateSlasReqHandlerInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["slashBps", getU16Encoder()],
-      ["slashClaimant", getAddressEncoder()],
+      ["claimants", getArrayEncoder(getSlashShareEncoder())],
+      ["insuranceBps", getU16Encoder()],
+      ["burnBps", getU16Encoder()],
+      ["evidenceHash", fixEncoderSize(getBytesEncoder(), 32)],
+      ["evidenceUri", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -97,15 +134,19 @@ export function getCreateSlasReqHandlerInstructionDataEncoder(): FixedSizeEncode
   );
 }
 
-export function getCreateSlasReqHandlerInstructionDataDecoder(): FixedSizeDecoder<CreateSlasReqHandlerInstructionData> {
+export function getCreateSlasReqHandlerInstructionDataDecoder(): Decoder<CreateSlasReqHandlerInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["slashBps", getU16Decoder()],
-    ["slashClaimant", getAddressDecoder()],
+    ["claimants", getArrayDecoder(getSlashShareDecoder())],
+    ["insuranceBps", getU16Decoder()],
+    ["burnBps", getU16Decoder()],
+    ["evidenceHash", fixDecoderSize(getBytesDecoder(), 32)],
+    ["evidenceUri", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
   ]);
 }
 
-export function getCreateSlasReqHandlerInstructionDataCodec(): FixedSizeCodec<
+export function getCreateSlasReqHandlerInstructionDataCodec(): Codec<
   CreateSlasReqHandlerInstructionDataArgs,
   CreateSlasReqHandlerInstructionData
 > {
@@ -118,29 +159,47 @@ export function getCreateSlasReqHandlerInstructionDataCodec(): FixedSizeCodec<
 export type CreateSlasReqHandlerAsyncInput<
   TAccountAgent extends string = string,
   TAccountVault extends string = string,
+  TAccountSlashRequest extends string = string,
+  TAccountAttestation extends string = string,
   TAccountConfig extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   /**
-   * and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-   * STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+   * and can be anyone, either one of benificiaries or some DePIN network outside solana.
+   * A failing attestation can back the request instead of the agent's word alone
    */
   agent: TransactionSigner<TAccountAgent>;
   vault: Address<TAccountVault>;
+  /** The new request, numbered by the vault's slash request count */
+  slashRequest: Address<TAccountSlashRequest>;
+  /** Optional failing attestation of the vault backing the request */
+  attestation?: Address<TAccountAttestation>;
   config?: Address<TAccountConfig>;
+  systemProgram?: Address<TAccountSystemProgram>;
   slashBps: CreateSlasReqHandlerInstructionDataArgs["slashBps"];
-  slashClaimant: CreateSlasReqHandlerInstructionDataArgs["slashClaimant"];
+  claimants: CreateSlasReqHandlerInstructionDataArgs["claimants"];
+  insuranceBps: CreateSlasReqHandlerInstructionDataArgs["insuranceBps"];
+  burnBps: CreateSlasReqHandlerInstructionDataArgs["burnBps"];
+  evidenceHash: CreateSlasReqHandlerInstructionDataArgs["evidenceHash"];
+  evidenceUri: CreateSlasReqHandlerInstructionDataArgs["evidenceUri"];
 };
 
 export async function getCreateSlasReqHandlerInstructionAsync<
   TAccountAgent extends string,
   TAccountVault extends string,
+  TAccountSlashRequest extends string,
+  TAccountAttestation extends string,
   TAccountConfig extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: CreateSlasReqHandlerAsyncInput<
     TAccountAgent,
     TAccountVault,
-    TAccountConfig
+    TAccountSlashRequest,
+    TAccountAttestation,
+    TAccountConfig,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -148,7 +207,10 @@ export async function getCreateSlasReqHandlerInstructionAsync<
     TProgramAddress,
     TAccountAgent,
     TAccountVault,
-    TAccountConfig
+    TAccountSlashRequest,
+    TAccountAttestation,
+    TAccountConfig,
+    TAccountSystemProgram
   >
 > {
   // Program address.
@@ -159,7 +221,10 @@ export async function getCreateSlasReqHandlerInstructionAsync<
   const originalAccounts = {
     agent: { value: input.agent ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    slashRequest: { value: input.slashRequest ?? null, isWritable: true },
+    attestation: { value: input.attestation ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -178,13 +243,20 @@ export async function getCreateSlasReqHandlerInstructionAsync<
       ],
     });
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.agent),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slashRequest),
+      getAccountMeta(accounts.attestation),
       getAccountMeta(accounts.config),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getCreateSlasReqHandlerInstructionDataEncoder().encode(
       args as CreateSlasReqHandlerInstructionDataArgs,
@@ -194,43 +266,67 @@ export async function getCreateSlasReqHandlerInstructionAsync<
     TProgramAddress,
     TAccountAgent,
     TAccountVault,
-    TAccountConfig
+    TAccountSlashRequest,
+    TAccountAttestation,
+    TAccountConfig,
+    TAccountSystemProgram
   >);
 }
 
 export type CreateSlasReqHandlerInput<
   TAccountAgent extends string = string,
   TAccountVault extends string = string,
+  TAccountSlashRequest extends string = string,
+  TAccountAttestation extends string = string,
   TAccountConfig extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   /**
-   * and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-   * STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+   * and can be anyone, either one of benificiaries or some DePIN network outside solana.
+   * A failing attestation can back the request instead of the agent's word alone
    */
   agent: TransactionSigner<TAccountAgent>;
   vault: Address<TAccountVault>;
+  /** The new request, numbered by the vault's slash request count */
+  slashRequest: Address<TAccountSlashRequest>;
+  /** Optional failing attestation of the vault backing the request */
+  attestation?: Address<TAccountAttestation>;
   config: Address<TAccountConfig>;
+  systemProgram?: Address<TAccountSystemProgram>;
   slashBps: CreateSlasReqHandlerInstructionDataArgs["slashBps"];
-  slashClaimant: CreateSlasReqHandlerInstructionDataArgs["slashClaimant"];
+  claimants: CreateSlasReqHandlerInstructionDataArgs["claimants"];
+  insuranceBps: CreateSlasReqHandlerInstructionDataArgs["insuranceBps"];
+  burnBps: CreateSlasReqHandlerInstructionDataArgs["burnBps"];
+  evidenceHash: CreateSlasReqHandlerInstructionDataArgs["evidenceHash"];
+  evidenceUri: CreateSlasReqHandlerInstructionDataArgs["evidenceUri"];
 };
 
 export function getCreateSlasReqHandlerInstruction<
   TAccountAgent extends string,
   TAccountVault extends string,
+  TAccountSlashRequest extends string,
+  TAccountAttestation extends string,
   TAccountConfig extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: CreateSlasReqHandlerInput<
     TAccountAgent,
     TAccountVault,
-    TAccountConfig
+    TAccountSlashRequest,
+    TAccountAttestation,
+    TAccountConfig,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): CreateSlasReqHandlerInstruction<
   TProgramAddress,
   TAccountAgent,
   TAccountVault,
-  TAccountConfig
+  TAccountSlashRequest,
+  TAccountAttestation,
+  TAccountConfig,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -240,7 +336,10 @@ export function getCreateSlasReqHandlerInstruction<
   const originalAccounts = {
     agent: { value: input.agent ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    slashRequest: { value: input.slashRequest ?? null, isWritable: true },
+    attestation: { value: input.attestation ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -250,12 +349,21 @@ export function getCreateSlasReqHandlerInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.agent),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slashRequest),
+      getAccountMeta(accounts.attestation),
       getAccountMeta(accounts.config),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getCreateSlasReqHandlerInstructionDataEncoder().encode(
       args as CreateSlasReqHandlerInstructionDataArgs,
@@ -265,7 +373,10 @@ export function getCreateSlasReqHandlerInstruction<
     TProgramAddress,
     TAccountAgent,
     TAccountVault,
-    TAccountConfig
+    TAccountSlashRequest,
+    TAccountAttestation,
+    TAccountConfig,
+    TAccountSystemProgram
   >);
 }
 
@@ -276,12 +387,17 @@ export type ParsedCreateSlasReqHandlerInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     /**
-     * and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-     * STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+     * and can be anyone, either one of benificiaries or some DePIN network outside solana.
+     * A failing attestation can back the request instead of the agent's word alone
      */
     agent: TAccountMetas[0];
     vault: TAccountMetas[1];
-    config: TAccountMetas[2];
+    /** The new request, numbered by the vault's slash request count */
+    slashRequest: TAccountMetas[2];
+    /** Optional failing attestation of the vault backing the request */
+    attestation?: TAccountMetas[3] | undefined;
+    config: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: CreateSlasReqHandlerInstructionData;
 };
@@ -294,7 +410,7 @@ export function parseCreateSlasReqHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCreateSlasReqHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -304,12 +420,21 @@ export function parseCreateSlasReqHandlerInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CAPITAL_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       agent: getNextAccount(),
       vault: getNextAccount(),
+      slashRequest: getNextAccount(),
+      attestation: getNextOptionalAccount(),
       config: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getCreateSlasReqHandlerInstructionDataDecoder().decode(
       instruction.data,
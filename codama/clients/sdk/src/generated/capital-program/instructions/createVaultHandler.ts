@@ -12,8 +12,6 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
-  getArrayDecoder,
-  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
@@ -29,9 +27,9 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type Codec,
-  type Decoder,
-  type Encoder,
+  type FixedSizeCodec,
+  type FixedSizeDecoder,
+  type FixedSizeEncoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -47,12 +45,6 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from "../shared";
-import {
-  getBeneficiaryDecoder,
-  getBeneficiaryEncoder,
-  type Beneficiary,
-  type BeneficiaryArgs,
-} from "../types";
 
 export const CREATE_VAULT_HANDLER_DISCRIMINATOR = new Uint8Array([
   166, 83, 111, 163, 111, 146, 16, 162,
@@ -67,11 +59,14 @@ export function getCreateVaultHandlerDiscriminatorBytes() {
 export type CreateVaultHandlerInstruction<
   TProgram extends string = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
   TAccountProvider extends string | AccountMeta<string> = string,
+  TAccountOperatorRegistry extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountConfigAccount extends string | AccountMeta<string> = string,
   TAccountNftConfig extends string | AccountMeta<string> = string,
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
   TAccountLockMint extends string | AccountMeta<string> = string,
+  TAccountVaultLockAta extends string | AccountMeta<string> = string,
+  TAccountProviderLockAta extends string | AccountMeta<string> = string,
   TAccountNftCollection extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
@@ -92,6 +87,9 @@ export type CreateVaultHandlerInstruction<
         ? WritableSignerAccount<TAccountProvider> &
             AccountSignerMeta<TAccountProvider>
         : TAccountProvider,
+      TAccountOperatorRegistry extends string
+        ? WritableAccount<TAccountOperatorRegistry>
+        : TAccountOperatorRegistry,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
@@ -107,6 +105,12 @@ export type CreateVaultHandlerInstruction<
       TAccountLockMint extends string
         ? ReadonlyAccount<TAccountLockMint>
         : TAccountLockMint,
+      TAccountVaultLockAta extends string
+        ? WritableAccount<TAccountVaultLockAta>
+        : TAccountVaultLockAta,
+      TAccountProviderLockAta extends string
+        ? WritableAccount<TAccountProviderLockAta>
+        : TAccountProviderLockAta,
       TAccountNftCollection extends string
         ? WritableSignerAccount<TAccountNftCollection> &
             AccountSignerMeta<TAccountNftCollection>
@@ -135,10 +139,10 @@ export type CreateVaultHandlerInstructionData = {
   minCap: bigint;
   maxCap: bigint;
   minLockAmount: bigint;
-  beneficiaries: Array<Beneficiary>;
+  operatorBond: bigint;
   investorBps: number;
+  operatorCommissionBps: number;
   maxSlashBps: number;
-  slashClaimant: Address;
   rewardDistributor: Address;
   nodeOperator: Address;
   lockPhaseDuration: bigint;
@@ -149,27 +153,27 @@ export type CreateVaultHandlerInstructionDataArgs = {
   minCap: number | bigint;
   maxCap: number | bigint;
   minLockAmount: number | bigint;
-  beneficiaries: Array<BeneficiaryArgs>;
+  operatorBond: number | bigint;
   investorBps: number;
+  operatorCommissionBps: number;
   maxSlashBps: number;
-  slashClaimant: Address;
   rewardDistributor: Address;
   nodeOperator: Address;
   lockPhaseDuration: number | bigint;
   lockPhaseStartTime: number | bigint;
 };
 
-export function getCreateVaultHandlerInstructionDataEncoder(): Encoder<CreateVaultHandlerInstructionDataArgs> {
+export function getCreateVaultHandlerInstructionDataEncoder(): FixedSizeEncoder<CreateVaultHandlerInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["minCap", getU64Encoder()],
       ["maxCap", getU64Encoder()],
       ["minLockAmount", getU64Encoder()],
-      ["beneficiaries", getArrayEncoder(getBeneficiaryEncoder())],
+      ["operatorBond", getU64Encoder()],
       ["investorBps", getU16Encoder()],
+      ["operatorCommissionBps", getU16Encoder()],
       ["maxSlashBps", getU16Encoder()],
-      ["slashClaimant", getAddressEncoder()],
       ["rewardDistributor", getAddressEncoder()],
       ["nodeOperator", getAddressEncoder()],
       ["lockPhaseDuration", getI64Encoder()],
@@ -182,16 +186,16 @@ export function getCreateVaultHandlerInstructionDataEncoder(): Encoder<CreateVau
   );
 }
 
-export function getCreateVaultHandlerInstructionDataDecoder(): Decoder<CreateVaultHandlerInstructionData> {
+export function getCreateVaultHandlerInstructionDataDecoder(): FixedSizeDecoder<CreateVaultHandlerInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["minCap", getU64Decoder()],
     ["maxCap", getU64Decoder()],
     ["minLockAmount", getU64Decoder()],
-    ["beneficiaries", getArrayDecoder(getBeneficiaryDecoder())],
+    ["operatorBond", getU64Decoder()],
     ["investorBps", getU16Decoder()],
+    ["operatorCommissionBps", getU16Decoder()],
     ["maxSlashBps", getU16Decoder()],
-    ["slashClaimant", getAddressDecoder()],
     ["rewardDistributor", getAddressDecoder()],
     ["nodeOperator", getAddressDecoder()],
     ["lockPhaseDuration", getI64Decoder()],
@@ -199,7 +203,7 @@ export function getCreateVaultHandlerInstructionDataDecoder(): Decoder<CreateVau
   ]);
 }
 
-export function getCreateVaultHandlerInstructionDataCodec(): Codec<
+export function getCreateVaultHandlerInstructionDataCodec(): FixedSizeCodec<
   CreateVaultHandlerInstructionDataArgs,
   CreateVaultHandlerInstructionData
 > {
@@ -211,11 +215,14 @@ export function getCreateVaultHandlerInstructionDataCodec(): Codec<
 
 export type CreateVaultHandlerAsyncInput<
   TAccountProvider extends string = string,
+  TAccountOperatorRegistry extends string = string,
   TAccountVault extends string = string,
   TAccountConfigAccount extends string = string,
   TAccountNftConfig extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountLockMint extends string = string,
+  TAccountVaultLockAta extends string = string,
+  TAccountProviderLockAta extends string = string,
   TAccountNftCollection extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
@@ -225,16 +232,22 @@ export type CreateVaultHandlerAsyncInput<
 > = {
   /** The vault provider/creator who pays for account initialization */
   provider: TransactionSigner<TAccountProvider>;
+  /** Per-operator vault counter, created with the operator's first vault */
+  operatorRegistry: Address<TAccountOperatorRegistry>;
   /** The vault account to be created */
-  vault?: Address<TAccountVault>;
+  vault: Address<TAccountVault>;
   /** Global authority configuration */
   configAccount?: Address<TAccountConfigAccount>;
   /** NFT marketplace configuration */
   nftConfig: Address<TAccountNftConfig>;
-  /** Reward token mint - tokens distributed as rewards */
+  /** Reward token mint - first reward stream of the vault */
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   /** Staking/locking token mint - tokens locked by investors */
   lockMint: Address<TAccountLockMint>;
+  /** Vault's locking token account, holds the operator bond and investor capital */
+  vaultLockAta?: Address<TAccountVaultLockAta>;
+  /** Provider's locking token account funding the operator bond */
+  providerLockAta: Address<TAccountProviderLockAta>;
   /** NFT collection for vault positions */
   nftCollection: TransactionSigner<TAccountNftCollection>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -245,10 +258,10 @@ export type CreateVaultHandlerAsyncInput<
   minCap: CreateVaultHandlerInstructionDataArgs["minCap"];
   maxCap: CreateVaultHandlerInstructionDataArgs["maxCap"];
   minLockAmount: CreateVaultHandlerInstructionDataArgs["minLockAmount"];
-  beneficiaries: CreateVaultHandlerInstructionDataArgs["beneficiaries"];
+  operatorBond: CreateVaultHandlerInstructionDataArgs["operatorBond"];
   investorBps: CreateVaultHandlerInstructionDataArgs["investorBps"];
+  operatorCommissionBps: CreateVaultHandlerInstructionDataArgs["operatorCommissionBps"];
   maxSlashBps: CreateVaultHandlerInstructionDataArgs["maxSlashBps"];
-  slashClaimant: CreateVaultHandlerInstructionDataArgs["slashClaimant"];
   rewardDistributor: CreateVaultHandlerInstructionDataArgs["rewardDistributor"];
   nodeOperator: CreateVaultHandlerInstructionDataArgs["nodeOperator"];
   lockPhaseDuration: CreateVaultHandlerInstructionDataArgs["lockPhaseDuration"];
@@ -257,11 +270,14 @@ export type CreateVaultHandlerAsyncInput<
 
 export async function getCreateVaultHandlerInstructionAsync<
   TAccountProvider extends string,
+  TAccountOperatorRegistry extends string,
   TAccountVault extends string,
   TAccountConfigAccount extends string,
   TAccountNftConfig extends string,
   TAccountRewardTokenMint extends string,
   TAccountLockMint extends string,
+  TAccountVaultLockAta extends string,
+  TAccountProviderLockAta extends string,
   TAccountNftCollection extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
@@ -272,11 +288,14 @@ export async function getCreateVaultHandlerInstructionAsync<
 >(
   input: CreateVaultHandlerAsyncInput<
     TAccountProvider,
+    TAccountOperatorRegistry,
     TAccountVault,
     TAccountConfigAccount,
     TAccountNftConfig,
     TAccountRewardTokenMint,
     TAccountLockMint,
+    TAccountVaultLockAta,
+    TAccountProviderLockAta,
     TAccountNftCollection,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
@@ -289,11 +308,14 @@ export async function getCreateVaultHandlerInstructionAsync<
   CreateVaultHandlerInstruction<
     TProgramAddress,
     TAccountProvider,
+    TAccountOperatorRegistry,
     TAccountVault,
     TAccountConfigAccount,
     TAccountNftConfig,
     TAccountRewardTokenMint,
     TAccountLockMint,
+    TAccountVaultLockAta,
+    TAccountProviderLockAta,
     TAccountNftCollection,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
@@ -309,6 +331,10 @@ export async function getCreateVaultHandlerInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     provider: { value: input.provider ?? null, isWritable: true },
+    operatorRegistry: {
+      value: input.operatorRegistry ?? null,
+      isWritable: true,
+    },
     vault: { value: input.vault ?? null, isWritable: true },
     configAccount: { value: input.configAccount ?? null, isWritable: false },
     nftConfig: { value: input.nftConfig ?? null, isWritable: false },
@@ -317,6 +343,8 @@ export async function getCreateVaultHandlerInstructionAsync<
       isWritable: false,
     },
     lockMint: { value: input.lockMint ?? null, isWritable: false },
+    vaultLockAta: { value: input.vaultLockAta ?? null, isWritable: true },
+    providerLockAta: { value: input.providerLockAta ?? null, isWritable: true },
     nftCollection: { value: input.nftCollection ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
@@ -336,15 +364,6 @@ export async function getCreateVaultHandlerInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.vault.value) {
-    accounts.vault.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(new Uint8Array([86, 97, 117, 108, 116])),
-        getAddressEncoder().encode(expectAddress(accounts.provider.value)),
-      ],
-    });
-  }
   if (!accounts.configAccount.value) {
     accounts.configAccount.value = await getProgramDerivedAddress({
       programAddress,
@@ -357,6 +376,17 @@ export async function getCreateVaultHandlerInstructionAsync<
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
   }
+  if (!accounts.vaultLockAta.value) {
+    accounts.vaultLockAta.value = await getProgramDerivedAddress({
+      programAddress:
+        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
+      seeds: [
+        getAddressEncoder().encode(expectAddress(accounts.vault.value)),
+        getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
+        getAddressEncoder().encode(expectAddress(accounts.lockMint.value)),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">;
@@ -378,11 +408,14 @@ export async function getCreateVaultHandlerInstructionAsync<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.provider),
+      getAccountMeta(accounts.operatorRegistry),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.configAccount),
       getAccountMeta(accounts.nftConfig),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.lockMint),
+      getAccountMeta(accounts.vaultLockAta),
+      getAccountMeta(accounts.providerLockAta),
       getAccountMeta(accounts.nftCollection),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
@@ -397,11 +430,14 @@ export async function getCreateVaultHandlerInstructionAsync<
   } as CreateVaultHandlerInstruction<
     TProgramAddress,
     TAccountProvider,
+    TAccountOperatorRegistry,
     TAccountVault,
     TAccountConfigAccount,
     TAccountNftConfig,
     TAccountRewardTokenMint,
     TAccountLockMint,
+    TAccountVaultLockAta,
+    TAccountProviderLockAta,
     TAccountNftCollection,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
@@ -413,11 +449,14 @@ export async function getCreateVaultHandlerInstructionAsync<
 
 export type CreateVaultHandlerInput<
   TAccountProvider extends string = string,
+  TAccountOperatorRegistry extends string = string,
   TAccountVault extends string = string,
   TAccountConfigAccount extends string = string,
   TAccountNftConfig extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountLockMint extends string = string,
+  TAccountVaultLockAta extends string = string,
+  TAccountProviderLockAta extends string = string,
   TAccountNftCollection extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
@@ -427,16 +466,22 @@ export type CreateVaultHandlerInput<
 > = {
   /** The vault provider/creator who pays for account initialization */
   provider: TransactionSigner<TAccountProvider>;
+  /** Per-operator vault counter, created with the operator's first vault */
+  operatorRegistry: Address<TAccountOperatorRegistry>;
   /** The vault account to be created */
   vault: Address<TAccountVault>;
   /** Global authority configuration */
   configAccount: Address<TAccountConfigAccount>;
   /** NFT marketplace configuration */
   nftConfig: Address<TAccountNftConfig>;
-  /** Reward token mint - tokens distributed as rewards */
+  /** Reward token mint - first reward stream of the vault */
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   /** Staking/locking token mint - tokens locked by investors */
   lockMint: Address<TAccountLockMint>;
+  /** Vault's locking token account, holds the operator bond and investor capital */
+  vaultLockAta: Address<TAccountVaultLockAta>;
+  /** Provider's locking token account funding the operator bond */
+  providerLockAta: Address<TAccountProviderLockAta>;
   /** NFT collection for vault positions */
   nftCollection: TransactionSigner<TAccountNftCollection>;
   tokenProgram?: Address<TAccountTokenProgram>;
@@ -447,10 +492,10 @@ export type CreateVaultHandlerInput<
   minCap: CreateVaultHandlerInstructionDataArgs["minCap"];
   maxCap: CreateVaultHandlerInstructionDataArgs["maxCap"];
   minLockAmount: CreateVaultHandlerInstructionDataArgs["minLockAmount"];
-  beneficiaries: CreateVaultHandlerInstructionDataArgs["beneficiaries"];
+  operatorBond: CreateVaultHandlerInstructionDataArgs["operatorBond"];
   investorBps: CreateVaultHandlerInstructionDataArgs["investorBps"];
+  operatorCommissionBps: CreateVaultHandlerInstructionDataArgs["operatorCommissionBps"];
   maxSlashBps: CreateVaultHandlerInstructionDataArgs["maxSlashBps"];
-  slashClaimant: CreateVaultHandlerInstructionDataArgs["slashClaimant"];
   rewardDistributor: CreateVaultHandlerInstructionDataArgs["rewardDistributor"];
   nodeOperator: CreateVaultHandlerInstructionDataArgs["nodeOperator"];
   lockPhaseDuration: CreateVaultHandlerInstructionDataArgs["lockPhaseDuration"];
@@ -459,11 +504,14 @@ export type CreateVaultHandlerInput<
 
 export function getCreateVaultHandlerInstruction<
   TAccountProvider extends string,
+  TAccountOperatorRegistry extends string,
   TAccountVault extends string,
   TAccountConfigAccount extends string,
   TAccountNftConfig extends string,
   TAccountRewardTokenMint extends string,
   TAccountLockMint extends string,
+  TAccountVaultLockAta extends string,
+  TAccountProviderLockAta extends string,
   TAccountNftCollection extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
@@ -474,11 +522,14 @@ export function getCreateVaultHandlerInstruction<
 >(
   input: CreateVaultHandlerInput<
     TAccountProvider,
+    TAccountOperatorRegistry,
     TAccountVault,
     TAccountConfigAccount,
     TAccountNftConfig,
     TAccountRewardTokenMint,
     TAccountLockMint,
+    TAccountVaultLockAta,
+    TAccountProviderLockAta,
     TAccountNftCollection,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
@@ -490,11 +541,14 @@ export function getCreateVaultHandlerInstruction<
 ): CreateVaultHandlerInstruction<
   TProgramAddress,
   TAccountProvider,
+  TAccountOperatorRegistry,
   TAccountVault,
   TAccountConfigAccount,
   TAccountNftConfig,
   TAccountRewardTokenMint,
   TAccountLockMint,
+  TAccountVaultLockAta,
+  TAccountProviderLockAta,
   TAccountNftCollection,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
@@ -509,6 +563,10 @@ export function getCreateVaultHandlerInstruction<
   // Original accounts.
   const originalAccounts = {
     provider: { value: input.provider ?? null, isWritable: true },
+    operatorRegistry: {
+      value: input.operatorRegistry ?? null,
+      isWritable: true,
+    },
     vault: { value: input.vault ?? null, isWritable: true },
     configAccount: { value: input.configAccount ?? null, isWritable: false },
     nftConfig: { value: input.nftConfig ?? null, isWritable: false },
@@ -517,6 +575,8 @@ export function getCreateVaultHandlerInstruction<
       isWritable: false,
     },
     lockMint: { value: input.lockMint ?? null, isWritable: false },
+    vaultLockAta: { value: input.vaultLockAta ?? null, isWritable: true },
+    providerLockAta: { value: input.providerLockAta ?? null, isWritable: true },
     nftCollection: { value: input.nftCollection ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
@@ -561,11 +621,14 @@ export function getCreateVaultHandlerInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.provider),
+      getAccountMeta(accounts.operatorRegistry),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.configAccount),
       getAccountMeta(accounts.nftConfig),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.lockMint),
+      getAccountMeta(accounts.vaultLockAta),
+      getAccountMeta(accounts.providerLockAta),
       getAccountMeta(accounts.nftCollection),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
@@ -580,11 +643,14 @@ export function getCreateVaultHandlerInstruction<
   } as CreateVaultHandlerInstruction<
     TProgramAddress,
     TAccountProvider,
+    TAccountOperatorRegistry,
     TAccountVault,
     TAccountConfigAccount,
     TAccountNftConfig,
     TAccountRewardTokenMint,
     TAccountLockMint,
+    TAccountVaultLockAta,
+    TAccountProviderLockAta,
     TAccountNftCollection,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
@@ -602,23 +668,29 @@ export type ParsedCreateVaultHandlerInstruction<
   accounts: {
     /** The vault provider/creator who pays for account initialization */
     provider: TAccountMetas[0];
+    /** Per-operator vault counter, created with the operator's first vault */
+    operatorRegistry: TAccountMetas[1];
     /** The vault account to be created */
-    vault: TAccountMetas[1];
+    vault: TAccountMetas[2];
     /** Global authority configuration */
-    configAccount: TAccountMetas[2];
+    configAccount: TAccountMetas[3];
     /** NFT marketplace configuration */
-    nftConfig: TAccountMetas[3];
-    /** Reward token mint - tokens distributed as rewards */
-    rewardTokenMint: TAccountMetas[4];
+    nftConfig: TAccountMetas[4];
+    /** Reward token mint - first reward stream of the vault */
+    rewardTokenMint: TAccountMetas[5];
     /** Staking/locking token mint - tokens locked by investors */
-    lockMint: TAccountMetas[5];
+    lockMint: TAccountMetas[6];
+    /** Vault's locking token account, holds the operator bond and investor capital */
+    vaultLockAta: TAccountMetas[7];
+    /** Provider's locking token account funding the operator bond */
+    providerLockAta: TAccountMetas[8];
     /** NFT collection for vault positions */
-    nftCollection: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    mplCoreProgram: TAccountMetas[9];
-    nftMarketplace: TAccountMetas[10];
-    systemProgram: TAccountMetas[11];
+    nftCollection: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    associatedTokenProgram: TAccountMetas[11];
+    mplCoreProgram: TAccountMetas[12];
+    nftMarketplace: TAccountMetas[13];
+    systemProgram: TAccountMetas[14];
   };
   data: CreateVaultHandlerInstructionData;
 };
@@ -631,7 +703,7 @@ export function parseCreateVaultHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCreateVaultHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -645,11 +717,14 @@ export function parseCreateVaultHandlerInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       provider: getNextAccount(),
+      operatorRegistry: getNextAccount(),
       vault: getNextAccount(),
       configAccount: getNextAccount(),
       nftConfig: getNextAccount(),
       rewardTokenMint: getNextAccount(),
       lockMint: getNextAccount(),
+      vaultLockAta: getNextAccount(),
+      providerLockAta: getNextAccount(),
       nftCollection: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
@@ -13,6 +13,10 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -22,12 +26,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -53,12 +59,16 @@ export function getDepositRewardsHandlerDiscriminatorBytes() {
 
 export type DepositRewardsHandlerInstruction<
   TProgram extends string = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
-  TAccountAgent extends string | AccountMeta<string> = string,
+  TAccountDepositor extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountRewardTokenMint extends string | AccountMeta<string> = string,
   TAccountVaultRewardAta extends string | AccountMeta<string> = string,
-  TAccountAgentRewardAta extends string | AccountMeta<string> = string,
+  TAccountTreasury extends string | AccountMeta<string> = string,
+  TAccountTreasuryRewardAta extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountInsuranceFundAta extends string | AccountMeta<string> = string,
+  TAccountDepositorRewardAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
   TAccountAssociatedTokenProgram extends string | AccountMeta<string> =
@@ -70,10 +80,10 @@ export type DepositRewardsHandlerInstruction<
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
-      TAccountAgent extends string
-        ? WritableSignerAccount<TAccountAgent> &
-            AccountSignerMeta<TAccountAgent>
-        : TAccountAgent,
+      TAccountDepositor extends string
+        ? WritableSignerAccount<TAccountDepositor> &
+            AccountSignerMeta<TAccountDepositor>
+        : TAccountDepositor,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
@@ -86,9 +96,21 @@ export type DepositRewardsHandlerInstruction<
       TAccountVaultRewardAta extends string
         ? WritableAccount<TAccountVaultRewardAta>
         : TAccountVaultRewardAta,
-      TAccountAgentRewardAta extends string
-        ? WritableAccount<TAccountAgentRewardAta>
-        : TAccountAgentRewardAta,
+      TAccountTreasury extends string
+        ? ReadonlyAccount<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountTreasuryRewardAta extends string
+        ? WritableAccount<TAccountTreasuryRewardAta>
+        : TAccountTreasuryRewardAta,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountInsuranceFundAta extends string
+        ? WritableAccount<TAccountInsuranceFundAta>
+        : TAccountInsuranceFundAta,
+      TAccountDepositorRewardAta extends string
+        ? WritableAccount<TAccountDepositorRewardAta>
+        : TAccountDepositorRewardAta,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -105,17 +127,20 @@ export type DepositRewardsHandlerInstruction<
 export type DepositRewardsHandlerInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
+  streamDuration: Option<bigint>;
 };
 
 export type DepositRewardsHandlerInstructionDataArgs = {
   amount: number | bigint;
+  streamDuration: OptionOrNullable<number | bigint>;
 };
 
-export function getDepositRewardsHandlerInstructionDataEncoder(): FixedSizeEncoder<DepositRewardsHandlerInstructionDataArgs> {
+export function getDepositRewardsHandlerInstructionDataEncoder(): Encoder<DepositRewardsHandlerInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["amount", getU64Encoder()],
+      ["streamDuration", getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -124,14 +149,15 @@ export function getDepositRewardsHandlerInstructionDataEncoder(): FixedSizeEncod
   );
 }
 
-export function getDepositRewardsHandlerInstructionDataDecoder(): FixedSizeDecoder<DepositRewardsHandlerInstructionData> {
+export function getDepositRewardsHandlerInstructionDataDecoder(): Decoder<DepositRewardsHandlerInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["amount", getU64Decoder()],
+    ["streamDuration", getOptionDecoder(getI64Decoder())],
   ]);
 }
 
-export function getDepositRewardsHandlerInstructionDataCodec(): FixedSizeCodec<
+export function getDepositRewardsHandlerInstructionDataCodec(): Codec<
   DepositRewardsHandlerInstructionDataArgs,
   DepositRewardsHandlerInstructionData
 > {
@@ -142,53 +168,81 @@ export function getDepositRewardsHandlerInstructionDataCodec(): FixedSizeCodec<
 }
 
 export type DepositRewardsHandlerAsyncInput<
-  TAccountAgent extends string = string,
+  TAccountDepositor extends string = string,
   TAccountVault extends string = string,
   TAccountConfig extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountVaultRewardAta extends string = string,
-  TAccountAgentRewardAta extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountTreasuryRewardAta extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountInsuranceFundAta extends string = string,
+  TAccountDepositorRewardAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  /** The authorized agent depositing rewards */
-  agent: TransactionSigner<TAccountAgent>;
+  /** The vault's reward distributor or the global agent */
+  depositor: TransactionSigner<TAccountDepositor>;
   /** The vault receiving rewards */
   vault: Address<TAccountVault>;
   /** Global configuration */
   config?: Address<TAccountConfig>;
-  /** Reward token mint */
+  /** Mint of the reward stream being funded */
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   /** Vault's reward token account */
   vaultRewardAta?: Address<TAccountVaultRewardAta>;
-  /** Agent's reward token account */
-  agentRewardAta?: Address<TAccountAgentRewardAta>;
+  treasury: Address<TAccountTreasury>;
+  /**
+   * Treasury's token account collecting the protocol fee for this mint,
+   * created by the admin through init_fee_accounts. Only required while the
+   * treasury's share of the protocol fee is positive
+   */
+  treasuryRewardAta?: Address<TAccountTreasuryRewardAta>;
+  /**
+   * Insurance fund of the reward mint, receives its slice of the protocol fee.
+   * The admin moves it into the locking token's fund with withdraw_insurance.
+   * Only required while the insurance share of the protocol fee is positive
+   */
+  insuranceFund?: Address<TAccountInsuranceFund>;
+  /** Insurance fund's token account for the reward mint */
+  insuranceFundAta?: Address<TAccountInsuranceFundAta>;
+  /** Depositor's reward token account */
+  depositorRewardAta?: Address<TAccountDepositorRewardAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: DepositRewardsHandlerInstructionDataArgs["amount"];
+  streamDuration: DepositRewardsHandlerInstructionDataArgs["streamDuration"];
 };
 
 export async function getDepositRewardsHandlerInstructionAsync<
-  TAccountAgent extends string,
+  TAccountDepositor extends string,
   TAccountVault extends string,
   TAccountConfig extends string,
   TAccountRewardTokenMint extends string,
   TAccountVaultRewardAta extends string,
-  TAccountAgentRewardAta extends string,
+  TAccountTreasury extends string,
+  TAccountTreasuryRewardAta extends string,
+  TAccountInsuranceFund extends string,
+  TAccountInsuranceFundAta extends string,
+  TAccountDepositorRewardAta extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DepositRewardsHandlerAsyncInput<
-    TAccountAgent,
+    TAccountDepositor,
     TAccountVault,
     TAccountConfig,
     TAccountRewardTokenMint,
     TAccountVaultRewardAta,
-    TAccountAgentRewardAta,
+    TAccountTreasury,
+    TAccountTreasuryRewardAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
+    TAccountDepositorRewardAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -197,12 +251,16 @@ export async function getDepositRewardsHandlerInstructionAsync<
 ): Promise<
   DepositRewardsHandlerInstruction<
     TProgramAddress,
-    TAccountAgent,
+    TAccountDepositor,
     TAccountVault,
     TAccountConfig,
     TAccountRewardTokenMint,
     TAccountVaultRewardAta,
-    TAccountAgentRewardAta,
+    TAccountTreasury,
+    TAccountTreasuryRewardAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
+    TAccountDepositorRewardAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -214,7 +272,7 @@ export async function getDepositRewardsHandlerInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    agent: { value: input.agent ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
     rewardTokenMint: {
@@ -222,7 +280,20 @@ export async function getDepositRewardsHandlerInstructionAsync<
       isWritable: false,
     },
     vaultRewardAta: { value: input.vaultRewardAta ?? null, isWritable: true },
-    agentRewardAta: { value: input.agentRewardAta ?? null, isWritable: true },
+    treasury: { value: input.treasury ?? null, isWritable: false },
+    treasuryRewardAta: {
+      value: input.treasuryRewardAta ?? null,
+      isWritable: true,
+    },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    insuranceFundAta: {
+      value: input.insuranceFundAta ?? null,
+      isWritable: true,
+    },
+    depositorRewardAta: {
+      value: input.depositorRewardAta ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -264,12 +335,12 @@ export async function getDepositRewardsHandlerInstructionAsync<
       ],
     });
   }
-  if (!accounts.agentRewardAta.value) {
-    accounts.agentRewardAta.value = await getProgramDerivedAddress({
+  if (!accounts.depositorRewardAta.value) {
+    accounts.depositorRewardAta.value = await getProgramDerivedAddress({
       programAddress:
         "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.agent.value)),
+        getAddressEncoder().encode(expectAddress(accounts.depositor.value)),
         getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(
           expectAddress(accounts.rewardTokenMint.value),
@@ -289,12 +360,16 @@ export async function getDepositRewardsHandlerInstructionAsync<
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
-      getAccountMeta(accounts.agent),
+      getAccountMeta(accounts.depositor),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.vaultRewardAta),
-      getAccountMeta(accounts.agentRewardAta),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.treasuryRewardAta),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.insuranceFundAta),
+      getAccountMeta(accounts.depositorRewardAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -305,12 +380,16 @@ export async function getDepositRewardsHandlerInstructionAsync<
     programAddress,
   } as DepositRewardsHandlerInstruction<
     TProgramAddress,
-    TAccountAgent,
+    TAccountDepositor,
     TAccountVault,
     TAccountConfig,
     TAccountRewardTokenMint,
     TAccountVaultRewardAta,
-    TAccountAgentRewardAta,
+    TAccountTreasury,
+    TAccountTreasuryRewardAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
+    TAccountDepositorRewardAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -318,53 +397,81 @@ export async function getDepositRewardsHandlerInstructionAsync<
 }
 
 export type DepositRewardsHandlerInput<
-  TAccountAgent extends string = string,
+  TAccountDepositor extends string = string,
   TAccountVault extends string = string,
   TAccountConfig extends string = string,
   TAccountRewardTokenMint extends string = string,
   TAccountVaultRewardAta extends string = string,
-  TAccountAgentRewardAta extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountTreasuryRewardAta extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountInsuranceFundAta extends string = string,
+  TAccountDepositorRewardAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  /** The authorized agent depositing rewards */
-  agent: TransactionSigner<TAccountAgent>;
+  /** The vault's reward distributor or the global agent */
+  depositor: TransactionSigner<TAccountDepositor>;
   /** The vault receiving rewards */
   vault: Address<TAccountVault>;
   /** Global configuration */
   config: Address<TAccountConfig>;
-  /** Reward token mint */
+  /** Mint of the reward stream being funded */
   rewardTokenMint: Address<TAccountRewardTokenMint>;
   /** Vault's reward token account */
   vaultRewardAta: Address<TAccountVaultRewardAta>;
-  /** Agent's reward token account */
-  agentRewardAta: Address<TAccountAgentRewardAta>;
+  treasury: Address<TAccountTreasury>;
+  /**
+   * Treasury's token account collecting the protocol fee for this mint,
+   * created by the admin through init_fee_accounts. Only required while the
+   * treasury's share of the protocol fee is positive
+   */
+  treasuryRewardAta?: Address<TAccountTreasuryRewardAta>;
+  /**
+   * Insurance fund of the reward mint, receives its slice of the protocol fee.
+   * The admin moves it into the locking token's fund with withdraw_insurance.
+   * Only required while the insurance share of the protocol fee is positive
+   */
+  insuranceFund?: Address<TAccountInsuranceFund>;
+  /** Insurance fund's token account for the reward mint */
+  insuranceFundAta?: Address<TAccountInsuranceFundAta>;
+  /** Depositor's reward token account */
+  depositorRewardAta: Address<TAccountDepositorRewardAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: DepositRewardsHandlerInstructionDataArgs["amount"];
+  streamDuration: DepositRewardsHandlerInstructionDataArgs["streamDuration"];
 };
 
 export function getDepositRewardsHandlerInstruction<
-  TAccountAgent extends string,
+  TAccountDepositor extends string,
   TAccountVault extends string,
   TAccountConfig extends string,
   TAccountRewardTokenMint extends string,
   TAccountVaultRewardAta extends string,
-  TAccountAgentRewardAta extends string,
+  TAccountTreasury extends string,
+  TAccountTreasuryRewardAta extends string,
+  TAccountInsuranceFund extends string,
+  TAccountInsuranceFundAta extends string,
+  TAccountDepositorRewardAta extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: DepositRewardsHandlerInput<
-    TAccountAgent,
+    TAccountDepositor,
     TAccountVault,
     TAccountConfig,
     TAccountRewardTokenMint,
     TAccountVaultRewardAta,
-    TAccountAgentRewardAta,
+    TAccountTreasury,
+    TAccountTreasuryRewardAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
+    TAccountDepositorRewardAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -372,12 +479,16 @@ export function getDepositRewardsHandlerInstruction<
   config?: { programAddress?: TProgramAddress },
 ): DepositRewardsHandlerInstruction<
   TProgramAddress,
-  TAccountAgent,
+  TAccountDepositor,
   TAccountVault,
   TAccountConfig,
   TAccountRewardTokenMint,
   TAccountVaultRewardAta,
-  TAccountAgentRewardAta,
+  TAccountTreasury,
+  TAccountTreasuryRewardAta,
+  TAccountInsuranceFund,
+  TAccountInsuranceFundAta,
+  TAccountDepositorRewardAta,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
   TAccountSystemProgram
@@ -388,7 +499,7 @@ export function getDepositRewardsHandlerInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    agent: { value: input.agent ?? null, isWritable: true },
+    depositor: { value: input.depositor ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
     rewardTokenMint: {
@@ -396,7 +507,20 @@ export function getDepositRewardsHandlerInstruction<
       isWritable: false,
     },
     vaultRewardAta: { value: input.vaultRewardAta ?? null, isWritable: true },
-    agentRewardAta: { value: input.agentRewardAta ?? null, isWritable: true },
+    treasury: { value: input.treasury ?? null, isWritable: false },
+    treasuryRewardAta: {
+      value: input.treasuryRewardAta ?? null,
+      isWritable: true,
+    },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    insuranceFundAta: {
+      value: input.insuranceFundAta ?? null,
+      isWritable: true,
+    },
+    depositorRewardAta: {
+      value: input.depositorRewardAta ?? null,
+      isWritable: true,
+    },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -429,12 +553,16 @@ export function getDepositRewardsHandlerInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
-      getAccountMeta(accounts.agent),
+      getAccountMeta(accounts.depositor),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.rewardTokenMint),
       getAccountMeta(accounts.vaultRewardAta),
-      getAccountMeta(accounts.agentRewardAta),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.treasuryRewardAta),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.insuranceFundAta),
+      getAccountMeta(accounts.depositorRewardAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -445,12 +573,16 @@ export function getDepositRewardsHandlerInstruction<
     programAddress,
   } as DepositRewardsHandlerInstruction<
     TProgramAddress,
-    TAccountAgent,
+    TAccountDepositor,
     TAccountVault,
     TAccountConfig,
     TAccountRewardTokenMint,
     TAccountVaultRewardAta,
-    TAccountAgentRewardAta,
+    TAccountTreasury,
+    TAccountTreasuryRewardAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
+    TAccountDepositorRewardAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -463,21 +595,36 @@ export type ParsedDepositRewardsHandlerInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** The authorized agent depositing rewards */
-    agent: TAccountMetas[0];
+    /** The vault's reward distributor or the global agent */
+    depositor: TAccountMetas[0];
     /** The vault receiving rewards */
     vault: TAccountMetas[1];
     /** Global configuration */
     config: TAccountMetas[2];
-    /** Reward token mint */
+    /** Mint of the reward stream being funded */
     rewardTokenMint: TAccountMetas[3];
     /** Vault's reward token account */
     vaultRewardAta: TAccountMetas[4];
-    /** Agent's reward token account */
-    agentRewardAta: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
-    associatedTokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    treasury: TAccountMetas[5];
+    /**
+     * Treasury's token account collecting the protocol fee for this mint,
+     * created by the admin through init_fee_accounts. Only required while the
+     * treasury's share of the protocol fee is positive
+     */
+    treasuryRewardAta?: TAccountMetas[6] | undefined;
+    /**
+     * Insurance fund of the reward mint, receives its slice of the protocol fee.
+     * The admin moves it into the locking token's fund with withdraw_insurance.
+     * Only required while the insurance share of the protocol fee is positive
+     */
+    insuranceFund?: TAccountMetas[7] | undefined;
+    /** Insurance fund's token account for the reward mint */
+    insuranceFundAta?: TAccountMetas[8] | undefined;
+    /** Depositor's reward token account */
+    depositorRewardAta: TAccountMetas[9];
+    tokenProgram: TAccountMetas[10];
+    associatedTokenProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[12];
   };
   data: DepositRewardsHandlerInstructionData;
 };
@@ -490,7 +637,7 @@ export function parseDepositRewardsHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedDepositRewardsHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -500,15 +647,25 @@ export function parseDepositRewardsHandlerInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CAPITAL_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
-      agent: getNextAccount(),
+      depositor: getNextAccount(),
       vault: getNextAccount(),
       config: getNextAccount(),
       rewardTokenMint: getNextAccount(),
       vaultRewardAta: getNextAccount(),
-      agentRewardAta: getNextAccount(),
+      treasury: getNextAccount(),
+      treasuryRewardAta: getNextOptionalAccount(),
+      insuranceFund: getNextOptionalAccount(),
+      insuranceFundAta: getNextOptionalAccount(),
+      depositorRewardAta: getNextAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
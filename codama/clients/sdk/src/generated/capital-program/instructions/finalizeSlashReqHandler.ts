@@ -55,12 +55,15 @@ export function getFinalizeSlashReqHandlerDiscriminatorBytes() {
 
 export type FinalizeSlashReqHandlerInstruction<
   TProgram extends string = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
-  TAccountAgent extends string | AccountMeta<string> = string,
+  TAccountResolver extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
+  TAccountSlashRequest extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
   TAccountLockingTokenMint extends string | AccountMeta<string> = string,
   TAccountVaultTokenAta extends string | AccountMeta<string> = string,
-  TAccountSlashClaimantAta extends string | AccountMeta<string> = string,
+  TAccountSlashEscrowAta extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountInsuranceFundAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
   TAccountAssociatedTokenProgram extends string | AccountMeta<string> =
@@ -72,25 +75,34 @@ export type FinalizeSlashReqHandlerInstruction<
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
-      TAccountAgent extends string
-        ? WritableSignerAccount<TAccountAgent> &
-            AccountSignerMeta<TAccountAgent>
-        : TAccountAgent,
+      TAccountResolver extends string
+        ? WritableSignerAccount<TAccountResolver> &
+            AccountSignerMeta<TAccountResolver>
+        : TAccountResolver,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
+      TAccountSlashRequest extends string
+        ? WritableAccount<TAccountSlashRequest>
+        : TAccountSlashRequest,
       TAccountConfig extends string
         ? ReadonlyAccount<TAccountConfig>
         : TAccountConfig,
       TAccountLockingTokenMint extends string
-        ? ReadonlyAccount<TAccountLockingTokenMint>
+        ? WritableAccount<TAccountLockingTokenMint>
         : TAccountLockingTokenMint,
       TAccountVaultTokenAta extends string
         ? WritableAccount<TAccountVaultTokenAta>
         : TAccountVaultTokenAta,
-      TAccountSlashClaimantAta extends string
-        ? WritableAccount<TAccountSlashClaimantAta>
-        : TAccountSlashClaimantAta,
+      TAccountSlashEscrowAta extends string
+        ? WritableAccount<TAccountSlashEscrowAta>
+        : TAccountSlashEscrowAta,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountInsuranceFundAta extends string
+        ? WritableAccount<TAccountInsuranceFundAta>
+        : TAccountInsuranceFundAta,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -148,25 +160,34 @@ export function getFinalizeSlashReqHandlerInstructionDataCodec(): FixedSizeCodec
 }
 
 export type FinalizeSlashReqHandlerAsyncInput<
-  TAccountAgent extends string = string,
+  TAccountResolver extends string = string,
   TAccountVault extends string = string,
+  TAccountSlashRequest extends string = string,
   TAccountConfig extends string = string,
   TAccountLockingTokenMint extends string = string,
   TAccountVaultTokenAta extends string = string,
-  TAccountSlashClaimantAta extends string = string,
+  TAccountSlashEscrowAta extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountInsuranceFundAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  agent: TransactionSigner<TAccountAgent>;
+  /** The agent, or the admin once the node operator appealed */
+  resolver: TransactionSigner<TAccountResolver>;
   vault: Address<TAccountVault>;
+  /** The vault's open slash request, kept as a record once resolved */
+  slashRequest: Address<TAccountSlashRequest>;
   config?: Address<TAccountConfig>;
   /** Locking token mint */
   lockingTokenMint: Address<TAccountLockingTokenMint>;
   /** Vault's token account */
   vaultTokenAta?: Address<TAccountVaultTokenAta>;
-  /** Capital provider's token account */
-  slashClaimantAta?: Address<TAccountSlashClaimantAta>;
+  /** Holds the claimants' part of the slash until each of them claims it */
+  slashEscrowAta?: Address<TAccountSlashEscrowAta>;
+  /** Insurance fund of the locking token, required when part of the slash goes there */
+  insuranceFund?: Address<TAccountInsuranceFund>;
+  insuranceFundAta?: Address<TAccountInsuranceFundAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -175,24 +196,30 @@ export type FinalizeSlashReqHandlerAsyncInput<
 };
 
 export async function getFinalizeSlashReqHandlerInstructionAsync<
-  TAccountAgent extends string,
+  TAccountResolver extends string,
   TAccountVault extends string,
+  TAccountSlashRequest extends string,
   TAccountConfig extends string,
   TAccountLockingTokenMint extends string,
   TAccountVaultTokenAta extends string,
-  TAccountSlashClaimantAta extends string,
+  TAccountSlashEscrowAta extends string,
+  TAccountInsuranceFund extends string,
+  TAccountInsuranceFundAta extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: FinalizeSlashReqHandlerAsyncInput<
-    TAccountAgent,
+    TAccountResolver,
     TAccountVault,
+    TAccountSlashRequest,
     TAccountConfig,
     TAccountLockingTokenMint,
     TAccountVaultTokenAta,
-    TAccountSlashClaimantAta,
+    TAccountSlashEscrowAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -201,12 +228,15 @@ export async function getFinalizeSlashReqHandlerInstructionAsync<
 ): Promise<
   FinalizeSlashReqHandlerInstruction<
     TProgramAddress,
-    TAccountAgent,
+    TAccountResolver,
     TAccountVault,
+    TAccountSlashRequest,
     TAccountConfig,
     TAccountLockingTokenMint,
     TAccountVaultTokenAta,
-    TAccountSlashClaimantAta,
+    TAccountSlashEscrowAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -218,16 +248,19 @@ export async function getFinalizeSlashReqHandlerInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    agent: { value: input.agent ?? null, isWritable: true },
+    resolver: { value: input.resolver ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    slashRequest: { value: input.slashRequest ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
     lockingTokenMint: {
       value: input.lockingTokenMint ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     vaultTokenAta: { value: input.vaultTokenAta ?? null, isWritable: true },
-    slashClaimantAta: {
-      value: input.slashClaimantAta ?? null,
+    slashEscrowAta: { value: input.slashEscrowAta ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    insuranceFundAta: {
+      value: input.insuranceFundAta ?? null,
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -271,12 +304,12 @@ export async function getFinalizeSlashReqHandlerInstructionAsync<
       ],
     });
   }
-  if (!accounts.slashClaimantAta.value) {
-    accounts.slashClaimantAta.value = await getProgramDerivedAddress({
+  if (!accounts.slashEscrowAta.value) {
+    accounts.slashEscrowAta.value = await getProgramDerivedAddress({
       programAddress:
         "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">,
       seeds: [
-        getAddressEncoder().encode(expectAddress(accounts.agent.value)),
+        getAddressEncoder().encode(expectAddress(accounts.slashRequest.value)),
         getAddressEncoder().encode(expectAddress(accounts.tokenProgram.value)),
         getAddressEncoder().encode(
           expectAddress(accounts.lockingTokenMint.value),
@@ -296,12 +329,15 @@ export async function getFinalizeSlashReqHandlerInstructionAsync<
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
-      getAccountMeta(accounts.agent),
+      getAccountMeta(accounts.resolver),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slashRequest),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.lockingTokenMint),
       getAccountMeta(accounts.vaultTokenAta),
-      getAccountMeta(accounts.slashClaimantAta),
+      getAccountMeta(accounts.slashEscrowAta),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.insuranceFundAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -312,12 +348,15 @@ export async function getFinalizeSlashReqHandlerInstructionAsync<
     programAddress,
   } as FinalizeSlashReqHandlerInstruction<
     TProgramAddress,
-    TAccountAgent,
+    TAccountResolver,
     TAccountVault,
+    TAccountSlashRequest,
     TAccountConfig,
     TAccountLockingTokenMint,
     TAccountVaultTokenAta,
-    TAccountSlashClaimantAta,
+    TAccountSlashEscrowAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -325,25 +364,34 @@ export async function getFinalizeSlashReqHandlerInstructionAsync<
 }
 
 export type FinalizeSlashReqHandlerInput<
-  TAccountAgent extends string = string,
+  TAccountResolver extends string = string,
   TAccountVault extends string = string,
+  TAccountSlashRequest extends string = string,
   TAccountConfig extends string = string,
   TAccountLockingTokenMint extends string = string,
   TAccountVaultTokenAta extends string = string,
-  TAccountSlashClaimantAta extends string = string,
+  TAccountSlashEscrowAta extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountInsuranceFundAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  agent: TransactionSigner<TAccountAgent>;
+  /** The agent, or the admin once the node operator appealed */
+  resolver: TransactionSigner<TAccountResolver>;
   vault: Address<TAccountVault>;
+  /** The vault's open slash request, kept as a record once resolved */
+  slashRequest: Address<TAccountSlashRequest>;
   config: Address<TAccountConfig>;
   /** Locking token mint */
   lockingTokenMint: Address<TAccountLockingTokenMint>;
   /** Vault's token account */
   vaultTokenAta: Address<TAccountVaultTokenAta>;
-  /** Capital provider's token account */
-  slashClaimantAta: Address<TAccountSlashClaimantAta>;
+  /** Holds the claimants' part of the slash until each of them claims it */
+  slashEscrowAta: Address<TAccountSlashEscrowAta>;
+  /** Insurance fund of the locking token, required when part of the slash goes there */
+  insuranceFund?: Address<TAccountInsuranceFund>;
+  insuranceFundAta?: Address<TAccountInsuranceFundAta>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -352,24 +400,30 @@ export type FinalizeSlashReqHandlerInput<
 };
 
 export function getFinalizeSlashReqHandlerInstruction<
-  TAccountAgent extends string,
+  TAccountResolver extends string,
   TAccountVault extends string,
+  TAccountSlashRequest extends string,
   TAccountConfig extends string,
   TAccountLockingTokenMint extends string,
   TAccountVaultTokenAta extends string,
-  TAccountSlashClaimantAta extends string,
+  TAccountSlashEscrowAta extends string,
+  TAccountInsuranceFund extends string,
+  TAccountInsuranceFundAta extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAPITAL_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: FinalizeSlashReqHandlerInput<
-    TAccountAgent,
+    TAccountResolver,
     TAccountVault,
+    TAccountSlashRequest,
     TAccountConfig,
     TAccountLockingTokenMint,
     TAccountVaultTokenAta,
-    TAccountSlashClaimantAta,
+    TAccountSlashEscrowAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -377,12 +431,15 @@ export function getFinalizeSlashReqHandlerInstruction<
   config?: { programAddress?: TProgramAddress },
 ): FinalizeSlashReqHandlerInstruction<
   TProgramAddress,
-  TAccountAgent,
+  TAccountResolver,
   TAccountVault,
+  TAccountSlashRequest,
   TAccountConfig,
   TAccountLockingTokenMint,
   TAccountVaultTokenAta,
-  TAccountSlashClaimantAta,
+  TAccountSlashEscrowAta,
+  TAccountInsuranceFund,
+  TAccountInsuranceFundAta,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
   TAccountSystemProgram
@@ -393,16 +450,19 @@ export function getFinalizeSlashReqHandlerInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    agent: { value: input.agent ?? null, isWritable: true },
+    resolver: { value: input.resolver ?? null, isWritable: true },
     vault: { value: input.vault ?? null, isWritable: true },
+    slashRequest: { value: input.slashRequest ?? null, isWritable: true },
     config: { value: input.config ?? null, isWritable: false },
     lockingTokenMint: {
       value: input.lockingTokenMint ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     vaultTokenAta: { value: input.vaultTokenAta ?? null, isWritable: true },
-    slashClaimantAta: {
-      value: input.slashClaimantAta ?? null,
+    slashEscrowAta: { value: input.slashEscrowAta ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    insuranceFundAta: {
+      value: input.insuranceFundAta ?? null,
       isWritable: true,
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -437,12 +497,15 @@ export function getFinalizeSlashReqHandlerInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
-      getAccountMeta(accounts.agent),
+      getAccountMeta(accounts.resolver),
       getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.slashRequest),
       getAccountMeta(accounts.config),
       getAccountMeta(accounts.lockingTokenMint),
       getAccountMeta(accounts.vaultTokenAta),
-      getAccountMeta(accounts.slashClaimantAta),
+      getAccountMeta(accounts.slashEscrowAta),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.insuranceFundAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -453,12 +516,15 @@ export function getFinalizeSlashReqHandlerInstruction<
     programAddress,
   } as FinalizeSlashReqHandlerInstruction<
     TProgramAddress,
-    TAccountAgent,
+    TAccountResolver,
     TAccountVault,
+    TAccountSlashRequest,
     TAccountConfig,
     TAccountLockingTokenMint,
     TAccountVaultTokenAta,
-    TAccountSlashClaimantAta,
+    TAccountSlashEscrowAta,
+    TAccountInsuranceFund,
+    TAccountInsuranceFundAta,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram
@@ -471,18 +537,24 @@ export type ParsedFinalizeSlashReqHandlerInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    agent: TAccountMetas[0];
+    /** The agent, or the admin once the node operator appealed */
+    resolver: TAccountMetas[0];
     vault: TAccountMetas[1];
-    config: TAccountMetas[2];
+    /** The vault's open slash request, kept as a record once resolved */
+    slashRequest: TAccountMetas[2];
+    config: TAccountMetas[3];
     /** Locking token mint */
-    lockingTokenMint: TAccountMetas[3];
+    lockingTokenMint: TAccountMetas[4];
     /** Vault's token account */
-    vaultTokenAta: TAccountMetas[4];
-    /** Capital provider's token account */
-    slashClaimantAta: TAccountMetas[5];
-    tokenProgram: TAccountMetas[6];
-    associatedTokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    vaultTokenAta: TAccountMetas[5];
+    /** Holds the claimants' part of the slash until each of them claims it */
+    slashEscrowAta: TAccountMetas[6];
+    /** Insurance fund of the locking token, required when part of the slash goes there */
+    insuranceFund?: TAccountMetas[7] | undefined;
+    insuranceFundAta?: TAccountMetas[8] | undefined;
+    tokenProgram: TAccountMetas[9];
+    associatedTokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
   };
   data: FinalizeSlashReqHandlerInstructionData;
 };
@@ -495,7 +567,7 @@ export function parseFinalizeSlashReqHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedFinalizeSlashReqHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -505,15 +577,24 @@ export function parseFinalizeSlashReqHandlerInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CAPITAL_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
-      agent: getNextAccount(),
+      resolver: getNextAccount(),
       vault: getNextAccount(),
+      slashRequest: getNextAccount(),
       config: getNextAccount(),
       lockingTokenMint: getNextAccount(),
       vaultTokenAta: getNextAccount(),
-      slashClaimantAta: getNextAccount(),
+      slashEscrowAta: getNextAccount(),
+      insuranceFund: getNextOptionalAccount(),
+      insuranceFundAta: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
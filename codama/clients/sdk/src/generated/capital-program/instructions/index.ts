@@ -6,14 +6,49 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./acceptAdminHandler";
+export * from "./addBeneficiaryHandler";
+export * from "./addRewardStreamHandler";
+export * from "./advancePhaseHandler";
+export * from "./appealSlashReqHandler";
+export * from "./challengeMissedEpochHandler";
 export * from "./claimBeneficiaryRewardsHandler";
 export * from "./claimInvestorRewardsHandler";
+export * from "./claimOperatorCommissionHandler";
+export * from "./claimSlashPayoutHandler";
 export * from "./closePositionHandler";
 export * from "./closeVaultHandler";
+export * from "./commitEpochHandler";
 export * from "./createSlasReqHandler";
 export * from "./createVaultHandler";
+export * from "./depositInsuranceHandler";
 export * from "./depositRewardsHandler";
+export * from "./dismissSlashReqHandler";
+export * from "./donateRewardsHandler";
+export * from "./earlyUnlockPositionHandler";
 export * from "./finalizeSlashReqHandler";
 export * from "./initCapitalProgramHandler";
+export * from "./initFeeAccountsHandler";
+export * from "./issueChallengeHandler";
 export * from "./openPositionHandler";
+export * from "./proposeAdminHandler";
+export * from "./registerAttesterHandler";
+export * from "./removeAttesterHandler";
+export * from "./removeBeneficiaryHandler";
+export * from "./resizeBeneficiaryHandler";
+export * from "./respondChallengeHandler";
+export * from "./setProtocolPauseHandler";
+export * from "./setVaultPauseHandler";
+export * from "./slashFailedChallengeHandler";
+export * from "./submitAttestationHandler";
+export * from "./sweepVaultHandler";
+export * from "./transferBeneficiaryHandler";
+export * from "./updateAgentHandler";
+export * from "./updateArbitersHandler";
+export * from "./updateGuardianHandler";
 export * from "./updatePositionHandler";
+export * from "./updateProgramConfigHandler";
+export * from "./updateRewardDistributorHandler";
+export * from "./voteSlashHandler";
+export * from "./withdrawInsuranceHandler";
+export * from "./withdrawTreasuryHandler";
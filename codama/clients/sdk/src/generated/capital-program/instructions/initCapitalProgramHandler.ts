@@ -88,10 +88,8 @@ export type InitCapitalProgramHandlerInstructionData = {
   discriminator: ReadonlyUint8Array;
   /** Agent address for administrative operations */
   agent: Address;
-  /** Fee charged for early unlock (in lamports or basis points) */
+  /** Fee charged on principal for early unlock (in basis points) */
   earlyUnlockFee: bigint;
-  /** Time window for dispute resolution (in seconds) */
-  disputeWindow: bigint;
   /** Maximum allowed lock duration (in seconds) */
   maxLockDuration: bigint;
   /** Minimum allowed lock duration (in seconds) */
@@ -101,10 +99,8 @@ export type InitCapitalProgramHandlerInstructionData = {
 export type InitCapitalProgramHandlerInstructionDataArgs = {
   /** Agent address for administrative operations */
   agent: Address;
-  /** Fee charged for early unlock (in lamports or basis points) */
+  /** Fee charged on principal for early unlock (in basis points) */
   earlyUnlockFee: number | bigint;
-  /** Time window for dispute resolution (in seconds) */
-  disputeWindow: number | bigint;
   /** Maximum allowed lock duration (in seconds) */
   maxLockDuration: number | bigint;
   /** Minimum allowed lock duration (in seconds) */
@@ -117,7 +113,6 @@ export function getInitCapitalProgramHandlerInstructionDataEncoder(): FixedSizeE
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["agent", getAddressEncoder()],
       ["earlyUnlockFee", getU64Encoder()],
-      ["disputeWindow", getI64Encoder()],
       ["maxLockDuration", getI64Encoder()],
       ["minLockDuration", getI64Encoder()],
     ]),
@@ -133,7 +128,6 @@ export function getInitCapitalProgramHandlerInstructionDataDecoder(): FixedSizeD
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["agent", getAddressDecoder()],
     ["earlyUnlockFee", getU64Decoder()],
-    ["disputeWindow", getI64Decoder()],
     ["maxLockDuration", getI64Decoder()],
     ["minLockDuration", getI64Decoder()],
   ]);
@@ -156,7 +150,10 @@ export type InitCapitalProgramHandlerAsyncInput<
   TAccountNftProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  /** Global authority configuration account */
+  /**
+   * Global authority configuration account
+   * re-initialization is not possible because of the init constraint
+   */
   config?: Address<TAccountConfig>;
   /** NFT program configuration account */
   nftConfig: Address<TAccountNftConfig>;
@@ -167,7 +164,6 @@ export type InitCapitalProgramHandlerAsyncInput<
   systemProgram?: Address<TAccountSystemProgram>;
   agent: InitCapitalProgramHandlerInstructionDataArgs["agent"];
   earlyUnlockFee: InitCapitalProgramHandlerInstructionDataArgs["earlyUnlockFee"];
-  disputeWindow: InitCapitalProgramHandlerInstructionDataArgs["disputeWindow"];
   maxLockDuration: InitCapitalProgramHandlerInstructionDataArgs["maxLockDuration"];
   minLockDuration: InitCapitalProgramHandlerInstructionDataArgs["minLockDuration"];
 };
@@ -266,7 +262,10 @@ export type InitCapitalProgramHandlerInput<
   TAccountNftProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
-  /** Global authority configuration account */
+  /**
+   * Global authority configuration account
+   * re-initialization is not possible because of the init constraint
+   */
   config: Address<TAccountConfig>;
   /** NFT program configuration account */
   nftConfig: Address<TAccountNftConfig>;
@@ -277,7 +276,6 @@ export type InitCapitalProgramHandlerInput<
   systemProgram?: Address<TAccountSystemProgram>;
   agent: InitCapitalProgramHandlerInstructionDataArgs["agent"];
   earlyUnlockFee: InitCapitalProgramHandlerInstructionDataArgs["earlyUnlockFee"];
-  disputeWindow: InitCapitalProgramHandlerInstructionDataArgs["disputeWindow"];
   maxLockDuration: InitCapitalProgramHandlerInstructionDataArgs["maxLockDuration"];
   minLockDuration: InitCapitalProgramHandlerInstructionDataArgs["minLockDuration"];
 };
@@ -365,7 +363,10 @@ export type ParsedInitCapitalProgramHandlerInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** Global authority configuration account */
+    /**
+     * Global authority configuration account
+     * re-initialization is not possible because of the init constraint
+     */
     config: TAccountMetas[0];
     /** NFT program configuration account */
     nftConfig: TAccountMetas[1];
@@ -14,27 +14,70 @@ import {
   type ReadonlyUint8Array,
 } from "gill";
 import {
+  type ParsedAcceptAdminHandlerInstruction,
+  type ParsedAddBeneficiaryHandlerInstruction,
+  type ParsedAddRewardStreamHandlerInstruction,
+  type ParsedAdvancePhaseHandlerInstruction,
+  type ParsedAppealSlashReqHandlerInstruction,
+  type ParsedChallengeMissedEpochHandlerInstruction,
   type ParsedClaimBeneficiaryRewardsHandlerInstruction,
   type ParsedClaimInvestorRewardsHandlerInstruction,
+  type ParsedClaimOperatorCommissionHandlerInstruction,
+  type ParsedClaimSlashPayoutHandlerInstruction,
   type ParsedClosePositionHandlerInstruction,
   type ParsedCloseVaultHandlerInstruction,
+  type ParsedCommitEpochHandlerInstruction,
   type ParsedCreateSlasReqHandlerInstruction,
   type ParsedCreateVaultHandlerInstruction,
+  type ParsedDepositInsuranceHandlerInstruction,
   type ParsedDepositRewardsHandlerInstruction,
+  type ParsedDismissSlashReqHandlerInstruction,
+  type ParsedDonateRewardsHandlerInstruction,
+  type ParsedEarlyUnlockPositionHandlerInstruction,
   type ParsedFinalizeSlashReqHandlerInstruction,
   type ParsedInitCapitalProgramHandlerInstruction,
+  type ParsedInitFeeAccountsHandlerInstruction,
+  type ParsedIssueChallengeHandlerInstruction,
   type ParsedOpenPositionHandlerInstruction,
+  type ParsedProposeAdminHandlerInstruction,
+  type ParsedRegisterAttesterHandlerInstruction,
+  type ParsedRemoveAttesterHandlerInstruction,
+  type ParsedRemoveBeneficiaryHandlerInstruction,
+  type ParsedResizeBeneficiaryHandlerInstruction,
+  type ParsedRespondChallengeHandlerInstruction,
+  type ParsedSetProtocolPauseHandlerInstruction,
+  type ParsedSetVaultPauseHandlerInstruction,
+  type ParsedSlashFailedChallengeHandlerInstruction,
+  type ParsedSubmitAttestationHandlerInstruction,
+  type ParsedSweepVaultHandlerInstruction,
+  type ParsedTransferBeneficiaryHandlerInstruction,
+  type ParsedUpdateAgentHandlerInstruction,
+  type ParsedUpdateArbitersHandlerInstruction,
+  type ParsedUpdateGuardianHandlerInstruction,
   type ParsedUpdatePositionHandlerInstruction,
+  type ParsedUpdateProgramConfigHandlerInstruction,
+  type ParsedUpdateRewardDistributorHandlerInstruction,
+  type ParsedVoteSlashHandlerInstruction,
+  type ParsedWithdrawInsuranceHandlerInstruction,
+  type ParsedWithdrawTreasuryHandlerInstruction,
 } from "../instructions";
 
 export const CAPITAL_PROGRAM_PROGRAM_ADDRESS =
   "8syRdGBunFUWZzkgGigv1KWcTEhxPkc15DMVXEYj9qVn" as Address<"8syRdGBunFUWZzkgGigv1KWcTEhxPkc15DMVXEYj9qVn">;
 
 export enum CapitalProgramAccount {
+  Attestation,
+  Attester,
   AuthorityConfig,
   BaseAssetV1,
+  BeneficiaryAccount,
+  Challenge,
+  EpochCommitment,
+  InsuranceFund,
   NFTConfig,
+  OperatorRegistry,
   Position,
+  SlashRequest,
   Vault,
 }
 
@@ -42,6 +85,28 @@ export function identifyCapitalProgramAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): CapitalProgramAccount {
   const data = "data" in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([152, 125, 183, 86, 36, 146, 121, 73]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.Attestation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([145, 187, 162, 7, 215, 117, 215, 94]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.Attester;
+  }
   if (
     containsBytes(
       data,
@@ -62,6 +127,50 @@ export function identifyCapitalProgramAccount(
   ) {
     return CapitalProgramAccount.BaseAssetV1;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 115, 143, 239, 199, 217, 180, 40]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.BeneficiaryAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 250, 161, 121, 119, 81, 22, 208]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.Challenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([162, 40, 224, 45, 195, 238, 208, 120]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.EpochCommitment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([43, 134, 170, 87, 102, 16, 142, 147]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.InsuranceFund;
+  }
   if (
     containsBytes(
       data,
@@ -73,6 +182,17 @@ export function identifyCapitalProgramAccount(
   ) {
     return CapitalProgramAccount.NFTConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([194, 188, 172, 240, 220, 209, 36, 100]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.OperatorRegistry;
+  }
   if (
     containsBytes(
       data,
@@ -84,6 +204,17 @@ export function identifyCapitalProgramAccount(
   ) {
     return CapitalProgramAccount.Position;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([147, 209, 38, 84, 60, 216, 230, 70]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramAccount.SlashRequest;
+  }
   if (
     containsBytes(
       data,
@@ -101,23 +232,124 @@ export function identifyCapitalProgramAccount(
 }
 
 export enum CapitalProgramInstruction {
+  AcceptAdminHandler,
+  AddBeneficiaryHandler,
+  AddRewardStreamHandler,
+  AdvancePhaseHandler,
+  AppealSlashReqHandler,
+  ChallengeMissedEpochHandler,
   ClaimBeneficiaryRewardsHandler,
   ClaimInvestorRewardsHandler,
+  ClaimOperatorCommissionHandler,
+  ClaimSlashPayoutHandler,
   ClosePositionHandler,
   CloseVaultHandler,
+  CommitEpochHandler,
   CreateSlasReqHandler,
   CreateVaultHandler,
+  DepositInsuranceHandler,
   DepositRewardsHandler,
+  DismissSlashReqHandler,
+  DonateRewardsHandler,
+  EarlyUnlockPositionHandler,
   FinalizeSlashReqHandler,
   InitCapitalProgramHandler,
+  InitFeeAccountsHandler,
+  IssueChallengeHandler,
   OpenPositionHandler,
+  ProposeAdminHandler,
+  RegisterAttesterHandler,
+  RemoveAttesterHandler,
+  RemoveBeneficiaryHandler,
+  ResizeBeneficiaryHandler,
+  RespondChallengeHandler,
+  SetProtocolPauseHandler,
+  SetVaultPauseHandler,
+  SlashFailedChallengeHandler,
+  SubmitAttestationHandler,
+  SweepVaultHandler,
+  TransferBeneficiaryHandler,
+  UpdateAgentHandler,
+  UpdateArbitersHandler,
+  UpdateGuardianHandler,
   UpdatePositionHandler,
+  UpdateProgramConfigHandler,
+  UpdateRewardDistributorHandler,
+  VoteSlashHandler,
+  WithdrawInsuranceHandler,
+  WithdrawTreasuryHandler,
 }
 
 export function identifyCapitalProgramInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): CapitalProgramInstruction {
   const data = "data" in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 160, 95, 25, 154, 44, 52, 76]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.AcceptAdminHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([220, 153, 168, 16, 29, 103, 158, 153]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.AddBeneficiaryHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 197, 232, 248, 125, 67, 74, 224]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.AddRewardStreamHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 177, 253, 106, 231, 2, 110, 97]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.AdvancePhaseHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([41, 125, 242, 251, 43, 97, 206, 30]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.AppealSlashReqHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([69, 193, 55, 77, 16, 110, 101, 117]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.ChallengeMissedEpochHandler;
+  }
   if (
     containsBytes(
       data,
@@ -140,6 +372,28 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.ClaimInvestorRewardsHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([159, 189, 109, 37, 174, 216, 13, 232]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.ClaimOperatorCommissionHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([50, 223, 174, 244, 250, 103, 203, 60]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.ClaimSlashPayoutHandler;
+  }
   if (
     containsBytes(
       data,
@@ -162,6 +416,17 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.CloseVaultHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([56, 187, 90, 12, 106, 2, 84, 167]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.CommitEpochHandler;
+  }
   if (
     containsBytes(
       data,
@@ -184,6 +449,17 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.CreateVaultHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([247, 68, 152, 212, 56, 251, 173, 126]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.DepositInsuranceHandler;
+  }
   if (
     containsBytes(
       data,
@@ -195,6 +471,39 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.DepositRewardsHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([22, 78, 156, 73, 212, 23, 233, 188]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.DismissSlashReqHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 8, 104, 252, 175, 99, 200, 112]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.DonateRewardsHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 172, 114, 218, 200, 42, 69, 14]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.EarlyUnlockPositionHandler;
+  }
   if (
     containsBytes(
       data,
@@ -217,6 +526,28 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.InitCapitalProgramHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([224, 206, 104, 192, 163, 154, 123, 118]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.InitFeeAccountsHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 41, 27, 32, 244, 69, 46, 108]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.IssueChallengeHandler;
+  }
   if (
     containsBytes(
       data,
@@ -228,6 +559,171 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.OpenPositionHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([166, 66, 191, 93, 254, 0, 238, 221]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.ProposeAdminHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([81, 47, 48, 212, 137, 155, 161, 184]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.RegisterAttesterHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 188, 48, 12, 27, 124, 151, 186]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.RemoveAttesterHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([177, 197, 91, 42, 54, 248, 129, 182]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.RemoveBeneficiaryHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 169, 252, 54, 182, 68, 56, 215]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.ResizeBeneficiaryHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([183, 67, 98, 20, 109, 0, 126, 167]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.RespondChallengeHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 172, 101, 246, 77, 159, 90, 181]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.SetProtocolPauseHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 41, 111, 222, 184, 62, 115, 238]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.SetVaultPauseHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([196, 238, 59, 143, 199, 182, 242, 113]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.SlashFailedChallengeHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([224, 153, 19, 67, 246, 46, 23, 102]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.SubmitAttestationHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 209, 249, 178, 241, 19, 251, 138]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.SweepVaultHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 89, 56, 126, 16, 133, 83, 41]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.TransferBeneficiaryHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([147, 90, 45, 104, 252, 140, 161, 214]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.UpdateAgentHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([76, 89, 247, 240, 11, 247, 211, 243]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.UpdateArbitersHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([46, 6, 32, 87, 251, 121, 255, 81]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.UpdateGuardianHandler;
+  }
   if (
     containsBytes(
       data,
@@ -239,6 +735,61 @@ export function identifyCapitalProgramInstruction(
   ) {
     return CapitalProgramInstruction.UpdatePositionHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([225, 91, 134, 192, 56, 217, 107, 162]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.UpdateProgramConfigHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 118, 55, 26, 33, 50, 196, 35]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.UpdateRewardDistributorHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([104, 19, 117, 60, 100, 177, 54, 96]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.VoteSlashHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([7, 219, 218, 239, 241, 106, 157, 252]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.WithdrawInsuranceHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 129, 210, 203, 200, 97, 63, 148]),
+      ),
+      0,
+    )
+  ) {
+    return CapitalProgramInstruction.WithdrawTreasuryHandler;
+  }
   throw new Error(
     "The provided instruction could not be identified as a capitalProgram instruction.",
   );
@@ -247,36 +798,141 @@ export function identifyCapitalProgramInstruction(
 export type ParsedCapitalProgramInstruction<
   TProgram extends string = "8syRdGBunFUWZzkgGigv1KWcTEhxPkc15DMVXEYj9qVn",
 > =
+  | ({
+      instructionType: CapitalProgramInstruction.AcceptAdminHandler;
+    } & ParsedAcceptAdminHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.AddBeneficiaryHandler;
+    } & ParsedAddBeneficiaryHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.AddRewardStreamHandler;
+    } & ParsedAddRewardStreamHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.AdvancePhaseHandler;
+    } & ParsedAdvancePhaseHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.AppealSlashReqHandler;
+    } & ParsedAppealSlashReqHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.ChallengeMissedEpochHandler;
+    } & ParsedChallengeMissedEpochHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.ClaimBeneficiaryRewardsHandler;
     } & ParsedClaimBeneficiaryRewardsHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.ClaimInvestorRewardsHandler;
     } & ParsedClaimInvestorRewardsHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.ClaimOperatorCommissionHandler;
+    } & ParsedClaimOperatorCommissionHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.ClaimSlashPayoutHandler;
+    } & ParsedClaimSlashPayoutHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.ClosePositionHandler;
     } & ParsedClosePositionHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.CloseVaultHandler;
     } & ParsedCloseVaultHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.CommitEpochHandler;
+    } & ParsedCommitEpochHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.CreateSlasReqHandler;
     } & ParsedCreateSlasReqHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.CreateVaultHandler;
     } & ParsedCreateVaultHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.DepositInsuranceHandler;
+    } & ParsedDepositInsuranceHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.DepositRewardsHandler;
     } & ParsedDepositRewardsHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.DismissSlashReqHandler;
+    } & ParsedDismissSlashReqHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.DonateRewardsHandler;
+    } & ParsedDonateRewardsHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.EarlyUnlockPositionHandler;
+    } & ParsedEarlyUnlockPositionHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.FinalizeSlashReqHandler;
     } & ParsedFinalizeSlashReqHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.InitCapitalProgramHandler;
     } & ParsedInitCapitalProgramHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.InitFeeAccountsHandler;
+    } & ParsedInitFeeAccountsHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.IssueChallengeHandler;
+    } & ParsedIssueChallengeHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.OpenPositionHandler;
     } & ParsedOpenPositionHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.ProposeAdminHandler;
+    } & ParsedProposeAdminHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.RegisterAttesterHandler;
+    } & ParsedRegisterAttesterHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.RemoveAttesterHandler;
+    } & ParsedRemoveAttesterHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.RemoveBeneficiaryHandler;
+    } & ParsedRemoveBeneficiaryHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.ResizeBeneficiaryHandler;
+    } & ParsedResizeBeneficiaryHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.RespondChallengeHandler;
+    } & ParsedRespondChallengeHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.SetProtocolPauseHandler;
+    } & ParsedSetProtocolPauseHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.SetVaultPauseHandler;
+    } & ParsedSetVaultPauseHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.SlashFailedChallengeHandler;
+    } & ParsedSlashFailedChallengeHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.SubmitAttestationHandler;
+    } & ParsedSubmitAttestationHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.SweepVaultHandler;
+    } & ParsedSweepVaultHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.TransferBeneficiaryHandler;
+    } & ParsedTransferBeneficiaryHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.UpdateAgentHandler;
+    } & ParsedUpdateAgentHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.UpdateArbitersHandler;
+    } & ParsedUpdateArbitersHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.UpdateGuardianHandler;
+    } & ParsedUpdateGuardianHandlerInstruction<TProgram>)
   | ({
       instructionType: CapitalProgramInstruction.UpdatePositionHandler;
-    } & ParsedUpdatePositionHandlerInstruction<TProgram>);
+    } & ParsedUpdatePositionHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.UpdateProgramConfigHandler;
+    } & ParsedUpdateProgramConfigHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.UpdateRewardDistributorHandler;
+    } & ParsedUpdateRewardDistributorHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.VoteSlashHandler;
+    } & ParsedVoteSlashHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.WithdrawInsuranceHandler;
+    } & ParsedWithdrawInsuranceHandlerInstruction<TProgram>)
+  | ({
+      instructionType: CapitalProgramInstruction.WithdrawTreasuryHandler;
+    } & ParsedWithdrawTreasuryHandlerInstruction<TProgram>);
@@ -25,14 +25,18 @@ import {
 export type BeneficiaryRewardsClaimedEvent = {
   beneficiary: Address;
   vault: Address;
+  rewardMint: Address;
   amount: bigint;
+  unvestedAmount: bigint;
   timestamp: bigint;
 };
 
 export type BeneficiaryRewardsClaimedEventArgs = {
   beneficiary: Address;
   vault: Address;
+  rewardMint: Address;
   amount: number | bigint;
+  unvestedAmount: number | bigint;
   timestamp: number | bigint;
 };
 
@@ -40,7 +44,9 @@ export function getBeneficiaryRewardsClaimedEventEncoder(): FixedSizeEncoder<Ben
   return getStructEncoder([
     ["beneficiary", getAddressEncoder()],
     ["vault", getAddressEncoder()],
+    ["rewardMint", getAddressEncoder()],
     ["amount", getU64Encoder()],
+    ["unvestedAmount", getU64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
 }
@@ -49,7 +55,9 @@ export function getBeneficiaryRewardsClaimedEventDecoder(): FixedSizeDecoder<Ben
   return getStructDecoder([
     ["beneficiary", getAddressDecoder()],
     ["vault", getAddressDecoder()],
+    ["rewardMint", getAddressDecoder()],
     ["amount", getU64Decoder()],
+    ["unvestedAmount", getU64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
 }
@@ -6,17 +6,61 @@
  * @see https://github.com/codama-idl/codama
  */
 
-export * from "./beneficiary";
+export * from "./adminTransferProposedEvent";
+export * from "./adminTransferredEvent";
+export * from "./agentUpdatedEvent";
+export * from "./arbiterApproval";
+export * from "./arbitersUpdatedEvent";
+export * from "./attestationSubmittedEvent";
+export * from "./attesterRegisteredEvent";
+export * from "./attesterRemovedEvent";
+export * from "./beneficiaryAddedEvent";
+export * from "./beneficiaryRemovedEvent";
+export * from "./beneficiaryResizedEvent";
 export * from "./beneficiaryRewardsClaimedEvent";
+export * from "./beneficiaryTransferredEvent";
+export * from "./beneficiaryVesting";
+export * from "./challengeAnsweredEvent";
+export * from "./challengeIssuedEvent";
+export * from "./challengeSlashedEvent";
+export * from "./challengeSlashTerms";
+export * from "./challengeStatus";
+export * from "./depositorRole";
+export * from "./epochCommittedEvent";
+export * from "./epochMissedEvent";
+export * from "./guardianUpdatedEvent";
+export * from "./insuranceClaimedEvent";
+export * from "./insuranceDepositedEvent";
+export * from "./insuranceWithdrawnEvent";
 export * from "./key";
+export * from "./operatorCommissionClaimedEvent";
+export * from "./pausedEvent";
 export * from "./positionClosedEvent";
+export * from "./positionEarlyUnlockedEvent";
 export * from "./positionOpenedEvent";
 export * from "./positionUpdatedEvent";
+export * from "./programConfigUpdatedEvent";
 export * from "./programInitializedEvent";
+export * from "./rewardDistributorUpdatedEvent";
 export * from "./rewardsClaimedEvent";
 export * from "./rewardsDepositedEvent";
+export * from "./rewardsDonatedEvent";
+export * from "./rewardStream";
+export * from "./rewardStreamAddedEvent";
+export * from "./slashAppealedEvent";
+export * from "./slashClaimant";
+export * from "./slashPayoutClaimedEvent";
+export * from "./slashReqDismissedEvent";
 export * from "./slashReqFinalizedEvent";
 export * from "./slashRequestCreatedEvent";
+export * from "./slashShare";
+export * from "./slashStatus";
+export * from "./slashVoteCastEvent";
+export * from "./treasuryWithdrawnEvent";
+export * from "./unpausedEvent";
 export * from "./updateAuthority";
 export * from "./vaultClosedEvent";
 export * from "./vaultCreatedEvent";
+export * from "./vaultPhase";
+export * from "./vaultPhaseChangedEvent";
+export * from "./vaultSweptEvent";
@@ -29,7 +29,6 @@ export type ProgramInitializedEvent = {
   nftProgram: Address;
   capitalProgram: Address;
   earlyUnlockFee: bigint;
-  disputeWindow: bigint;
   minLockDuration: bigint;
   maxLockDuration: bigint;
   timestamp: bigint;
@@ -42,7 +41,6 @@ export type ProgramInitializedEventArgs = {
   nftProgram: Address;
   capitalProgram: Address;
   earlyUnlockFee: number | bigint;
-  disputeWindow: number | bigint;
   minLockDuration: number | bigint;
   maxLockDuration: number | bigint;
   timestamp: number | bigint;
@@ -56,7 +54,6 @@ export function getProgramInitializedEventEncoder(): FixedSizeEncoder<ProgramIni
     ["nftProgram", getAddressEncoder()],
     ["capitalProgram", getAddressEncoder()],
     ["earlyUnlockFee", getU64Encoder()],
-    ["disputeWindow", getI64Encoder()],
     ["minLockDuration", getI64Encoder()],
     ["maxLockDuration", getI64Encoder()],
     ["timestamp", getI64Encoder()],
@@ -71,7 +68,6 @@ export function getProgramInitializedEventDecoder(): FixedSizeDecoder<ProgramIni
     ["nftProgram", getAddressDecoder()],
     ["capitalProgram", getAddressDecoder()],
     ["earlyUnlockFee", getU64Decoder()],
-    ["disputeWindow", getI64Decoder()],
     ["minLockDuration", getI64Decoder()],
     ["maxLockDuration", getI64Decoder()],
     ["timestamp", getI64Decoder()],
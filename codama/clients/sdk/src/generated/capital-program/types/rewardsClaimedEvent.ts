@@ -26,6 +26,7 @@ export type RewardsClaimedEvent = {
   holder: Address;
   vault: Address;
   position: Address;
+  rewardMint: Address;
   amount: bigint;
   timestamp: bigint;
 };
@@ -34,6 +35,7 @@ export type RewardsClaimedEventArgs = {
   holder: Address;
   vault: Address;
   position: Address;
+  rewardMint: Address;
   amount: number | bigint;
   timestamp: number | bigint;
 };
@@ -43,6 +45,7 @@ export function getRewardsClaimedEventEncoder(): FixedSizeEncoder<RewardsClaimed
     ["holder", getAddressEncoder()],
     ["vault", getAddressEncoder()],
     ["position", getAddressEncoder()],
+    ["rewardMint", getAddressEncoder()],
     ["amount", getU64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
@@ -53,6 +56,7 @@ export function getRewardsClaimedEventDecoder(): FixedSizeDecoder<RewardsClaimed
     ["holder", getAddressDecoder()],
     ["vault", getAddressDecoder()],
     ["position", getAddressDecoder()],
+    ["rewardMint", getAddressDecoder()],
     ["amount", getU64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
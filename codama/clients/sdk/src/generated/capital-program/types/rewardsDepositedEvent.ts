@@ -21,19 +21,35 @@ import {
   type FixedSizeDecoder,
   type FixedSizeEncoder,
 } from "gill";
+import {
+  getDepositorRoleDecoder,
+  getDepositorRoleEncoder,
+  type DepositorRole,
+  type DepositorRoleArgs,
+} from ".";
 
 export type RewardsDepositedEvent = {
   vault: Address;
-  agent: Address;
+  depositor: Address;
+  depositorRole: DepositorRole;
+  rewardMint: Address;
   amount: bigint;
+  protocolFee: bigint;
+  insuranceFee: bigint;
+  streamDuration: bigint;
   totalRewards: bigint;
   timestamp: bigint;
 };
 
 export type RewardsDepositedEventArgs = {
   vault: Address;
-  agent: Address;
+  depositor: Address;
+  depositorRole: DepositorRoleArgs;
+  rewardMint: Address;
   amount: number | bigint;
+  protocolFee: number | bigint;
+  insuranceFee: number | bigint;
+  streamDuration: number | bigint;
   totalRewards: number | bigint;
   timestamp: number | bigint;
 };
@@ -41,8 +57,13 @@ export type RewardsDepositedEventArgs = {
 export function getRewardsDepositedEventEncoder(): FixedSizeEncoder<RewardsDepositedEventArgs> {
   return getStructEncoder([
     ["vault", getAddressEncoder()],
-    ["agent", getAddressEncoder()],
+    ["depositor", getAddressEncoder()],
+    ["depositorRole", getDepositorRoleEncoder()],
+    ["rewardMint", getAddressEncoder()],
     ["amount", getU64Encoder()],
+    ["protocolFee", getU64Encoder()],
+    ["insuranceFee", getU64Encoder()],
+    ["streamDuration", getI64Encoder()],
     ["totalRewards", getU64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
@@ -51,8 +72,13 @@ export function getRewardsDepositedEventEncoder(): FixedSizeEncoder<RewardsDepos
 export function getRewardsDepositedEventDecoder(): FixedSizeDecoder<RewardsDepositedEvent> {
   return getStructDecoder([
     ["vault", getAddressDecoder()],
-    ["agent", getAddressDecoder()],
+    ["depositor", getAddressDecoder()],
+    ["depositorRole", getDepositorRoleDecoder()],
+    ["rewardMint", getAddressDecoder()],
     ["amount", getU64Decoder()],
+    ["protocolFee", getU64Decoder()],
+    ["insuranceFee", getU64Decoder()],
+    ["streamDuration", getI64Decoder()],
     ["totalRewards", getU64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
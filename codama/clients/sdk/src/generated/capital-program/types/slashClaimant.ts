@@ -10,49 +10,45 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
-  getU64Decoder,
-  getU64Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
   type FixedSizeEncoder,
 } from "gill";
 
-export type Beneficiary = {
+export type SlashClaimant = {
   address: Address;
   shareBps: number;
-  totalClaimed: bigint;
+  claimed: boolean;
 };
 
-export type BeneficiaryArgs = {
-  address: Address;
-  shareBps: number;
-  totalClaimed: number | bigint;
-};
+export type SlashClaimantArgs = SlashClaimant;
 
-export function getBeneficiaryEncoder(): FixedSizeEncoder<BeneficiaryArgs> {
+export function getSlashClaimantEncoder(): FixedSizeEncoder<SlashClaimantArgs> {
   return getStructEncoder([
     ["address", getAddressEncoder()],
     ["shareBps", getU16Encoder()],
-    ["totalClaimed", getU64Encoder()],
+    ["claimed", getBooleanEncoder()],
   ]);
 }
 
-export function getBeneficiaryDecoder(): FixedSizeDecoder<Beneficiary> {
+export function getSlashClaimantDecoder(): FixedSizeDecoder<SlashClaimant> {
   return getStructDecoder([
     ["address", getAddressDecoder()],
     ["shareBps", getU16Decoder()],
-    ["totalClaimed", getU64Decoder()],
+    ["claimed", getBooleanDecoder()],
   ]);
 }
 
-export function getBeneficiaryCodec(): FixedSizeCodec<
-  BeneficiaryArgs,
-  Beneficiary
+export function getSlashClaimantCodec(): FixedSizeCodec<
+  SlashClaimantArgs,
+  SlashClaimant
 > {
-  return combineCodec(getBeneficiaryEncoder(), getBeneficiaryDecoder());
+  return combineCodec(getSlashClaimantEncoder(), getSlashClaimantDecoder());
 }
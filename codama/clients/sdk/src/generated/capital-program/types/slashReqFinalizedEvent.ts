@@ -10,6 +10,8 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getI64Decoder,
   getI64Encoder,
   getStructDecoder,
@@ -21,35 +23,65 @@ import {
   type FixedSizeDecoder,
   type FixedSizeEncoder,
 } from "gill";
+import {
+  getSlashStatusDecoder,
+  getSlashStatusEncoder,
+  type SlashStatus,
+  type SlashStatusArgs,
+} from ".";
 
 export type SlashReqFinalizedEvent = {
-  claimant: Address;
   vault: Address;
+  slashRequest: Address;
+  resolver: Address;
+  appealed: boolean;
+  status: SlashStatus;
   amount: bigint;
+  bondSlashed: bigint;
+  insuranceAmount: bigint;
+  burnedAmount: bigint;
   timestamp: bigint;
 };
 
 export type SlashReqFinalizedEventArgs = {
-  claimant: Address;
   vault: Address;
+  slashRequest: Address;
+  resolver: Address;
+  appealed: boolean;
+  status: SlashStatusArgs;
   amount: number | bigint;
+  bondSlashed: number | bigint;
+  insuranceAmount: number | bigint;
+  burnedAmount: number | bigint;
   timestamp: number | bigint;
 };
 
 export function getSlashReqFinalizedEventEncoder(): FixedSizeEncoder<SlashReqFinalizedEventArgs> {
   return getStructEncoder([
-    ["claimant", getAddressEncoder()],
     ["vault", getAddressEncoder()],
+    ["slashRequest", getAddressEncoder()],
+    ["resolver", getAddressEncoder()],
+    ["appealed", getBooleanEncoder()],
+    ["status", getSlashStatusEncoder()],
     ["amount", getU64Encoder()],
+    ["bondSlashed", getU64Encoder()],
+    ["insuranceAmount", getU64Encoder()],
+    ["burnedAmount", getU64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
 }
 
 export function getSlashReqFinalizedEventDecoder(): FixedSizeDecoder<SlashReqFinalizedEvent> {
   return getStructDecoder([
-    ["claimant", getAddressDecoder()],
     ["vault", getAddressDecoder()],
+    ["slashRequest", getAddressDecoder()],
+    ["resolver", getAddressDecoder()],
+    ["appealed", getBooleanDecoder()],
+    ["status", getSlashStatusDecoder()],
     ["amount", getU64Decoder()],
+    ["bondSlashed", getU64Decoder()],
+    ["insuranceAmount", getU64Decoder()],
+    ["burnedAmount", getU64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
 }
@@ -8,34 +8,57 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
   type FixedSizeEncoder,
+  type ReadonlyUint8Array,
 } from "gill";
 
 export type SlashRequestCreatedEvent = {
   vault: Address;
+  slashRequest: Address;
+  sequence: bigint;
   agent: Address;
-  slashClaimant: Address;
+  claimantCount: number;
+  insuranceBps: number;
+  burnBps: number;
   slashBps: number;
+  requestedAmount: bigint;
+  evidenceHash: ReadonlyUint8Array;
+  attestation: Address;
   disputeStartTime: bigint;
   timestamp: bigint;
 };
 
 export type SlashRequestCreatedEventArgs = {
   vault: Address;
+  slashRequest: Address;
+  sequence: number | bigint;
   agent: Address;
-  slashClaimant: Address;
+  claimantCount: number;
+  insuranceBps: number;
+  burnBps: number;
   slashBps: number;
+  requestedAmount: number | bigint;
+  evidenceHash: ReadonlyUint8Array;
+  attestation: Address;
   disputeStartTime: number | bigint;
   timestamp: number | bigint;
 };
@@ -43,9 +66,16 @@ export type SlashRequestCreatedEventArgs = {
 export function getSlashRequestCreatedEventEncoder(): FixedSizeEncoder<SlashRequestCreatedEventArgs> {
   return getStructEncoder([
     ["vault", getAddressEncoder()],
+    ["slashRequest", getAddressEncoder()],
+    ["sequence", getU64Encoder()],
     ["agent", getAddressEncoder()],
-    ["slashClaimant", getAddressEncoder()],
+    ["claimantCount", getU8Encoder()],
+    ["insuranceBps", getU16Encoder()],
+    ["burnBps", getU16Encoder()],
     ["slashBps", getU16Encoder()],
+    ["requestedAmount", getU64Encoder()],
+    ["evidenceHash", fixEncoderSize(getBytesEncoder(), 32)],
+    ["attestation", getAddressEncoder()],
     ["disputeStartTime", getI64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
@@ -54,9 +84,16 @@ export function getSlashRequestCreatedEventEncoder(): FixedSizeEncoder<SlashRequ
 export function getSlashRequestCreatedEventDecoder(): FixedSizeDecoder<SlashRequestCreatedEvent> {
   return getStructDecoder([
     ["vault", getAddressDecoder()],
+    ["slashRequest", getAddressDecoder()],
+    ["sequence", getU64Decoder()],
     ["agent", getAddressDecoder()],
-    ["slashClaimant", getAddressDecoder()],
+    ["claimantCount", getU8Decoder()],
+    ["insuranceBps", getU16Decoder()],
+    ["burnBps", getU16Decoder()],
     ["slashBps", getU16Decoder()],
+    ["requestedAmount", getU64Decoder()],
+    ["evidenceHash", fixDecoderSize(getBytesDecoder(), 32)],
+    ["attestation", getAddressDecoder()],
     ["disputeStartTime", getI64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
@@ -14,22 +14,30 @@ import {
   getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
   type FixedSizeEncoder,
 } from "gill";
 
-export type VaultClosedEvent = { nodeOperator: Address; timestamp: bigint };
+export type VaultClosedEvent = {
+  nodeOperator: Address;
+  bondReturned: bigint;
+  timestamp: bigint;
+};
 
 export type VaultClosedEventArgs = {
   nodeOperator: Address;
+  bondReturned: number | bigint;
   timestamp: number | bigint;
 };
 
 export function getVaultClosedEventEncoder(): FixedSizeEncoder<VaultClosedEventArgs> {
   return getStructEncoder([
     ["nodeOperator", getAddressEncoder()],
+    ["bondReturned", getU64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
 }
@@ -37,6 +45,7 @@ export function getVaultClosedEventEncoder(): FixedSizeEncoder<VaultClosedEventA
 export function getVaultClosedEventDecoder(): FixedSizeDecoder<VaultClosedEvent> {
   return getStructDecoder([
     ["nodeOperator", getAddressDecoder()],
+    ["bondReturned", getU64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
 }
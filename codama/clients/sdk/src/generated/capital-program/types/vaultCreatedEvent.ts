@@ -26,11 +26,13 @@ export type VaultCreatedEvent = {
   vault: Address;
   provider: Address;
   nodeOperator: Address;
+  vaultIndex: bigint;
   stakingToken: Address;
   rewardToken: Address;
   nftCollection: Address;
   minCap: bigint;
   maxCap: bigint;
+  operatorBond: bigint;
   lockPhaseStartTime: bigint;
   timestamp: bigint;
 };
@@ -39,11 +41,13 @@ export type VaultCreatedEventArgs = {
   vault: Address;
   provider: Address;
   nodeOperator: Address;
+  vaultIndex: number | bigint;
   stakingToken: Address;
   rewardToken: Address;
   nftCollection: Address;
   minCap: number | bigint;
   maxCap: number | bigint;
+  operatorBond: number | bigint;
   lockPhaseStartTime: number | bigint;
   timestamp: number | bigint;
 };
@@ -53,11 +57,13 @@ export function getVaultCreatedEventEncoder(): FixedSizeEncoder<VaultCreatedEven
     ["vault", getAddressEncoder()],
     ["provider", getAddressEncoder()],
     ["nodeOperator", getAddressEncoder()],
+    ["vaultIndex", getU64Encoder()],
     ["stakingToken", getAddressEncoder()],
     ["rewardToken", getAddressEncoder()],
     ["nftCollection", getAddressEncoder()],
     ["minCap", getU64Encoder()],
     ["maxCap", getU64Encoder()],
+    ["operatorBond", getU64Encoder()],
     ["lockPhaseStartTime", getI64Encoder()],
     ["timestamp", getI64Encoder()],
   ]);
@@ -68,11 +74,13 @@ export function getVaultCreatedEventDecoder(): FixedSizeDecoder<VaultCreatedEven
     ["vault", getAddressDecoder()],
     ["provider", getAddressDecoder()],
     ["nodeOperator", getAddressDecoder()],
+    ["vaultIndex", getU64Decoder()],
     ["stakingToken", getAddressDecoder()],
     ["rewardToken", getAddressDecoder()],
     ["nftCollection", getAddressDecoder()],
     ["minCap", getU64Decoder()],
     ["maxCap", getU64Decoder()],
+    ["operatorBond", getU64Decoder()],
     ["lockPhaseStartTime", getI64Decoder()],
     ["timestamp", getI64Decoder()],
   ]);
@@ -6,9 +6,12 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./acceptAdminHandler";
 export * from "./burnAssetHandler";
 export * from "./createCoreAssetHandler";
 export * from "./createVaultCollectionHandler";
 export * from "./initNftProgramHandler";
 export * from "./listAssetHandler";
+export * from "./proposeAdminHandler";
+export * from "./setMarketplacePauseHandler";
 export * from "./unlistAssetHandler";
@@ -62,6 +62,7 @@ export type ListAssetHandlerInstruction<
     "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d",
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
+  TAccountConfig extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -86,6 +87,9 @@ export type ListAssetHandlerInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -137,6 +141,7 @@ export type ListAssetHandlerAsyncInput<
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountConfig extends string = string,
 > = {
   seller: TransactionSigner<TAccountSeller>;
   asset: Address<TAccountAsset>;
@@ -145,6 +150,8 @@ export type ListAssetHandlerAsyncInput<
   collection: Address<TAccountCollection>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Appended last to keep the account order of existing clients */
+  config?: Address<TAccountConfig>;
   price: ListAssetHandlerInstructionDataArgs["price"];
   payingTokenMint: ListAssetHandlerInstructionDataArgs["payingTokenMint"];
 };
@@ -156,6 +163,7 @@ export async function getListAssetHandlerInstructionAsync<
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountConfig extends string,
   TProgramAddress extends Address = typeof NFT_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: ListAssetHandlerAsyncInput<
@@ -164,7 +172,8 @@ export async function getListAssetHandlerInstructionAsync<
     TAccountOffer,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountConfig
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -175,7 +184,8 @@ export async function getListAssetHandlerInstructionAsync<
     TAccountOffer,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountConfig
   >
 > {
   // Program address.
@@ -189,6 +199,7 @@ export async function getListAssetHandlerInstructionAsync<
     collection: { value: input.collection ?? null, isWritable: true },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    config: { value: input.config ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -216,6 +227,16 @@ export async function getListAssetHandlerInstructionAsync<
     accounts.systemProgram.value =
       "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
   }
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([78, 70, 84, 95, 67, 111, 110, 102, 105, 103]),
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
@@ -226,6 +247,7 @@ export async function getListAssetHandlerInstructionAsync<
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.config),
     ],
     data: getListAssetHandlerInstructionDataEncoder().encode(
       args as ListAssetHandlerInstructionDataArgs,
@@ -238,7 +260,8 @@ export async function getListAssetHandlerInstructionAsync<
     TAccountOffer,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountConfig
   >);
 }
 
@@ -249,6 +272,7 @@ export type ListAssetHandlerInput<
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountConfig extends string = string,
 > = {
   seller: TransactionSigner<TAccountSeller>;
   asset: Address<TAccountAsset>;
@@ -257,6 +281,8 @@ export type ListAssetHandlerInput<
   collection: Address<TAccountCollection>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Appended last to keep the account order of existing clients */
+  config: Address<TAccountConfig>;
   price: ListAssetHandlerInstructionDataArgs["price"];
   payingTokenMint: ListAssetHandlerInstructionDataArgs["payingTokenMint"];
 };
@@ -268,6 +294,7 @@ export function getListAssetHandlerInstruction<
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountConfig extends string,
   TProgramAddress extends Address = typeof NFT_PROGRAM_PROGRAM_ADDRESS,
 >(
   input: ListAssetHandlerInput<
@@ -276,7 +303,8 @@ export function getListAssetHandlerInstruction<
     TAccountOffer,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountConfig
   >,
   config?: { programAddress?: TProgramAddress },
 ): ListAssetHandlerInstruction<
@@ -286,7 +314,8 @@ export function getListAssetHandlerInstruction<
   TAccountOffer,
   TAccountCollection,
   TAccountMplCoreProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountConfig
 > {
   // Program address.
   const programAddress = config?.programAddress ?? NFT_PROGRAM_PROGRAM_ADDRESS;
@@ -299,6 +328,7 @@ export function getListAssetHandlerInstruction<
     collection: { value: input.collection ?? null, isWritable: true },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    config: { value: input.config ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -327,6 +357,7 @@ export function getListAssetHandlerInstruction<
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.config),
     ],
     data: getListAssetHandlerInstructionDataEncoder().encode(
       args as ListAssetHandlerInstructionDataArgs,
@@ -339,7 +370,8 @@ export function getListAssetHandlerInstruction<
     TAccountOffer,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountConfig
   >);
 }
 
@@ -356,6 +388,8 @@ export type ParsedListAssetHandlerInstruction<
     collection: TAccountMetas[3];
     mplCoreProgram: TAccountMetas[4];
     systemProgram: TAccountMetas[5];
+    /** Appended last to keep the account order of existing clients */
+    config: TAccountMetas[6];
   };
   data: ListAssetHandlerInstructionData;
 };
@@ -368,7 +402,7 @@ export function parseListAssetHandlerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedListAssetHandlerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -387,6 +421,7 @@ export function parseListAssetHandlerInstruction<
       collection: getNextAccount(),
       mplCoreProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      config: getNextAccount(),
     },
     data: getListAssetHandlerInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,11 +14,14 @@ import {
   type ReadonlyUint8Array,
 } from "gill";
 import {
+  type ParsedAcceptAdminHandlerInstruction,
   type ParsedBurnAssetHandlerInstruction,
   type ParsedCreateCoreAssetHandlerInstruction,
   type ParsedCreateVaultCollectionHandlerInstruction,
   type ParsedInitNftProgramHandlerInstruction,
   type ParsedListAssetHandlerInstruction,
+  type ParsedProposeAdminHandlerInstruction,
+  type ParsedSetMarketplacePauseHandlerInstruction,
   type ParsedUnlistAssetHandlerInstruction,
 } from "../instructions";
 
@@ -72,11 +75,14 @@ export function identifyNftProgramAccount(
 }
 
 export enum NftProgramInstruction {
+  AcceptAdminHandler,
   BurnAssetHandler,
   CreateCoreAssetHandler,
   CreateVaultCollectionHandler,
   InitNftProgramHandler,
   ListAssetHandler,
+  ProposeAdminHandler,
+  SetMarketplacePauseHandler,
   UnlistAssetHandler,
 }
 
@@ -84,6 +90,17 @@ export function identifyNftProgramInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): NftProgramInstruction {
   const data = "data" in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 160, 95, 25, 154, 44, 52, 76]),
+      ),
+      0,
+    )
+  ) {
+    return NftProgramInstruction.AcceptAdminHandler;
+  }
   if (
     containsBytes(
       data,
@@ -139,6 +156,28 @@ export function identifyNftProgramInstruction(
   ) {
     return NftProgramInstruction.ListAssetHandler;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([166, 66, 191, 93, 254, 0, 238, 221]),
+      ),
+      0,
+    )
+  ) {
+    return NftProgramInstruction.ProposeAdminHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([122, 37, 10, 184, 10, 113, 227, 174]),
+      ),
+      0,
+    )
+  ) {
+    return NftProgramInstruction.SetMarketplacePauseHandler;
+  }
   if (
     containsBytes(
       data,
@@ -158,6 +197,9 @@ export function identifyNftProgramInstruction(
 export type ParsedNftProgramInstruction<
   TProgram extends string = "AkFAoXys2zhqE15q8XJJJRqXgxLdtJ1kb9ec4fCo1GgH",
 > =
+  | ({
+      instructionType: NftProgramInstruction.AcceptAdminHandler;
+    } & ParsedAcceptAdminHandlerInstruction<TProgram>)
   | ({
       instructionType: NftProgramInstruction.BurnAssetHandler;
     } & ParsedBurnAssetHandlerInstruction<TProgram>)
@@ -173,6 +215,12 @@ export type ParsedNftProgramInstruction<
   | ({
       instructionType: NftProgramInstruction.ListAssetHandler;
     } & ParsedListAssetHandlerInstruction<TProgram>)
+  | ({
+      instructionType: NftProgramInstruction.ProposeAdminHandler;
+    } & ParsedProposeAdminHandlerInstruction<TProgram>)
+  | ({
+      instructionType: NftProgramInstruction.SetMarketplacePauseHandler;
+    } & ParsedSetMarketplacePauseHandlerInstruction<TProgram>)
   | ({
       instructionType: NftProgramInstruction.UnlistAssetHandler;
     } & ParsedUnlistAssetHandlerInstruction<TProgram>);
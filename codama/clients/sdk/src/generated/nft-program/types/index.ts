@@ -6,10 +6,13 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./adminTransferProposedEvent";
+export * from "./adminTransferredEvent";
 export * from "./assetBurnedEvent";
 export * from "./assetMintedEvent";
 export * from "./collectionCreatedEvent";
 export * from "./key";
+export * from "./marketplacePauseUpdatedEvent";
 export * from "./offerCancelledEvent";
 export * from "./offerCreatedEvent";
 export * from "./offerPurchasedEvent";
@@ -1,6 +1,12 @@
 #!/bin/bash
 set -e
 
+# The renderers read the IDLs, rebuild them so the clients match the programs
+echo "Building IDLs..."
+mkdir -p target/idl
+anchor idl build -p capital_program -o target/idl/capital_program.json
+anchor idl build -p nft_program -o target/idl/nft_program.json
+
 echo "Generating clients for all programs..."
 
 echo "Generating sol-mind-protocol clients..."
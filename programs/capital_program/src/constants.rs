@@ -1,4 +1,17 @@
 pub const BASE_BPS: u16 = 10_000; // 100%
 pub const MIN_LOCK_PERIOD: i64 = 31 * 86400; // 31 days in seconds
 pub const DISPUTE_WINDOW: i64 = 2 * 86400;
+pub const DECISION_WINDOW: i64 = 2 * 86400; // after the dispute window, to decide an unappealed slash
+pub const APPEAL_WINDOW: i64 = 7 * 86400; // extra time the admin gets to resolve an appealed slash
+pub const WORK_EPOCH_DURATION: i64 = 7 * 86400; // epochs count from the lock phase start
+pub const COMMITMENT_WINDOW: i64 = 86400; // after an epoch ends, to post its Merkle root
+pub const CHALLENGE_RESPONSE_WINDOW: i64 = 86400; // for the operator to answer a work challenge
 pub const MIN_FUND_RAISE_DURATION: i64 = 7 * 86400;
+pub const SWEEP_GRACE_PERIOD: i64 = 180 * 86400; // after maturity, before residual balances can be swept
+pub const MAX_DONATION_MEMO_LEN: usize = 128; // bytes
+pub const MAX_EVIDENCE_URI_LEN: usize = 200; // bytes
+pub const MAX_SLASH_CLAIMANTS: usize = 10; // claimants sharing one slash
+pub const MAX_ARBITERS: usize = 7; // members of the slash arbiter committee
+pub const MAX_MERKLE_DEPTH: u32 = 24; // levels of an epoch work tree, proofs must fit in a transaction
+pub const MAX_REWARD_STREAMS: usize = 4; // reward mints a vault can pay out
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000; // scale of acc_reward_per_share
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum AttestationError {
+    #[msg("Missing or malformed ed25519 signature verification instruction")]
+    InvalidSignatureInstruction,
+
+    #[msg("The ed25519 signature was not made by the attester over this report")]
+    SignatureMismatch,
+
+    #[msg("The attestation does not belong to this vault")]
+    AttestationVaultMismatch,
+
+    #[msg("The attestation reports no failure")]
+    AttestationNotFailing,
+
+    #[msg("The attestation already backs a slash request")]
+    AttestationAlreadyUsed,
+}
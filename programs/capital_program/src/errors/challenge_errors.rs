@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ChallengeError {
+    #[msg("The epoch does not end within the vault's lock phase")]
+    InvalidEpoch,
+
+    #[msg("The epoch has not ended yet")]
+    EpochNotEnded,
+
+    #[msg("The deadline to commit the epoch has passed")]
+    CommitmentWindowClosed,
+
+    #[msg("The epoch can still be committed")]
+    CommitmentWindowOpen,
+
+    #[msg("The epoch was committed, challenge one of its leaves instead")]
+    EpochCommitted,
+
+    #[msg("An epoch commitment needs between 1 and 2^24 leaves")]
+    InvalidLeafCount,
+
+    #[msg("The challenge does not belong to this vault")]
+    ChallengeVaultMismatch,
+
+    #[msg("The challenged leaf is not part of the commitment")]
+    LeafIndexOutOfRange,
+
+    #[msg("The challenge was already answered or failed")]
+    ChallengeNotOpen,
+
+    #[msg("The challenge response deadline has passed")]
+    ResponseWindowClosed,
+
+    #[msg("The challenge has not failed or was already slashed")]
+    ChallengeNotFailed,
+
+    #[msg("Failed challenges already slashed the vault's max_slash_bps")]
+    ChallengeSlashLimitReached,
+}
@@ -1,4 +1,6 @@
 pub mod arithmetic_errors;
+pub mod attestation_errors;
+pub mod challenge_errors;
 pub mod nft_program_errors;
 pub mod phase_errors;
 pub mod position_errors;
@@ -7,6 +9,8 @@ pub mod token_errors;
 pub mod vault_errors;
 
 pub use arithmetic_errors::*;
+pub use attestation_errors::*;
+pub use challenge_errors::*;
 pub use nft_program_errors::*;
 pub use phase_errors::*;
 pub use position_errors::*;
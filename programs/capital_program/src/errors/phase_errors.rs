@@ -40,4 +40,10 @@ pub enum PhaseError {
 
     #[msg("Lock duration constraints are contradictory")]
     ConflictingLockDurations,
+
+    #[msg("Residual balances can only be swept once the grace period after maturity is over")]
+    SweepGracePeriodActive,
+
+    #[msg("The vault's residual balances were swept to the treasury")]
+    VaultSwept,
 }
@@ -16,4 +16,37 @@ pub enum SignerError {
 
     #[msg("Beneficiary not found in the Beneficary Array")]
     UnauthorizedBeneficiary,
+
+    #[msg("Unauthorized: caller is not the program admin")]
+    UnauthorizedAdmin,
+
+    #[msg("Unauthorized: caller is not the pending admin")]
+    UnauthorizedPendingAdmin,
+
+    #[msg("Unauthorized: only the admin can unpause, the admin or guardian can pause")]
+    UnauthorizedPauseAuthority,
+
+    #[msg("Unauthorized: caller is not the vault's node operator")]
+    UnauthorizedNodeOperator,
+
+    #[msg("Unauthorized: caller is neither the vault's reward distributor nor the agent")]
+    UnauthorizedDepositor,
+
+    #[msg("Unauthorized: caller is neither the admin nor the vault's node operator")]
+    UnauthorizedSweeper,
+
+    #[msg("Unauthorized: caller is not the agent, or the admin once the slash was appealed")]
+    UnauthorizedSlashResolver,
+
+    #[msg("Unauthorized: caller is not a member of the arbiter committee")]
+    UnauthorizedArbiter,
+
+    #[msg("Arbiter has already voted on this slash request")]
+    ArbiterAlreadyVoted,
+
+    #[msg("Slashes are decided by the arbiter committee, use vote_slash")]
+    ArbiterVoteRequired,
+
+    #[msg("Arbiters must be distinct, non-default and at most MAX_ARBITERS, with 0 < threshold <= count")]
+    InvalidArbiterSet,
 }
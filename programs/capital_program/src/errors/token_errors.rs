@@ -16,4 +16,24 @@ pub enum TokenError {
 
     #[msg("The vault has no Reward collected")]
     NoRewardsInVault,
+
+    #[msg("The vault has no reward stream for this mint")]
+    RewardStreamNotFound,
+
+    #[msg("The vault already has a reward stream for this mint")]
+    RewardStreamAlreadyExists,
+
+    #[msg("The vault reached the maximum number of reward streams")]
+    TooManyRewardStreams,
+
+    #[msg("Reward stream accounts must be passed as mint, vault account, recipient account")]
+    InvalidRewardStreamAccounts,
+
+    #[msg("Pass the treasury's token account of the reward mint to collect the protocol fee")]
+    TreasuryAccountRequired,
+
+    #[msg(
+        "Pass the reward mint's insurance fund and its token account to collect the insurance fee"
+    )]
+    InsuranceAccountRequired,
 }
@@ -64,4 +64,73 @@ pub enum VaultError {
 
     #[msg("After this operation vault reaches less than MIN_CAP")]
     VaultReachedMinCap,
+
+    #[msg("The vault has no slash request under dispute")]
+    NoActiveDispute,
+
+    #[msg("The protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("The vault is paused")]
+    VaultPaused,
+
+    #[msg("Reward stream duration must be positive")]
+    InvalidStreamDuration,
+
+    #[msg("No beneficiary with this address in the vault")]
+    BeneficiaryNotFound,
+
+    #[msg("Beneficiary still has vested rewards, pass the reward accounts of every stream")]
+    BeneficiaryHasAccruedRewards,
+
+    #[msg("Vesting must end in the future with the cliff no later than its end")]
+    InvalidVestingSchedule,
+
+    #[msg("Donation memo is too long")]
+    DonationMemoTooLong,
+
+    #[msg("Investor, beneficiary and operator commission shares must sum to 10000 BPS")]
+    RewardSplitMismatch,
+
+    #[msg("Operator bond is below the configured share of the maximum cap")]
+    OperatorBondTooLow,
+
+    #[msg("The slash request has already been appealed")]
+    SlashAlreadyAppealed,
+
+    #[msg("The dispute window for appealing the slash request has passed")]
+    AppealWindowClosed,
+
+    #[msg("The node operator can still appeal the slash request")]
+    AppealWindowOpen,
+
+    #[msg("Slash evidence URI is too long")]
+    EvidenceUriTooLong,
+
+    #[msg("The slash request can only be dismissed once its decision deadline has passed")]
+    DisputeWindowNotExpired,
+
+    #[msg("Slash claimant shares, insurance and burn must sum to 10000 BPS")]
+    SlashSplitMismatch,
+
+    #[msg("Too many slash claimants or a claimant listed twice")]
+    InvalidSlashClaimants,
+
+    #[msg("Caller is not a claimant of this slash request")]
+    SlashClaimantNotFound,
+
+    #[msg("Slash payout has already been claimed")]
+    SlashPayoutAlreadyClaimed,
+
+    #[msg("Pass the locking token's insurance fund to redirect part of the slash")]
+    InsuranceFundRequired,
+
+    #[msg("Slash request was not executed, there is nothing to claim")]
+    SlashNotExecuted,
+
+    #[msg("Approved slash amount must be positive and at most the requested amount")]
+    InvalidSlashAmount,
+
+    #[msg("Arbiter voting on the slash request is closed")]
+    SlashVotingClosed,
 }
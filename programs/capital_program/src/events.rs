@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{DepositorRole, SlashStatus, VaultPhase};
+
 #[event]
 pub struct ProgramInitializedEvent {
     pub config: Pubkey,
@@ -8,22 +10,115 @@ pub struct ProgramInitializedEvent {
     pub nft_program: Pubkey,
     pub capital_program: Pubkey,
     pub early_unlock_fee: u64,
-    pub dispute_window: i64,
     pub min_lock_duration: i64,
     pub max_lock_duration: i64,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AdminTransferProposedEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferredEvent {
+    pub config: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AgentUpdatedEvent {
+    pub config: Pubkey,
+    pub old_agent: Pubkey,
+    pub new_agent: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianUpdatedEvent {
+    pub config: Pubkey,
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ArbitersUpdatedEvent {
+    pub config: Pubkey,
+    pub arbiters: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttesterRegisteredEvent {
+    pub attester: Pubkey,
+    pub key: Pubkey,
+    pub min_uptime_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttesterRemovedEvent {
+    pub attester: Pubkey,
+    pub key: Pubkey,
+    pub timestamp: i64,
+}
+
+/// `target` is the config account for a protocol-wide pause, otherwise the vault
+#[event]
+pub struct PausedEvent {
+    pub target: Pubkey,
+    pub protocol_wide: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnpausedEvent {
+    pub target: Pubkey,
+    pub protocol_wide: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProgramConfigUpdatedEvent {
+    pub config: Pubkey,
+    pub old_early_unlock_fee: u64,
+    pub new_early_unlock_fee: u64,
+    pub old_protocol_fee_bps: u16,
+    pub new_protocol_fee_bps: u16,
+    pub old_min_operator_bond_bps: u16,
+    pub new_min_operator_bond_bps: u16,
+    pub old_insurance_fee_bps: u16,
+    pub new_insurance_fee_bps: u16,
+    pub old_insurance_coverage_bps: u16,
+    pub new_insurance_coverage_bps: u16,
+    pub old_min_lock_duration: i64,
+    pub new_min_lock_duration: i64,
+    pub old_max_lock_duration: i64,
+    pub new_max_lock_duration: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct VaultCreatedEvent {
     pub vault: Pubkey,
     pub provider: Pubkey,
     pub node_operator: Pubkey,
+    pub vault_index: u64,
     pub staking_token: Pubkey,
     pub reward_token: Pubkey,
     pub nft_collection: Pubkey,
     pub min_cap: u64,
     pub max_cap: u64,
+    pub operator_bond: u64,
     pub lock_phase_start_time: i64,
     pub timestamp: i64,
 }
@@ -51,17 +146,114 @@ pub struct PositionUpdatedEvent {
 #[event]
 pub struct RewardsDepositedEvent {
     pub vault: Pubkey,
-    pub agent: Pubkey,
+    pub depositor: Pubkey,
+    pub depositor_role: DepositorRole,
+    pub reward_mint: Pubkey,
+    // Deposited into the vault, after the protocol fee
     pub amount: u64,
+    pub protocol_fee: u64,
+    // Part of protocol_fee sent to the insurance fund instead of the treasury
+    pub insurance_fee: u64,
+    // Seconds the investor share vests over, 0 when claimable right away
+    pub stream_duration: i64,
     pub total_rewards: u64,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct VaultSweptEvent {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    // Part of `amount` that was rounding dust, zero for the lock token
+    pub rounding_remainder: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceDepositedEvent {
+    pub insurance_fund: Pubkey,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceClaimedEvent {
+    pub insurance_fund: Pubkey,
+    pub vault: Pubkey,
+    pub position: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InsuranceWithdrawnEvent {
+    pub insurance_fund: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawnEvent {
+    pub treasury: Pubkey,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsDonatedEvent {
+    pub vault: Pubkey,
+    pub donor: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub campaign_id: u64,
+    pub memo: String,
+    pub total_rewards: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardDistributorUpdatedEvent {
+    pub vault: Pubkey,
+    pub old_distributor: Pubkey,
+    pub new_distributor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardStreamAddedEvent {
+    pub vault: Pubkey,
+    pub reward_mint: Pubkey,
+    pub stream_index: u8,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RewardsClaimedEvent {
     pub holder: Pubkey,
     pub vault: Pubkey,
     pub position: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorCommissionClaimedEvent {
+    pub vault: Pubkey,
+    pub node_operator: Pubkey,
+    pub reward_mint: Pubkey,
     pub amount: u64,
     pub timestamp: i64,
 }
@@ -70,25 +262,197 @@ pub struct RewardsClaimedEvent {
 pub struct BeneficiaryRewardsClaimedEvent {
     pub beneficiary: Pubkey,
     pub vault: Pubkey,
+    pub reward_mint: Pubkey,
     pub amount: u64,
+    // Earned but still vesting, claimable later or, on removal, forfeited
+    // to the operator commission
+    pub unvested_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryAddedEvent {
+    pub vault: Pubkey,
+    pub beneficiary: Pubkey,
+    pub share_bps: u16,
+    pub cliff_at: i64,
+    pub vesting_end_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryResizedEvent {
+    pub vault: Pubkey,
+    pub beneficiary: Pubkey,
+    pub old_share_bps: u16,
+    pub new_share_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryRemovedEvent {
+    pub vault: Pubkey,
+    pub beneficiary: Pubkey,
+    pub share_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryTransferredEvent {
+    pub vault: Pubkey,
+    pub old_address: Pubkey,
+    pub new_address: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationSubmittedEvent {
+    pub vault: Pubkey,
+    pub attestation: Pubkey,
+    pub attester: Pubkey,
+    pub epoch: u64,
+    pub uptime_bps: u16,
+    pub work_units: u64,
+    pub failed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochCommittedEvent {
+    pub vault: Pubkey,
+    pub commitment: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub leaf_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChallengeIssuedEvent {
+    pub vault: Pubkey,
+    pub challenge: Pubkey,
+    pub commitment: Pubkey,
+    pub leaf_index: u64,
+    pub slash_bps: u16,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpochMissedEvent {
+    pub vault: Pubkey,
+    pub challenge: Pubkey,
+    pub epoch: u64,
+    pub slash_bps: u16,
+    pub commitment_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChallengeAnsweredEvent {
+    pub vault: Pubkey,
+    pub challenge: Pubkey,
+    // False when the proof did not match the commitment, the challenge failed
+    pub valid: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChallengeSlashedEvent {
+    pub vault: Pubkey,
+    pub challenge: Pubkey,
+    pub slash_request: Pubkey,
+    pub caller: Pubkey,
+    // The operator never answered, rather than answering with an invalid proof
+    pub unanswered: bool,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct SlashRequestCreatedEvent {
     pub vault: Pubkey,
+    pub slash_request: Pubkey,
+    pub sequence: u64,
     pub agent: Pubkey,
-    pub slash_claimant: Pubkey,
+    pub claimant_count: u8,
+    pub insurance_bps: u16,
+    pub burn_bps: u16,
     pub slash_bps: u16,
+    pub requested_amount: u64,
+    pub evidence_hash: [u8; 32],
+    // Failing attestation backing the request, default when there is none
+    pub attestation: Pubkey,
     pub dispute_start_time: i64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct SlashReqFinalizedEvent {
+pub struct SlashAppealedEvent {
+    pub vault: Pubkey,
+    pub slash_request: Pubkey,
+    pub node_operator: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlashPayoutClaimedEvent {
+    pub vault: Pubkey,
+    pub slash_request: Pubkey,
     pub claimant: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlashReqDismissedEvent {
     pub vault: Pubkey,
+    pub slash_request: Pubkey,
+    pub caller: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlashVoteCastEvent {
+    pub vault: Pubkey,
+    pub slash_request: Pubkey,
+    pub arbiter: Pubkey,
+    pub approve: bool,
+    // Slash amount the arbiter approved, zero for a rejection
+    pub amount: u64,
+    // Votes of the current committee so far
+    pub approvals: u8,
+    pub rejections: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SlashReqFinalizedEvent {
+    pub vault: Pubkey,
+    pub slash_request: Pubkey,
+    // The agent, or the admin for an appealed slash
+    pub resolver: Pubkey,
+    pub appealed: bool,
+    pub status: SlashStatus,
+    // Executed slash, zero when rejected
     pub amount: u64,
+    // Part of the slash taken from the operator bond, the rest hit investors
+    pub bond_slashed: u64,
+    pub insurance_amount: u64,
+    pub burned_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionEarlyUnlockedEvent {
+    pub holder: Pubkey,
+    pub vault: Pubkey,
+    pub position: Pubkey,
+    pub asset: Pubkey,
+    pub principal_returned: u64,
+    pub early_unlock_fee: u64,
+    pub rewards_settled: u64,
+    pub remaining_capital: u64,
     pub timestamp: i64,
 }
 
@@ -101,5 +465,14 @@ pub struct PositionClosedEvent {
 #[event]
 pub struct VaultClosedEvent {
     pub node_operator: Pubkey,
+    pub bond_returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPhaseChangedEvent {
+    pub vault: Pubkey,
+    pub previous_phase: VaultPhase,
+    pub new_phase: VaultPhase,
     pub timestamp: i64,
 }
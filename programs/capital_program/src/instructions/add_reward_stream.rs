@@ -0,0 +1,82 @@
+use crate::errors::*;
+use crate::state::{AuthorityConfig, Vault, VaultPhase};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct AddRewardStream<'info> {
+    /// The node operator of the vault, pays for the vault's reward token account
+    #[account(
+        mut,
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    /// The vault registering the reward mint
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// Mint of the new reward stream
+    #[account(
+        mint::token_program = token_program,
+        constraint = reward_mint.decimals > 0 @ TokenError::InvalidRewardMint
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's token account for the new reward stream
+    #[account(
+        init_if_needed,
+        payer = node_operator,
+        associated_token::mint = reward_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddRewardStream<'info> {
+    /// Registers the reward mint on the vault and returns its stream index
+    pub fn add_reward_stream(&mut self) -> Result<usize> {
+        self.vault.require_not_paused(&self.config)?;
+
+        // Rewards in the lock mint would be paid out of locked capital
+        require_keys_neq!(
+            self.reward_mint.key(),
+            self.vault.locking_token_mint,
+            TokenError::InvalidRewardMint
+        );
+
+        // Streams can be added until the vault stops paying rewards
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault.require_phase(&[
+            VaultPhase::Formation,
+            VaultPhase::Active,
+            VaultPhase::Matured,
+        ])?;
+
+        self.vault.add_reward_stream(self.reward_mint.key())
+    }
+}
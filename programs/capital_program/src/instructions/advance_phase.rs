@@ -0,0 +1,28 @@
+use crate::state::Vault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdvancePhase<'info> {
+    /// Anyone can crank a vault into the phase its timestamps call for
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> AdvancePhase<'info> {
+    /// Applies any time-driven transition that is due
+    pub fn advance_phase(&mut self) -> Result<()> {
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        Ok(())
+    }
+}
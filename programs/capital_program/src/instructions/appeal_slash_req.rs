@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{AuthorityConfig, SlashRequest, SlashStatus, Vault, VaultPhase};
+
+#[derive(Accounts)]
+pub struct AppealSlashReq<'info> {
+    /// The node operator of the slashed vault
+    #[account(
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+        constraint = vault.phase == VaultPhase::Disputed @ VaultError::NoActiveDispute
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The vault's open slash request
+    #[account(
+        mut,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            slash_request.sequence.to_le_bytes().as_ref()
+        ],
+        bump = slash_request.bump,
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
+
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+}
+
+impl<'info> AppealSlashReq<'info> {
+    /// Records the hash of the operator's counter-evidence and hands the
+    /// decision over from the agent to the admin
+    pub fn appeal(&mut self, evidence_hash: [u8; 32]) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        require!(
+            self.slash_request.status == SlashStatus::Pending,
+            VaultError::SlashAlreadyAppealed
+        );
+
+        let clock = Clock::get()?;
+        require_gt!(
+            self.slash_request.appeal_deadline()?,
+            clock.unix_timestamp,
+            VaultError::AppealWindowClosed
+        );
+
+        self.slash_request.status = SlashStatus::Appealed;
+        self.slash_request.appeal_evidence_hash = evidence_hash;
+        self.slash_request.appealed_at = clock.unix_timestamp;
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::errors::*;
+use crate::instructions::{validate_split, ChallengeSlashTerms};
+use crate::state::{AuthorityConfig, Challenge, ChallengeStatus, Vault, VaultPhase};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct ChallengeMissedEpoch<'info> {
+    /// The agent, pays for the challenge account
+    #[account(
+        mut,
+        address = config.agent @ SignerError::UnauthorizedAgent
+    )]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Commitment PDA of the epoch, must never have been created
+    #[account(
+        seeds = [b"Commitment", vault.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump,
+        constraint = commitment.data_is_empty() @ ChallengeError::EpochCommitted
+    )]
+    pub commitment: UncheckedAccount<'info>,
+
+    /// Keyed like a challenge of the commitment's first leaf, which can no
+    /// longer be posted
+    #[account(
+        init,
+        payer = agent,
+        seeds = [
+            b"Challenge",
+            commitment.key().as_ref(),
+            0u64.to_le_bytes().as_ref()
+        ],
+        space = Challenge::INIT_SPACE + 8,
+        bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ChallengeMissedEpoch<'info> {
+    /// Records an epoch the operator never committed as an unanswered challenge,
+    /// slashable right away through slash_failed_challenge
+    pub fn challenge_missed_epoch(
+        &mut self,
+        epoch: u64,
+        terms: ChallengeSlashTerms,
+        bumps: &ChallengeMissedEpochBumps,
+    ) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        require_gte!(
+            self.vault.max_slash_bps,
+            terms.slash_bps,
+            VaultError::SlashReqExceedsMaxBps
+        );
+        require_gt!(
+            self.vault.challenge_slash_allowance()?,
+            0,
+            ChallengeError::ChallengeSlashLimitReached
+        );
+        let claimants = validate_split(&terms.claimants, terms.insurance_bps, terms.burn_bps)?;
+
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Active, VaultPhase::Disputed])?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.vault.require_epoch_ended(epoch, now)?;
+        let deadline = self.vault.commitment_deadline(epoch)?;
+        require_gte!(now, deadline, ChallengeError::CommitmentWindowOpen);
+
+        self.challenge.set_inner(Challenge {
+            vault: vault_key,
+            commitment: self.commitment.key(),
+            leaf_index: 0,
+            challenger: self.agent.key(),
+            slash_bps: terms.slash_bps,
+            claimants,
+            insurance_bps: terms.insurance_bps,
+            burn_bps: terms.burn_bps,
+            // Open past its deadline, so it already counts as failed
+            status: ChallengeStatus::Open,
+            issued_at: now,
+            deadline,
+            answered_at: 0,
+            slash_request: Pubkey::default(),
+            bump: bumps.challenge,
+        });
+        Ok(())
+    }
+}
@@ -1,9 +1,8 @@
-use crate::constants::BASE_BPS;
-use crate::{errors::*, state::*};
+use crate::{errors::*, reward_streams::RewardStreamAccounts, state::*};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 #[derive(Accounts)]
@@ -13,11 +12,30 @@ pub struct ClaimBeneficiaryRewards<'info> {
 
     #[account(
         mut,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// The caller's share of the vault rewards
+    #[account(
+        mut,
+        seeds = [b"Beneficiary", vault.key().as_ref(), beneficiary.key().as_ref()],
+        bump = beneficiary_account.bump,
+    )]
+    pub beneficiary_account: Account<'info, BeneficiaryAccount>,
+
+    /// Mint of the reward stream to claim, further streams go in remaining accounts
     #[account(mint::token_program = token_program)]
     pub reward_mint: InterfaceAccount<'info, Mint>,
 
@@ -44,80 +62,61 @@ pub struct ClaimBeneficiaryRewards<'info> {
 }
 
 impl<'info> ClaimBeneficiaryRewards<'info> {
-    pub fn calculate_claimable(&self, beneficiary_index: u8) -> Result<u64> {
-        require!(
-            beneficiary_index < self.vault.beneficiaries.len() as u8,
-            VaultError::InvalidBeneficiaryIndex
-        );
-
-        let beneficiary = &self.vault.beneficiaries[beneficiary_index as usize];
+    pub fn validate_claim(&self) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        self.vault.require_not_swept()?;
 
         // Validate caller is the beneficiary
         require_keys_eq!(
-            beneficiary.address,
+            self.beneficiary_account.address,
             self.beneficiary.key(),
             SignerError::UnauthorizedBeneficiary
         );
 
-        // Calculate total rewards for beneficiaries
-        let total_beneficiary_bps: u16 = self.vault.beneficiaries.iter().map(|b| b.share_bps).sum();
-
-        let total_beneficiary_rewards = self
-            .vault
-            .total_rewards_deposited
-            .checked_mul(total_beneficiary_bps as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(BASE_BPS as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        // Calculate this beneficiary's share
-        let beneficiary_total = total_beneficiary_rewards
-            .checked_mul(beneficiary.share_bps as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(total_beneficiary_bps as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        // Calculate claimable (total - already claimed)
-        let claimable = beneficiary_total
-            .checked_sub(beneficiary.total_claimed)
-            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
-
-        Ok(claimable)
+        Ok(())
     }
 
-    pub fn process_claim(&mut self, beneficiary_index: u8, amount: u64) -> Result<()> {
-        require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
-
-        // Update beneficiary's claimed amount
-        self.vault.beneficiaries[beneficiary_index as usize].total_claimed =
-            self.vault.beneficiaries[beneficiary_index as usize]
-                .total_claimed
-                .checked_add(amount)
-                .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        Ok(())
+    /// The named reward stream followed by the ones passed in `remaining_accounts`
+    pub fn reward_streams(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<RewardStreamAccounts<'info>>> {
+        let mut streams = vec![RewardStreamAccounts::new(
+            &self.vault,
+            &self.reward_mint,
+            &self.vault_ata,
+            &self.beneficiary_ata,
+        )?];
+        streams.extend(RewardStreamAccounts::parse_remaining(
+            &self.vault,
+            self.beneficiary.key(),
+            remaining_accounts,
+        )?);
+        Ok(streams)
     }
 
-    pub fn transfer_rewards(&self, amount: u64) -> Result<()> {
-        let node_operator_key = self.vault.node_operator.key();
-        let signer_seeds: &[&[&[u8]]] =
-            &[&[b"Vault", node_operator_key.as_ref(), &[self.vault.bump]]];
-
-        let transfer_accounts = TransferChecked {
-            from: self.vault_ata.to_account_info(),
-            to: self.beneficiary_ata.to_account_info(),
-            authority: self.vault.to_account_info(),
-            mint: self.reward_mint.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            transfer_accounts,
-            signer_seeds,
-        );
+    /// Pays out the beneficiary's vested rewards of one stream.
+    /// Returns (claimed, unvested)
+    pub fn claim_stream(&mut self, stream: &RewardStreamAccounts<'info>) -> Result<(u64, u64)> {
+        let stream_index = stream.stream_index;
+        let now = Clock::get()?.unix_timestamp;
+
+        // Settle what the current share earned, then claim the vested part
+        self.beneficiary_account
+            .settle(&self.vault.reward_streams)?;
+        let (claimable, unvested) = self.beneficiary_account.take_vested(stream_index, now)?;
+        if claimable == 0 {
+            return Ok((0, unvested));
+        }
+
+        let reward_stream = &mut self.vault.reward_streams[stream_index];
+        reward_stream.total_claimed = reward_stream
+            .total_claimed
+            .checked_add(claimable)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
 
-        transfer_checked(cpi_ctx, amount, self.reward_mint.decimals)?;
+        stream.transfer_from_vault(&self.vault, &self.token_program, claimable)?;
 
-        Ok(())
+        Ok((claimable, unvested))
     }
 }
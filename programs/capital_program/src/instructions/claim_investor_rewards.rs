@@ -1,9 +1,8 @@
-use crate::constants::BASE_BPS;
-use crate::{errors::*, state::*};
+use crate::{errors::*, reward_streams::RewardStreamAccounts, state::*};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use mpl_core::accounts::BaseAssetV1;
 
@@ -17,16 +16,21 @@ pub struct ClaimInvestorRewards<'info> {
 
     /// Global configuration account
     #[account(
-        seeds = [b"config"],
+        seeds = [b"Config"],
         bump = config.bump
     )]
     pub config: Account<'info, AuthorityConfig>,
 
     /// Vault account holding the pooled capital and rewards
     #[account(
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute,
+        constraint = vault.phase != VaultPhase::Disputed @ VaultError::VaultUnderDispute,
     )]
     pub vault: Account<'info, Vault>,
 
@@ -46,11 +50,8 @@ pub struct ClaimInvestorRewards<'info> {
     )]
     pub asset: Account<'info, BaseAssetV1>,
 
-    /// The reward token mint
-    #[account(
-        address = vault.reward_token_mint @ TokenError::InvalidRewardMint,
-        mint::token_program = token_program
-    )]
+    /// Mint of the reward stream to claim, further streams go in remaining accounts
+    #[account(mint::token_program = token_program)]
     pub reward_mint: InterfaceAccount<'info, Mint>,
 
     /// Vault's token account holding rewards
@@ -58,8 +59,7 @@ pub struct ClaimInvestorRewards<'info> {
         mut,
         associated_token::mint = reward_mint,
         associated_token::authority = vault,
-        associated_token::token_program = token_program,
-        constraint = vault_ata.amount > 0 @ TokenError::InsufficientVaultBalance
+        associated_token::token_program = token_program
     )]
     pub vault_ata: InterfaceAccount<'info, TokenAccount>,
 
@@ -78,86 +78,64 @@ pub struct ClaimInvestorRewards<'info> {
 }
 
 impl<'info> ClaimInvestorRewards<'info> {
-    /// Calculates pending rewards for this position
-    ///
-    /// Formula:
-    /// 1. Total investor rewards = total_rewards_deposited * investor_bps / BASE_BPS
-    /// 2. Position share = investor_rewards * position_capital / total_vault_capital
-    /// 3. Claimable = position_share - already_claimed
-    pub fn calculate_claimable_rewards(&self) -> Result<u64> {
-        let total_vault_capital = self.vault.total_capital_collected;
-        let position_locked_capital = self.position.total_value_locked;
-        let total_rewards_deposited = self.vault.total_rewards_deposited;
-        let investors_share_bps = self.vault.investor_bps as u64;
-
-        // Validate preconditions
-        require_gt!(total_vault_capital, 0, TokenError::InsufficientVaultBalance);
-
-        // Calculate total rewards allocated to investors
-        let rewards_for_investors = total_rewards_deposited
-            .checked_mul(investors_share_bps)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(BASE_BPS as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        // Calculate this position's share of investor rewards
-        let position_total_rewards = rewards_for_investors
-            .checked_mul(position_locked_capital)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(total_vault_capital)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        // Calculate claimable amount (total earned - already claimed)
-        let claimable = position_total_rewards
-            .checked_sub(self.position.total_rewards_claimed)
-            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
-
-        Ok(claimable)
-    }
-
-    /// Updates the position state with claimed rewards
-    pub fn process_claim(&mut self, amount: u64) -> Result<()> {
-        // Validate amount is greater than zero
-        require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
-
-        // Validate vault has sufficient balance
-        require_gte!(
-            self.vault_ata.amount,
-            amount,
-            TokenError::InsufficientVaultBalance
-        );
-
-        // Update position state
-        self.position.total_rewards_claimed = self
-            .position
-            .total_rewards_claimed
-            .checked_add(amount)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
+    /// Checks the vault is paying out investor rewards
+    pub fn validate_claim(&mut self) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        self.vault.require_not_swept()?;
+
+        // Rewards are only distributed once the vault is locked
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Active, VaultPhase::Matured])?;
+
+        // Only the vested part of streamed deposits is claimable
+        self.vault.vest_rewards()?;
         Ok(())
     }
 
-    /// Transfers rewards from vault to holder
-    pub fn transfer_rewards(&self, amount: u64) -> Result<()> {
-        let node_operator_key = self.vault.node_operator.key();
-        let signer_seeds: &[&[&[u8]]] =
-            &[&[b"Vault", node_operator_key.as_ref(), &[self.vault.bump]]];
-
-        let transfer_accounts = TransferChecked {
-            from: self.vault_ata.to_account_info(),
-            to: self.holder_ata.to_account_info(),
-            authority: self.vault.to_account_info(),
-            mint: self.reward_mint.to_account_info(),
-        };
+    /// The named reward stream followed by the ones passed in `remaining_accounts`
+    pub fn reward_streams(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<RewardStreamAccounts<'info>>> {
+        let mut streams = vec![RewardStreamAccounts::new(
+            &self.vault,
+            &self.reward_mint,
+            &self.vault_ata,
+            &self.holder_ata,
+        )?];
+        streams.extend(RewardStreamAccounts::parse_remaining(
+            &self.vault,
+            self.holder.key(),
+            remaining_accounts,
+        )?);
+        Ok(streams)
+    }
 
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            transfer_accounts,
-            signer_seeds,
-        );
+    /// Pays out the position's pending rewards of one stream
+    ///
+    /// Formula:
+    /// 1. Earned = position_capital * acc_reward_per_share / REWARD_PRECISION
+    /// 2. Claimable = earned - reward_debt
+    pub fn claim_stream(&mut self, stream: &RewardStreamAccounts<'info>) -> Result<u64> {
+        let stream_index = stream.stream_index;
+        let claimable = self.position.pending_rewards(&self.vault, stream_index)?;
+        if claimable == 0 {
+            return Ok(0);
+        }
+
+        // Update position and stream state
+        self.position.settle_rewards(stream_index, claimable)?;
+        let reward_stream = &mut self.vault.reward_streams[stream_index];
+        reward_stream.total_claimed = reward_stream
+            .total_claimed
+            .checked_add(claimable)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
 
-        transfer_checked(cpi_ctx, amount, self.reward_mint.decimals)?;
+        // Transfer rewards to holder
+        stream.transfer_from_vault(&self.vault, &self.token_program, claimable)?;
 
-        Ok(())
+        Ok(claimable)
     }
 }
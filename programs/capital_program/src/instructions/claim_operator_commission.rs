@@ -0,0 +1,96 @@
+use crate::{errors::*, reward_streams::RewardStreamAccounts, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClaimOperatorCommission<'info> {
+    /// The node operator of the vault
+    #[account(
+        mut,
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// Mint of the reward stream to claim, further streams go in remaining accounts
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = node_operator,
+        associated_token::mint = reward_mint,
+        associated_token::authority = node_operator,
+        associated_token::token_program = token_program
+    )]
+    pub node_operator_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimOperatorCommission<'info> {
+    pub fn validate_claim(&self) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        self.vault.require_not_swept()
+    }
+
+    /// The named reward stream followed by the ones passed in `remaining_accounts`
+    pub fn reward_streams(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<RewardStreamAccounts<'info>>> {
+        let mut streams = vec![RewardStreamAccounts::new(
+            &self.vault,
+            &self.reward_mint,
+            &self.vault_ata,
+            &self.node_operator_ata,
+        )?];
+        streams.extend(RewardStreamAccounts::parse_remaining(
+            &self.vault,
+            self.node_operator.key(),
+            remaining_accounts,
+        )?);
+        Ok(streams)
+    }
+
+    /// Pays out the operator commission accrued in one stream, returns the amount
+    pub fn claim_stream(&mut self, stream: &RewardStreamAccounts<'info>) -> Result<u64> {
+        let commission = self.vault.take_operator_commission(stream.stream_index)?;
+        if commission == 0 {
+            return Ok(0);
+        }
+
+        stream.transfer_from_vault(&self.vault, &self.token_program, commission)?;
+
+        Ok(commission)
+    }
+}
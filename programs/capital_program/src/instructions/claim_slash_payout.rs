@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{SlashRequest, SlashStatus, Vault};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct ClaimSlashPayout<'info> {
+    /// One of the slash request's claimants
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The executed slash request
+    #[account(
+        mut,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            slash_request.sequence.to_le_bytes().as_ref()
+        ],
+        bump = slash_request.bump,
+        constraint = slash_request.status == SlashStatus::Executed @ VaultError::SlashNotExecuted
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
+
+    /// Locking token mint
+    #[account(
+        mint::token_program = token_program,
+        address = vault.locking_token_mint @ TokenError::InvalidLockingMint
+    )]
+    pub locking_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holds the claimants' part of the slash
+    #[account(
+        mut,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = slash_request,
+        associated_token::token_program = token_program
+    )]
+    pub slash_escrow_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program
+    )]
+    pub claimant_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSlashPayout<'info> {
+    /// Pays the caller's share of the executed slash, returns the amount
+    pub fn claim_payout(&mut self) -> Result<u64> {
+        let index = self.slash_request.claimant_index(self.claimant.key())?;
+        let payout = self.slash_request.take_payout(index)?;
+        if payout == 0 {
+            return Ok(0);
+        }
+
+        let vault_key = self.vault.key();
+        let sequence = self.slash_request.sequence.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"SlashRequest",
+            vault_key.as_ref(),
+            sequence.as_ref(),
+            &[self.slash_request.bump],
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.slash_escrow_ata.to_account_info(),
+            to: self.claimant_ata.to_account_info(),
+            authority: self.slash_request.to_account_info(),
+            mint: self.locking_token_mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, payout, self.locking_token_mint.decimals)?;
+
+        Ok(payout)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::constants::BASE_BPS;
 use crate::errors::*;
+use crate::reward_math;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -22,10 +22,13 @@ pub struct ClosePosition<'info> {
     /// The vault containing this position
     #[account(
         mut,
-        close = position_holder,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute
+        constraint = vault.phase != VaultPhase::Disputed @ VaultError::VaultUnderDispute
     )]
     pub vault: Account<'info, Vault>,
 
@@ -36,9 +39,10 @@ pub struct ClosePosition<'info> {
     )]
     pub config: Account<'info, AuthorityConfig>,
 
-    /// The position being updated
+    /// The position being closed
     #[account(
         mut,
+        close = position_holder,
         seeds = [b"Position", asset.key().as_ref()],
         bump = position.bump,
         constraint = position.vault == vault.key() @ PositionError::PositionVaultMismatch,
@@ -79,6 +83,22 @@ pub struct ClosePosition<'info> {
     )]
     pub capital_provider_lock_ata: InterfaceAccount<'info, TokenAccount>,
 
+    /// Insurance fund of the locking token, pass it to claim coverage of slashed principal
+    #[account(
+        mut,
+        seeds = [b"Insurance", lock_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lock_mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     /// CHECK: this will be cheked at marketplace
@@ -88,37 +108,6 @@ pub struct ClosePosition<'info> {
 }
 
 impl<'info> ClosePosition<'info> {
-    fn calculate_claimable_rewards(&self) -> Result<u64> {
-        let total_vault_capital = self.vault.total_capital_collected;
-        let position_locked_capital = self.position.total_value_locked;
-        let total_rewards_deposited = self.vault.total_rewards_deposited;
-        let investors_share_bps = self.vault.investor_bps as u64;
-
-        // Validate preconditions
-        require_gt!(total_vault_capital, 0, TokenError::NoRewardsInVault);
-
-        // Calculate total rewards allocated to investors
-        let rewards_for_investors = total_rewards_deposited
-            .checked_mul(investors_share_bps)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(BASE_BPS as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        // Calculate this position's share of investor rewards
-        let position_total_rewards = rewards_for_investors
-            .checked_mul(position_locked_capital)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(total_vault_capital)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-
-        // Calculate claimable amount (total earned - already claimed)
-        let claimable = position_total_rewards
-            .checked_sub(self.position.total_rewards_claimed)
-            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
-
-        Ok(claimable)
-    }
-
     fn transfer_capital(&self, amount: u64) -> Result<()> {
         let cpi_accounts = TransferChecked {
             from: self.vault_lock_ata.to_account_info(),
@@ -127,34 +116,105 @@ impl<'info> ClosePosition<'info> {
             mint: self.lock_mint.to_account_info(),
         };
         let operator = self.vault.node_operator.key();
-        let seeds = &[b"Vault", operator.as_ref(), &[self.vault.bump]];
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let seeds = &[
+            b"Vault".as_ref(),
+            operator.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ];
         let signer = &[&seeds[..]];
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
         transfer_checked(cpi_ctx, amount, self.lock_mint.decimals)
     }
 
-    pub fn validate_closing_process_unlock(&mut self) -> Result<()> {
-        let claimable_rewards = self.calculate_claimable_rewards()?;
-        require_gte!(0, claimable_rewards, PositionError::PositionIsNotEmpty);
-        let clock = Clock::get()?;
-        let lock_starts_at = self.vault.lock_phase_start_at;
-        let lock_ends_at = lock_starts_at + self.vault.lock_phase_duration;
-        require!(
-            clock.unix_timestamp > lock_ends_at
-                || (clock.unix_timestamp > lock_starts_at
-                    && self.vault.min_cap > self.vault.total_capital_collected),
-            PhaseError::InvalidPhase
+    /// Pays the insurance coverage of a slashed position out of the fund, if it
+    /// was passed in. Returns the amount paid
+    fn claim_insurance(&mut self, position_capital: u64) -> Result<u64> {
+        let (Some(insurance_fund), Some(insurance_fund_ata)) =
+            (&mut self.insurance_fund, &self.insurance_fund_ata)
+        else {
+            return Ok(0);
+        };
+
+        let coverage = self
+            .vault
+            .insurance_coverage(
+                self.position.total_value_locked,
+                position_capital,
+                self.config.insurance_coverage_bps,
+            )?
+            .min(insurance_fund_ata.amount);
+        if coverage == 0 {
+            return Ok(0);
+        }
+
+        insurance_fund.record_payout(coverage)?;
+        self.vault.insurance_claimed = self
+            .vault
+            .insurance_claimed
+            .checked_add(coverage)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+        let mint = self.lock_mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"Insurance", mint.as_ref(), &[insurance_fund.bump]]];
+
+        let cpi_accounts = TransferChecked {
+            from: insurance_fund_ata.to_account_info(),
+            to: self.capital_provider_lock_ata.to_account_info(),
+            authority: insurance_fund.to_account_info(),
+            mint: self.lock_mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
         );
+        transfer_checked(cpi_ctx, coverage, self.lock_mint.decimals)?;
+
+        Ok(coverage)
+    }
+
+    /// Returns the position's principal, slashed pro-rata, plus any insurance
+    /// coverage of the slashed part. Returns the coverage paid
+    pub fn validate_closing_process_unlock(&mut self) -> Result<u64> {
+        // Every reward stream must be claimed before the position is closed,
+        // unless the vault was swept and the unclaimed rewards are forfeited
+        if !self.vault.swept {
+            self.vault.vest_rewards()?;
+            require!(
+                self.position.rewards_settled(&self.vault)?,
+                PositionError::PositionIsNotEmpty
+            );
+        }
+        // Principal unlocks once the vault matured or failed to reach min_cap
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Matured, VaultPhase::Failed])?;
+
+        // Refunds from a vault that never locked stay open while paused
+        if self.vault.phase != VaultPhase::Failed {
+            self.vault.require_not_paused(&self.config)?;
+        }
         let total_capital_collected = self.vault.total_capital_collected;
         let capital_after_slashing = self.vault.capital_after_slashing;
         let mut position_capital = self.position.total_value_locked;
         if total_capital_collected != capital_after_slashing {
-            position_capital =
-                self.position.total_value_locked * capital_after_slashing / total_capital_collected;
+            position_capital = reward_math::mul_div(
+                self.position.total_value_locked,
+                capital_after_slashing,
+                total_capital_collected,
+            )?;
         }
         self.transfer_capital(position_capital)?;
-        Ok(())
+        self.vault.capital_withdrawn = self
+            .vault
+            .capital_withdrawn
+            .checked_add(position_capital)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        self.claim_insurance(position_capital)
     }
 
     pub fn burn_nft(&mut self) -> Result<()> {
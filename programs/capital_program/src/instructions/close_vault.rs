@@ -1,8 +1,15 @@
-use crate::{errors::*, state::Vault};
+use crate::{
+    errors::*,
+    events::VaultPhaseChangedEvent,
+    state::{Vault, VaultPhase},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
 };
 #[derive(Accounts)]
 pub struct CloseVault<'info> {
@@ -14,7 +21,11 @@ pub struct CloseVault<'info> {
     #[account(
       mut,
       close = node_operator,
-      seeds = [b"Vault",vault.node_operator.key().as_ref()],
+      seeds = [
+        b"Vault",
+        vault.node_operator.key().as_ref(),
+        vault.vault_index.to_le_bytes().as_ref()
+      ],
       bump = vault.bump
     )]
     pub vault: Account<'info, Vault>,
@@ -32,9 +43,23 @@ pub struct CloseVault<'info> {
       associated_token::token_program = token_program
     )]
     pub vault_lock_ata: InterfaceAccount<'info, TokenAccount>,
-    #[account(mint::token_program = token_program)]
+    /// Receives what is left of the operator bond
+    #[account(
+      mut,
+      associated_token::mint = staking_token_mint,
+      associated_token::authority = node_operator,
+      associated_token::token_program = token_program
+    )]
+    pub node_operator_lock_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+      mint::token_program = token_program,
+      address = vault.locking_token_mint @ TokenError::InvalidLockingMint
+    )]
     pub staking_token_mint: InterfaceAccount<'info, Mint>,
-    #[account(mint::token_program = token_program)]
+    #[account(
+      mint::token_program = token_program,
+      address = vault.reward_streams[0].mint @ TokenError::InvalidRewardMint
+    )]
     pub reward_token_mint: InterfaceAccount<'info, Mint>,
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -42,16 +67,58 @@ pub struct CloseVault<'info> {
 }
 
 impl<'info> CloseVault<'info> {
-    pub fn close_vault_accounts(&mut self) -> Result<()> {
-        let clock = Clock::get()?;
-        let lock_starts_at = self.vault.lock_phase_start_at;
-        let lock_ends_at = lock_starts_at + self.vault.lock_phase_duration;
-        require!(
-            clock.unix_timestamp > lock_ends_at
-                || (clock.unix_timestamp > lock_starts_at
-                    && self.vault.min_cap > self.vault.total_capital_collected),
-            PhaseError::InvalidPhase
+    /// Returns the part of the operator bond no slash consumed, returns the amount
+    pub fn return_operator_bond(&mut self) -> Result<u64> {
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Matured, VaultPhase::Failed])?;
+
+        let amount = self.vault.operator_bond;
+        if amount == 0 {
+            return Ok(0);
+        }
+        self.vault.operator_bond = 0;
+
+        let operator = self.vault.node_operator.key();
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Vault",
+            operator.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.vault_lock_ata.to_account_info(),
+            to: self.node_operator_lock_ata.to_account_info(),
+            authority: self.vault.to_account_info(),
+            mint: self.staking_token_mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
         );
+
+        transfer_checked(cpi_ctx, amount, self.staking_token_mint.decimals)?;
+
+        // The balance is checked again before the account is closed
+        self.vault_lock_ata.reload()?;
+        Ok(amount)
+    }
+
+    /// Closes the vault's token accounts, the reward accounts of streams other
+    /// than the first are passed in `remaining_accounts` in stream order
+    pub fn close_vault_accounts(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Matured, VaultPhase::Failed])?;
         let vault_reward_balance = self.vault_reward_ata.amount;
         let vault_staked_balance = self.vault_lock_ata.amount;
         require!(
@@ -60,7 +127,13 @@ impl<'info> CloseVault<'info> {
         );
 
         let operator = self.vault.node_operator.key();
-        let seeds = &[b"Vault", operator.as_ref(), &[self.vault.bump]];
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let seeds = &[
+            b"Vault".as_ref(),
+            operator.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ];
         let signer = &[&seeds[..]];
         let colse_reward_accounts = CloseAccount {
             account: self.vault_reward_ata.to_account_info(),
@@ -75,6 +148,33 @@ impl<'info> CloseVault<'info> {
 
         msg!("position's Rewards ATA is closed");
 
+        require_eq!(
+            remaining_accounts.len(),
+            self.vault.reward_streams.len() - 1,
+            TokenError::InvalidRewardStreamAccounts
+        );
+        for (stream, account) in self.vault.reward_streams[1..]
+            .iter()
+            .zip(remaining_accounts.iter())
+        {
+            require!(account.is_writable, TokenError::InvalidRewardStreamAccounts);
+            let stream_ata = InterfaceAccount::<TokenAccount>::try_from(account)?;
+            require!(
+                stream_ata.mint == stream.mint && stream_ata.owner == self.vault.key(),
+                TokenError::InvalidRewardStreamAccounts
+            );
+            require!(stream_ata.amount == 0, VaultError::VaultNotEmpty);
+
+            let close_stream_accounts = CloseAccount {
+                account: account.clone(),
+                destination: self.node_operator.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+            let close_stream_ctx =
+                CpiContext::new_with_signer(closing_program.clone(), close_stream_accounts, signer);
+            close_account(close_stream_ctx)?;
+        }
+
         let close_staking_accounts = CloseAccount {
             account: self.vault_lock_ata.to_account_info(),
             destination: self.node_operator.to_account_info(),
@@ -86,6 +186,14 @@ impl<'info> CloseVault<'info> {
 
         close_account(close_staking_ctx)?;
         msg!("Vault's Lock Token ATA is close");
+
+        // The vault account is closed by this instruction, the phase only lives on in the event
+        emit!(VaultPhaseChangedEvent {
+            vault: vault_key,
+            previous_phase: self.vault.phase,
+            new_phase: VaultPhase::Closed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         Ok(())
     }
 }
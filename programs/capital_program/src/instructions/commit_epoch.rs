@@ -0,0 +1,79 @@
+use crate::constants::MAX_MERKLE_DEPTH;
+use crate::errors::*;
+use crate::state::{EpochCommitment, Vault, VaultPhase};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct CommitEpoch<'info> {
+    /// The node operator of the vault, pays for the commitment account
+    #[account(
+        mut,
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// One commitment per epoch, posted within COMMITMENT_WINDOW after the epoch
+    /// ended and never replaced
+    #[account(
+        init,
+        payer = node_operator,
+        seeds = [b"Commitment", vault.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        space = EpochCommitment::INIT_SPACE + 8,
+        bump,
+    )]
+    pub commitment: Account<'info, EpochCommitment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitEpoch<'info> {
+    pub fn commit_epoch(
+        &mut self,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        leaf_count: u64,
+        bumps: &CommitEpochBumps,
+    ) -> Result<()> {
+        require!(
+            leaf_count > 0 && leaf_count <= 1 << MAX_MERKLE_DEPTH,
+            ChallengeError::InvalidLeafCount
+        );
+        // Epochs follow the lock phase, the last one can be committed after maturity
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault.require_phase(&[
+            VaultPhase::Active,
+            VaultPhase::Disputed,
+            VaultPhase::Matured,
+        ])?;
+        let now = Clock::get()?.unix_timestamp;
+        self.vault.require_epoch_ended(epoch, now)?;
+        require_gt!(
+            self.vault.commitment_deadline(epoch)?,
+            now,
+            ChallengeError::CommitmentWindowClosed
+        );
+
+        self.commitment.set_inner(EpochCommitment {
+            vault: vault_key,
+            epoch,
+            merkle_root,
+            leaf_count,
+            committed_at: now,
+            bump: bumps.commitment,
+        });
+        Ok(())
+    }
+}
@@ -1,16 +1,17 @@
-use crate::constants::BASE_BPS;
-use crate::state::Vault;
+use crate::state::{Attestation, SlashClaimant, SlashRequest, SlashStatus, Vault, VaultPhase};
 use anchor_lang::prelude::*;
 
+use crate::constants::{BASE_BPS, MAX_EVIDENCE_URI_LEN, MAX_SLASH_CLAIMANTS};
 use crate::errors::*;
+use crate::reward_math;
 
 use crate::state::AuthorityConfig;
 
 #[derive(Accounts)]
 pub struct CreateSlashReq<'info> {
-    /// CHECK: Here agent is a trusted multi-sig or a DAAO so the claimant needs no validation
-    /// and claimant can be anyone either one of benificiaries or some DePIN network outside solana
-    /// STILL WOKRING ON TRUST-MINIMIZING OPTIONS - DAAO's or Oracle Netwroks or a MULTI-SIG
+    /// CHECK: Here agent is a trusted multi-sig or a DAAO so the claimants need no validation
+    /// and can be anyone, either one of benificiaries or some DePIN network outside solana.
+    /// A failing attestation can back the request instead of the agent's word alone
     #[account(
         mut,
         address = config.agent @ SignerError::UnauthorizedAgent
@@ -18,42 +19,176 @@ pub struct CreateSlashReq<'info> {
     pub agent: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute
+        constraint = vault.phase != VaultPhase::Disputed @ VaultError::VaultUnderDispute
     )]
     pub vault: Account<'info, Vault>,
+    /// The new request, numbered by the vault's slash request count
+    #[account(
+        init,
+        payer = agent,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            vault.slash_request_count.to_le_bytes().as_ref()
+        ],
+        space = SlashRequest::INIT_SPACE + 8,
+        bump,
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
+    /// Optional failing attestation of the vault backing the request
+    #[account(
+        mut,
+        constraint = attestation.vault == vault.key() @ AttestationError::AttestationVaultMismatch
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
     #[account(
         seeds = [b"Config"],
         bump = config.bump,
     )]
     pub config: Account<'info, AuthorityConfig>,
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> CreateSlashReq<'info> {
-    pub fn create_slas_req(&mut self, slash_bps: u16, slash_claimant: Pubkey) -> Result<()> {
-        let clock = Clock::get()?;
+    pub fn create_slas_req(
+        &mut self,
+        params: CreateSlashReqParams,
+        bumps: &CreateSlashReqBumps,
+    ) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
         require_gte!(
             self.vault.max_slash_bps,
-            slash_bps,
+            params.slash_bps,
             VaultError::SlashReqExceedsMaxBps
         );
-        require!(
-            clock.unix_timestamp > self.vault.lock_phase_start_at
-                && clock.unix_timestamp
-                    < self.vault.lock_phase_start_at + self.vault.lock_phase_duration,
-            PhaseError::InvalidPhase
+        require_gte!(
+            MAX_EVIDENCE_URI_LEN,
+            params.evidence_uri.len(),
+            VaultError::EvidenceUriTooLong
         );
-        let slash_amount = (slash_bps as u64)
-            .checked_mul(self.vault.total_capital_collected)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?
-            .checked_div(BASE_BPS as u64)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
-        self.vault.is_dispute_active = true;
-        self.vault.pending_slash_amount = slash_amount;
-        self.vault.slash_claimant = slash_claimant;
+        let claimants = validate_split(&params.claimants, params.insurance_bps, params.burn_bps)?;
+        let attestation = self.use_attestation()?;
+        // Slashing is only possible while capital is locked
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault.require_phase(&[VaultPhase::Active])?;
+        let slash_amount =
+            reward_math::bps_share(self.vault.total_capital_collected, params.slash_bps)?;
+        self.vault.transition_to(vault_key, VaultPhase::Disputed)?;
+
         let clock = Clock::get()?;
-        self.vault.dispute_start_time = clock.unix_timestamp;
+        self.slash_request.set_inner(SlashRequest {
+            vault: vault_key,
+            sequence: self.vault.slash_request_count,
+            requester: self.agent.key(),
+            claimants,
+            insurance_bps: params.insurance_bps,
+            burn_bps: params.burn_bps,
+            slash_bps: params.slash_bps,
+            requested_amount: slash_amount,
+            evidence_hash: params.evidence_hash,
+            evidence_uri: params.evidence_uri,
+            attestation,
+            challenge: Pubkey::default(),
+            status: SlashStatus::Pending,
+            appeal_evidence_hash: [0; 32],
+            resolver: Pubkey::default(),
+            executed_amount: 0,
+            bond_slashed: 0,
+            insurance_amount: 0,
+            burned_amount: 0,
+            approvals: Vec::new(),
+            rejections: Vec::new(),
+            created_at: clock.unix_timestamp,
+            appealed_at: 0,
+            resolved_at: 0,
+            bump: bumps.slash_request,
+        });
+        self.vault.slash_request_count = self
+            .vault
+            .slash_request_count
+            .checked_add(1)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
         Ok(())
     }
+
+    /// Marks the referenced attestation as used by this request, returns its
+    /// address or the default one when the request has no attestation
+    fn use_attestation(&mut self) -> Result<Pubkey> {
+        let Some(attestation) = self.attestation.as_mut() else {
+            return Ok(Pubkey::default());
+        };
+        require!(attestation.failed, AttestationError::AttestationNotFailing);
+        require_keys_eq!(
+            attestation.slash_request,
+            Pubkey::default(),
+            AttestationError::AttestationAlreadyUsed
+        );
+        attestation.slash_request = self.slash_request.key();
+        Ok(attestation.key())
+    }
+}
+
+/// Checks the claimants are distinct and the split covers exactly 100%
+pub fn validate_split(
+    shares: &[SlashShare],
+    insurance_bps: u16,
+    burn_bps: u16,
+) -> Result<Vec<SlashClaimant>> {
+    require_gte!(
+        MAX_SLASH_CLAIMANTS,
+        shares.len(),
+        VaultError::InvalidSlashClaimants
+    );
+
+    let mut total_bps = insurance_bps
+        .checked_add(burn_bps)
+        .ok_or(VaultError::SlashSplitMismatch)?;
+    let mut claimants: Vec<SlashClaimant> = Vec::with_capacity(shares.len());
+    for share in shares.iter() {
+        require!(
+            claimants
+                .iter()
+                .all(|claimant| claimant.address != share.address),
+            VaultError::InvalidSlashClaimants
+        );
+        total_bps = total_bps
+            .checked_add(share.share_bps)
+            .ok_or(VaultError::SlashSplitMismatch)?;
+        claimants.push(SlashClaimant {
+            address: share.address,
+            share_bps: share.share_bps,
+            claimed: false,
+        });
+    }
+    require_eq!(total_bps, BASE_BPS, VaultError::SlashSplitMismatch);
+
+    Ok(claimants)
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct CreateSlashReqParams {
+    pub slash_bps: u16,
+
+    // Who receives the executed slash, claimant shares plus insurance and
+    // burn must sum to 10000 BPS
+    pub claimants: Vec<SlashShare>,
+    pub insurance_bps: u16,
+    pub burn_bps: u16,
+
+    // Commitment to the off-chain proof, and where to find it
+    pub evidence_hash: [u8; 32],
+    pub evidence_uri: String,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SlashShare {
+    pub address: Pubkey,
+    pub share_bps: u16,
 }
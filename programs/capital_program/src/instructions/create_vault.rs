@@ -1,6 +1,7 @@
 use crate::constants::*;
 use crate::errors::*;
-use crate::state::{AuthorityConfig, Beneficiary, Vault};
+use crate::reward_math::bps_share;
+use crate::state::{AuthorityConfig, OperatorRegistry, RewardStream, Vault, VaultPhase};
 use nft_program::cpi::accounts::CreateVaultCollection;
 use nft_program::program::NftProgram;
 use nft_program::state::NFTConfig;
@@ -8,7 +9,7 @@ use nft_program::state::NFTConfig;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{Mint, TokenInterface},
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 #[derive(Accounts)]
@@ -18,11 +19,25 @@ pub struct CreateVault<'info> {
     #[account(mut)]
     pub provider: Signer<'info>,
 
+    /// Per-operator vault counter, created with the operator's first vault
+    #[account(
+        init_if_needed,
+        payer = provider,
+        seeds = [b"Operator", config.node_operator.as_ref()],
+        space = OperatorRegistry::INIT_SPACE + 8,
+        bump,
+    )]
+    pub operator_registry: Account<'info, OperatorRegistry>,
+
     /// The vault account to be created
     #[account(
         init,
         payer = provider,
-        seeds = [b"Vault", provider.key().as_ref()],
+        seeds = [
+            b"Vault",
+            config.node_operator.as_ref(),
+            operator_registry.vault_count.to_le_bytes().as_ref()
+        ],
         space = Vault::INIT_SPACE + 8,
         bump,
     )]
@@ -38,7 +53,7 @@ pub struct CreateVault<'info> {
     /// NFT marketplace configuration
     pub nft_config: Account<'info, NFTConfig>,
 
-    /// Reward token mint - tokens distributed as rewards
+    /// Reward token mint - first reward stream of the vault
     #[account(
         mint::token_program = token_program,
         constraint = reward_token_mint.decimals > 0 @ TokenError::InvalidRewardMint
@@ -52,6 +67,25 @@ pub struct CreateVault<'info> {
     )]
     pub lock_mint: InterfaceAccount<'info, Mint>,
 
+    /// Vault's locking token account, holds the operator bond and investor capital
+    #[account(
+        init_if_needed,
+        payer = provider,
+        associated_token::mint = lock_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_lock_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Provider's locking token account funding the operator bond
+    #[account(
+        mut,
+        token::mint = lock_mint,
+        token::authority = provider,
+        token::token_program = token_program
+    )]
+    pub provider_lock_ata: InterfaceAccount<'info, TokenAccount>,
+
     /// NFT collection for vault positions
     #[account(
         mut,
@@ -74,50 +108,53 @@ impl<'info> CreateVault<'info> {
     /// Validates all configuration parameters for vault creation
     ///
     /// Checks:
-    /// - Total BPS allocation doesn't exceed 100%
-    /// - Lock phase duration meets minimum requirements
+    /// - Reward token mint differs from the lock mint
+    /// - Investor BPS and operator commission sum to exactly 100%
+    /// - Lock phase duration is within the configured min/max lock duration
     /// - Capital caps are properly ordered
     /// - Timing constraints are satisfied
-    /// - Beneficiary configuration is valid
+    /// - Operator bond covers the configured share of max_cap
     pub fn validate_config(&self, config: &InitVaultConfig) -> Result<()> {
-        // Validate no duplicate beneficiaries and calculate total BPS
-        let mut total_beneficiary_bps: u16 = 0;
-        for i in 0..config.beneficiaries.len() {
-            // Check for duplicates
-            for j in (i + 1)..config.beneficiaries.len() {
-                require_neq!(
-                    config.beneficiaries[i].address,
-                    config.beneficiaries[j].address,
-                    VaultError::DuplicateBeneficiary
-                );
-            }
-
-            // Validate individual share is reasonable
-            require_gt!(
-                config.beneficiaries[i].share_bps,
-                0,
-                VaultError::BeneficiaryShareMustBePositive
-            );
-
-            // Accumulate total
-            total_beneficiary_bps = total_beneficiary_bps
-                .checked_add(config.beneficiaries[i].share_bps)
-                .ok_or(ArithmeticError::ArithmeticOverflow)?;
-        }
+        // The node operator anchors the vault PDA, so it must be a real address
+        require_keys_neq!(
+            config.node_operator,
+            Pubkey::default(),
+            SignerError::InvalidAddress
+        );
 
-        // Validate total BPS doesn't exceed 100%
-        let total_bps = total_beneficiary_bps
-            .checked_add(config.investor_bps)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        // Rewards and principal share the vault's token accounts per mint, a
+        // reward stream in the lock mint would pay out locked capital
+        require_keys_neq!(
+            self.reward_token_mint.key(),
+            self.lock_mint.key(),
+            TokenError::InvalidRewardMint
+        );
 
-        require_gte!(BASE_BPS, total_bps, VaultError::BPSExceedsMaximum);
+        // Investors and the operator split 100%, beneficiaries are added later
+        // out of the operator commission
+        let total_bps = config
+            .investor_bps
+            .checked_add(config.operator_commission_bps)
+            .ok_or(VaultError::BPSExceedsMaximum)?;
+        require_eq!(total_bps, BASE_BPS, VaultError::RewardSplitMismatch);
 
-        // Validate lock phase duration
+        // Validate lock phase duration against the protocol floor and the
+        // admin-configured range
         require_gte!(
             config.lock_phase_duration,
             MIN_LOCK_PERIOD,
             PhaseError::LockPhaseTooShort
         );
+        require_gte!(
+            config.lock_phase_duration,
+            self.config_account.min_lock_duration,
+            PhaseError::LockPhaseTooShort
+        );
+        require_gte!(
+            self.config_account.max_lock_duration,
+            config.lock_phase_duration,
+            PhaseError::LockPhaseTooLong
+        );
 
         // Validate capital caps (max should be greater than min)
         require_gt!(
@@ -147,6 +184,14 @@ impl<'info> CreateVault<'info> {
             PhaseError::LockPhaseStartsTooSoon
         );
 
+        let min_operator_bond =
+            bps_share(config.max_cap, self.config_account.min_operator_bond_bps)?;
+        require_gte!(
+            config.operator_bond,
+            min_operator_bond,
+            VaultError::OperatorBondTooLow
+        );
+
         Ok(())
     }
 
@@ -159,24 +204,40 @@ impl<'info> CreateVault<'info> {
         self.vault.set_inner(Vault {
             // Token configuration
             locking_token_mint: self.lock_mint.key(),
-            reward_token_mint: self.reward_token_mint.key(),
+            reward_streams: vec![RewardStream {
+                mint: self.reward_token_mint.key(),
+                total_deposited: 0,
+                total_claimed: 0,
+                acc_reward_per_share: 0,
+                acc_reward_per_bps: 0,
+                unvested_rewards: 0,
+                last_vested_at: 0,
+                vesting_end_at: 0,
+                operator_reward_debt: 0,
+                operator_accrued: 0,
+                rounding_remainder: 0,
+            }],
 
             // Capital configuration
             min_cap: config.min_cap,
             max_cap: config.max_cap,
             min_lock_amount: config.min_lock_amount,
             total_capital_collected: 0,
-            total_rewards_deposited: 0,
             capital_after_slashing: 0,
+            operator_bond: config.operator_bond,
+            insurance_claimed: 0,
+            capital_withdrawn: 0,
+            challenge_slashed: 0,
 
             // Beneficiary configuration
-            beneficiaries: config.beneficiaries,
+            beneficiary_count: 0,
+            total_beneficiary_bps: 0,
             investor_bps: config.investor_bps,
+            operator_commission_bps: config.operator_commission_bps,
 
             // Slash configuration
             max_slash_bps: config.max_slash_bps,
-            pending_slash_amount: 0,
-            slash_claimant: config.slash_claimant,
+            slash_request_count: 0,
 
             // NFT configuration
             nft_collection: self.nft_collection.key(),
@@ -188,9 +249,11 @@ impl<'info> CreateVault<'info> {
             // Timing configuration
             lock_phase_start_at: config.lock_phase_start_time,
             lock_phase_duration: config.lock_phase_duration,
+            vault_index: self.operator_registry.vault_count,
 
-            is_dispute_active: false,
-            dispute_start_time: 0,
+            phase: VaultPhase::Formation,
+            paused: false,
+            swept: false,
 
             // Account metadata
             bump: bumps.vault,
@@ -199,6 +262,41 @@ impl<'info> CreateVault<'info> {
         Ok(())
     }
 
+    /// Reserves the vault index for this vault in the operator registry
+    pub fn register_vault(
+        &mut self,
+        node_operator: Pubkey,
+        bumps: &CreateVaultBumps,
+    ) -> Result<()> {
+        let registry = &mut self.operator_registry;
+        registry.node_operator = node_operator;
+        registry.bump = bumps.operator_registry;
+        registry.vault_count = registry
+            .vault_count
+            .checked_add(1)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Moves the operator bond from the provider into the vault
+    pub fn post_operator_bond(&self, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let transfer_accounts = TransferChecked {
+            from: self.provider_lock_ata.to_account_info(),
+            to: self.vault_lock_ata.to_account_info(),
+            authority: self.provider.to_account_info(),
+            mint: self.lock_mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+        transfer_checked(cpi_ctx, amount, self.lock_mint.decimals)
+    }
+
     /// Creates the NFT collection for this vault via CPI
     pub fn create_nft_collection(&self) -> Result<()> {
         let signer_seeds: &[&[&[u8]]] = &[&[b"Config", &[self.config_account.bump]]];
@@ -231,13 +329,17 @@ pub struct InitVaultConfig {
     pub max_cap: u64,
     pub min_lock_amount: u64,
 
-    // Beneficiary configuration
-    pub beneficiaries: Vec<Beneficiary>,
+    // First-loss bond in the locking token, posted by the provider and
+    // returned to the node operator when the vault is closed
+    pub operator_bond: u64,
+
+    // Reward split, beneficiaries are added with add_beneficiary_handler
+    // and take their share from the operator commission
     pub investor_bps: u16,
+    pub operator_commission_bps: u16,
 
     // Slash configuration
     pub max_slash_bps: u16,
-    pub slash_claimant: Pubkey,
 
     // Authority configuration
     pub reward_distributor: Pubkey,
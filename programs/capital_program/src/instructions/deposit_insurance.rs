@@ -0,0 +1,70 @@
+use crate::errors::*;
+use crate::state::InsuranceFund;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct DepositInsurance<'info> {
+    /// Anyone can top up the insurance fund
+    pub depositor: Signer<'info>,
+
+    /// Mint of the insurance fund being funded
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Created by the admin through init_fee_accounts
+    #[account(
+        mut,
+        seeds = [b"Insurance", mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Insurance fund's token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+        token::token_program = token_program
+    )]
+    pub depositor_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositInsurance<'info> {
+    /// Moves `amount` into the insurance fund, returns its new total deposited
+    pub fn deposit(&mut self, amount: u64) -> Result<u64> {
+        require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
+        require_gte!(
+            self.depositor_ata.amount,
+            amount,
+            TokenError::InsufficientBalance
+        );
+
+        self.insurance_fund.record_deposit(amount)?;
+
+        let transfer_accounts = TransferChecked {
+            from: self.depositor_ata.to_account_info(),
+            to: self.insurance_fund_ata.to_account_info(),
+            authority: self.depositor.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        Ok(self.insurance_fund.total_deposited)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::errors::*;
-use crate::state::{AuthorityConfig, Vault};
+use crate::reward_math::bps_share;
+use crate::state::{AuthorityConfig, DepositorRole, InsuranceFund, Vault, VaultPhase};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -8,19 +9,24 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 pub struct DepositRewards<'info> {
-    /// The authorized agent depositing rewards
+    /// The vault's reward distributor or the global agent
     #[account(
         mut,
-        address = config.agent @ SignerError::UnauthorizedAgent
+        constraint = depositor.key() == vault.reward_distributor
+            || depositor.key() == config.agent @ SignerError::UnauthorizedDepositor
     )]
-    pub agent: Signer<'info>,
+    pub depositor: Signer<'info>,
 
     /// The vault receiving rewards
     #[account(
         mut,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute
+        constraint = vault.phase != VaultPhase::Disputed @ VaultError::VaultUnderDispute
     )]
     pub vault: Account<'info, Vault>,
 
@@ -31,32 +37,63 @@ pub struct DepositRewards<'info> {
     )]
     pub config: Account<'info, AuthorityConfig>,
 
-    /// Reward token mint
-    #[account(
-        mint::token_program = token_program,
-        address = vault.reward_token_mint @ TokenError::InvalidRewardMint
-    )]
+    /// Mint of the reward stream being funded
+    #[account(mint::token_program = token_program)]
     pub reward_token_mint: InterfaceAccount<'info, Mint>,
 
     /// Vault's reward token account
     #[account(
         init_if_needed,
-        payer = agent,
+        payer = depositor,
         associated_token::mint = reward_token_mint,
         associated_token::authority = vault,
         associated_token::token_program = token_program
     )]
     pub vault_reward_ata: InterfaceAccount<'info, TokenAccount>,
 
-    /// Agent's reward token account
+    /// CHECK: Protocol treasury PDA, only owns the fee token accounts
+    #[account(address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Treasury's token account collecting the protocol fee for this mint,
+    /// created by the admin through init_fee_accounts. Only required while the
+    /// treasury's share of the protocol fee is positive
     #[account(
         mut,
         associated_token::mint = reward_token_mint,
-        associated_token::authority = agent,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_reward_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Insurance fund of the reward mint, receives its slice of the protocol fee.
+    /// The admin moves it into the locking token's fund with withdraw_insurance.
+    /// Only required while the insurance share of the protocol fee is positive
+    #[account(
+        mut,
+        seeds = [b"Insurance", reward_token_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    /// Insurance fund's token account for the reward mint
+    #[account(
+        mut,
+        associated_token::mint = reward_token_mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Depositor's reward token account
+    #[account(
+        mut,
+        associated_token::mint = reward_token_mint,
+        associated_token::authority = depositor,
         associated_token::token_program = token_program,
-        constraint = agent_reward_ata.amount > 0 @ TokenError::InsufficientBalance
+        constraint = depositor_reward_ata.amount > 0 @ TokenError::InsufficientBalance
     )]
-    pub agent_reward_ata: InterfaceAccount<'info, TokenAccount>,
+    pub depositor_reward_ata: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -64,53 +101,110 @@ pub struct DepositRewards<'info> {
 }
 
 impl<'info> DepositRewards<'info> {
+    /// Role the depositor signed as, the vault's own distributor taking precedence
+    pub fn depositor_role(&self) -> DepositorRole {
+        if self.depositor.key() == self.vault.reward_distributor {
+            DepositorRole::RewardDistributor
+        } else {
+            DepositorRole::Agent
+        }
+    }
+
     /// Validates reward deposit parameters
-    pub fn validate_deposit(&self, amount: u64) -> Result<()> {
+    pub fn validate_deposit(&mut self, amount: u64) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+
         // Validate amount is positive
         require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
 
-        // Validate agent has sufficient balance
+        // Validate depositor has sufficient balance
         require_gte!(
-            self.agent_reward_ata.amount,
+            self.depositor_reward_ata.amount,
             amount,
             TokenError::InsufficientBalance
         );
 
-        // Validate vault has capital collected
-        require_gt!(
-            self.vault.total_capital_collected,
-            self.vault.min_cap,
-            VaultError::BelowMinCap
-        );
-
-        // Validate timing - can only deposit rewards after lock phase starts
-        let clock = Clock::get()?;
-        require!(
-            clock.unix_timestamp >= self.vault.lock_phase_start_at,
-            PhaseError::InvalidPhase
-        );
+        // Validate timing and that the mint is one of the vault's reward streams
+        let vault_key = self.vault.key();
+        self.vault
+            .require_accepting_rewards(vault_key, self.reward_token_mint.key())?;
         Ok(())
     }
 
-    /// Updates vault state with new reward deposit
-    pub fn update_vault_state(&mut self, amount: u64) -> Result<()> {
-        let new_total_rewards = self
-            .vault
-            .total_rewards_deposited
-            .checked_add(amount)
-            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+    /// Protocol fee carved off `amount` before it reaches the vault
+    pub fn protocol_fee(&self, amount: u64) -> Result<u64> {
+        bps_share(amount, self.config.protocol_fee_bps)
+    }
 
-        self.vault.total_rewards_deposited = new_total_rewards;
+    /// Records the insurance fund's slice of `protocol_fee` and returns it
+    pub fn fund_insurance(&mut self, protocol_fee: u64) -> Result<u64> {
+        let insurance_fee = bps_share(protocol_fee, self.config.insurance_fee_bps)?;
+        if insurance_fee > 0 {
+            let Some(insurance_fund) = &mut self.insurance_fund else {
+                return err!(TokenError::InsuranceAccountRequired);
+            };
+            insurance_fund.record_deposit(insurance_fee)?;
+        }
+        Ok(insurance_fee)
+    }
 
-        Ok(())
+    /// Updates vault state with new reward deposit
+    /// Returns the new total deposited into the stream
+    pub fn update_vault_state(&mut self, amount: u64, stream_duration: Option<i64>) -> Result<u64> {
+        let stream_index = self
+            .vault
+            .reward_stream_index(self.reward_token_mint.key())?;
+        self.vault
+            .record_rewards(stream_index, amount, stream_duration)
     }
 
-    /// Transfers reward tokens from agent to vault
-    pub fn transfer_rewards(&self, amount: u64) -> Result<()> {
+    /// Transfers reward tokens from depositor to vault, and the protocol fee to
+    /// the treasury and the insurance fund
+    pub fn transfer_rewards(
+        &self,
+        amount: u64,
+        protocol_fee: u64,
+        insurance_fee: u64,
+    ) -> Result<()> {
+        if insurance_fee > 0 {
+            let Some(insurance_fund_ata) = &self.insurance_fund_ata else {
+                return err!(TokenError::InsuranceAccountRequired);
+            };
+            let insurance_accounts = TransferChecked {
+                from: self.depositor_reward_ata.to_account_info(),
+                to: insurance_fund_ata.to_account_info(),
+                authority: self.depositor.to_account_info(),
+                mint: self.reward_token_mint.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), insurance_accounts);
+
+            transfer_checked(cpi_ctx, insurance_fee, self.reward_token_mint.decimals)?;
+        }
+
+        let treasury_fee = protocol_fee
+            .checked_sub(insurance_fee)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        if treasury_fee > 0 {
+            let Some(treasury_reward_ata) = &self.treasury_reward_ata else {
+                return err!(TokenError::TreasuryAccountRequired);
+            };
+            let fee_accounts = TransferChecked {
+                from: self.depositor_reward_ata.to_account_info(),
+                to: treasury_reward_ata.to_account_info(),
+                authority: self.depositor.to_account_info(),
+                mint: self.reward_token_mint.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), fee_accounts);
+
+            transfer_checked(cpi_ctx, treasury_fee, self.reward_token_mint.decimals)?;
+        }
+
         let transfer_accounts = TransferChecked {
-            from: self.agent_reward_ata.to_account_info(),
+            from: self.depositor_reward_ata.to_account_info(),
             to: self.vault_reward_ata.to_account_info(),
-            authority: self.agent.to_account_info(),
+            authority: self.depositor.to_account_info(),
             mint: self.reward_token_mint.to_account_info(),
         };
 
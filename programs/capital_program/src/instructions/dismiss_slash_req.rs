@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{AuthorityConfig, SlashRequest, SlashStatus, Vault, VaultPhase};
+
+#[derive(Accounts)]
+pub struct DismissSlashReq<'info> {
+    /// Anyone can dismiss an expired slash request
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+        constraint = vault.phase == VaultPhase::Disputed @ VaultError::NoActiveDispute
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// The vault's open slash request
+    #[account(
+        mut,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            slash_request.sequence.to_le_bytes().as_ref()
+        ],
+        bump = slash_request.bump,
+        constraint = slash_request.is_open() @ VaultError::NoActiveDispute
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
+
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+}
+
+impl<'info> DismissSlashReq<'info> {
+    /// Clears a slash request nobody decided in time, without touching capital
+    pub fn dismiss(&mut self) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+
+        let clock = Clock::get()?;
+        require_gte!(
+            clock.unix_timestamp,
+            self.slash_request.decision_deadline()?,
+            VaultError::DisputeWindowNotExpired
+        );
+
+        self.slash_request.status = SlashStatus::Dismissed;
+        self.slash_request.resolver = self.caller.key();
+        self.slash_request.resolved_at = clock.unix_timestamp;
+
+        // Dispute over, resume the lock period (or mature if it elapsed meanwhile)
+        let vault_key = self.vault.key();
+        self.vault.transition_to(vault_key, VaultPhase::Active)?;
+        self.vault.sync_phase(vault_key)?;
+        Ok(())
+    }
+}
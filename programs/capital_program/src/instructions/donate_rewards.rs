@@ -0,0 +1,110 @@
+use crate::constants::MAX_DONATION_MEMO_LEN;
+use crate::errors::*;
+use crate::state::{AuthorityConfig, Vault, VaultPhase};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct DonateRewards<'info> {
+    /// Anyone topping up the vault's rewards
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    /// The vault receiving the donation
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+        constraint = vault.phase != VaultPhase::Disputed @ VaultError::VaultUnderDispute
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// Mint of the reward stream being topped up
+    #[account(mint::token_program = token_program)]
+    pub reward_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's reward token account
+    #[account(
+        init_if_needed,
+        payer = donor,
+        associated_token::mint = reward_token_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Donor's reward token account
+    #[account(
+        mut,
+        token::mint = reward_token_mint,
+        token::authority = donor,
+        token::token_program = token_program,
+    )]
+    pub donor_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DonateRewards<'info> {
+    /// Validates donation parameters
+    pub fn validate_donation(&mut self, amount: u64, memo: &str) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+
+        require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
+        require_gte!(
+            self.donor_reward_ata.amount,
+            amount,
+            TokenError::InsufficientBalance
+        );
+        require_gte!(
+            MAX_DONATION_MEMO_LEN,
+            memo.len(),
+            VaultError::DonationMemoTooLong
+        );
+
+        // Donations follow the same timing and stream rules as deposits
+        let vault_key = self.vault.key();
+        self.vault
+            .require_accepting_rewards(vault_key, self.reward_token_mint.key())?;
+        Ok(())
+    }
+
+    /// Splits the donation like any reward deposit.
+    /// Returns the new total deposited into the stream
+    pub fn record_donation(&mut self, amount: u64) -> Result<u64> {
+        let stream_index = self
+            .vault
+            .reward_stream_index(self.reward_token_mint.key())?;
+        self.vault.record_rewards(stream_index, amount, None)
+    }
+
+    /// Transfers reward tokens from donor to vault
+    pub fn transfer_donation(&self, amount: u64) -> Result<()> {
+        let transfer_accounts = TransferChecked {
+            from: self.donor_reward_ata.to_account_info(),
+            to: self.vault_reward_ata.to_account_info(),
+            authority: self.donor.to_account_info(),
+            mint: self.reward_token_mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+        transfer_checked(cpi_ctx, amount, self.reward_token_mint.decimals)
+    }
+}
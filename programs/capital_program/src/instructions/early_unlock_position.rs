@@ -0,0 +1,290 @@
+use crate::constants::BASE_BPS;
+use crate::errors::*;
+use crate::reward_math;
+use crate::reward_streams::RewardStreamAccounts;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use mpl_core::accounts::BaseAssetV1;
+use nft_program::cpi::accounts::BurnAsset;
+use nft_program::program::NftProgram;
+
+#[derive(Accounts)]
+pub struct EarlyUnlockPosition<'info> {
+    /// The capital provider who owns the position
+    #[account(
+        mut,
+        address = asset.owner @ SignerError::InvalidAssetOwner
+    )]
+    pub position_holder: Signer<'info>,
+
+    /// The vault containing this position
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+        constraint = vault.phase != VaultPhase::Disputed @ VaultError::VaultUnderDispute
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Global configuration, holds the early unlock fee
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// The position being unlocked, closed to the holder
+    #[account(
+        mut,
+        close = position_holder,
+        seeds = [b"Position", asset.key().as_ref()],
+        bump = position.bump,
+        constraint = position.vault == vault.key() @ PositionError::PositionVaultMismatch,
+    )]
+    pub position: Account<'info, Position>,
+
+    /// The NFT asset representing the position
+    #[account(
+        mut,
+        address = position.asset @ PositionError::InvalidAsset
+    )]
+    pub asset: Account<'info, BaseAssetV1>,
+
+    /// The vault's NFT collection
+    /// CHECK: Validated against vault.nft_collection
+    #[account(
+        mut,
+        address = vault.nft_collection @ PositionError::InvalidCollection
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = vault.locking_token_mint @ TokenError::InvalidLockingMint
+    )]
+    pub lock_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = lock_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_lock_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = lock_mint,
+        associated_token::authority = position_holder,
+        associated_token::token_program = token_program
+    )]
+    pub capital_provider_lock_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint of the vault's first reward stream, further streams go in remaining accounts
+    #[account(
+        mint::token_program = token_program,
+        address = vault.reward_streams[0].mint @ TokenError::InvalidRewardMint
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's reward token account, created if no rewards were deposited yet
+    #[account(
+        init_if_needed,
+        payer = position_holder,
+        associated_token::mint = reward_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Holder's reward token account receiving the settled rewards
+    #[account(
+        init_if_needed,
+        payer = position_holder,
+        associated_token::mint = reward_mint,
+        associated_token::authority = position_holder,
+        associated_token::token_program = token_program
+    )]
+    pub holder_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: this will be cheked at marketplace
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub nft_program: Program<'info, NftProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EarlyUnlockPosition<'info> {
+    /// Pays out the rewards the position accrued before leaving the vault
+    ///
+    /// Streams other than the first are passed in `remaining_accounts` and every
+    /// stream with pending rewards must be included.
+    /// Returns the rewards settled from the first stream
+    pub fn settle_rewards(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<u64> {
+        self.vault.require_not_paused(&self.config)?;
+
+        // Early unlock only exists while capital is locked
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault.require_phase(&[VaultPhase::Active])?;
+
+        // Rewards still streaming stay with the remaining holders
+        self.vault.vest_rewards()?;
+
+        let mut streams = vec![RewardStreamAccounts::new(
+            &self.vault,
+            &self.reward_mint,
+            &self.vault_reward_ata,
+            &self.holder_reward_ata,
+        )?];
+        streams.extend(RewardStreamAccounts::parse_remaining(
+            &self.vault,
+            self.position_holder.key(),
+            remaining_accounts,
+        )?);
+
+        let mut rewards_settled = 0;
+        for stream in streams.iter() {
+            let claimable = self
+                .position
+                .pending_rewards(&self.vault, stream.stream_index)?;
+            if claimable == 0 {
+                continue;
+            }
+
+            self.position
+                .settle_rewards(stream.stream_index, claimable)?;
+            let reward_stream = &mut self.vault.reward_streams[stream.stream_index];
+            reward_stream.total_claimed = reward_stream
+                .total_claimed
+                .checked_add(claimable)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+            stream.transfer_from_vault(&self.vault, &self.token_program, claimable)?;
+
+            if stream.stream_index == 0 {
+                rewards_settled = claimable;
+            }
+        }
+
+        // The position is closed, no stream may be left behind
+        require!(
+            self.position.rewards_settled(&self.vault)?,
+            PositionError::PositionIsNotEmpty
+        );
+
+        Ok(rewards_settled)
+    }
+
+    /// Returns the slash-adjusted principal minus the early unlock fee
+    ///
+    /// The fee stays in the vault as part of `capital_after_slashing`, so it is
+    /// shared pro-rata by the remaining holders when they close their positions.
+    /// Returns (principal_returned, fee)
+    pub fn process_unlock(&mut self) -> Result<(u64, u64)> {
+        let total_capital_collected = self.vault.total_capital_collected;
+        let capital_after_slashing = self.vault.capital_after_slashing;
+        let position_capital = self.position.total_value_locked;
+
+        // Removing this position must not leave the vault below min_cap
+        let remaining_capital = total_capital_collected
+            .checked_sub(position_capital)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        require_gte!(
+            remaining_capital,
+            self.vault.min_cap,
+            VaultError::VaultReachedMinCap
+        );
+
+        // Position's pro-rata share of the (possibly slashed) capital
+        let position_principal = reward_math::mul_div(
+            position_capital,
+            capital_after_slashing,
+            total_capital_collected,
+        )?;
+
+        let fee = reward_math::mul_div(
+            position_principal,
+            self.config.early_unlock_fee,
+            BASE_BPS as u64,
+        )?;
+
+        let principal_returned = position_principal
+            .checked_sub(fee)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+
+        self.vault.total_capital_collected = remaining_capital;
+        self.vault.capital_after_slashing = capital_after_slashing
+            .checked_sub(principal_returned)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        self.position.total_value_locked = 0;
+
+        self.transfer_from_vault(
+            self.vault_lock_ata.to_account_info(),
+            self.capital_provider_lock_ata.to_account_info(),
+            self.lock_mint.to_account_info(),
+            self.lock_mint.decimals,
+            principal_returned,
+        )?;
+
+        Ok((principal_returned, fee))
+    }
+
+    fn transfer_from_vault(
+        &self,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        decimals: u8,
+        amount: u64,
+    ) -> Result<()> {
+        let node_operator_key = self.vault.node_operator.key();
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Vault",
+            node_operator_key.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from,
+            to,
+            authority: self.vault.to_account_info(),
+            mint,
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, amount, decimals)
+    }
+
+    pub fn burn_nft(&mut self) -> Result<()> {
+        let burn_asset_accounts = BurnAsset {
+            asset: self.asset.to_account_info(),
+            holder: self.position_holder.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            mpl_core_program: self.mpl_core_program.to_account_info(),
+            collection: self.collection.to_account_info(),
+        };
+        let burn_cpi = CpiContext::new(self.nft_program.to_account_info(), burn_asset_accounts);
+        nft_program::cpi::burn_asset_handler(burn_cpi)?;
+        Ok(())
+    }
+}
@@ -1,27 +1,45 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::DISPUTE_WINDOW;
 use crate::errors::*;
-use crate::state::{AuthorityConfig, Vault};
+use crate::slashing::SlashAccounts;
+use crate::state::{AuthorityConfig, InsuranceFund, SlashRequest, Vault, VaultPhase};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 #[derive(Accounts)]
 pub struct FinalizeSlashReq<'info> {
+    /// The agent, or the admin once the node operator appealed
     #[account(
         mut,
-        address = config.agent @ SignerError::UnauthorizedAgent
+        constraint = resolver.key() == slash_request.resolver_authority(&config)
+            @ SignerError::UnauthorizedSlashResolver
     )]
-    pub agent: Signer<'info>,
+    pub resolver: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute
+        constraint = vault.phase == VaultPhase::Disputed @ VaultError::NoActiveDispute
     )]
     pub vault: Account<'info, Vault>,
+    /// The vault's open slash request, kept as a record once resolved
+    #[account(
+        mut,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            slash_request.sequence.to_le_bytes().as_ref()
+        ],
+        bump = slash_request.bump,
+        constraint = slash_request.is_open() @ VaultError::NoActiveDispute
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
     #[account(
         seeds = [b"Config"],
         bump = config.bump,
@@ -29,6 +47,7 @@ pub struct FinalizeSlashReq<'info> {
     pub config: Account<'info, AuthorityConfig>,
     /// Locking token mint
     #[account(
+        mut,
         mint::token_program = token_program,
         address = vault.locking_token_mint @ TokenError::InvalidLockingMint
     )]
@@ -43,70 +62,72 @@ pub struct FinalizeSlashReq<'info> {
     )]
     pub vault_token_ata: InterfaceAccount<'info, TokenAccount>,
 
-    /// Capital provider's token account
+    /// Holds the claimants' part of the slash until each of them claims it
+    #[account(
+        init_if_needed,
+        payer = resolver,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = slash_request,
+        associated_token::token_program = token_program
+    )]
+    pub slash_escrow_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Insurance fund of the locking token, required when part of the slash goes there
+    #[account(
+        mut,
+        seeds = [b"Insurance", locking_token_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
     #[account(
         mut,
         associated_token::mint = locking_token_mint,
-        associated_token::authority = agent,
+        associated_token::authority = insurance_fund,
         associated_token::token_program = token_program
     )]
-    pub slash_claimant_ata: InterfaceAccount<'info, TokenAccount>,
+    pub insurance_fund_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> FinalizeSlashReq<'info> {
+    /// Resolves the dispute and records the outcome on the slash request
     pub fn process_req(&mut self, decision: bool, amount: u64) -> Result<()> {
-        let clock = Clock::get()?;
-        if decision == true
-            && amount <= self.vault.pending_slash_amount
-            && amount > 0
-            && clock.unix_timestamp < self.vault.dispute_start_time + DISPUTE_WINDOW
-        {
-            self.process_withdrawal(amount)?;
-        }
-        self.vault.pending_slash_amount = 0;
-        self.vault.dispute_start_time = 0;
-        self.vault.is_dispute_active = false;
-        Ok(())
-    }
-
-    fn process_withdrawal(&mut self, amount: u64) -> Result<()> {
-        // Calculate new total vault capital
-        let new_slashed_capital = self
-            .vault
-            .capital_after_slashing
-            .checked_sub(amount)
-            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
-
-        require_gte!(
-            new_slashed_capital,
-            self.vault.min_cap,
-            VaultError::VaultReachedMinCap
+        self.vault.require_not_paused(&self.config)?;
+        // With an arbiter committee in place slashes are decided by vote_slash
+        require!(
+            !self.config.has_arbiters(),
+            SignerError::ArbiterVoteRequired
         );
 
-        self.vault.capital_after_slashing = new_slashed_capital;
-
-        let node_operator_key = self.vault.node_operator.key();
-        let signer_seeds: &[&[&[u8]]] =
-            &[&[b"Vault", node_operator_key.as_ref(), &[self.vault.bump]]];
-
-        let transfer_accounts = TransferChecked {
-            from: self.vault_token_ata.to_account_info(),
-            to: self.slash_claimant_ata.to_account_info(),
-            authority: self.vault.to_account_info(),
-            mint: self.locking_token_mint.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            transfer_accounts,
-            signer_seeds,
-        );
+        let clock = Clock::get()?;
+        if decision {
+            self.slash_request
+                .require_appeal_window_closed(clock.unix_timestamp)?;
+        }
+        let approved = decision
+            && amount <= self.slash_request.requested_amount
+            && amount > 0
+            && clock.unix_timestamp < self.slash_request.decision_deadline()?;
 
-        transfer_checked(cpi_ctx, amount, self.locking_token_mint.decimals)?;
+        let resolver = self.resolver.key();
+        self.slash_accounts()
+            .resolve(approved.then_some(amount), resolver)
+    }
 
-        Ok(())
+    fn slash_accounts(&mut self) -> SlashAccounts<'_, 'info> {
+        SlashAccounts {
+            vault: &mut self.vault,
+            slash_request: &mut self.slash_request,
+            locking_token_mint: &self.locking_token_mint,
+            vault_token_ata: &self.vault_token_ata,
+            slash_escrow_ata: &self.slash_escrow_ata,
+            insurance_fund: self.insurance_fund.as_mut(),
+            insurance_fund_ata: self.insurance_fund_ata.as_ref(),
+            token_program: &self.token_program,
+        }
     }
 }
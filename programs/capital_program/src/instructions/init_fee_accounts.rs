@@ -0,0 +1,80 @@
+use crate::errors::*;
+use crate::state::{AuthorityConfig, InsuranceFund};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct InitFeeAccounts<'info> {
+    /// Current program administrator, pays for the accounts
+    #[account(
+        mut,
+        address = config.admin @ SignerError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// Mint protocol fees or insurance will be collected in
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Protocol treasury PDA, only owns the fee token accounts
+    #[account(address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Treasury's token account collecting the protocol fee for this mint
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"Insurance", mint.key().as_ref()],
+        space = InsuranceFund::INIT_SPACE + 8,
+        bump,
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Insurance fund's token account for the mint
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitFeeAccounts<'info> {
+    /// Sets up the mint's insurance fund, reward deposits and insurance top ups
+    /// of the mint need it and the treasury token account to exist
+    pub fn init_fee_accounts(&mut self, bumps: &InitFeeAccountsBumps) -> Result<()> {
+        self.insurance_fund.set_inner(InsuranceFund {
+            mint: self.mint.key(),
+            total_deposited: 0,
+            total_paid: 0,
+            total_withdrawn: 0,
+            bump: bumps.insurance_fund,
+        });
+        Ok(())
+    }
+}
@@ -47,13 +47,19 @@ impl<'info> InitProgram<'info> {
     ///
     /// Checks:
     /// - Fee amounts are within acceptable ranges
-    /// - Dispute window is reasonable
     /// - Lock duration constraints are valid
     /// - Agent address is valid
     pub fn validate_params(&self, params: &InitProgramConfig) -> Result<()> {
         // Validate agent address
         require_keys_neq!(params.agent, Pubkey::default(), SignerError::InvalidAddress);
 
+        // Early unlock fee is charged in bps of the returned principal
+        require_gte!(
+            BASE_BPS as u64,
+            params.early_unlock_fee,
+            VaultError::InvalidBasisPoints
+        );
+
         // Validate reasonable duration range
         let duration_range = params
             .max_lock_duration
@@ -75,21 +81,37 @@ impl<'info> InitProgram<'info> {
         params: InitProgramConfig,
         bumps: &InitProgramBumps,
     ) -> Result<()> {
+        // The treasury only signs for its token accounts, it holds no data
+        let (treasury, treasury_bump) = Pubkey::find_program_address(&[b"Treasury"], &crate::ID);
+
         self.config.set_inner(AuthorityConfig {
             // Program references
             nft_program: self.nft_program.key(),
 
             // Authority configuration
+            treasury,
             admin: self.admin.key(),
+            pending_admin: Pubkey::default(),
             agent: params.agent,
+            guardian: Pubkey::default(),
+            arbiters: [Pubkey::default(); MAX_ARBITERS],
+            arbiter_count: 0,
+            arbiter_threshold: 0,
 
             // Fee configuration
             early_unlock_fee: params.early_unlock_fee,
+            protocol_fee_bps: 0,
+            min_operator_bond_bps: 0,
+            insurance_fee_bps: 0,
+            insurance_coverage_bps: 0,
 
             min_lock_duration: params.min_lock_duration,
             max_lock_duration: params.max_lock_duration,
 
-            // PDA bump
+            paused: false,
+
+            // PDA bumps
+            treasury_bump,
             bump: bumps.config,
         });
 
@@ -127,12 +149,9 @@ pub struct InitProgramConfig {
     /// Agent address for administrative operations
     pub agent: Pubkey,
 
-    /// Fee charged for early unlock (in lamports or basis points)
+    /// Fee charged on principal for early unlock (in basis points)
     pub early_unlock_fee: u64,
 
-    /// Time window for dispute resolution (in seconds)
-    pub dispute_window: i64,
-
     /// Maximum allowed lock duration (in seconds)
     pub max_lock_duration: i64,
 
@@ -0,0 +1,125 @@
+use crate::constants::CHALLENGE_RESPONSE_WINDOW;
+use crate::errors::*;
+use crate::instructions::{validate_split, SlashShare};
+use crate::state::{
+    AuthorityConfig, Challenge, ChallengeStatus, EpochCommitment, Vault, VaultPhase,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(leaf_index: u64)]
+pub struct IssueChallenge<'info> {
+    /// The agent, pays for the challenge account
+    #[account(
+        mut,
+        address = config.agent @ SignerError::UnauthorizedAgent
+    )]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"Commitment", vault.key().as_ref(), commitment.epoch.to_le_bytes().as_ref()],
+        bump = commitment.bump,
+    )]
+    pub commitment: Account<'info, EpochCommitment>,
+
+    #[account(
+        init,
+        payer = agent,
+        seeds = [
+            b"Challenge",
+            commitment.key().as_ref(),
+            leaf_index.to_le_bytes().as_ref()
+        ],
+        space = Challenge::INIT_SPACE + 8,
+        bump,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> IssueChallenge<'info> {
+    /// Challenges the operator to prove `leaf_index` of the commitment, the slash
+    /// taken if it fails is fixed now
+    pub fn issue_challenge(
+        &mut self,
+        leaf_index: u64,
+        terms: ChallengeSlashTerms,
+        bumps: &IssueChallengeBumps,
+    ) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        require_gt!(
+            self.commitment.leaf_count,
+            leaf_index,
+            ChallengeError::LeafIndexOutOfRange
+        );
+        require_gte!(
+            self.vault.max_slash_bps,
+            terms.slash_bps,
+            VaultError::SlashReqExceedsMaxBps
+        );
+        require_gt!(
+            self.vault.challenge_slash_allowance()?,
+            0,
+            ChallengeError::ChallengeSlashLimitReached
+        );
+        let claimants = validate_split(&terms.claimants, terms.insurance_bps, terms.burn_bps)?;
+
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Active, VaultPhase::Disputed])?;
+
+        let clock = Clock::get()?;
+        self.challenge.set_inner(Challenge {
+            vault: vault_key,
+            commitment: self.commitment.key(),
+            leaf_index,
+            challenger: self.agent.key(),
+            slash_bps: terms.slash_bps,
+            claimants,
+            insurance_bps: terms.insurance_bps,
+            burn_bps: terms.burn_bps,
+            status: ChallengeStatus::Open,
+            issued_at: clock.unix_timestamp,
+            deadline: clock
+                .unix_timestamp
+                .checked_add(CHALLENGE_RESPONSE_WINDOW)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?,
+            answered_at: 0,
+            slash_request: Pubkey::default(),
+            bump: bumps.challenge,
+        });
+        Ok(())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ChallengeSlashTerms {
+    /// Of the capital left after earlier slashes, at most the vault's max_slash_bps.
+    /// All challenge slashes of a vault together stay within max_slash_bps
+    pub slash_bps: u16,
+
+    // Split of the slash, claimant shares plus insurance and burn must sum
+    // to 10000 BPS
+    pub claimants: Vec<SlashShare>,
+    pub insurance_bps: u16,
+    pub burn_bps: u16,
+}
@@ -0,0 +1,79 @@
+use crate::constants::BASE_BPS;
+use crate::errors::*;
+use crate::state::{Attester, AuthorityConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(key: Pubkey)]
+pub struct RegisterAttester<'info> {
+    /// Program administrator, pays for the attester account
+    #[account(
+        mut,
+        address = config.admin @ SignerError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"Attester", key.as_ref()],
+        space = Attester::INIT_SPACE + 8,
+        bump,
+    )]
+    pub attester: Account<'info, Attester>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterAttester<'info> {
+    pub fn register_attester(
+        &mut self,
+        key: Pubkey,
+        min_uptime_bps: u16,
+        bumps: &RegisterAttesterBumps,
+    ) -> Result<()> {
+        require_keys_neq!(key, Pubkey::default(), SignerError::InvalidAddress);
+        require_gte!(BASE_BPS, min_uptime_bps, VaultError::InvalidBasisPoints);
+
+        self.attester.set_inner(Attester {
+            key,
+            min_uptime_bps,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump: bumps.attester,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveAttester<'info> {
+    /// Program administrator, receives the attester account rent
+    #[account(
+        mut,
+        address = config.admin @ SignerError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// Attestations it already signed stay valid
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"Attester", attester.key.as_ref()],
+        bump = attester.bump,
+    )]
+    pub attester: Account<'info, Attester>,
+}
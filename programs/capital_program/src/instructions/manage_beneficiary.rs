@@ -0,0 +1,367 @@
+use crate::constants::MAX_REWARD_STREAMS;
+use crate::errors::*;
+use crate::reward_streams::RewardStreamAccounts;
+use crate::state::{AuthorityConfig, BeneficiaryAccount, Vault, VaultPhase};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenInterface;
+
+/// Beneficiaries can change until the vault is closed or failed
+fn validate_change(vault: &mut Account<Vault>, config: &AuthorityConfig) -> Result<()> {
+    vault.require_not_paused(config)?;
+    vault.require_not_swept()?;
+
+    let vault_key = vault.key();
+    vault.sync_phase(vault_key)?;
+    vault.require_phase(&[
+        VaultPhase::Formation,
+        VaultPhase::Active,
+        VaultPhase::Disputed,
+        VaultPhase::Matured,
+    ])?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddBeneficiary<'info> {
+    /// The node operator of the vault, pays for the beneficiary account
+    #[account(
+        mut,
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    /// The beneficiary being added, co-signs the change
+    pub beneficiary: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = node_operator,
+        seeds = [b"Beneficiary", vault.key().as_ref(), beneficiary.key().as_ref()],
+        space = BeneficiaryAccount::INIT_SPACE + 8,
+        bump,
+    )]
+    pub beneficiary_account: Account<'info, BeneficiaryAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddBeneficiary<'info> {
+    /// Gives the beneficiary `share_bps` out of the unallocated basis points,
+    /// optionally vesting everything the share earns
+    pub fn add_beneficiary(
+        &mut self,
+        share_bps: u16,
+        vesting: Option<BeneficiaryVesting>,
+        bumps: &AddBeneficiaryBumps,
+    ) -> Result<()> {
+        validate_change(&mut self.vault, &self.config)?;
+        require_gt!(share_bps, 0, VaultError::BeneficiaryShareMustBePositive);
+
+        let now = Clock::get()?.unix_timestamp;
+        let (cliff_at, vesting_end_at) = match vesting {
+            Some(vesting) => {
+                require_gt!(
+                    vesting.vesting_end_at,
+                    now,
+                    VaultError::InvalidVestingSchedule
+                );
+                require_gte!(
+                    vesting.vesting_end_at,
+                    vesting.cliff_at,
+                    VaultError::InvalidVestingSchedule
+                );
+                (vesting.cliff_at, vesting.vesting_end_at)
+            }
+            None => (0, 0),
+        };
+
+        self.vault.reallocate_beneficiary_bps(0, share_bps)?;
+
+        self.beneficiary_account.set_inner(BeneficiaryAccount {
+            vault: self.vault.key(),
+            address: self.beneficiary.key(),
+            share_bps,
+            reward_debts: [0; MAX_REWARD_STREAMS],
+            accrued: [0; MAX_REWARD_STREAMS],
+            total_claimed: [0; MAX_REWARD_STREAMS],
+            vesting_start_at: now,
+            cliff_at,
+            vesting_end_at,
+            bump: bumps.beneficiary_account,
+        });
+        // Only rewards deposited from now on count towards the new share
+        self.beneficiary_account
+            .rebase_reward_debts(&self.vault.reward_streams)?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ResizeBeneficiary<'info> {
+    /// The node operator of the vault
+    #[account(
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    /// The beneficiary being resized, co-signs the change
+    pub beneficiary: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"Beneficiary", vault.key().as_ref(), beneficiary.key().as_ref()],
+        bump = beneficiary_account.bump,
+    )]
+    pub beneficiary_account: Account<'info, BeneficiaryAccount>,
+}
+
+impl<'info> ResizeBeneficiary<'info> {
+    /// Changes the share, settling what accrued under the old one.
+    /// Returns the old share
+    pub fn resize_beneficiary(&mut self, share_bps: u16) -> Result<u16> {
+        validate_change(&mut self.vault, &self.config)?;
+        require_gt!(share_bps, 0, VaultError::BeneficiaryShareMustBePositive);
+
+        let old_share_bps = self.beneficiary_account.share_bps;
+        self.vault
+            .reallocate_beneficiary_bps(old_share_bps, share_bps)?;
+
+        let streams = &self.vault.reward_streams;
+        self.beneficiary_account.settle(streams)?;
+        self.beneficiary_account.share_bps = share_bps;
+        self.beneficiary_account.rebase_reward_debts(streams)?;
+        Ok(old_share_bps)
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveBeneficiary<'info> {
+    /// The node operator of the vault, receives the beneficiary account rent
+    #[account(
+        mut,
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    /// The beneficiary being removed, co-signs the change
+    pub beneficiary: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        close = node_operator,
+        seeds = [b"Beneficiary", vault.key().as_ref(), beneficiary.key().as_ref()],
+        bump = beneficiary_account.bump,
+    )]
+    pub beneficiary_account: Account<'info, BeneficiaryAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RemoveBeneficiary<'info> {
+    /// Pays out everything the beneficiary vested and releases its share,
+    /// rewards still vesting are forfeited to the operator commission
+    ///
+    /// The reward accounts of every stream with vested rewards are passed in
+    /// `remaining_accounts` as `[mint, vault_ata, beneficiary_ata]` triples.
+    /// Returns the removed share and what was paid or forfeited per reward stream
+    pub fn remove_beneficiary(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<(u16, Vec<BeneficiaryPayout>)> {
+        validate_change(&mut self.vault, &self.config)?;
+
+        let streams = RewardStreamAccounts::parse_remaining(
+            &self.vault,
+            self.beneficiary.key(),
+            remaining_accounts,
+        )?;
+
+        self.beneficiary_account
+            .settle(&self.vault.reward_streams)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut payouts = Vec::with_capacity(self.vault.reward_streams.len());
+        for stream_index in 0..self.vault.reward_streams.len() {
+            let stream = streams
+                .iter()
+                .find(|stream| stream.stream_index == stream_index);
+            let mut amount = 0;
+            if let Some(stream) = stream {
+                (amount, _) = self.beneficiary_account.take_vested(stream_index, now)?;
+                if amount > 0 {
+                    let reward_stream = &mut self.vault.reward_streams[stream_index];
+                    reward_stream.total_claimed = reward_stream
+                        .total_claimed
+                        .checked_add(amount)
+                        .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+                    stream.transfer_from_vault(&self.vault, &self.token_program, amount)?;
+                }
+            }
+
+            // Nothing vested may be lost with the account
+            let (claimable, unvested) = self.beneficiary_account.claimable(stream_index, now)?;
+            require_eq!(claimable, 0, VaultError::BeneficiaryHasAccruedRewards);
+
+            // The share was carved out of the operator commission, so is what it forfeits
+            let reward_stream = &mut self.vault.reward_streams[stream_index];
+            reward_stream.operator_accrued = reward_stream
+                .operator_accrued
+                .checked_add(unvested)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+            if amount > 0 || unvested > 0 {
+                payouts.push(BeneficiaryPayout {
+                    reward_mint: reward_stream.mint,
+                    amount,
+                    unvested_amount: unvested,
+                });
+            }
+        }
+
+        let share_bps = self.beneficiary_account.share_bps;
+        self.vault.reallocate_beneficiary_bps(share_bps, 0)?;
+        Ok((share_bps, payouts))
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(new_address: Pubkey)]
+pub struct TransferBeneficiary<'info> {
+    /// The current beneficiary handing over its share, pays for the new account
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        close = beneficiary,
+        seeds = [b"Beneficiary", vault.key().as_ref(), beneficiary.key().as_ref()],
+        bump = beneficiary_account.bump,
+    )]
+    pub beneficiary_account: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        init,
+        payer = beneficiary,
+        seeds = [b"Beneficiary", vault.key().as_ref(), new_address.as_ref()],
+        space = BeneficiaryAccount::INIT_SPACE + 8,
+        bump,
+    )]
+    pub new_beneficiary_account: Account<'info, BeneficiaryAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransferBeneficiary<'info> {
+    /// Moves the share, including rewards accrued but not claimed, to `new_address`
+    pub fn transfer_beneficiary(
+        &mut self,
+        new_address: Pubkey,
+        bumps: &TransferBeneficiaryBumps,
+    ) -> Result<()> {
+        validate_change(&mut self.vault, &self.config)?;
+        require_keys_neq!(new_address, Pubkey::default(), SignerError::InvalidAddress);
+
+        let old = &self.beneficiary_account;
+        self.new_beneficiary_account.set_inner(BeneficiaryAccount {
+            vault: old.vault,
+            address: new_address,
+            share_bps: old.share_bps,
+            reward_debts: old.reward_debts,
+            accrued: old.accrued,
+            total_claimed: old.total_claimed,
+            vesting_start_at: old.vesting_start_at,
+            cliff_at: old.cliff_at,
+            vesting_end_at: old.vesting_end_at,
+            bump: bumps.new_beneficiary_account,
+        });
+        Ok(())
+    }
+}
+
+pub struct BeneficiaryPayout {
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    // Still vesting, forfeited to the operator commission on removal
+    pub unvested_amount: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct BeneficiaryVesting {
+    // Nothing is claimable before the cliff
+    pub cliff_at: i64,
+    // Earnings vest linearly from the time the beneficiary is added until here
+    pub vesting_end_at: i64,
+}
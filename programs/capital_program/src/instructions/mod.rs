@@ -1,23 +1,73 @@
+pub mod add_reward_stream;
+pub mod advance_phase;
+pub mod appeal_slash_req;
+pub mod challenge_missed_epoch;
 pub mod claim_beneficiary_rewards;
 pub mod claim_investor_rewards;
+pub mod claim_operator_commission;
+pub mod claim_slash_payout;
 pub mod close_position;
 pub mod close_vault;
+pub mod commit_epoch;
 pub mod create_slash_req;
 pub mod create_vault;
+pub mod deposit_insurance;
 pub mod deposit_rewards;
+pub mod dismiss_slash_req;
+pub mod donate_rewards;
+pub mod early_unlock_position;
 pub mod finalize_slash_req;
+pub mod init_fee_accounts;
 pub mod init_program;
+pub mod issue_challenge;
+pub mod manage_attester;
+pub mod manage_beneficiary;
 pub mod open_position;
+pub mod respond_challenge;
+pub mod set_pause;
+pub mod slash_failed_challenge;
+pub mod submit_attestation;
+pub mod sweep_vault;
 pub mod update_position;
+pub mod update_program;
+pub mod update_reward_distributor;
+pub mod vote_slash;
+pub mod withdraw_insurance;
+pub mod withdraw_treasury;
 
+pub use add_reward_stream::*;
+pub use advance_phase::*;
+pub use appeal_slash_req::*;
+pub use challenge_missed_epoch::*;
 pub use claim_beneficiary_rewards::*;
 pub use claim_investor_rewards::*;
+pub use claim_operator_commission::*;
+pub use claim_slash_payout::*;
 pub use close_position::*;
 pub use close_vault::*;
+pub use commit_epoch::*;
 pub use create_slash_req::*;
 pub use create_vault::*;
+pub use deposit_insurance::*;
 pub use deposit_rewards::*;
+pub use dismiss_slash_req::*;
+pub use donate_rewards::*;
+pub use early_unlock_position::*;
 pub use finalize_slash_req::*;
+pub use init_fee_accounts::*;
 pub use init_program::*;
+pub use issue_challenge::*;
+pub use manage_attester::*;
+pub use manage_beneficiary::*;
 pub use open_position::*;
+pub use respond_challenge::*;
+pub use set_pause::*;
+pub use slash_failed_challenge::*;
+pub use submit_attestation::*;
+pub use sweep_vault::*;
 pub use update_position::*;
+pub use update_program::*;
+pub use update_reward_distributor::*;
+pub use vote_slash::*;
+pub use withdraw_insurance::*;
+pub use withdraw_treasury::*;
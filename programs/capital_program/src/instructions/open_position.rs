@@ -4,6 +4,7 @@ use anchor_spl::{
     token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
+use crate::constants::MAX_REWARD_STREAMS;
 use crate::errors::*;
 use crate::state::*;
 
@@ -32,9 +33,12 @@ pub struct OpenPosition<'info> {
     /// The vault where capital will be locked
     #[account(
         mut,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute
     )]
     pub vault: Account<'info, Vault>,
 
@@ -98,7 +102,9 @@ pub struct OpenPosition<'info> {
 
 impl<'info> OpenPosition<'info> {
     /// Validates position opening parameters
-    fn validate_position(&self, amount: u64) -> Result<()> {
+    fn validate_position(&mut self, amount: u64) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+
         // Validate amount meets minimum
         require_gte!(
             amount,
@@ -133,10 +139,11 @@ impl<'info> OpenPosition<'info> {
             return err!(VaultError::VaultMaxCapReached);
         }
 
-        // Validate timing constraints
-        let clock = Clock::get()?;
+        // Deposits are only accepted while the vault is forming
+        let vault_key = self.vault.key();
+        let phase = self.vault.sync_phase(vault_key)?;
         require!(
-            clock.unix_timestamp < self.vault.lock_phase_start_at,
+            phase == VaultPhase::Formation,
             PhaseError::LockPhaseAlreadyStarted
         );
 
@@ -150,10 +157,12 @@ impl<'info> OpenPosition<'info> {
         self.position.set_inner(Position {
             vault: self.vault.key(),
             total_value_locked: amount,
-            total_rewards_claimed: 0,
+            rewards_claimed: [0; MAX_REWARD_STREAMS],
+            reward_debts: [0; MAX_REWARD_STREAMS],
             asset: self.asset.key(),
             bump: bumps.position,
         });
+        self.position.rebase_reward_debts(&self.vault)?;
 
         Ok(())
     }
@@ -207,10 +216,7 @@ impl<'info> OpenPosition<'info> {
 
         // Create dynamic NFT metadata based on position
         let args = CreateAssetArgs {
-            name: format!(
-                "Vault Position #{}",
-                self.position.key().to_string()[..8].to_string()
-            ),
+            name: format!("Vault Position #{}", &self.position.key().to_string()[..8]),
             uri: format!("https://api.vault.com/position/{}", self.position.key()),
         };
 
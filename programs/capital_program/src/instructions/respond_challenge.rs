@@ -0,0 +1,70 @@
+use crate::errors::*;
+use crate::merkle;
+use crate::state::{Challenge, ChallengeStatus, EpochCommitment, Vault};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RespondChallenge<'info> {
+    /// The node operator of the vault
+    #[account(
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(address = challenge.commitment)]
+    pub commitment: Account<'info, EpochCommitment>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Challenge",
+            commitment.key().as_ref(),
+            challenge.leaf_index.to_le_bytes().as_ref()
+        ],
+        bump = challenge.bump,
+        constraint = challenge.vault == vault.key() @ ChallengeError::ChallengeVaultMismatch
+    )]
+    pub challenge: Account<'info, Challenge>,
+}
+
+impl<'info> RespondChallenge<'info> {
+    /// Checks the Merkle path of the challenged leaf against the committed root.
+    /// An invalid proof fails the challenge for good, returns whether it was valid
+    pub fn respond(&mut self, leaf: [u8; 32], proof: Vec<[u8; 32]>) -> Result<bool> {
+        require!(
+            self.challenge.status == ChallengeStatus::Open,
+            ChallengeError::ChallengeNotOpen
+        );
+        let clock = Clock::get()?;
+        require_gt!(
+            self.challenge.deadline,
+            clock.unix_timestamp,
+            ChallengeError::ResponseWindowClosed
+        );
+
+        let valid = merkle::verify_proof(
+            &self.commitment.merkle_root,
+            &leaf,
+            self.challenge.leaf_index,
+            self.commitment.leaf_count,
+            &proof,
+        );
+        self.challenge.status = if valid {
+            ChallengeStatus::Answered
+        } else {
+            ChallengeStatus::Failed
+        };
+        self.challenge.answered_at = clock.unix_timestamp;
+        Ok(valid)
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use nft_program::cpi::accounts::SetMarketplacePause;
+use nft_program::program::NftProgram;
+use nft_program::state::NFTConfig;
+
+use crate::errors::*;
+use crate::state::{AuthorityConfig, Vault};
+
+#[derive(Accounts)]
+pub struct SetProtocolPause<'info> {
+    /// Admin, or the guardian when pausing
+    pub authority: Signer<'info>,
+
+    /// Global authority configuration account
+    #[account(
+        mut,
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// NFT program configuration, the marketplace is paused along with the protocol
+    #[account(
+        mut,
+        owner = nft_program.key() @ NFTProgramError::InvalidNFTConfigOwner
+    )]
+    pub nft_config: Account<'info, NFTConfig>,
+
+    #[account(
+        constraint = nft_program.key() == nft_program::ID @ NFTProgramError::InvalidNFTProgram
+    )]
+    pub nft_program: Program<'info, NftProgram>,
+}
+
+impl<'info> SetProtocolPause<'info> {
+    /// Pauses or unpauses every vault and the position marketplace
+    pub fn set_pause(&mut self, paused: bool) -> Result<()> {
+        self.config
+            .require_pause_authority(self.authority.key(), paused)?;
+
+        self.config.paused = paused;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"Config", &[self.config.bump]]];
+
+        let cpi_accounts = SetMarketplacePause {
+            authority: self.config.to_account_info(),
+            config: self.nft_config.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.nft_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+
+        nft_program::cpi::set_marketplace_pause_handler(cpi_ctx, paused)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetVaultPause<'info> {
+    /// Admin, or the guardian when pausing
+    pub authority: Signer<'info>,
+
+    /// Global authority configuration account
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> SetVaultPause<'info> {
+    pub fn set_pause(&mut self, paused: bool) -> Result<()> {
+        self.config
+            .require_pause_authority(self.authority.key(), paused)?;
+
+        self.vault.paused = paused;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::reward_math;
+use crate::slashing::SlashAccounts;
+use crate::state::{
+    AuthorityConfig, Challenge, ChallengeStatus, InsuranceFund, SlashRequest, SlashStatus, Vault,
+    VaultPhase,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct SlashFailedChallenge<'info> {
+    /// Anyone, pays for the slash request and its escrow account
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [
+            b"Challenge",
+            challenge.commitment.as_ref(),
+            challenge.leaf_index.to_le_bytes().as_ref()
+        ],
+        bump = challenge.bump,
+        constraint = challenge.vault == vault.key() @ ChallengeError::ChallengeVaultMismatch
+    )]
+    pub challenge: Account<'info, Challenge>,
+    /// Executed right away, kept as the record of the slash
+    #[account(
+        init,
+        payer = caller,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            vault.slash_request_count.to_le_bytes().as_ref()
+        ],
+        space = SlashRequest::INIT_SPACE + 8,
+        bump,
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+    /// Locking token mint
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        address = vault.locking_token_mint @ TokenError::InvalidLockingMint
+    )]
+    pub locking_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's token account
+    #[account(
+        mut,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Holds the claimants' part of the slash until each of them claims it
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = slash_request,
+        associated_token::token_program = token_program
+    )]
+    pub slash_escrow_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Insurance fund of the locking token, required when part of the slash goes there
+    #[account(
+        mut,
+        seeds = [b"Insurance", locking_token_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SlashFailedChallenge<'info> {
+    /// Executes the slash fixed in a failed or unanswered challenge, no agent or
+    /// arbiter decision involved
+    pub fn slash(&mut self, bumps: &SlashFailedChallengeBumps) -> Result<()> {
+        self.vault.require_not_paused(&self.config)?;
+        let clock = Clock::get()?;
+        require!(
+            self.challenge.is_failed(clock.unix_timestamp),
+            ChallengeError::ChallengeNotFailed
+        );
+
+        // Slashing is only possible while capital is locked and no other slash is open
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault.require_phase(&[VaultPhase::Active])?;
+        // Taken from what is left after earlier slashes, all challenge slashes
+        // together stay within max_slash_bps
+        let slash_amount =
+            reward_math::bps_share(self.vault.capital_after_slashing, self.challenge.slash_bps)?
+                .min(self.vault.challenge_slash_allowance()?);
+        require_gt!(slash_amount, 0, ChallengeError::ChallengeSlashLimitReached);
+        self.vault.challenge_slashed = self
+            .vault
+            .challenge_slashed
+            .checked_add(slash_amount)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        self.vault.transition_to(vault_key, VaultPhase::Disputed)?;
+
+        self.slash_request.set_inner(SlashRequest {
+            vault: vault_key,
+            sequence: self.vault.slash_request_count,
+            requester: self.challenge.challenger,
+            claimants: self.challenge.claimants.clone(),
+            insurance_bps: self.challenge.insurance_bps,
+            burn_bps: self.challenge.burn_bps,
+            slash_bps: self.challenge.slash_bps,
+            requested_amount: slash_amount,
+            evidence_hash: [0; 32],
+            evidence_uri: String::new(),
+            attestation: Pubkey::default(),
+            challenge: self.challenge.key(),
+            status: SlashStatus::Pending,
+            appeal_evidence_hash: [0; 32],
+            resolver: Pubkey::default(),
+            executed_amount: 0,
+            bond_slashed: 0,
+            insurance_amount: 0,
+            burned_amount: 0,
+            approvals: Vec::new(),
+            rejections: Vec::new(),
+            created_at: clock.unix_timestamp,
+            appealed_at: 0,
+            resolved_at: 0,
+            bump: bumps.slash_request,
+        });
+        self.vault.slash_request_count = self
+            .vault
+            .slash_request_count
+            .checked_add(1)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+        self.challenge.status = ChallengeStatus::Slashed;
+        self.challenge.slash_request = self.slash_request.key();
+
+        let caller = self.caller.key();
+        self.slash_accounts().resolve(Some(slash_amount), caller)
+    }
+
+    fn slash_accounts(&mut self) -> SlashAccounts<'_, 'info> {
+        SlashAccounts {
+            vault: &mut self.vault,
+            slash_request: &mut self.slash_request,
+            locking_token_mint: &self.locking_token_mint,
+            vault_token_ata: &self.vault_token_ata,
+            slash_escrow_ata: &self.slash_escrow_ata,
+            insurance_fund: self.insurance_fund.as_mut(),
+            insurance_fund_ata: self.insurance_fund_ata.as_ref(),
+            token_program: &self.token_program,
+        }
+    }
+}
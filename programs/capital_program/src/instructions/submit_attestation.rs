@@ -0,0 +1,141 @@
+use crate::constants::BASE_BPS;
+use crate::errors::*;
+use crate::state::{Attestation, Attester, Vault};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+// Layout of the ed25519 program instruction data, see solana_sdk::ed25519_instruction
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SubmitAttestation<'info> {
+    /// Anyone can relay a signed report, pays for the attestation account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"Attester", attester.key.as_ref()],
+        bump = attester.bump,
+    )]
+    pub attester: Account<'info, Attester>,
+
+    #[account(
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            b"Attestation",
+            vault.key().as_ref(),
+            attester.key.as_ref(),
+            epoch.to_le_bytes().as_ref()
+        ],
+        space = Attestation::INIT_SPACE + 8,
+        bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: Instructions sysvar, checked by address
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SubmitAttestation<'info> {
+    /// Stores the report once the ed25519 program verified the attester's
+    /// signature over it in the instruction right before this one
+    pub fn submit_attestation(
+        &mut self,
+        epoch: u64,
+        uptime_bps: u16,
+        work_units: u64,
+        bumps: &SubmitAttestationBumps,
+    ) -> Result<()> {
+        require_gte!(BASE_BPS, uptime_bps, VaultError::InvalidBasisPoints);
+
+        let vault = self.vault.key();
+        let message = Attestation::message(&vault, epoch, uptime_bps, work_units);
+        self.verify_signature(&message)?;
+
+        self.attestation.set_inner(Attestation {
+            vault,
+            attester: self.attester.key,
+            epoch,
+            uptime_bps,
+            work_units,
+            failed: uptime_bps < self.attester.min_uptime_bps,
+            slash_request: Pubkey::default(),
+            submitted_at: Clock::get()?.unix_timestamp,
+            bump: bumps.attestation,
+        });
+        Ok(())
+    }
+
+    /// Checks the previous instruction verifies exactly one signature of the
+    /// attester over `message`, with all data inside that instruction
+    fn verify_signature(&self, message: &[u8]) -> Result<()> {
+        let instructions = self.instructions.to_account_info();
+        let current_index = load_current_index_checked(&instructions)?;
+        require_gt!(
+            current_index,
+            0,
+            AttestationError::InvalidSignatureInstruction
+        );
+        let ix = load_instruction_at_checked((current_index - 1) as usize, &instructions)?;
+        require_keys_eq!(
+            ix.program_id,
+            ed25519_program::ID,
+            AttestationError::InvalidSignatureInstruction
+        );
+        require!(
+            ix.accounts.is_empty(),
+            AttestationError::InvalidSignatureInstruction
+        );
+
+        let data = &ix.data;
+        require!(
+            data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN && data[0] == 1,
+            AttestationError::InvalidSignatureInstruction
+        );
+        let offsets =
+            &data[SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN];
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+        let public_key_offset = read_u16(4) as usize;
+        let message_offset = read_u16(8) as usize;
+        let message_size = read_u16(10) as usize;
+        // u16::MAX points the ed25519 program at its own instruction data,
+        // which it already checked the signature against
+        require!(
+            read_u16(2) == u16::MAX && read_u16(6) == u16::MAX && read_u16(12) == u16::MAX,
+            AttestationError::InvalidSignatureInstruction
+        );
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + PUBKEY_LEN)
+            .ok_or(AttestationError::InvalidSignatureInstruction)?;
+        let signed_message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(AttestationError::InvalidSignatureInstruction)?;
+
+        require!(
+            public_key == self.attester.key.as_ref() && signed_message == message,
+            AttestationError::SignatureMismatch
+        );
+        Ok(())
+    }
+}
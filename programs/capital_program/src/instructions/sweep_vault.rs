@@ -0,0 +1,182 @@
+use crate::{errors::*, reward_streams::RewardStreamAccounts, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+#[derive(Accounts)]
+pub struct SweepVault<'info> {
+    /// The admin or the vault's node operator, pays for the treasury token accounts
+    #[account(
+        mut,
+        constraint = authority.key() == config.admin
+            || authority.key() == vault.node_operator @ SignerError::UnauthorizedSweeper
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Protocol treasury PDA, only owns the token accounts receiving the sweep
+    #[account(address = config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Mint of the first reward stream, further streams go in remaining accounts
+    #[account(
+        mint::token_program = token_program,
+        address = vault.reward_streams[0].mint @ TokenError::InvalidRewardMint
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = reward_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_reward_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mint::token_program = token_program,
+        address = vault.locking_token_mint @ TokenError::InvalidLockingMint
+    )]
+    pub lock_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = lock_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_lock_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = lock_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_lock_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SweepVault<'info> {
+    /// Residual balances can be swept from matured or failed vaults once the
+    /// grace period is over
+    pub fn validate_sweep(&mut self) -> Result<()> {
+        let vault_key = self.vault.key();
+        self.vault.sync_phase(vault_key)?;
+        self.vault
+            .require_phase(&[VaultPhase::Matured, VaultPhase::Failed])?;
+
+        let now = Clock::get()?.unix_timestamp;
+        require_gte!(
+            now,
+            self.vault.sweep_available_at()?,
+            PhaseError::SweepGracePeriodActive
+        );
+
+        // Unclaimed rewards are forfeited, reward claims fail with VaultSwept from now on
+        self.vault.swept = true;
+        Ok(())
+    }
+
+    /// The first reward stream followed by the ones passed in `remaining_accounts`
+    /// as `[mint, vault_ata, treasury_ata]` triples
+    pub fn reward_streams(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<RewardStreamAccounts<'info>>> {
+        let mut streams = vec![RewardStreamAccounts::new(
+            &self.vault,
+            &self.reward_mint,
+            &self.vault_reward_ata,
+            &self.treasury_reward_ata,
+        )?];
+        streams.extend(RewardStreamAccounts::parse_remaining(
+            &self.vault,
+            self.treasury.key(),
+            remaining_accounts,
+        )?);
+        Ok(streams)
+    }
+
+    /// Moves the whole reward balance of one stream to the treasury.
+    /// Returns (swept, rounding remainder)
+    pub fn sweep_stream(&mut self, stream: &RewardStreamAccounts<'info>) -> Result<(u64, u64)> {
+        let amount = stream.vault_ata.amount;
+        let reward_stream = &mut self.vault.reward_streams[stream.stream_index];
+        let rounding_remainder = reward_stream.rounding_remainder;
+        reward_stream.rounding_remainder = 0;
+        if amount == 0 {
+            return Ok((0, rounding_remainder));
+        }
+
+        stream.transfer_from_vault(&self.vault, &self.token_program, amount)?;
+        Ok((amount, rounding_remainder))
+    }
+
+    /// Moves the lock tokens nobody is owed to the treasury, returns the amount.
+    /// The principal of open positions stays claimable and the operator bond
+    /// stays in the vault until it is closed
+    pub fn sweep_lock_tokens(&self) -> Result<u64> {
+        let amount = self.vault.lock_residue(self.vault_lock_ata.amount);
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let node_operator_key = self.vault.node_operator.key();
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Vault",
+            node_operator_key.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.vault_lock_ata.to_account_info(),
+            to: self.treasury_lock_ata.to_account_info(),
+            authority: self.vault.to_account_info(),
+            mint: self.lock_mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, amount, self.lock_mint.decimals)?;
+        Ok(amount)
+    }
+}
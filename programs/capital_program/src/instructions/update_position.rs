@@ -1,6 +1,6 @@
 use crate::{
     errors::*,
-    state::{AuthorityConfig, Position, Vault},
+    state::{AuthorityConfig, Position, Vault, VaultPhase},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
@@ -21,9 +21,12 @@ pub struct UpdatePosition<'info> {
     /// The vault containing this position
     #[account(
         mut,
-        seeds = [b"Vault", vault.node_operator.key().as_ref()],
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
         bump = vault.bump,
-        constraint = !vault.is_dispute_active @ VaultError::VaultUnderDispute
     )]
     pub vault: Account<'info, Vault>,
 
@@ -83,15 +86,18 @@ pub struct UpdatePosition<'info> {
 impl<'info> UpdatePosition<'info> {
     /// Validates and processes position update (deposit or withdrawal)
     pub fn process_update(&mut self, update_amount: i64) -> Result<()> {
-        let clock = Clock::get()?;
-
-        // Validate timing - can't update during lock phase
+        // Validate timing - can't update once the vault leaves formation
+        let vault_key = self.vault.key();
+        let phase = self.vault.sync_phase(vault_key)?;
         require!(
-            clock.unix_timestamp < self.vault.lock_phase_start_at,
+            phase == VaultPhase::Formation,
             PhaseError::LockPhaseAlreadyStarted
         );
 
         if update_amount > 0 {
+            // Formation withdrawals stay open as a safe exit while paused
+            self.vault.require_not_paused(&self.config)?;
+
             // Deposit additional capital
             self.process_deposit(update_amount as u64)?;
         } else if update_amount < 0 {
@@ -101,6 +107,9 @@ impl<'info> UpdatePosition<'info> {
             return err!(ArithmeticError::UpdateAmountCannotBeZero);
         }
 
+        // No rewards accrue during formation, re-base the debt on the new capital
+        self.position.rebase_reward_debts(&self.vault)?;
+
         Ok(())
     }
 
@@ -209,8 +218,13 @@ impl<'info> UpdatePosition<'info> {
     /// Transfers tokens from vault to provider
     fn transfer_from_vault(&self, amount: u64) -> Result<()> {
         let node_operator_key = self.vault.node_operator.key();
-        let signer_seeds: &[&[&[u8]]] =
-            &[&[b"Vault", node_operator_key.as_ref(), &[self.vault.bump]]];
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Vault",
+            node_operator_key.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ]];
 
         let transfer_accounts = TransferChecked {
             from: self.vault_token_ata.to_account_info(),
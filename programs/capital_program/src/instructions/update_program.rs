@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::*;
+use crate::state::AuthorityConfig;
+
+#[derive(Accounts)]
+pub struct UpdateProgram<'info> {
+    /// Current program administrator
+    #[account(
+        address = config.admin @ SignerError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global authority configuration account
+    #[account(
+        mut,
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+}
+
+impl<'info> UpdateProgram<'info> {
+    /// First step of the admin transfer, the new admin has to accept it
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        require_keys_neq!(new_admin, Pubkey::default(), SignerError::InvalidAddress);
+
+        self.config.pending_admin = new_admin;
+
+        Ok(())
+    }
+
+    /// Rotates the agent, returns the previous agent
+    pub fn update_agent(&mut self, new_agent: Pubkey) -> Result<Pubkey> {
+        require_keys_neq!(new_agent, Pubkey::default(), SignerError::InvalidAddress);
+
+        let old_agent = self.config.agent;
+        self.config.agent = new_agent;
+
+        Ok(old_agent)
+    }
+
+    /// Sets the guardian allowed to pause, default removes it. Returns the previous guardian
+    pub fn update_guardian(&mut self, new_guardian: Pubkey) -> Result<Pubkey> {
+        let old_guardian = self.config.guardian;
+        self.config.guardian = new_guardian;
+
+        Ok(old_guardian)
+    }
+
+    /// Replaces the slash arbiter committee, an empty set hands decisions back
+    /// to the agent and admin
+    pub fn update_arbiters(&mut self, arbiters: &[Pubkey], threshold: u8) -> Result<()> {
+        require_gte!(MAX_ARBITERS, arbiters.len(), SignerError::InvalidArbiterSet);
+        if arbiters.is_empty() {
+            require_eq!(threshold, 0, SignerError::InvalidArbiterSet);
+        } else {
+            require!(
+                threshold > 0 && threshold as usize <= arbiters.len(),
+                SignerError::InvalidArbiterSet
+            );
+        }
+        for (index, arbiter) in arbiters.iter().enumerate() {
+            require_keys_neq!(*arbiter, Pubkey::default(), SignerError::InvalidArbiterSet);
+            require!(
+                !arbiters[..index].contains(arbiter),
+                SignerError::InvalidArbiterSet
+            );
+        }
+
+        let mut arbiter_set = [Pubkey::default(); MAX_ARBITERS];
+        arbiter_set[..arbiters.len()].copy_from_slice(arbiters);
+        self.config.arbiters = arbiter_set;
+        self.config.arbiter_count = arbiters.len() as u8;
+        self.config.arbiter_threshold = threshold;
+
+        Ok(())
+    }
+
+    /// Updates fees and lock duration bounds, returns the previous values
+    pub fn update_config(&mut self, params: &UpdateProgramConfig) -> Result<UpdateProgramConfig> {
+        // Early unlock fee is charged in bps of the returned principal
+        require_gte!(
+            BASE_BPS as u64,
+            params.early_unlock_fee,
+            VaultError::InvalidBasisPoints
+        );
+
+        // Validate reasonable duration range
+        let duration_range = params
+            .max_lock_duration
+            .checked_sub(params.min_lock_duration)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+
+        require_gte!(
+            duration_range,
+            MIN_LOCK_PERIOD,
+            VaultError::LockDurationRangeTooNarrow
+        );
+
+        require_gte!(
+            BASE_BPS,
+            params.protocol_fee_bps,
+            VaultError::InvalidBasisPoints
+        );
+        require_gte!(
+            BASE_BPS,
+            params.min_operator_bond_bps,
+            VaultError::InvalidBasisPoints
+        );
+        require_gte!(
+            BASE_BPS,
+            params.insurance_fee_bps,
+            VaultError::InvalidBasisPoints
+        );
+        require_gte!(
+            BASE_BPS,
+            params.insurance_coverage_bps,
+            VaultError::InvalidBasisPoints
+        );
+
+        let old_config = UpdateProgramConfig {
+            early_unlock_fee: self.config.early_unlock_fee,
+            protocol_fee_bps: self.config.protocol_fee_bps,
+            min_operator_bond_bps: self.config.min_operator_bond_bps,
+            insurance_fee_bps: self.config.insurance_fee_bps,
+            insurance_coverage_bps: self.config.insurance_coverage_bps,
+            min_lock_duration: self.config.min_lock_duration,
+            max_lock_duration: self.config.max_lock_duration,
+        };
+
+        self.config.early_unlock_fee = params.early_unlock_fee;
+        self.config.protocol_fee_bps = params.protocol_fee_bps;
+        self.config.min_operator_bond_bps = params.min_operator_bond_bps;
+        self.config.insurance_fee_bps = params.insurance_fee_bps;
+        self.config.insurance_coverage_bps = params.insurance_coverage_bps;
+        self.config.min_lock_duration = params.min_lock_duration;
+        self.config.max_lock_duration = params.max_lock_duration;
+
+        Ok(old_config)
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Admin proposed by the current admin
+    #[account(
+        address = config.pending_admin @ SignerError::UnauthorizedPendingAdmin
+    )]
+    pub pending_admin: Signer<'info>,
+
+    /// Global authority configuration account
+    #[account(
+        mut,
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    /// Completes the admin transfer, returns the previous admin
+    pub fn accept_admin(&mut self) -> Result<Pubkey> {
+        let old_admin = self.config.admin;
+        self.config.admin = self.pending_admin.key();
+        self.config.pending_admin = Pubkey::default();
+
+        Ok(old_admin)
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct UpdateProgramConfig {
+    /// Fee charged on principal for early unlock (in basis points)
+    pub early_unlock_fee: u64,
+
+    /// Cut of every reward deposit sent to the treasury (in basis points)
+    pub protocol_fee_bps: u16,
+
+    /// Minimum operator bond posted at vault creation (in basis points of max_cap)
+    pub min_operator_bond_bps: u16,
+
+    /// Part of the protocol fee routed to the insurance fund (in basis points of the fee)
+    pub insurance_fee_bps: u16,
+
+    /// Slashed principal the insurance fund covers (in basis points of a vault's capital)
+    pub insurance_coverage_bps: u16,
+
+    /// Minimum allowed lock duration (in seconds)
+    pub min_lock_duration: i64,
+
+    /// Maximum allowed lock duration (in seconds)
+    pub max_lock_duration: i64,
+}
@@ -0,0 +1,34 @@
+use crate::errors::*;
+use crate::state::Vault;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRewardDistributor<'info> {
+    /// The node operator of the vault
+    #[account(
+        address = vault.node_operator @ SignerError::UnauthorizedNodeOperator
+    )]
+    pub node_operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> UpdateRewardDistributor<'info> {
+    /// Rotates the key allowed to deposit rewards next to the agent.
+    /// The default address leaves deposits to the agent only.
+    /// Returns the old distributor
+    pub fn update_reward_distributor(&mut self, new_distributor: Pubkey) -> Result<Pubkey> {
+        let old_distributor = self.vault.reward_distributor;
+        self.vault.reward_distributor = new_distributor;
+        Ok(old_distributor)
+    }
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::slashing::SlashAccounts;
+use crate::state::{
+    AuthorityConfig, InsuranceFund, SlashDecision, SlashRequest, Vault, VaultPhase,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct VoteSlash<'info> {
+    /// A member of the arbiter committee, pays for the escrow if its vote executes the slash
+    #[account(
+        mut,
+        constraint = config.is_arbiter(arbiter.key()) @ SignerError::UnauthorizedArbiter
+    )]
+    pub arbiter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"Vault",
+            vault.node_operator.key().as_ref(),
+            vault.vault_index.to_le_bytes().as_ref()
+        ],
+        bump = vault.bump,
+        constraint = vault.phase == VaultPhase::Disputed @ VaultError::NoActiveDispute
+    )]
+    pub vault: Account<'info, Vault>,
+    /// The vault's open slash request, kept as a record once resolved
+    #[account(
+        mut,
+        seeds = [
+            b"SlashRequest",
+            vault.key().as_ref(),
+            slash_request.sequence.to_le_bytes().as_ref()
+        ],
+        bump = slash_request.bump,
+        constraint = slash_request.is_open() @ VaultError::NoActiveDispute
+    )]
+    pub slash_request: Account<'info, SlashRequest>,
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+    /// Locking token mint
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        address = vault.locking_token_mint @ TokenError::InvalidLockingMint
+    )]
+    pub locking_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's token account
+    #[account(
+        mut,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Holds the claimants' part of the slash until each of them claims it
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = slash_request,
+        associated_token::token_program = token_program
+    )]
+    pub slash_escrow_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Insurance fund of the locking token, required when part of the slash goes there
+    #[account(
+        mut,
+        seeds = [b"Insurance", locking_token_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Option<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = locking_token_mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VoteSlash<'info> {
+    /// Records the arbiter's vote and resolves the slash request once the
+    /// committee reached a decision. Returns the decision
+    pub fn vote(&mut self, approve: bool, amount: u64) -> Result<SlashDecision> {
+        self.vault.require_not_paused(&self.config)?;
+        require!(self.config.has_arbiters(), SignerError::UnauthorizedArbiter);
+
+        let clock = Clock::get()?;
+        require_gt!(
+            self.slash_request.decision_deadline()?,
+            clock.unix_timestamp,
+            VaultError::SlashVotingClosed
+        );
+        if approve {
+            self.slash_request
+                .require_appeal_window_closed(clock.unix_timestamp)?;
+        }
+
+        self.slash_request
+            .record_vote(self.arbiter.key(), approve, amount)?;
+
+        let decision = self
+            .slash_request
+            .committee_decision(self.config.active_arbiters(), self.config.arbiter_threshold);
+        let arbiter = self.arbiter.key();
+        match decision {
+            SlashDecision::Undecided => {}
+            SlashDecision::Execute(amount) => {
+                self.slash_accounts().resolve(Some(amount), arbiter)?
+            }
+            SlashDecision::Reject => self.slash_accounts().resolve(None, arbiter)?,
+        }
+        Ok(decision)
+    }
+
+    fn slash_accounts(&mut self) -> SlashAccounts<'_, 'info> {
+        SlashAccounts {
+            vault: &mut self.vault,
+            slash_request: &mut self.slash_request,
+            locking_token_mint: &self.locking_token_mint,
+            vault_token_ata: &self.vault_token_ata,
+            slash_escrow_ata: &self.slash_escrow_ata,
+            insurance_fund: self.insurance_fund.as_mut(),
+            insurance_fund_ata: self.insurance_fund_ata.as_ref(),
+            token_program: &self.token_program,
+        }
+    }
+}
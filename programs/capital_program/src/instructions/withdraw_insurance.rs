@@ -0,0 +1,86 @@
+use crate::errors::*;
+use crate::state::{AuthorityConfig, InsuranceFund};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawInsurance<'info> {
+    /// Current program administrator
+    #[account(
+        address = config.admin @ SignerError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// Mint of the insurance fund being withdrawn from
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"Insurance", mint.key().as_ref()],
+        bump = insurance_fund.bump,
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Insurance fund's token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_fund_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Any token account of the mint receiving the funds
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawInsurance<'info> {
+    /// Transfers `amount` out of the fund. Positions are only covered in the
+    /// locking token, so the fee-funded insurance of a reward mint has to be
+    /// withdrawn, converted and deposited into the locking token's fund
+    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
+        require_gte!(
+            self.insurance_fund_ata.amount,
+            amount,
+            TokenError::InsufficientBalance
+        );
+        self.insurance_fund.record_withdrawal(amount)?;
+
+        let mint = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[b"Insurance", mint.as_ref(), &[self.insurance_fund.bump]]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.insurance_fund_ata.to_account_info(),
+            to: self.destination.to_account_info(),
+            authority: self.insurance_fund.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
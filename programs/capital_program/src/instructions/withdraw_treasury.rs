@@ -0,0 +1,81 @@
+use crate::errors::*;
+use crate::state::AuthorityConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// Current program administrator
+    #[account(
+        address = config.admin @ SignerError::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration
+    #[account(
+        seeds = [b"Config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, AuthorityConfig>,
+
+    /// CHECK: Protocol treasury PDA, signs for its token accounts
+    #[account(
+        seeds = [b"Treasury"],
+        bump = config.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Mint of the collected fees being withdrawn
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury's token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Any token account of the mint receiving the fees
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    /// Transfers `amount` of collected protocol fees to the destination
+    pub fn withdraw(&self, amount: u64) -> Result<()> {
+        require_gt!(amount, 0, ArithmeticError::AmountMustBePositive);
+        require_gte!(
+            self.treasury_ata.amount,
+            amount,
+            TokenError::InsufficientBalance
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"Treasury", &[self.config.treasury_bump]]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.treasury_ata.to_account_info(),
+            to: self.destination.to_account_info(),
+            authority: self.treasury.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
@@ -7,11 +7,16 @@ pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod merkle;
+pub mod reward_math;
+pub mod reward_streams;
+pub mod slashing;
 pub mod state;
 
 use errors::*;
 use events::*;
 use instructions::*;
+use state::{ChallengeStatus, SlashDecision, SlashStatus};
 
 #[program]
 pub mod capital_program {
@@ -39,7 +44,6 @@ pub mod capital_program {
             nft_program: ctx.accounts.nft_program.key(),
             capital_program: *ctx.program_id,
             early_unlock_fee: params.early_unlock_fee,
-            dispute_window: params.dispute_window,
             min_lock_duration: params.min_lock_duration,
             max_lock_duration: params.max_lock_duration,
             timestamp: Clock::get()?.unix_timestamp,
@@ -52,6 +56,299 @@ pub mod capital_program {
         Ok(())
     }
 
+    pub fn propose_admin_handler(ctx: Context<UpdateProgram>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)?;
+
+        emit!(AdminTransferProposedEvent {
+            config: ctx.accounts.config.key(),
+            admin: ctx.accounts.admin.key(),
+            pending_admin: new_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin transfer proposed to {}", new_admin);
+        Ok(())
+    }
+
+    pub fn accept_admin_handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let old_admin = ctx.accounts.accept_admin()?;
+
+        emit!(AdminTransferredEvent {
+            config: ctx.accounts.config.key(),
+            old_admin,
+            new_admin: ctx.accounts.config.admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Admin transferred to {}", ctx.accounts.config.admin);
+        Ok(())
+    }
+
+    pub fn update_agent_handler(ctx: Context<UpdateProgram>, new_agent: Pubkey) -> Result<()> {
+        let old_agent = ctx.accounts.update_agent(new_agent)?;
+
+        emit!(AgentUpdatedEvent {
+            config: ctx.accounts.config.key(),
+            old_agent,
+            new_agent,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Agent updated to {}", new_agent);
+        Ok(())
+    }
+
+    pub fn update_guardian_handler(
+        ctx: Context<UpdateProgram>,
+        new_guardian: Pubkey,
+    ) -> Result<()> {
+        let old_guardian = ctx.accounts.update_guardian(new_guardian)?;
+
+        emit!(GuardianUpdatedEvent {
+            config: ctx.accounts.config.key(),
+            old_guardian,
+            new_guardian,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Guardian updated to {}", new_guardian);
+        Ok(())
+    }
+
+    /// Replaces the arbiter committee, slashes then need `threshold` arbiter
+    /// approvals. An empty set hands slash decisions back to the agent
+    pub fn update_arbiters_handler(
+        ctx: Context<UpdateProgram>,
+        arbiters: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.update_arbiters(&arbiters, threshold)?;
+
+        msg!(
+            "Arbiter committee updated: {}-of-{}",
+            threshold,
+            arbiters.len()
+        );
+        emit!(ArbitersUpdatedEvent {
+            config: ctx.accounts.config.key(),
+            arbiters,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    /// Registers a key whose signed performance reports are accepted,
+    /// reports below `min_uptime_bps` count as failing
+    pub fn register_attester_handler(
+        ctx: Context<RegisterAttester>,
+        key: Pubkey,
+        min_uptime_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts
+            .register_attester(key, min_uptime_bps, &ctx.bumps)?;
+
+        emit!(AttesterRegisteredEvent {
+            attester: ctx.accounts.attester.key(),
+            key,
+            min_uptime_bps,
+            timestamp: ctx.accounts.attester.registered_at,
+        });
+
+        msg!("Attester registered: {}", key);
+        Ok(())
+    }
+
+    pub fn remove_attester_handler(ctx: Context<RemoveAttester>) -> Result<()> {
+        emit!(AttesterRemovedEvent {
+            attester: ctx.accounts.attester.key(),
+            key: ctx.accounts.attester.key,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Attester removed: {}", ctx.accounts.attester.key);
+        Ok(())
+    }
+
+    /// Moves residual reward and lock token balances of a finished vault to the
+    /// treasury, so that it can be closed
+    pub fn sweep_vault_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepVault<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.validate_sweep()?;
+        let streams = ctx.accounts.reward_streams(ctx.remaining_accounts)?;
+
+        let vault = ctx.accounts.vault.key();
+        let authority = ctx.accounts.authority.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        for stream in streams.iter() {
+            let (amount, rounding_remainder) = ctx.accounts.sweep_stream(stream)?;
+            emit!(VaultSweptEvent {
+                vault,
+                authority,
+                mint: stream.mint.key(),
+                amount,
+                rounding_remainder,
+                timestamp,
+            });
+        }
+
+        let lock_amount = ctx.accounts.sweep_lock_tokens()?;
+        emit!(VaultSweptEvent {
+            vault,
+            authority,
+            mint: ctx.accounts.lock_mint.key(),
+            amount: lock_amount,
+            rounding_remainder: 0,
+            timestamp,
+        });
+
+        msg!("Vault swept to treasury");
+        Ok(())
+    }
+
+    /// Permissionless top-up of the insurance fund of one mint
+    pub fn deposit_insurance_handler(ctx: Context<DepositInsurance>, amount: u64) -> Result<()> {
+        let total_deposited = ctx.accounts.deposit(amount)?;
+
+        emit!(InsuranceDepositedEvent {
+            insurance_fund: ctx.accounts.insurance_fund.key(),
+            depositor: ctx.accounts.depositor.key(),
+            mint: ctx.accounts.mint.key(),
+            amount,
+            total_deposited,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Insurance deposited: {}", amount);
+        Ok(())
+    }
+
+    /// Creates the treasury token account and the insurance fund of a mint,
+    /// once before fees or insurance are collected in it
+    pub fn init_fee_accounts_handler(ctx: Context<InitFeeAccounts>) -> Result<()> {
+        ctx.accounts.init_fee_accounts(&ctx.bumps)?;
+
+        msg!("Fee accounts initialized for {}", ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    /// Moves funds of one mint out of its insurance fund, to convert fee-funded
+    /// insurance of a reward mint into the locking token
+    pub fn withdraw_insurance_handler(ctx: Context<WithdrawInsurance>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw(amount)?;
+
+        emit!(InsuranceWithdrawnEvent {
+            insurance_fund: ctx.accounts.insurance_fund.key(),
+            admin: ctx.accounts.admin.key(),
+            mint: ctx.accounts.mint.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Insurance withdrawn: {}", amount);
+        Ok(())
+    }
+
+    /// Moves collected protocol fees of one mint out of the treasury
+    pub fn withdraw_treasury_handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw(amount)?;
+
+        emit!(TreasuryWithdrawnEvent {
+            treasury: ctx.accounts.treasury.key(),
+            admin: ctx.accounts.admin.key(),
+            mint: ctx.accounts.mint.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Treasury withdrawn: {}", amount);
+        Ok(())
+    }
+
+    pub fn set_protocol_pause_handler(ctx: Context<SetProtocolPause>, paused: bool) -> Result<()> {
+        ctx.accounts.set_pause(paused)?;
+
+        let target = ctx.accounts.config.key();
+        let authority = ctx.accounts.authority.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        if paused {
+            emit!(PausedEvent {
+                target,
+                protocol_wide: true,
+                authority,
+                timestamp,
+            });
+        } else {
+            emit!(UnpausedEvent {
+                target,
+                protocol_wide: true,
+                authority,
+                timestamp,
+            });
+        }
+
+        msg!("Protocol paused: {}", paused);
+        Ok(())
+    }
+
+    pub fn set_vault_pause_handler(ctx: Context<SetVaultPause>, paused: bool) -> Result<()> {
+        ctx.accounts.set_pause(paused)?;
+
+        let target = ctx.accounts.vault.key();
+        let authority = ctx.accounts.authority.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        if paused {
+            emit!(PausedEvent {
+                target,
+                protocol_wide: false,
+                authority,
+                timestamp,
+            });
+        } else {
+            emit!(UnpausedEvent {
+                target,
+                protocol_wide: false,
+                authority,
+                timestamp,
+            });
+        }
+
+        msg!("Vault paused: {}", paused);
+        Ok(())
+    }
+
+    pub fn update_program_config_handler(
+        ctx: Context<UpdateProgram>,
+        params: UpdateProgramConfig,
+    ) -> Result<()> {
+        let old_config = ctx.accounts.update_config(&params)?;
+
+        emit!(ProgramConfigUpdatedEvent {
+            config: ctx.accounts.config.key(),
+            old_early_unlock_fee: old_config.early_unlock_fee,
+            new_early_unlock_fee: params.early_unlock_fee,
+            old_protocol_fee_bps: old_config.protocol_fee_bps,
+            new_protocol_fee_bps: params.protocol_fee_bps,
+            old_min_operator_bond_bps: old_config.min_operator_bond_bps,
+            new_min_operator_bond_bps: params.min_operator_bond_bps,
+            old_insurance_fee_bps: old_config.insurance_fee_bps,
+            new_insurance_fee_bps: params.insurance_fee_bps,
+            old_insurance_coverage_bps: old_config.insurance_coverage_bps,
+            new_insurance_coverage_bps: params.insurance_coverage_bps,
+            old_min_lock_duration: old_config.min_lock_duration,
+            new_min_lock_duration: params.min_lock_duration,
+            old_max_lock_duration: old_config.max_lock_duration,
+            new_max_lock_duration: params.max_lock_duration,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Program config updated");
+        Ok(())
+    }
+
     pub fn create_vault_handler(ctx: Context<CreateVault>, config: InitVaultConfig) -> Result<()> {
         // Step 1: Validate configuration parameters
         ctx.accounts.validate_config(&config)?;
@@ -59,7 +356,14 @@ pub mod capital_program {
         // Step 2: Initialize vault account
         ctx.accounts.initialize_vault(config.clone(), &ctx.bumps)?;
 
-        // Step 3: Create NFT collection via CPI
+        // Step 3: Bump the operator's vault counter
+        ctx.accounts
+            .register_vault(config.node_operator, &ctx.bumps)?;
+
+        // Step 4: Post the operator bond
+        ctx.accounts.post_operator_bond(config.operator_bond)?;
+
+        // Step 5: Create NFT collection via CPI
         ctx.accounts.create_nft_collection()?;
 
         // Emit event for indexing
@@ -67,11 +371,13 @@ pub mod capital_program {
             vault: ctx.accounts.vault.key(),
             provider: ctx.accounts.provider.key(),
             node_operator: config.node_operator,
+            vault_index: ctx.accounts.vault.vault_index,
             staking_token: ctx.accounts.lock_mint.key(),
             reward_token: ctx.accounts.reward_token_mint.key(),
             nft_collection: ctx.accounts.nft_collection.key(),
             min_cap: config.min_cap,
             max_cap: config.max_cap,
+            operator_bond: config.operator_bond,
             lock_phase_start_time: config.lock_phase_start_time,
             timestamp: Clock::get()?.unix_timestamp,
         });
@@ -81,6 +387,41 @@ pub mod capital_program {
         Ok(())
     }
 
+    pub fn update_reward_distributor_handler(
+        ctx: Context<UpdateRewardDistributor>,
+        new_distributor: Pubkey,
+    ) -> Result<()> {
+        let old_distributor = ctx.accounts.update_reward_distributor(new_distributor)?;
+
+        emit!(RewardDistributorUpdatedEvent {
+            vault: ctx.accounts.vault.key(),
+            old_distributor,
+            new_distributor,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Reward distributor updated to {}", new_distributor);
+        Ok(())
+    }
+
+    pub fn add_reward_stream_handler(ctx: Context<AddRewardStream>) -> Result<()> {
+        let stream_index = ctx.accounts.add_reward_stream()?;
+
+        emit!(RewardStreamAddedEvent {
+            vault: ctx.accounts.vault.key(),
+            reward_mint: ctx.accounts.reward_mint.key(),
+            stream_index: stream_index as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Reward stream {} added: {}",
+            stream_index,
+            ctx.accounts.reward_mint.key()
+        );
+        Ok(())
+    }
+
     pub fn open_position_handler(ctx: Context<OpenPosition>, amount: u64) -> Result<()> {
         // Step 1: Initialize position account
         ctx.accounts.initialize_position(amount, &ctx.bumps)?;
@@ -131,129 +472,582 @@ pub mod capital_program {
         Ok(())
     }
 
-    pub fn deposit_rewards_handler(ctx: Context<DepositRewards>, amount: u64) -> Result<()> {
+    pub fn deposit_rewards_handler(
+        ctx: Context<DepositRewards>,
+        amount: u64,
+        stream_duration: Option<i64>,
+    ) -> Result<()> {
         // Step 1: Validate deposit parameters
         ctx.accounts.validate_deposit(amount)?;
 
-        // Step 2: Update vault state
-        ctx.accounts.update_vault_state(amount)?;
+        // Step 2: Carve off the protocol fee, the vault only accounts for the rest
+        let protocol_fee = ctx.accounts.protocol_fee(amount)?;
+        let vault_amount = amount
+            .checked_sub(protocol_fee)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
 
-        // Step 3: Transfer reward tokens
-        ctx.accounts.transfer_rewards(amount)?;
+        let insurance_fee = ctx.accounts.fund_insurance(protocol_fee)?;
+
+        // Step 3: Update vault state
+        let total_rewards = ctx
+            .accounts
+            .update_vault_state(vault_amount, stream_duration)?;
+
+        // Step 4: Transfer reward tokens
+        ctx.accounts
+            .transfer_rewards(vault_amount, protocol_fee, insurance_fee)?;
 
         // Emit event for indexing
         emit!(RewardsDepositedEvent {
             vault: ctx.accounts.vault.key(),
-            agent: ctx.accounts.agent.key(),
-            amount,
-            total_rewards: ctx.accounts.vault.total_rewards_deposited,
+            depositor: ctx.accounts.depositor.key(),
+            depositor_role: ctx.accounts.depositor_role(),
+            reward_mint: ctx.accounts.reward_token_mint.key(),
+            amount: vault_amount,
+            protocol_fee,
+            insurance_fee,
+            stream_duration: stream_duration.unwrap_or(0),
+            total_rewards,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         msg!("Rewards deposited successfully");
-        msg!("Amount: {}", amount);
-        msg!(
-            "Total rewards in vault: {}",
-            ctx.accounts.vault.total_rewards_deposited
-        );
+        msg!("Amount: {}", vault_amount);
+        msg!("Protocol fee: {}", protocol_fee);
+        msg!("Total rewards in stream: {}", total_rewards);
 
         Ok(())
     }
 
-    pub fn claim_investor_rewards_handler(ctx: Context<ClaimInvestorRewards>) -> Result<()> {
-        // Calculate claimable rewards
-        let claimable_amount = ctx.accounts.calculate_claimable_rewards()?;
-
-        // Ensure there are rewards to claim
-        require_gt!(claimable_amount, 0, PositionError::NoRewardsToClaim);
+    /// Permissionless top-up, split like a deposit and attributed to a campaign.
+    /// Donations are not charged the protocol fee
+    pub fn donate_rewards_handler(
+        ctx: Context<DonateRewards>,
+        amount: u64,
+        campaign_id: u64,
+        memo: String,
+    ) -> Result<()> {
+        // Step 1: Validate donation parameters
+        ctx.accounts.validate_donation(amount, &memo)?;
 
-        // Update position state
-        ctx.accounts.process_claim(claimable_amount)?;
+        // Step 2: Update vault state
+        let total_rewards = ctx.accounts.record_donation(amount)?;
 
-        // Transfer rewards to holder
-        ctx.accounts.transfer_rewards(claimable_amount)?;
+        // Step 3: Transfer reward tokens
+        ctx.accounts.transfer_donation(amount)?;
 
-        // Emit event for indexing
-        emit!(RewardsClaimedEvent {
-            holder: ctx.accounts.holder.key(),
+        emit!(RewardsDonatedEvent {
             vault: ctx.accounts.vault.key(),
-            position: ctx.accounts.position.key(),
-            amount: claimable_amount,
+            donor: ctx.accounts.donor.key(),
+            reward_mint: ctx.accounts.reward_token_mint.key(),
+            amount,
+            campaign_id,
+            memo,
+            total_rewards,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
-        msg!("Successfully claimed {} rewards", claimable_amount);
+        msg!("Rewards donated: {} for campaign {}", amount, campaign_id);
+
+        Ok(())
+    }
+
+    /// Claims the named reward stream plus every stream passed in remaining accounts
+    pub fn claim_investor_rewards_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimInvestorRewards<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.validate_claim()?;
+        let streams = ctx.accounts.reward_streams(ctx.remaining_accounts)?;
+
+        let mut claimed_any = false;
+        for stream in streams.iter() {
+            let claimable_amount = ctx.accounts.claim_stream(stream)?;
+            if claimable_amount == 0 {
+                continue;
+            }
+            claimed_any = true;
+
+            // Emit event for indexing
+            emit!(RewardsClaimedEvent {
+                holder: ctx.accounts.holder.key(),
+                vault: ctx.accounts.vault.key(),
+                position: ctx.accounts.position.key(),
+                reward_mint: stream.mint.key(),
+                amount: claimable_amount,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+
+            msg!(
+                "Successfully claimed {} rewards of {}",
+                claimable_amount,
+                stream.mint.key()
+            );
+        }
+
+        // Ensure there were rewards to claim
+        require!(claimed_any, PositionError::NoRewardsToClaim);
+
+        Ok(())
+    }
+
+    /// Claims the named reward stream plus every stream passed in remaining accounts
+    pub fn claim_beneficiary_rewards_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimBeneficiaryRewards<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.validate_claim()?;
+        let streams = ctx.accounts.reward_streams(ctx.remaining_accounts)?;
+
+        let mut claimed_any = false;
+        for stream in streams.iter() {
+            let (claimable, unvested_amount) = ctx.accounts.claim_stream(stream)?;
+            if claimable == 0 {
+                continue;
+            }
+            claimed_any = true;
+
+            emit!(BeneficiaryRewardsClaimedEvent {
+                vault: ctx.accounts.vault.key(),
+                beneficiary: ctx.accounts.beneficiary.key(),
+                reward_mint: stream.mint.key(),
+                amount: claimable,
+                unvested_amount,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        require!(claimed_any, PositionError::NoRewardsToClaim);
 
         Ok(())
     }
 
-    pub fn claim_beneficiary_rewards_handler(
-        ctx: Context<ClaimBeneficiaryRewards>,
-        beneficiary_index: u8,
+    pub fn claim_operator_commission_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimOperatorCommission<'info>>,
     ) -> Result<()> {
-        // Calculate claimable amount
-        let claimable = ctx.accounts.calculate_claimable(beneficiary_index)?;
+        ctx.accounts.validate_claim()?;
+        let streams = ctx.accounts.reward_streams(ctx.remaining_accounts)?;
+
+        let mut claimed_any = false;
+        for stream in streams.iter() {
+            let commission = ctx.accounts.claim_stream(stream)?;
+            if commission == 0 {
+                continue;
+            }
+            claimed_any = true;
+
+            emit!(OperatorCommissionClaimedEvent {
+                vault: ctx.accounts.vault.key(),
+                node_operator: ctx.accounts.node_operator.key(),
+                reward_mint: stream.mint.key(),
+                amount: commission,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
 
-        require_gt!(claimable, 0, PositionError::NoRewardsToClaim);
+        require!(claimed_any, PositionError::NoRewardsToClaim);
 
-        // Process claim
-        ctx.accounts.process_claim(beneficiary_index, claimable)?;
+        Ok(())
+    }
 
-        // Transfer rewards
-        ctx.accounts.transfer_rewards(claimable)?;
+    pub fn add_beneficiary_handler(
+        ctx: Context<AddBeneficiary>,
+        share_bps: u16,
+        vesting: Option<BeneficiaryVesting>,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_beneficiary(share_bps, vesting, &ctx.bumps)?;
 
-        emit!(BeneficiaryRewardsClaimedEvent {
+        emit!(BeneficiaryAddedEvent {
             vault: ctx.accounts.vault.key(),
             beneficiary: ctx.accounts.beneficiary.key(),
-            amount: claimable,
+            share_bps,
+            cliff_at: ctx.accounts.beneficiary_account.cliff_at,
+            vesting_end_at: ctx.accounts.beneficiary_account.vesting_end_at,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Beneficiary added with {} bps", share_bps);
+        Ok(())
+    }
+
+    pub fn resize_beneficiary_handler(
+        ctx: Context<ResizeBeneficiary>,
+        share_bps: u16,
+    ) -> Result<()> {
+        let old_share_bps = ctx.accounts.resize_beneficiary(share_bps)?;
+
+        emit!(BeneficiaryResizedEvent {
+            vault: ctx.accounts.vault.key(),
+            beneficiary: ctx.accounts.beneficiary.key(),
+            old_share_bps,
+            new_share_bps: share_bps,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Beneficiary resized from {} to {} bps",
+            old_share_bps,
+            share_bps
+        );
+        Ok(())
+    }
+
+    /// Accrued rewards are paid out to the beneficiary through the reward stream
+    /// accounts passed in remaining accounts
+    pub fn remove_beneficiary_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveBeneficiary<'info>>,
+    ) -> Result<()> {
+        let (share_bps, payouts) = ctx.accounts.remove_beneficiary(ctx.remaining_accounts)?;
+
+        let vault = ctx.accounts.vault.key();
+        let beneficiary = ctx.accounts.beneficiary.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        for payout in payouts {
+            emit!(BeneficiaryRewardsClaimedEvent {
+                vault,
+                beneficiary,
+                reward_mint: payout.reward_mint,
+                amount: payout.amount,
+                unvested_amount: payout.unvested_amount,
+                timestamp,
+            });
+        }
+
+        emit!(BeneficiaryRemovedEvent {
+            vault,
+            beneficiary,
+            share_bps,
+            timestamp,
+        });
+
+        msg!("Beneficiary removed, {} bps released", share_bps);
+        Ok(())
+    }
+
+    pub fn transfer_beneficiary_handler(
+        ctx: Context<TransferBeneficiary>,
+        new_address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.transfer_beneficiary(new_address, &ctx.bumps)?;
+
+        emit!(BeneficiaryTransferredEvent {
+            vault: ctx.accounts.vault.key(),
+            old_address: ctx.accounts.beneficiary.key(),
+            new_address,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
+        msg!("Beneficiary transferred to {}", new_address);
+        Ok(())
+    }
+
+    /// Stores a performance report of the vault signed by a registered attester,
+    /// verified by an ed25519 program instruction placed right before this one
+    pub fn submit_attestation_handler(
+        ctx: Context<SubmitAttestation>,
+        epoch: u64,
+        uptime_bps: u16,
+        work_units: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .submit_attestation(epoch, uptime_bps, work_units, &ctx.bumps)?;
+
+        let attestation = &ctx.accounts.attestation;
+        emit!(AttestationSubmittedEvent {
+            vault: attestation.vault,
+            attestation: attestation.key(),
+            attester: attestation.attester,
+            epoch,
+            uptime_bps,
+            work_units,
+            failed: attestation.failed,
+            timestamp: attestation.submitted_at,
+        });
+
+        msg!("Attestation submitted for epoch {}", epoch);
+        msg!("Uptime BPS: {}", uptime_bps);
+        Ok(())
+    }
+
+    /// Posts the Merkle root of the operator's work in `epoch`
+    pub fn commit_epoch_handler(
+        ctx: Context<CommitEpoch>,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        leaf_count: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .commit_epoch(epoch, merkle_root, leaf_count, &ctx.bumps)?;
+
+        let commitment = &ctx.accounts.commitment;
+        emit!(EpochCommittedEvent {
+            vault: commitment.vault,
+            commitment: commitment.key(),
+            epoch,
+            merkle_root,
+            leaf_count,
+            timestamp: commitment.committed_at,
+        });
+
+        msg!("Epoch {} committed with {} leaves", epoch, leaf_count);
+        Ok(())
+    }
+
+    /// Challenges the operator to prove one leaf of an epoch commitment,
+    /// the vault is slashed by `terms` if no valid proof arrives in time
+    pub fn issue_challenge_handler(
+        ctx: Context<IssueChallenge>,
+        leaf_index: u64,
+        terms: ChallengeSlashTerms,
+    ) -> Result<()> {
+        ctx.accounts
+            .issue_challenge(leaf_index, terms, &ctx.bumps)?;
+
+        let challenge = &ctx.accounts.challenge;
+        emit!(ChallengeIssuedEvent {
+            vault: challenge.vault,
+            challenge: challenge.key(),
+            commitment: challenge.commitment,
+            leaf_index,
+            slash_bps: challenge.slash_bps,
+            deadline: challenge.deadline,
+            timestamp: challenge.issued_at,
+        });
+
+        msg!("Challenge issued for leaf {}", leaf_index);
+        Ok(())
+    }
+
+    /// Challenges an epoch whose Merkle root was never posted, the vault can be
+    /// slashed by `terms` for it right away
+    pub fn challenge_missed_epoch_handler(
+        ctx: Context<ChallengeMissedEpoch>,
+        epoch: u64,
+        terms: ChallengeSlashTerms,
+    ) -> Result<()> {
+        ctx.accounts
+            .challenge_missed_epoch(epoch, terms, &ctx.bumps)?;
+
+        let challenge = &ctx.accounts.challenge;
+        emit!(EpochMissedEvent {
+            vault: challenge.vault,
+            challenge: challenge.key(),
+            epoch,
+            slash_bps: challenge.slash_bps,
+            commitment_deadline: challenge.deadline,
+            timestamp: challenge.issued_at,
+        });
+
+        msg!("Epoch {} was never committed", epoch);
+        Ok(())
+    }
+
+    /// Answers a challenge with the Merkle path of the challenged leaf
+    pub fn respond_challenge_handler(
+        ctx: Context<RespondChallenge>,
+        leaf: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let valid = ctx.accounts.respond(leaf, proof)?;
+
+        emit!(ChallengeAnsweredEvent {
+            vault: ctx.accounts.vault.key(),
+            challenge: ctx.accounts.challenge.key(),
+            valid,
+            timestamp: ctx.accounts.challenge.answered_at,
+        });
+
+        msg!("Challenge answered, proof valid: {}", valid);
+        Ok(())
+    }
+
+    /// Slashes the vault for a failed or unanswered challenge, callable by anyone
+    pub fn slash_failed_challenge_handler(ctx: Context<SlashFailedChallenge>) -> Result<()> {
+        let unanswered = ctx.accounts.challenge.status == ChallengeStatus::Open;
+        ctx.accounts.slash(&ctx.bumps)?;
+
+        let slash_request = &ctx.accounts.slash_request;
+        emit!(ChallengeSlashedEvent {
+            vault: ctx.accounts.vault.key(),
+            challenge: ctx.accounts.challenge.key(),
+            slash_request: slash_request.key(),
+            caller: ctx.accounts.caller.key(),
+            unanswered,
+            timestamp: slash_request.resolved_at,
+        });
+        emit!(SlashReqFinalizedEvent {
+            vault: ctx.accounts.vault.key(),
+            slash_request: slash_request.key(),
+            resolver: ctx.accounts.caller.key(),
+            appealed: false,
+            status: slash_request.status,
+            amount: slash_request.executed_amount,
+            bond_slashed: slash_request.bond_slashed,
+            insurance_amount: slash_request.insurance_amount,
+            burned_amount: slash_request.burned_amount,
+            timestamp: slash_request.resolved_at,
+        });
+
+        msg!("Vault slashed for failed challenge");
+        msg!("Amount: {}", slash_request.executed_amount);
         Ok(())
     }
 
     pub fn create_slas_req_handler(
         ctx: Context<CreateSlashReq>,
-        slash_bps: u16,
-        slash_claimant: Pubkey,
+        params: CreateSlashReqParams,
     ) -> Result<()> {
-        ctx.accounts.create_slas_req(slash_bps, slash_claimant)?;
+        ctx.accounts.create_slas_req(params, &ctx.bumps)?;
+
+        let slash_request = &ctx.accounts.slash_request;
         emit!(SlashRequestCreatedEvent {
             vault: ctx.accounts.vault.key(),
+            slash_request: slash_request.key(),
+            sequence: slash_request.sequence,
             agent: ctx.accounts.agent.key(),
-            slash_claimant,
-            slash_bps,
-            dispute_start_time: ctx.accounts.vault.dispute_start_time,
+            claimant_count: slash_request.claimants.len() as u8,
+            insurance_bps: slash_request.insurance_bps,
+            burn_bps: slash_request.burn_bps,
+            slash_bps: slash_request.slash_bps,
+            requested_amount: slash_request.requested_amount,
+            evidence_hash: slash_request.evidence_hash,
+            attestation: slash_request.attestation,
+            dispute_start_time: slash_request.created_at,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         msg!("Slash request created successfully");
-        msg!("Slash BPS: {}", slash_bps);
-        msg!("Claimant: {}", slash_claimant);
+        msg!("Slash BPS: {}", slash_request.slash_bps);
+        msg!("Claimants: {}", slash_request.claimants.len());
         Ok(())
     }
+    /// Lets the node operator contest a pending slash within the dispute window
+    pub fn appeal_slash_req_handler(
+        ctx: Context<AppealSlashReq>,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.appeal(evidence_hash)?;
+        emit!(SlashAppealedEvent {
+            vault: ctx.accounts.vault.key(),
+            slash_request: ctx.accounts.slash_request.key(),
+            node_operator: ctx.accounts.node_operator.key(),
+            evidence_hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Slash request appealed");
+        Ok(())
+    }
+
+    /// Permissionless crank clearing a slash request left undecided past its deadline
+    pub fn dismiss_slash_req_handler(ctx: Context<DismissSlashReq>) -> Result<()> {
+        ctx.accounts.dismiss()?;
+        emit!(SlashReqDismissedEvent {
+            vault: ctx.accounts.vault.key(),
+            slash_request: ctx.accounts.slash_request.key(),
+            caller: ctx.accounts.caller.key(),
+            timestamp: ctx.accounts.slash_request.resolved_at,
+        });
+
+        msg!("Slash request dismissed");
+        Ok(())
+    }
+
     pub fn finalize_slash_req_handler(
         ctx: Context<FinalizeSlashReq>,
         decision: bool,
         amount: u64,
     ) -> Result<()> {
+        let appealed = ctx.accounts.slash_request.status == SlashStatus::Appealed;
         ctx.accounts.process_req(decision, amount)?;
         msg!("Slash request finalized successfully");
         msg!("Decision: {}", decision);
         msg!("Amount: {}", amount);
+
+        let slash_request = &ctx.accounts.slash_request;
         emit!(SlashReqFinalizedEvent {
-            claimant: ctx.accounts.vault.slash_claimant,
             vault: ctx.accounts.vault.key(),
+            slash_request: slash_request.key(),
+            resolver: ctx.accounts.resolver.key(),
+            appealed,
+            status: slash_request.status,
+            amount: slash_request.executed_amount,
+            bond_slashed: slash_request.bond_slashed,
+            insurance_amount: slash_request.insurance_amount,
+            burned_amount: slash_request.burned_amount,
+            timestamp: slash_request.resolved_at,
+        });
+        Ok(())
+    }
+
+    /// Records an arbiter vote on a slash request, the slash is executed or
+    /// rejected as soon as the committee threshold is reached
+    pub fn vote_slash_handler(ctx: Context<VoteSlash>, approve: bool, amount: u64) -> Result<()> {
+        let appealed = ctx.accounts.slash_request.status == SlashStatus::Appealed;
+        let decision = ctx.accounts.vote(approve, amount)?;
+
+        let slash_request = &ctx.accounts.slash_request;
+        let (approvals, rejections, _) =
+            slash_request.committee_votes(ctx.accounts.config.active_arbiters());
+        emit!(SlashVoteCastEvent {
+            vault: ctx.accounts.vault.key(),
+            slash_request: slash_request.key(),
+            arbiter: ctx.accounts.arbiter.key(),
+            approve,
+            amount: if approve { amount } else { 0 },
+            approvals: approvals as u8,
+            rejections: rejections as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        if decision != SlashDecision::Undecided {
+            msg!("Arbiter threshold reached, slash request finalized");
+            emit!(SlashReqFinalizedEvent {
+                vault: ctx.accounts.vault.key(),
+                slash_request: slash_request.key(),
+                resolver: ctx.accounts.arbiter.key(),
+                appealed,
+                status: slash_request.status,
+                amount: slash_request.executed_amount,
+                bond_slashed: slash_request.bond_slashed,
+                insurance_amount: slash_request.insurance_amount,
+                burned_amount: slash_request.burned_amount,
+                timestamp: slash_request.resolved_at,
+            });
+        }
+        Ok(())
+    }
+
+    /// Pays a claimant its share of an executed slash
+    pub fn claim_slash_payout_handler(ctx: Context<ClaimSlashPayout>) -> Result<()> {
+        let amount = ctx.accounts.claim_payout()?;
+        emit!(SlashPayoutClaimedEvent {
+            vault: ctx.accounts.vault.key(),
+            slash_request: ctx.accounts.slash_request.key(),
+            claimant: ctx.accounts.claimant.key(),
             amount,
             timestamp: Clock::get()?.unix_timestamp,
         });
+
+        msg!("Slash payout claimed: {}", amount);
         Ok(())
     }
 
     pub fn close_position_handler(ctx: Context<ClosePosition>) -> Result<()> {
-        ctx.accounts.validate_closing_process_unlock()?;
+        let insurance_coverage = ctx.accounts.validate_closing_process_unlock()?;
         ctx.accounts.burn_nft()?;
         msg!("Position closed successfully");
+        if let Some(insurance_fund) = &ctx.accounts.insurance_fund {
+            if insurance_coverage > 0 {
+                emit!(InsuranceClaimedEvent {
+                    insurance_fund: insurance_fund.key(),
+                    vault: ctx.accounts.vault.key(),
+                    position: ctx.accounts.position.key(),
+                    holder: ctx.accounts.position_holder.key(),
+                    amount: insurance_coverage,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+        }
         emit!(PositionClosedEvent {
             holder: ctx.accounts.position_holder.key(),
             timestamp: Clock::get()?.unix_timestamp,
@@ -261,11 +1055,52 @@ pub mod capital_program {
         Ok(())
     }
 
-    pub fn close_vault_handler(ctx: Context<CloseVault>) -> Result<()> {
-        ctx.accounts.close_vault_accounts()?;
+    pub fn advance_phase_handler(ctx: Context<AdvancePhase>) -> Result<()> {
+        ctx.accounts.advance_phase()?;
+        msg!("Vault phase: {:?}", ctx.accounts.vault.phase);
+        Ok(())
+    }
+
+    pub fn early_unlock_position_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EarlyUnlockPosition<'info>>,
+    ) -> Result<()> {
+        // Step 1: Pay out rewards accrued so far on every stream
+        let rewards_settled = ctx.accounts.settle_rewards(ctx.remaining_accounts)?;
+
+        // Step 2: Return principal minus the early unlock fee
+        let (principal_returned, fee) = ctx.accounts.process_unlock()?;
+
+        // Step 3: Burn the position NFT
+        ctx.accounts.burn_nft()?;
+
+        emit!(PositionEarlyUnlockedEvent {
+            holder: ctx.accounts.position_holder.key(),
+            vault: ctx.accounts.vault.key(),
+            position: ctx.accounts.position.key(),
+            asset: ctx.accounts.asset.key(),
+            principal_returned,
+            early_unlock_fee: fee,
+            rewards_settled,
+            remaining_capital: ctx.accounts.vault.total_capital_collected,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Position unlocked early");
+        msg!("Principal returned: {}", principal_returned);
+        msg!("Early unlock fee: {}", fee);
+
+        Ok(())
+    }
+
+    pub fn close_vault_handler<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseVault<'info>>,
+    ) -> Result<()> {
+        let bond_returned = ctx.accounts.return_operator_bond()?;
+        ctx.accounts.close_vault_accounts(ctx.remaining_accounts)?;
         msg!("Vault closed successfully");
         emit!(VaultClosedEvent {
             node_operator: ctx.accounts.node_operator.key(),
+            bond_returned,
             timestamp: Clock::get()?.unix_timestamp,
         });
         Ok(())
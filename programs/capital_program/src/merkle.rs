@@ -0,0 +1,133 @@
+//! Merkle proofs of the work operators commit to each epoch.
+//!
+//! Leaves are hashed as `sha256(0x00 || leaf)` and inner nodes as
+//! `sha256(0x01 || left || right)`, so an inner node never passes for a leaf.
+//! A tree of `leaf_count` leaves has `ceil(log2(leaf_count))` levels, operators
+//! pad the leaves up to the next power of two.
+
+use anchor_lang::solana_program::hash::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Levels between a leaf and the root
+pub fn tree_depth(leaf_count: u64) -> usize {
+    leaf_count.next_power_of_two().trailing_zeros() as usize
+}
+
+/// Whether `proof` (siblings from the leaf up) leads from `leaf` at
+/// `leaf_index` to `root`
+pub fn verify_proof(
+    root: &[u8; 32],
+    leaf: &[u8; 32],
+    leaf_index: u64,
+    leaf_count: u64,
+    proof: &[[u8; 32]],
+) -> bool {
+    if leaf_index >= leaf_count || proof.len() != tree_depth(leaf_count) {
+        return false;
+    }
+
+    let mut node = hashv(&[LEAF_PREFIX, leaf]).to_bytes();
+    for (level, sibling) in proof.iter().enumerate() {
+        node = if (leaf_index >> level) & 1 == 0 {
+            hashv(&[NODE_PREFIX, &node, sibling])
+        } else {
+            hashv(&[NODE_PREFIX, sibling, &node])
+        }
+        .to_bytes();
+    }
+    node == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Root and per-leaf proofs of `leaves`, padded with zero leaves
+    fn build_tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let mut level: Vec<[u8; 32]> = leaves
+            .iter()
+            .map(|leaf| hashv(&[LEAF_PREFIX, leaf]).to_bytes())
+            .collect();
+        level.resize(
+            leaves.len().next_power_of_two(),
+            hashv(&[LEAF_PREFIX, &[0; 32]]).to_bytes(),
+        );
+
+        let mut proofs = vec![Vec::new(); leaves.len()];
+        let mut positions: Vec<usize> = (0..leaves.len()).collect();
+        while level.len() > 1 {
+            for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+                proof.push(level[*position ^ 1]);
+                *position /= 2;
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| hashv(&[NODE_PREFIX, &pair[0], &pair[1]]).to_bytes())
+                .collect();
+        }
+        (level[0], proofs)
+    }
+
+    fn leaves(count: u8) -> Vec<[u8; 32]> {
+        (0..count).map(|index| [index + 1; 32]).collect()
+    }
+
+    #[test]
+    fn tree_depth_rounds_up_to_a_power_of_two() {
+        assert_eq!(tree_depth(1), 0);
+        assert_eq!(tree_depth(2), 1);
+        assert_eq!(tree_depth(3), 2);
+        assert_eq!(tree_depth(4), 2);
+        assert_eq!(tree_depth(5), 3);
+        assert_eq!(tree_depth(1 << 24), 24);
+    }
+
+    #[test]
+    fn verifies_every_leaf() {
+        let leaves = leaves(5);
+        let (root, proofs) = build_tree(&leaves);
+        for (index, (leaf, proof)) in leaves.iter().zip(&proofs).enumerate() {
+            assert!(verify_proof(&root, leaf, index as u64, 5, proof));
+        }
+    }
+
+    #[test]
+    fn single_leaf_tree_has_an_empty_proof() {
+        let leaves = leaves(1);
+        let (root, proofs) = build_tree(&leaves);
+        assert!(proofs[0].is_empty());
+        assert!(verify_proof(&root, &leaves[0], 0, 1, &[]));
+    }
+
+    #[test]
+    fn rejects_a_wrong_leaf_or_index() {
+        let leaves = leaves(4);
+        let (root, proofs) = build_tree(&leaves);
+        assert!(!verify_proof(&root, &leaves[1], 0, 4, &proofs[0]));
+        assert!(!verify_proof(&root, &leaves[0], 1, 4, &proofs[0]));
+        assert!(!verify_proof(&root, &leaves[0], 4, 4, &proofs[0]));
+    }
+
+    #[test]
+    fn rejects_a_proof_of_the_wrong_length() {
+        let leaves = leaves(4);
+        let (root, proofs) = build_tree(&leaves);
+        assert!(!verify_proof(&root, &leaves[0], 0, 8, &proofs[0]));
+        assert!(!verify_proof(&root, &leaves[0], 0, 4, &proofs[0][..1]));
+    }
+
+    #[test]
+    fn inner_node_does_not_pass_for_a_leaf() {
+        let leaves = leaves(4);
+        let (root, proofs) = build_tree(&leaves);
+        let inner = hashv(&[
+            NODE_PREFIX,
+            &hashv(&[LEAF_PREFIX, &leaves[0]]).to_bytes(),
+            &proofs[0][0],
+        ])
+        .to_bytes();
+        assert!(!verify_proof(&root, &inner, 0, 2, &proofs[0][1..]));
+    }
+}
@@ -0,0 +1,159 @@
+//! Shared reward arithmetic. Investor rewards use a MasterChef-style accumulator:
+//! every deposit raises `acc_reward_per_share` by `investor_amount * REWARD_PRECISION / total_shares`
+//! and a position with `shares` has earned `shares * acc_reward_per_share / REWARD_PRECISION`
+//! minus its `reward_debt`. Streamed deposits enter the accumulator linearly as they vest.
+//! All intermediate math is done in u128.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::{BASE_BPS, REWARD_PRECISION};
+use crate::errors::*;
+
+/// `amount * bps / BASE_BPS`, rounded down
+pub fn bps_share(amount: u64, bps: u16) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(ArithmeticError::ArithmeticOverflow)?
+        .checked_div(BASE_BPS as u128)
+        .ok_or(ArithmeticError::DivisionByZero)?;
+    to_u64(share)
+}
+
+/// `amount * numerator / denominator`, rounded down
+pub fn mul_div(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    let result = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(ArithmeticError::ArithmeticOverflow)?
+        .checked_div(denominator as u128)
+        .ok_or(ArithmeticError::DivisionByZero)?;
+    to_u64(result)
+}
+
+/// Accumulator increase for distributing `amount` over `total_shares`
+pub fn reward_per_share(amount: u64, total_shares: u64) -> Result<u128> {
+    require_gt!(total_shares, 0, VaultError::NoCapitalInVault);
+    let increase = (amount as u128)
+        .checked_mul(REWARD_PRECISION)
+        .ok_or(ArithmeticError::ArithmeticOverflow)?
+        .checked_div(total_shares as u128)
+        .ok_or(ArithmeticError::DivisionByZero)?;
+    Ok(increase)
+}
+
+/// Total rewards earned by `shares` since the accumulator started
+pub fn accumulated_rewards(shares: u64, acc_reward_per_share: u128) -> Result<u128> {
+    let accumulated = (shares as u128)
+        .checked_mul(acc_reward_per_share)
+        .ok_or(ArithmeticError::ArithmeticOverflow)?
+        .checked_div(REWARD_PRECISION)
+        .ok_or(ArithmeticError::DivisionByZero)?;
+    Ok(accumulated)
+}
+
+/// Part of `amount` the accumulator increase for `total_shares` loses to rounding
+pub fn rounding_remainder(amount: u64, total_shares: u64, increase: u128) -> Result<u64> {
+    let distributed = to_u64(accumulated_rewards(total_shares, increase)?)?;
+    let remainder = amount
+        .checked_sub(distributed)
+        .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+    Ok(remainder)
+}
+
+/// Rewards earned by `shares` that are not yet covered by `reward_debt`
+pub fn pending_rewards(shares: u64, acc_reward_per_share: u128, reward_debt: u128) -> Result<u64> {
+    let pending = accumulated_rewards(shares, acc_reward_per_share)?
+        .checked_sub(reward_debt)
+        .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+    to_u64(pending)
+}
+
+/// Part of `unvested` that vests between `last_vested_at` and `now` when vesting
+/// linearly until `vesting_end_at`
+pub fn vested_amount(
+    unvested: u64,
+    last_vested_at: i64,
+    vesting_end_at: i64,
+    now: i64,
+) -> Result<u64> {
+    if now >= vesting_end_at {
+        return Ok(unvested);
+    }
+    if now <= last_vested_at {
+        return Ok(0);
+    }
+    let elapsed = now
+        .checked_sub(last_vested_at)
+        .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+    let remaining = vesting_end_at
+        .checked_sub(last_vested_at)
+        .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+    mul_div(unvested, elapsed as u64, remaining as u64)
+}
+
+pub fn to_u64(amount: u128) -> Result<u64> {
+    Ok(u64::try_from(amount).map_err(|_| ArithmeticError::InvalidCalculation)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bps_share_rounds_down() {
+        assert_eq!(bps_share(1_000, 2_500).unwrap(), 250);
+        assert_eq!(bps_share(999, 1).unwrap(), 0);
+        assert_eq!(bps_share(u64::MAX, BASE_BPS).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn mul_div_checks_the_denominator_and_result() {
+        assert_eq!(mul_div(10, 2, 3).unwrap(), 6);
+        assert_eq!(
+            mul_div(10, 1, 0).unwrap_err(),
+            Error::from(ArithmeticError::DivisionByZero)
+        );
+        assert_eq!(
+            mul_div(u64::MAX, 2, 1).unwrap_err(),
+            Error::from(ArithmeticError::InvalidCalculation)
+        );
+    }
+
+    #[test]
+    fn reward_per_share_requires_shares() {
+        assert_eq!(
+            reward_per_share(100, 0).unwrap_err(),
+            Error::from(VaultError::NoCapitalInVault)
+        );
+        assert_eq!(reward_per_share(100, 4).unwrap(), 25 * REWARD_PRECISION);
+    }
+
+    #[test]
+    fn accumulator_splits_deposits_by_shares() {
+        let increase = reward_per_share(1_000, 3).unwrap();
+        let first = pending_rewards(1, increase, 0).unwrap();
+        let second = pending_rewards(2, increase, 0).unwrap();
+        assert_eq!((first, second), (333, 666));
+        assert_eq!(rounding_remainder(1_000, 3, increase).unwrap(), 1);
+    }
+
+    #[test]
+    fn pending_rewards_subtracts_the_debt() {
+        let acc = reward_per_share(600, 6).unwrap();
+        let debt = accumulated_rewards(2, acc).unwrap();
+        let acc = acc + reward_per_share(300, 6).unwrap();
+        assert_eq!(pending_rewards(2, acc, debt).unwrap(), 100);
+        assert_eq!(
+            pending_rewards(1, acc, debt * 2).unwrap_err(),
+            Error::from(ArithmeticError::ArithmeticUnderflow)
+        );
+    }
+
+    #[test]
+    fn vested_amount_is_linear_until_the_end() {
+        assert_eq!(vested_amount(1_000, 100, 200, 50).unwrap(), 0);
+        assert_eq!(vested_amount(1_000, 100, 200, 100).unwrap(), 0);
+        assert_eq!(vested_amount(1_000, 100, 200, 125).unwrap(), 250);
+        assert_eq!(vested_amount(1_000, 100, 200, 200).unwrap(), 1_000);
+        assert_eq!(vested_amount(1_000, 100, 200, 300).unwrap(), 1_000);
+    }
+}
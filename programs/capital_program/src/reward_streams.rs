@@ -0,0 +1,113 @@
+//! Token accounts for paying out a vault's reward streams.
+//!
+//! Instructions name the accounts of one reward stream explicitly and take any
+//! further streams through `remaining_accounts`, as `[mint, vault_ata, recipient_ata]`
+//! triples.
+use crate::{errors::*, state::Vault};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+pub struct RewardStreamAccounts<'info> {
+    pub stream_index: usize,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub vault_ata: InterfaceAccount<'info, TokenAccount>,
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+}
+
+impl<'info> RewardStreamAccounts<'info> {
+    /// Wraps already validated accounts of the stream paying out `mint`
+    pub fn new(
+        vault: &Vault,
+        mint: &InterfaceAccount<'info, Mint>,
+        vault_ata: &InterfaceAccount<'info, TokenAccount>,
+        recipient_ata: &InterfaceAccount<'info, TokenAccount>,
+    ) -> Result<Self> {
+        Ok(Self {
+            stream_index: vault.reward_stream_index(mint.key())?,
+            mint: mint.clone(),
+            vault_ata: vault_ata.clone(),
+            recipient_ata: recipient_ata.clone(),
+        })
+    }
+
+    /// Parses and validates the `[mint, vault_ata, recipient_ata]` triples
+    pub fn parse_remaining(
+        vault: &Account<'info, Vault>,
+        recipient: Pubkey,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<Vec<Self>> {
+        require!(
+            remaining_accounts.len() % 3 == 0,
+            TokenError::InvalidRewardStreamAccounts
+        );
+
+        let mut streams = Vec::with_capacity(remaining_accounts.len() / 3);
+        for accounts in remaining_accounts.chunks(3) {
+            let mint = InterfaceAccount::<Mint>::try_from(&accounts[0])?;
+            let vault_ata = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+            let recipient_ata = InterfaceAccount::<TokenAccount>::try_from(&accounts[2])?;
+
+            require!(
+                vault_ata.mint == mint.key() && vault_ata.owner == vault.key(),
+                TokenError::InvalidRewardStreamAccounts
+            );
+            require!(
+                recipient_ata.mint == mint.key() && recipient_ata.owner == recipient,
+                TokenError::InvalidRewardStreamAccounts
+            );
+            require!(
+                accounts[1].is_writable && accounts[2].is_writable,
+                TokenError::InvalidRewardStreamAccounts
+            );
+
+            streams.push(Self {
+                stream_index: vault.reward_stream_index(mint.key())?,
+                mint,
+                vault_ata,
+                recipient_ata,
+            });
+        }
+
+        Ok(streams)
+    }
+
+    /// Transfers `amount` of this stream's reward token from the vault to the recipient
+    pub fn transfer_from_vault(
+        &self,
+        vault: &Account<'info, Vault>,
+        token_program: &Interface<'info, TokenInterface>,
+        amount: u64,
+    ) -> Result<()> {
+        require_gte!(
+            self.vault_ata.amount,
+            amount,
+            TokenError::InsufficientVaultBalance
+        );
+
+        let node_operator_key = vault.node_operator.key();
+        let vault_index = vault.vault_index.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Vault",
+            node_operator_key.as_ref(),
+            vault_index.as_ref(),
+            &[vault.bump],
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.vault_ata.to_account_info(),
+            to: self.recipient_ata.to_account_info(),
+            authority: vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+    }
+}
@@ -0,0 +1,149 @@
+//! Execution of a decided slash request.
+//!
+//! A slash is resolved either by the agent or admin through `finalize_slash_req`,
+//! by the arbiter committee through `vote_slash`, or without any decision through
+//! `slash_failed_challenge`. All of them pass their accounts here so that the
+//! bond, capital and payout bookkeeping stays in one place.
+use crate::{
+    errors::*,
+    state::{InsuranceFund, SlashRequest, SlashStatus, Vault, VaultPhase},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    burn, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+pub struct SlashAccounts<'a, 'info> {
+    pub vault: &'a mut Account<'info, Vault>,
+    pub slash_request: &'a mut Account<'info, SlashRequest>,
+    pub locking_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault_token_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    pub slash_escrow_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    pub insurance_fund: Option<&'a mut Account<'info, InsuranceFund>>,
+    pub insurance_fund_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> SlashAccounts<'a, 'info> {
+    /// Closes the slash request, executing `amount` if it was approved or
+    /// rejecting it on `None`, and lets the vault leave the Disputed phase
+    pub fn resolve(mut self, amount: Option<u64>, resolver: Pubkey) -> Result<()> {
+        match amount {
+            Some(amount) => {
+                let bond_slashed = self.process_withdrawal(amount)?;
+                let (insurance_amount, burned_amount, _) = self.slash_request.split(amount)?;
+                self.slash_request.status = SlashStatus::Executed;
+                self.slash_request.executed_amount = amount;
+                self.slash_request.bond_slashed = bond_slashed;
+                self.slash_request.insurance_amount = insurance_amount;
+                self.slash_request.burned_amount = burned_amount;
+            }
+            None => self.slash_request.status = SlashStatus::Rejected,
+        }
+        self.slash_request.resolver = resolver;
+        self.slash_request.resolved_at = Clock::get()?.unix_timestamp;
+
+        // Dispute resolved, resume the lock period (or mature if it elapsed meanwhile)
+        let vault_key = self.vault.key();
+        self.vault.transition_to(vault_key, VaultPhase::Active)?;
+        self.vault.sync_phase(vault_key)?;
+        Ok(())
+    }
+
+    fn process_withdrawal(&mut self, amount: u64) -> Result<u64> {
+        // The operator bond takes the first loss, investors only the rest
+        let bond_slashed = amount.min(self.vault.operator_bond);
+        let capital_slashed = amount - bond_slashed;
+
+        if capital_slashed > 0 {
+            // Calculate new total vault capital
+            let new_slashed_capital = self
+                .vault
+                .capital_after_slashing
+                .checked_sub(capital_slashed)
+                .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+
+            require_gte!(
+                new_slashed_capital,
+                self.vault.min_cap,
+                VaultError::VaultReachedMinCap
+            );
+
+            self.vault.capital_after_slashing = new_slashed_capital;
+        }
+        self.vault.operator_bond -= bond_slashed;
+
+        self.distribute(amount)?;
+
+        Ok(bond_slashed)
+    }
+
+    /// Moves the slashed `amount` out of the vault: the insurance part to the
+    /// insurance fund, the burn part burned and the rest into the claimants' escrow
+    fn distribute(&mut self, amount: u64) -> Result<()> {
+        let (insurance_amount, burned_amount, claimants_amount) =
+            self.slash_request.split(amount)?;
+
+        let node_operator_key = self.vault.node_operator.key();
+        let vault_index = self.vault.vault_index.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"Vault",
+            node_operator_key.as_ref(),
+            vault_index.as_ref(),
+            &[self.vault.bump],
+        ]];
+
+        if insurance_amount > 0 {
+            let (Some(insurance_fund), Some(insurance_fund_ata)) =
+                (&mut self.insurance_fund, &self.insurance_fund_ata)
+            else {
+                return err!(VaultError::InsuranceFundRequired);
+            };
+            insurance_fund.record_deposit(insurance_amount)?;
+
+            let transfer_accounts = TransferChecked {
+                from: self.vault_token_ata.to_account_info(),
+                to: insurance_fund_ata.to_account_info(),
+                authority: self.vault.to_account_info(),
+                mint: self.locking_token_mint.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                transfer_accounts,
+                signer_seeds,
+            );
+            transfer_checked(cpi_ctx, insurance_amount, self.locking_token_mint.decimals)?;
+        }
+
+        if burned_amount > 0 {
+            let burn_accounts = Burn {
+                mint: self.locking_token_mint.to_account_info(),
+                from: self.vault_token_ata.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                burn_accounts,
+                signer_seeds,
+            );
+            burn(cpi_ctx, burned_amount)?;
+        }
+
+        if claimants_amount > 0 {
+            let transfer_accounts = TransferChecked {
+                from: self.vault_token_ata.to_account_info(),
+                to: self.slash_escrow_ata.to_account_info(),
+                authority: self.vault.to_account_info(),
+                mint: self.locking_token_mint.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                transfer_accounts,
+                signer_seeds,
+            );
+            transfer_checked(cpi_ctx, claimants_amount, self.locking_token_mint.decimals)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,33 +1,97 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    APPEAL_WINDOW, BASE_BPS, COMMITMENT_WINDOW, DECISION_WINDOW, DISPUTE_WINDOW, MAX_ARBITERS,
+    MAX_EVIDENCE_URI_LEN, MAX_REWARD_STREAMS, MAX_SLASH_CLAIMANTS, SWEEP_GRACE_PERIOD,
+    WORK_EPOCH_DURATION,
+};
+use crate::errors::*;
+use crate::events::VaultPhaseChangedEvent;
+use crate::reward_math;
+
 #[account]
 #[derive(InitSpace)]
 pub struct AuthorityConfig {
     pub nft_program: Pubkey,
     pub admin: Pubkey,
+    // Admin proposed through propose_admin, default when no transfer is pending
+    pub pending_admin: Pubkey,
     pub agent: Pubkey,
-    pub early_unlock_fee: u64, // in bps to the base 10_000
+    // Optional key that can pause (but not unpause), default when unset
+    pub guardian: Pubkey,
+    // Committee deciding slashes by vote once arbiter_threshold is set,
+    // only the first arbiter_count entries are used
+    pub arbiters: [Pubkey; MAX_ARBITERS],
+    pub arbiter_count: u8,
+    pub arbiter_threshold: u8,
+    // PDA [b"Treasury"] owning the protocol fee token accounts
+    pub treasury: Pubkey,
+    pub early_unlock_fee: u64,       // in bps to the base 10_000
+    pub protocol_fee_bps: u16,       // cut of every reward deposit sent to the treasury
+    pub min_operator_bond_bps: u16,  // of max_cap, posted by the operator at vault creation
+    pub insurance_fee_bps: u16,      // of the protocol fee, routed to the insurance fund
+    pub insurance_coverage_bps: u16, // of a vault's collected capital the insurance fund covers
     pub min_lock_duration: i64,
     pub max_lock_duration: i64,
+    pub paused: bool,
+    pub treasury_bump: u8,
     pub bump: u8,
 }
 
+impl AuthorityConfig {
+    /// True once slashes are decided by the arbiter committee
+    pub fn has_arbiters(&self) -> bool {
+        self.arbiter_threshold > 0
+    }
+
+    pub fn is_arbiter(&self, key: Pubkey) -> bool {
+        self.active_arbiters().contains(&key)
+    }
+
+    pub fn active_arbiters(&self) -> &[Pubkey] {
+        &self.arbiters[..self.arbiter_count as usize]
+    }
+
+    /// Admin can pause and unpause, the guardian can only pause
+    pub fn require_pause_authority(&self, authority: Pubkey, paused: bool) -> Result<()> {
+        let is_admin = authority == self.admin;
+        let is_guardian = self.guardian != Pubkey::default() && authority == self.guardian;
+        require!(
+            is_admin || (paused && is_guardian),
+            SignerError::UnauthorizedPauseAuthority
+        );
+        Ok(())
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
     pub locking_token_mint: Pubkey,
-    pub reward_token_mint: Pubkey,
+    // One entry per reward mint, the first one is set at vault creation
+    #[max_len(MAX_REWARD_STREAMS)]
+    pub reward_streams: Vec<RewardStream>,
 
     pub min_cap: u64,
     pub max_cap: u64,
     pub min_lock_amount: u64,
-    pub total_rewards_deposited: u64,
     pub total_capital_collected: u64,
     pub capital_after_slashing: u64,
+    // Operator first-loss bond in the locking token, kept outside the investor capital
+    pub operator_bond: u64,
+    // Slashed principal already covered by the insurance fund
+    pub insurance_claimed: u64,
+    // Principal already paid back to closed positions
+    pub capital_withdrawn: u64,
+    // Slashed for failed work challenges, at most max_slash_bps of the collected capital
+    pub challenge_slashed: u64,
 
-    #[max_len(5)]
-    pub beneficiaries: Vec<Beneficiary>,
+    // Beneficiaries live in their own BeneficiaryAccount PDAs
+    pub beneficiary_count: u32,
+    pub total_beneficiary_bps: u16,
     pub investor_bps: u16,
+    // Whatever investors and beneficiaries don't get, all three sum to BASE_BPS
+    pub operator_commission_bps: u16,
 
     pub max_slash_bps: u16,
     pub nft_collection: Pubkey,
@@ -37,19 +101,934 @@ pub struct Vault {
 
     pub lock_phase_start_at: i64,
     pub lock_phase_duration: i64,
-    // Slashing
-    pub is_dispute_active: bool,
-    pub dispute_start_time: i64,
-    pub pending_slash_amount: u64,
-    pub slash_claimant: Pubkey,
+    // Index of this vault among the node operator's vaults, part of the PDA seeds
+    pub vault_index: u64,
+    pub phase: VaultPhase,
+    pub paused: bool,
+    // Residual balances were moved to the treasury, nothing is paid out any more
+    pub swept: bool,
+    // Slashing, the open SlashRequest is the last one while Disputed
+    pub slash_request_count: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum VaultPhase {
+    /// Accepting capital until `lock_phase_start_at`
+    Formation,
+    /// Capital locked, rewards flowing
+    Active,
+    /// Active with a pending slash request
+    Disputed,
+    /// Lock period over, principal can be withdrawn
+    Matured,
+    /// Lock phase started below `min_cap`, principal can be withdrawn
+    Failed,
+    /// Vault accounts closed by the node operator
+    Closed,
+}
+
+impl Vault {
+    pub fn lock_phase_end_at(&self) -> Result<i64> {
+        let lock_ends_at = self
+            .lock_phase_start_at
+            .checked_add(self.lock_phase_duration)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(lock_ends_at)
+    }
+
+    /// Phase the vault should be in at `now`, following the time-driven transitions
+    /// Formation -> Active | Failed and Active -> Matured.
+    /// Disputed and Closed are only entered and left through explicit transitions.
+    pub fn next_phase(&self, now: i64) -> Result<VaultPhase> {
+        let lock_ends_at = self.lock_phase_end_at()?;
+        let next_phase = match self.phase {
+            VaultPhase::Formation if now >= self.lock_phase_start_at => {
+                if self.total_capital_collected < self.min_cap {
+                    VaultPhase::Failed
+                } else if now >= lock_ends_at {
+                    VaultPhase::Matured
+                } else {
+                    VaultPhase::Active
+                }
+            }
+            VaultPhase::Active if now >= lock_ends_at => VaultPhase::Matured,
+            phase => phase,
+        };
+        Ok(next_phase)
+    }
+
+    /// Moves the vault into `phase`, emitting a VaultPhaseChangedEvent if it changed
+    pub fn transition_to(&mut self, vault: Pubkey, phase: VaultPhase) -> Result<()> {
+        if self.phase == phase {
+            return Ok(());
+        }
+        let previous_phase = self.phase;
+        self.phase = phase;
+
+        emit!(VaultPhaseChangedEvent {
+            vault,
+            previous_phase,
+            new_phase: phase,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Applies any pending time-driven transition and returns the current phase
+    pub fn sync_phase(&mut self, vault: Pubkey) -> Result<VaultPhase> {
+        let clock = Clock::get()?;
+        let next_phase = self.next_phase(clock.unix_timestamp)?;
+        self.transition_to(vault, next_phase)?;
+        Ok(self.phase)
+    }
+
+    /// End of work epoch `epoch`, epochs of WORK_EPOCH_DURATION run back to back
+    /// from the lock phase start
+    pub fn epoch_end_at(&self, epoch: u64) -> Result<i64> {
+        let epochs = i64::try_from(epoch)
+            .ok()
+            .and_then(|epoch| epoch.checked_add(1))
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        let epoch_end_at = epochs
+            .checked_mul(WORK_EPOCH_DURATION)
+            .and_then(|duration| self.lock_phase_start_at.checked_add(duration))
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(epoch_end_at)
+    }
+
+    /// Last moment the Merkle root of `epoch` can be posted, the epoch becomes
+    /// slashable without one afterwards
+    pub fn commitment_deadline(&self, epoch: u64) -> Result<i64> {
+        let deadline = self
+            .epoch_end_at(epoch)?
+            .checked_add(COMMITMENT_WINDOW)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(deadline)
+    }
+
+    /// Fails unless `epoch` is a full epoch of the lock phase that ended by `now`
+    pub fn require_epoch_ended(&self, epoch: u64, now: i64) -> Result<()> {
+        let epoch_end_at = self.epoch_end_at(epoch)?;
+        require_gte!(
+            self.lock_phase_end_at()?,
+            epoch_end_at,
+            ChallengeError::InvalidEpoch
+        );
+        require_gte!(now, epoch_end_at, ChallengeError::EpochNotEnded);
+        Ok(())
+    }
+
+    /// What failed work challenges can still slash, the vault's max_slash_bps
+    /// caps all of them together
+    pub fn challenge_slash_allowance(&self) -> Result<u64> {
+        let limit = reward_math::bps_share(self.total_capital_collected, self.max_slash_bps)?;
+        Ok(limit.saturating_sub(self.challenge_slashed))
+    }
+
+    /// Fails once the vault's balances were swept to the treasury, claims
+    /// would otherwise fail inside the token transfer
+    pub fn require_not_swept(&self) -> Result<()> {
+        require!(!self.swept, PhaseError::VaultSwept);
+        Ok(())
+    }
+
+    /// Fails if either the whole protocol or this vault is paused
+    pub fn require_not_paused(&self, config: &AuthorityConfig) -> Result<()> {
+        require!(!config.paused, VaultError::ProtocolPaused);
+        require!(!self.paused, VaultError::VaultPaused);
+        Ok(())
+    }
+
+    /// Index of the reward stream paying out `mint`
+    pub fn reward_stream_index(&self, mint: Pubkey) -> Result<usize> {
+        let index = self
+            .reward_streams
+            .iter()
+            .position(|stream| stream.mint == mint)
+            .ok_or(TokenError::RewardStreamNotFound)?;
+        Ok(index)
+    }
+
+    /// Registers a new reward mint and returns its stream index
+    pub fn add_reward_stream(&mut self, mint: Pubkey) -> Result<usize> {
+        require!(
+            self.reward_streams.iter().all(|stream| stream.mint != mint),
+            TokenError::RewardStreamAlreadyExists
+        );
+        require_gt!(
+            MAX_REWARD_STREAMS,
+            self.reward_streams.len(),
+            TokenError::TooManyRewardStreams
+        );
+
+        self.reward_streams.push(RewardStream {
+            mint,
+            total_deposited: 0,
+            total_claimed: 0,
+            acc_reward_per_share: 0,
+            acc_reward_per_bps: 0,
+            unvested_rewards: 0,
+            last_vested_at: 0,
+            vesting_end_at: 0,
+            operator_reward_debt: 0,
+            operator_accrued: 0,
+            rounding_remainder: 0,
+        });
+        Ok(self.reward_streams.len() - 1)
+    }
+
+    /// Checks rewards can be paid into the stream of `mint` and returns its index.
+    /// Rewards only flow once the vault reached min_cap and locked.
+    pub fn require_accepting_rewards(&mut self, vault: Pubkey, mint: Pubkey) -> Result<usize> {
+        let phase = self.sync_phase(vault)?;
+        require!(phase != VaultPhase::Failed, VaultError::BelowMinCap);
+        self.require_phase(&[VaultPhase::Active, VaultPhase::Matured])?;
+
+        self.reward_stream_index(mint)
+    }
+
+    /// Splits `amount` of fresh rewards between investors, beneficiaries and the
+    /// node operator commission
+    ///
+    /// With a `stream_duration` the investor share vests linearly over that many
+    /// seconds instead of being claimable right away. Rewards still streaming from
+    /// earlier deposits are spread until the later of the two end times.
+    /// Returns the new total deposited into the stream
+    pub fn record_rewards(
+        &mut self,
+        stream_index: usize,
+        amount: u64,
+        stream_duration: Option<i64>,
+    ) -> Result<u64> {
+        // Settle what already vested before changing the schedule
+        self.vest_rewards()?;
+
+        let investor_rewards = reward_math::bps_share(amount, self.investor_bps)?;
+        // Beneficiaries and the operator are paid per basis point, never streamed
+        let reward_per_bps = reward_math::reward_per_share(amount, BASE_BPS as u64)?;
+
+        let vesting_end_at = match stream_duration {
+            Some(duration) => {
+                require_gt!(duration, 0, VaultError::InvalidStreamDuration);
+                let now = Clock::get()?.unix_timestamp;
+                now.checked_add(duration)
+                    .ok_or(ArithmeticError::ArithmeticOverflow)?
+                    .min(self.lock_phase_end_at()?)
+            }
+            None => 0,
+        };
+
+        // Spread the investor share over the capital locked in the vault
+        let reward_per_share = if vesting_end_at > 0 {
+            0
+        } else {
+            reward_math::reward_per_share(investor_rewards, self.total_capital_collected)?
+        };
+
+        // Whatever the accumulators can't pay out stays in the vault as dust
+        let mut rounding_remainder = reward_math::rounding_remainder(
+            amount
+                .checked_sub(investor_rewards)
+                .ok_or(ArithmeticError::ArithmeticUnderflow)?,
+            BASE_BPS
+                .checked_sub(self.investor_bps)
+                .ok_or(ArithmeticError::ArithmeticUnderflow)? as u64,
+            reward_per_bps,
+        )?;
+        if vesting_end_at == 0 {
+            rounding_remainder = rounding_remainder
+                .checked_add(reward_math::rounding_remainder(
+                    investor_rewards,
+                    self.total_capital_collected,
+                    reward_per_share,
+                )?)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        }
+
+        let stream = &mut self.reward_streams[stream_index];
+        stream.total_deposited = stream
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        stream.acc_reward_per_share = stream
+            .acc_reward_per_share
+            .checked_add(reward_per_share)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        stream.acc_reward_per_bps = stream
+            .acc_reward_per_bps
+            .checked_add(reward_per_bps)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        stream.rounding_remainder = stream
+            .rounding_remainder
+            .checked_add(rounding_remainder)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+        if vesting_end_at > 0 {
+            stream.unvested_rewards = stream
+                .unvested_rewards
+                .checked_add(investor_rewards)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+            stream.vesting_end_at = stream.vesting_end_at.max(vesting_end_at);
+        }
+
+        Ok(stream.total_deposited)
+    }
+
+    /// Adds the streamed investor rewards vested since the last update to the accumulators.
+    /// Streams never vest past the lock period, whatever is left vests at maturity.
+    pub fn vest_rewards(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let lock_ends_at = self.lock_phase_end_at()?;
+        let total_capital_collected = self.total_capital_collected;
+
+        for stream in self.reward_streams.iter_mut() {
+            if stream.unvested_rewards > 0 {
+                let vested = reward_math::vested_amount(
+                    stream.unvested_rewards,
+                    stream.last_vested_at,
+                    stream.vesting_end_at.min(lock_ends_at),
+                    now,
+                )?;
+                if vested > 0 {
+                    let reward_per_share =
+                        reward_math::reward_per_share(vested, total_capital_collected)?;
+                    stream.acc_reward_per_share = stream
+                        .acc_reward_per_share
+                        .checked_add(reward_per_share)
+                        .ok_or(ArithmeticError::ArithmeticOverflow)?;
+                    stream.rounding_remainder = stream
+                        .rounding_remainder
+                        .checked_add(reward_math::rounding_remainder(
+                            vested,
+                            total_capital_collected,
+                            reward_per_share,
+                        )?)
+                        .ok_or(ArithmeticError::ArithmeticOverflow)?;
+                    stream.unvested_rewards = stream
+                        .unvested_rewards
+                        .checked_sub(vested)
+                        .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+                }
+            }
+            stream.last_vested_at = now;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the commission earned under the current operator share into
+    /// each stream's `operator_accrued`
+    pub fn settle_operator_commission(&mut self) -> Result<()> {
+        let commission_bps = self.operator_commission_bps as u64;
+        for stream in self.reward_streams.iter_mut() {
+            let pending = reward_math::pending_rewards(
+                commission_bps,
+                stream.acc_reward_per_bps,
+                stream.operator_reward_debt,
+            )?;
+            stream.operator_accrued = stream
+                .operator_accrued
+                .checked_add(pending)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+            stream.operator_reward_debt = stream
+                .operator_reward_debt
+                .checked_add(pending as u128)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Marks the operator commission of `stream_index` as claimed and returns it
+    pub fn take_operator_commission(&mut self, stream_index: usize) -> Result<u64> {
+        self.settle_operator_commission()?;
+
+        let stream = &mut self.reward_streams[stream_index];
+        let commission = stream.operator_accrued;
+        stream.operator_accrued = 0;
+        stream.total_claimed = stream
+            .total_claimed
+            .checked_add(commission)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(commission)
+    }
+
+    /// Moves a beneficiary from `old_share_bps` to `new_share_bps`, 0 meaning no
+    /// share, keeping the beneficiary totals in sync.
+    /// Beneficiary shares are carved out of the operator commission.
+    pub fn reallocate_beneficiary_bps(
+        &mut self,
+        old_share_bps: u16,
+        new_share_bps: u16,
+    ) -> Result<()> {
+        let available_bps = self
+            .operator_commission_bps
+            .checked_add(old_share_bps)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        require_gte!(available_bps, new_share_bps, VaultError::BPSExceedsMaximum);
+
+        // Settle the commission earned so far before its share changes
+        self.settle_operator_commission()?;
+        self.operator_commission_bps = available_bps - new_share_bps;
+        for stream in self.reward_streams.iter_mut() {
+            stream.operator_reward_debt = reward_math::accumulated_rewards(
+                self.operator_commission_bps as u64,
+                stream.acc_reward_per_bps,
+            )?;
+        }
+
+        self.total_beneficiary_bps = self
+            .total_beneficiary_bps
+            .checked_sub(old_share_bps)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?
+            .checked_add(new_share_bps)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+
+        if old_share_bps == 0 {
+            self.beneficiary_count = self
+                .beneficiary_count
+                .checked_add(1)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        } else if new_share_bps == 0 {
+            self.beneficiary_count = self
+                .beneficiary_count
+                .checked_sub(1)
+                .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        }
+        Ok(())
+    }
+
+    /// Time from which residual balances can be swept: the grace period after
+    /// maturity, or after the lock phase start for a failed vault
+    pub fn sweep_available_at(&self) -> Result<i64> {
+        let ended_at = match self.phase {
+            VaultPhase::Failed => self.lock_phase_start_at,
+            _ => self.lock_phase_end_at()?,
+        };
+        let sweep_available_at = ended_at
+            .checked_add(SWEEP_GRACE_PERIOD)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(sweep_available_at)
+    }
+
+    /// Locking tokens in a vault balance of `vault_lock_balance` that nobody is
+    /// owed: what is left above the principal of the open positions and the
+    /// operator bond
+    pub fn lock_residue(&self, vault_lock_balance: u64) -> u64 {
+        let principal_owed = self
+            .capital_after_slashing
+            .saturating_sub(self.capital_withdrawn);
+        vault_lock_balance
+            .saturating_sub(principal_owed)
+            .saturating_sub(self.operator_bond)
+    }
+
+    /// Insurance coverage for a position of `total_value_locked` that gets back
+    /// `position_capital` after slashing: its pro-rata part of the vault's
+    /// coverage cap, limited to its loss and to what the vault has left
+    pub fn insurance_coverage(
+        &self,
+        total_value_locked: u64,
+        position_capital: u64,
+        coverage_bps: u16,
+    ) -> Result<u64> {
+        let loss = total_value_locked.saturating_sub(position_capital);
+        if loss == 0 {
+            return Ok(0);
+        }
+
+        let vault_cap = reward_math::bps_share(self.total_capital_collected, coverage_bps)?;
+        let position_cap =
+            reward_math::mul_div(vault_cap, total_value_locked, self.total_capital_collected)?;
+        let cap_left = vault_cap.saturating_sub(self.insurance_claimed);
+        Ok(loss.min(position_cap).min(cap_left))
+    }
+
+    /// Fails with InvalidPhase unless the vault is in one of `phases`
+    pub fn require_phase(&self, phases: &[VaultPhase]) -> Result<()> {
+        require!(phases.contains(&self.phase), PhaseError::InvalidPhase);
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DepositorRole {
+    /// The global agent from AuthorityConfig
+    Agent,
+    /// The vault's own reward_distributor
+    RewardDistributor,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RewardStream {
+    pub mint: Pubkey,
+    pub total_deposited: u64,
+    pub total_claimed: u64,
+    // Investor rewards per unit of locked capital, scaled by REWARD_PRECISION
+    pub acc_reward_per_share: u128,
+    // Rewards per beneficiary basis point, scaled by REWARD_PRECISION
+    pub acc_reward_per_bps: u128,
+    // Streamed investor rewards not yet added to acc_reward_per_share
+    pub unvested_rewards: u64,
+    pub last_vested_at: i64,
+    pub vesting_end_at: i64,
+    // Operator commission already moved into operator_accrued, scaled like accumulated_rewards
+    pub operator_reward_debt: u128,
+    // Settled operator commission not yet claimed
+    pub operator_accrued: u64,
+    // Deposits lost to rounding in the accumulators, left in the vault until swept
+    pub rounding_remainder: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorRegistry {
+    pub node_operator: Pubkey,
+    pub vault_count: u64,
+    pub bump: u8,
+}
+
+/// One slash against a vault, PDA [b"SlashRequest", vault, sequence].
+/// Kept after it is resolved as the audit trail of the vault's slashes.
+#[account]
+#[derive(InitSpace)]
+pub struct SlashRequest {
+    pub vault: Pubkey,
+    pub sequence: u64,
+    // Agent that raised the request
+    pub requester: Pubkey,
+    // Split of the executed slash, claimant shares plus insurance_bps and burn_bps
+    // sum to BASE_BPS
+    #[max_len(MAX_SLASH_CLAIMANTS)]
+    pub claimants: Vec<SlashClaimant>,
+    pub insurance_bps: u16,
+    pub burn_bps: u16,
+    pub slash_bps: u16,
+    pub requested_amount: u64,
+    pub evidence_hash: [u8; 32],
+    #[max_len(MAX_EVIDENCE_URI_LEN)]
+    pub evidence_uri: String,
+    // Failing attestation backing the request, default when it rests on the agent's word
+    pub attestation: Pubkey,
+    // Failed work challenge the slash was executed for, default otherwise
+    pub challenge: Pubkey,
+    pub status: SlashStatus,
+    // Node operator's counter-evidence, zero unless appealed
+    pub appeal_evidence_hash: [u8; 32],
+    // Who resolved the request, default until then
+    pub resolver: Pubkey,
+    pub executed_amount: u64,
+    // Part of executed_amount taken from the operator bond
+    pub bond_slashed: u64,
+    // Parts of executed_amount sent to the insurance fund and burned,
+    // the rest is held for the claimants by the request's token account
+    pub insurance_amount: u64,
+    pub burned_amount: u64,
+    // Arbiter votes, only used once the committee is in place. Only votes of
+    // current arbiters count, the smallest approved amount is executed
+    #[max_len(MAX_ARBITERS)]
+    pub approvals: Vec<ArbiterApproval>,
+    #[max_len(MAX_ARBITERS)]
+    pub rejections: Vec<Pubkey>,
+    pub created_at: i64,
+    pub appealed_at: i64,
+    pub resolved_at: i64,
     pub bump: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
-pub struct Beneficiary {
+pub struct SlashClaimant {
     pub address: Pubkey,
     pub share_bps: u16,
-    pub total_claimed: u64,
+    pub claimed: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ArbiterApproval {
+    pub arbiter: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlashDecision {
+    Undecided,
+    Execute(u64),
+    Reject,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SlashStatus {
+    /// Waiting for the agent's (or the arbiters') decision
+    Pending,
+    /// Contested by the node operator, waiting for the admin's (or the arbiters') decision
+    Appealed,
+    /// Slash taken from the vault, claimants can collect their shares
+    Executed,
+    /// Resolved without a payout
+    Rejected,
+    /// Nobody decided before the deadline, dismissed by anyone
+    Dismissed,
+}
+
+impl SlashRequest {
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, SlashStatus::Pending | SlashStatus::Appealed)
+    }
+
+    /// Who decides the request: the agent, or the admin once it was appealed
+    pub fn resolver_authority(&self, config: &AuthorityConfig) -> Pubkey {
+        if self.status == SlashStatus::Appealed {
+            config.admin
+        } else {
+            config.agent
+        }
+    }
+
+    /// Splits an executed `amount` into (insurance, burn, claimants) parts
+    pub fn split(&self, amount: u64) -> Result<(u64, u64, u64)> {
+        let insurance_amount = reward_math::bps_share(amount, self.insurance_bps)?;
+        let burned_amount = reward_math::bps_share(amount, self.burn_bps)?;
+        let claimants_amount = amount
+            .checked_sub(insurance_amount)
+            .and_then(|amount| amount.checked_sub(burned_amount))
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        Ok((insurance_amount, burned_amount, claimants_amount))
+    }
+
+    /// Index of `address` among the claimants
+    pub fn claimant_index(&self, address: Pubkey) -> Result<usize> {
+        let index = self
+            .claimants
+            .iter()
+            .position(|claimant| claimant.address == address)
+            .ok_or(VaultError::SlashClaimantNotFound)?;
+        Ok(index)
+    }
+
+    /// Marks the claimant at `index` as paid and returns its share of the executed slash
+    pub fn take_payout(&mut self, index: usize) -> Result<u64> {
+        let claimant = &mut self.claimants[index];
+        require!(!claimant.claimed, VaultError::SlashPayoutAlreadyClaimed);
+        claimant.claimed = true;
+        reward_math::bps_share(self.executed_amount, claimant.share_bps)
+    }
+
+    /// Records `arbiter`'s vote, an approval for `amount` of the requested slash
+    pub fn record_vote(&mut self, arbiter: Pubkey, approve: bool, amount: u64) -> Result<()> {
+        require!(
+            self.approvals.iter().all(|vote| vote.arbiter != arbiter)
+                && !self.rejections.contains(&arbiter),
+            SignerError::ArbiterAlreadyVoted
+        );
+
+        if approve {
+            require!(
+                amount > 0 && amount <= self.requested_amount,
+                VaultError::InvalidSlashAmount
+            );
+            self.approvals.push(ArbiterApproval { arbiter, amount });
+        } else {
+            self.rejections.push(arbiter);
+        }
+        Ok(())
+    }
+
+    /// Votes of the current committee as (approvals, rejections, smallest approved
+    /// amount). Votes of arbiters removed since they voted don't count
+    pub fn committee_votes(&self, arbiters: &[Pubkey]) -> (usize, usize, u64) {
+        let approved_amounts = self
+            .approvals
+            .iter()
+            .filter(|vote| arbiters.contains(&vote.arbiter))
+            .map(|vote| vote.amount);
+        let approvals = approved_amounts.clone().count();
+        let approved_amount = approved_amounts.min().unwrap_or(0);
+        let rejections = self
+            .rejections
+            .iter()
+            .filter(|arbiter| arbiters.contains(arbiter))
+            .count();
+        (approvals, rejections, approved_amount)
+    }
+
+    /// Committee decision once `threshold` approvals are in, or once enough
+    /// arbiters rejected that the threshold can no longer be reached
+    pub fn committee_decision(&self, arbiters: &[Pubkey], threshold: u8) -> SlashDecision {
+        let (approvals, rejections, approved_amount) = self.committee_votes(arbiters);
+        let threshold = threshold as usize;
+        if approvals >= threshold {
+            SlashDecision::Execute(approved_amount)
+        } else if rejections > arbiters.len().saturating_sub(threshold) {
+            SlashDecision::Reject
+        } else {
+            SlashDecision::Undecided
+        }
+    }
+
+    pub fn appeal_deadline(&self) -> Result<i64> {
+        let deadline = self
+            .created_at
+            .checked_add(DISPUTE_WINDOW)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(deadline)
+    }
+
+    /// Last moment a slash can still be executed, an appeal gives the admin extra time
+    pub fn decision_deadline(&self) -> Result<i64> {
+        let window = if self.status == SlashStatus::Appealed {
+            APPEAL_WINDOW
+        } else {
+            DECISION_WINDOW
+        };
+        let deadline = self
+            .appeal_deadline()?
+            .checked_add(window)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(deadline)
+    }
+
+    /// A pending slash can only be executed once the node operator's appeal
+    /// window closed, an appealed one right away
+    pub fn require_appeal_window_closed(&self, now: i64) -> Result<()> {
+        if self.status == SlashStatus::Pending {
+            require_gte!(now, self.appeal_deadline()?, VaultError::AppealWindowOpen);
+        }
+        Ok(())
+    }
+}
+
+/// Protocol-wide insurance for one mint, PDA [b"Insurance", mint].
+/// Its associated token account holds the funds.
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceFund {
+    pub mint: Pubkey,
+    pub total_deposited: u64,
+    pub total_paid: u64,
+    // Taken out by the admin, see withdraw_insurance
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl InsuranceFund {
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid = self
+            .total_paid
+            .checked_add(amount)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Key allowed to sign performance reports, PDA [b"Attester", key].
+/// Registered by the admin, usually the reporting service of one DePIN network.
+#[account]
+#[derive(InitSpace)]
+pub struct Attester {
+    pub key: Pubkey,
+    // Reports below this uptime are failing and can back a slash request
+    pub min_uptime_bps: u16,
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+/// Signed performance report of a vault for one epoch,
+/// PDA [b"Attestation", vault, attester, epoch]
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub vault: Pubkey,
+    pub attester: Pubkey,
+    pub epoch: u64,
+    pub uptime_bps: u16,
+    pub work_units: u64,
+    // Uptime below the attester's minimum when it was submitted
+    pub failed: bool,
+    // Set once a slash request referenced it, an attestation backs one slash only
+    pub slash_request: Pubkey,
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
+impl Attestation {
+    /// Message the attester signs: vault, epoch, uptime_bps and work_units,
+    /// integers little endian
+    pub fn message(vault: &Pubkey, epoch: u64, uptime_bps: u16, work_units: u64) -> Vec<u8> {
+        let mut message = Vec::with_capacity(50);
+        message.extend_from_slice(vault.as_ref());
+        message.extend_from_slice(&epoch.to_le_bytes());
+        message.extend_from_slice(&uptime_bps.to_le_bytes());
+        message.extend_from_slice(&work_units.to_le_bytes());
+        message
+    }
+}
+
+/// Merkle root of the work a vault's operator did in one epoch,
+/// PDA [b"Commitment", vault, epoch]
+#[account]
+#[derive(InitSpace)]
+pub struct EpochCommitment {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub leaf_count: u64,
+    pub committed_at: i64,
+    pub bump: u8,
+}
+
+/// Leaf of an epoch commitment the operator has to prove before the deadline,
+/// PDA [b"Challenge", commitment, leaf_index]
+#[account]
+#[derive(InitSpace)]
+pub struct Challenge {
+    pub vault: Pubkey,
+    pub commitment: Pubkey,
+    pub leaf_index: u64,
+    // Agent that issued the challenge
+    pub challenger: Pubkey,
+    // Slash executed without further decision if the challenge fails,
+    // split like a slash request
+    pub slash_bps: u16,
+    #[max_len(MAX_SLASH_CLAIMANTS)]
+    pub claimants: Vec<SlashClaimant>,
+    pub insurance_bps: u16,
+    pub burn_bps: u16,
+    pub status: ChallengeStatus,
+    pub issued_at: i64,
+    pub deadline: i64,
+    pub answered_at: i64,
+    // Slash request executed for the failed challenge, default until then
+    pub slash_request: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ChallengeStatus {
+    /// Waiting for the operator's Merkle proof
+    Open,
+    /// Proven in time
+    Answered,
+    /// Answered with an invalid proof
+    Failed,
+    /// Failed or unanswered, and the vault slashed for it
+    Slashed,
+}
+
+impl Challenge {
+    /// A challenge still open after its deadline failed as well
+    pub fn is_failed(&self, now: i64) -> bool {
+        match self.status {
+            ChallengeStatus::Failed => true,
+            ChallengeStatus::Open => now >= self.deadline,
+            ChallengeStatus::Answered | ChallengeStatus::Slashed => false,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BeneficiaryAccount {
+    pub vault: Pubkey,
+    pub address: Pubkey,
+    pub share_bps: u16,
+    // Per reward stream, indexed like Vault.reward_streams:
+    // rewards already moved into `accrued`, scaled like accumulated_rewards
+    pub reward_debts: [u128; MAX_REWARD_STREAMS],
+    // settled but not yet claimed, survives share changes
+    pub accrued: [u64; MAX_REWARD_STREAMS],
+    pub total_claimed: [u64; MAX_REWARD_STREAMS],
+    // Optional vesting of everything the share earns, vesting_end_at is 0 when unvested
+    pub vesting_start_at: i64,
+    pub cliff_at: i64,
+    pub vesting_end_at: i64,
+    pub bump: u8,
+}
+
+impl BeneficiaryAccount {
+    /// Moves the rewards earned under the current share into `accrued`
+    pub fn settle(&mut self, streams: &[RewardStream]) -> Result<()> {
+        for (index, stream) in streams.iter().enumerate() {
+            let pending = reward_math::pending_rewards(
+                self.share_bps as u64,
+                stream.acc_reward_per_bps,
+                self.reward_debts[index],
+            )?;
+            self.accrued[index] = self.accrued[index]
+                .checked_add(pending)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+            self.reward_debts[index] = self.reward_debts[index]
+                .checked_add(pending as u128)
+                .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Sets every stream's debt to the rewards the current share has already earned
+    pub fn rebase_reward_debts(&mut self, streams: &[RewardStream]) -> Result<()> {
+        for (index, stream) in streams.iter().enumerate() {
+            self.reward_debts[index] =
+                reward_math::accumulated_rewards(self.share_bps as u64, stream.acc_reward_per_bps)?;
+        }
+        Ok(())
+    }
+
+    /// Part of `amount` vested at `now`: nothing before the cliff, then linear
+    /// from `vesting_start_at` until `vesting_end_at`
+    pub fn vested_amount(&self, amount: u64, now: i64) -> Result<u64> {
+        if self.vesting_end_at == 0 {
+            return Ok(amount);
+        }
+        if now < self.cliff_at {
+            return Ok(0);
+        }
+        reward_math::vested_amount(amount, self.vesting_start_at, self.vesting_end_at, now)
+    }
+
+    /// Returns (claimable, unvested) rewards of `stream_index` at `now`
+    pub fn claimable(&self, stream_index: usize, now: i64) -> Result<(u64, u64)> {
+        let earned = self.total_claimed[stream_index]
+            .checked_add(self.accrued[stream_index])
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        let vested = self.vested_amount(earned, now)?;
+
+        let claimable = vested
+            .checked_sub(self.total_claimed[stream_index])
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        let unvested = earned
+            .checked_sub(vested)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        Ok((claimable, unvested))
+    }
+
+    /// Marks the vested rewards of `stream_index` as claimed.
+    /// Returns (claimed, unvested)
+    pub fn take_vested(&mut self, stream_index: usize, now: i64) -> Result<(u64, u64)> {
+        let (claimable, unvested) = self.claimable(stream_index, now)?;
+        self.accrued[stream_index] = self.accrued[stream_index]
+            .checked_sub(claimable)
+            .ok_or(ArithmeticError::ArithmeticUnderflow)?;
+        self.total_claimed[stream_index] = self.total_claimed[stream_index]
+            .checked_add(claimable)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok((claimable, unvested))
+    }
 }
 
 #[derive(InitSpace)]
@@ -58,6 +1037,473 @@ pub struct Position {
     pub vault: Pubkey,
     pub asset: Pubkey,
     pub total_value_locked: u64,
-    pub total_rewards_claimed: u64,
+    // Claimed amount per reward stream, indexed like Vault.reward_streams
+    pub rewards_claimed: [u64; MAX_REWARD_STREAMS],
+    // Investor rewards already settled per reward stream, in reward token units
+    pub reward_debts: [u128; MAX_REWARD_STREAMS],
     pub bump: u8,
 }
+
+impl Position {
+    /// Investor rewards of `stream_index` not yet paid to this position
+    pub fn pending_rewards(&self, vault: &Vault, stream_index: usize) -> Result<u64> {
+        reward_math::pending_rewards(
+            self.total_value_locked,
+            vault.reward_streams[stream_index].acc_reward_per_share,
+            self.reward_debts[stream_index],
+        )
+    }
+
+    /// Records `amount` of `stream_index` as paid to this position
+    pub fn settle_rewards(&mut self, stream_index: usize, amount: u64) -> Result<()> {
+        self.reward_debts[stream_index] = self.reward_debts[stream_index]
+            .checked_add(amount as u128)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        self.rewards_claimed[stream_index] = self.rewards_claimed[stream_index]
+            .checked_add(amount)
+            .ok_or(ArithmeticError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Sets every stream's debt to the rewards the current capital has already accrued
+    pub fn rebase_reward_debts(&mut self, vault: &Vault) -> Result<()> {
+        for (index, stream) in vault.reward_streams.iter().enumerate() {
+            self.reward_debts[index] = reward_math::accumulated_rewards(
+                self.total_value_locked,
+                stream.acc_reward_per_share,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// True once every stream's rewards have been paid out
+    pub fn rewards_settled(&self, vault: &Vault) -> Result<bool> {
+        for index in 0..vault.reward_streams.len() {
+            if self.pending_rewards(vault, index)? > 0 {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use std::cell::Cell;
+    use std::sync::Once;
+
+    const LOCK_START: i64 = 1_000;
+    const LOCK_DURATION: i64 = 4 * WORK_EPOCH_DURATION;
+
+    thread_local! {
+        static NOW: Cell<i64> = const { Cell::new(0) };
+    }
+
+    /// Serves the Clock sysvar from the test thread's NOW
+    struct TestClock;
+
+    impl SyscallStubs for TestClock {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: NOW.with(Cell::get),
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            0
+        }
+    }
+
+    fn set_now(now: i64) {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(TestClock));
+        });
+        NOW.with(|cell| cell.set(now));
+    }
+
+    /// Active vault of 1_000 collected, locked from LOCK_START for four epochs
+    fn vault_fixture() -> Vault {
+        Vault {
+            locking_token_mint: Pubkey::new_unique(),
+            reward_streams: Vec::new(),
+            min_cap: 1_000,
+            max_cap: 10_000,
+            min_lock_amount: 100,
+            total_capital_collected: 1_000,
+            capital_after_slashing: 1_000,
+            operator_bond: 0,
+            insurance_claimed: 0,
+            capital_withdrawn: 0,
+            challenge_slashed: 0,
+            beneficiary_count: 0,
+            total_beneficiary_bps: 0,
+            investor_bps: 8_000,
+            operator_commission_bps: 2_000,
+            max_slash_bps: 1_000,
+            nft_collection: Pubkey::new_unique(),
+            reward_distributor: Pubkey::new_unique(),
+            node_operator: Pubkey::new_unique(),
+            lock_phase_start_at: LOCK_START,
+            lock_phase_duration: LOCK_DURATION,
+            vault_index: 0,
+            phase: VaultPhase::Active,
+            paused: false,
+            swept: false,
+            slash_request_count: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn formation_waits_for_the_lock_start() {
+        let mut vault = vault_fixture();
+        vault.phase = VaultPhase::Formation;
+        assert_eq!(
+            vault.next_phase(LOCK_START - 1).unwrap(),
+            VaultPhase::Formation
+        );
+        assert_eq!(vault.next_phase(LOCK_START).unwrap(), VaultPhase::Active);
+    }
+
+    #[test]
+    fn formation_below_min_cap_fails() {
+        let mut vault = vault_fixture();
+        vault.phase = VaultPhase::Formation;
+        vault.total_capital_collected = vault.min_cap - 1;
+        assert_eq!(vault.next_phase(LOCK_START).unwrap(), VaultPhase::Failed);
+    }
+
+    #[test]
+    fn lock_end_matures_the_vault() {
+        let lock_end = LOCK_START + LOCK_DURATION;
+        let mut vault = vault_fixture();
+        assert_eq!(vault.next_phase(lock_end - 1).unwrap(), VaultPhase::Active);
+        assert_eq!(vault.next_phase(lock_end).unwrap(), VaultPhase::Matured);
+        // A vault nobody touched during the lock skips Active
+        vault.phase = VaultPhase::Formation;
+        assert_eq!(vault.next_phase(lock_end).unwrap(), VaultPhase::Matured);
+    }
+
+    #[test]
+    fn explicit_phases_ignore_the_clock() {
+        let lock_end = LOCK_START + LOCK_DURATION;
+        let mut vault = vault_fixture();
+        for phase in [
+            VaultPhase::Disputed,
+            VaultPhase::Matured,
+            VaultPhase::Failed,
+            VaultPhase::Closed,
+        ] {
+            vault.phase = phase;
+            assert_eq!(vault.next_phase(lock_end).unwrap(), phase);
+        }
+    }
+
+    #[test]
+    fn sync_phase_applies_the_pending_transition() {
+        let mut vault = vault_fixture();
+        vault.phase = VaultPhase::Formation;
+        set_now(LOCK_START);
+        assert_eq!(
+            vault.sync_phase(Pubkey::new_unique()).unwrap(),
+            VaultPhase::Active
+        );
+        assert_eq!(vault.phase, VaultPhase::Active);
+
+        set_now(LOCK_START + LOCK_DURATION);
+        vault.sync_phase(Pubkey::new_unique()).unwrap();
+        assert_eq!(vault.phase, VaultPhase::Matured);
+    }
+
+    #[test]
+    fn sweep_residue_leaves_the_principal_owed_and_the_bond() {
+        let mut vault = vault_fixture();
+        vault.capital_after_slashing = 900;
+        vault.operator_bond = 50;
+        assert_eq!(vault.lock_residue(1_000), 50);
+        vault.capital_withdrawn = 600;
+        assert_eq!(vault.lock_residue(400), 50);
+        assert_eq!(vault.lock_residue(300), 0);
+    }
+
+    fn beneficiary(
+        vesting_start_at: i64,
+        cliff_at: i64,
+        vesting_end_at: i64,
+    ) -> BeneficiaryAccount {
+        BeneficiaryAccount {
+            vault: Pubkey::new_unique(),
+            address: Pubkey::new_unique(),
+            share_bps: 1_000,
+            reward_debts: [0; MAX_REWARD_STREAMS],
+            accrued: [0; MAX_REWARD_STREAMS],
+            total_claimed: [0; MAX_REWARD_STREAMS],
+            vesting_start_at,
+            cliff_at,
+            vesting_end_at,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn unvested_share_is_claimable_right_away() {
+        let mut beneficiary = beneficiary(0, 0, 0);
+        beneficiary.accrued[0] = 500;
+        assert_eq!(beneficiary.claimable(0, 0).unwrap(), (500, 0));
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let beneficiary = beneficiary(100, 150, 200);
+        assert_eq!(beneficiary.vested_amount(1_000, 149).unwrap(), 0);
+        // The cliff releases everything vested since the start
+        assert_eq!(beneficiary.vested_amount(1_000, 150).unwrap(), 500);
+        assert_eq!(beneficiary.vested_amount(1_000, 175).unwrap(), 750);
+        assert_eq!(beneficiary.vested_amount(1_000, 250).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn claimable_counts_earlier_claims_as_vested() {
+        let mut beneficiary = beneficiary(100, 100, 200);
+        beneficiary.accrued[0] = 1_000;
+        assert_eq!(beneficiary.take_vested(0, 125).unwrap(), (250, 750));
+        assert_eq!(beneficiary.accrued[0], 750);
+        assert_eq!(beneficiary.total_claimed[0], 250);
+
+        // Rewards settled later vest on the same schedule
+        beneficiary.accrued[0] += 1_000;
+        assert_eq!(beneficiary.claimable(0, 150).unwrap(), (750, 1_000));
+        assert_eq!(beneficiary.take_vested(0, 200).unwrap(), (1_750, 0));
+        assert_eq!(beneficiary.claimable(0, 300).unwrap(), (0, 0));
+    }
+
+    /// Pending request of 1_000, tests set the claimants and the split they need
+    fn slash_request_fixture() -> SlashRequest {
+        SlashRequest {
+            vault: Pubkey::new_unique(),
+            sequence: 0,
+            requester: Pubkey::new_unique(),
+            claimants: Vec::new(),
+            insurance_bps: 0,
+            burn_bps: 0,
+            slash_bps: 1_000,
+            requested_amount: 1_000,
+            evidence_hash: [0; 32],
+            evidence_uri: String::new(),
+            attestation: Pubkey::default(),
+            challenge: Pubkey::default(),
+            status: SlashStatus::Pending,
+            appeal_evidence_hash: [0; 32],
+            resolver: Pubkey::default(),
+            executed_amount: 0,
+            bond_slashed: 0,
+            insurance_amount: 0,
+            burned_amount: 0,
+            approvals: Vec::new(),
+            rejections: Vec::new(),
+            created_at: 0,
+            appealed_at: 0,
+            resolved_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn split_leaves_the_rounding_to_the_claimants() {
+        let mut request = slash_request_fixture();
+        request.insurance_bps = 1_000;
+        request.burn_bps = 500;
+        assert_eq!(request.split(1_001).unwrap(), (100, 50, 851));
+        request.insurance_bps = BASE_BPS;
+        request.burn_bps = 0;
+        assert_eq!(request.split(1_001).unwrap(), (1_001, 0, 0));
+    }
+
+    #[test]
+    fn claimants_are_paid_once() {
+        let mut request = slash_request_fixture();
+        let claimant = Pubkey::new_unique();
+        request.claimants = vec![
+            SlashClaimant {
+                address: Pubkey::new_unique(),
+                share_bps: 4_000,
+                claimed: false,
+            },
+            SlashClaimant {
+                address: claimant,
+                share_bps: 6_000,
+                claimed: false,
+            },
+        ];
+        request.executed_amount = 1_000;
+
+        let index = request.claimant_index(claimant).unwrap();
+        assert_eq!(request.take_payout(index).unwrap(), 600);
+        assert_eq!(
+            request.take_payout(index).unwrap_err(),
+            Error::from(VaultError::SlashPayoutAlreadyClaimed)
+        );
+        assert_eq!(
+            request.claimant_index(Pubkey::new_unique()).unwrap_err(),
+            Error::from(VaultError::SlashClaimantNotFound)
+        );
+    }
+
+    #[test]
+    fn committee_executes_the_smallest_approved_amount() {
+        let arbiters = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut request = slash_request_fixture();
+
+        request.record_vote(arbiters[0], true, 400).unwrap();
+        assert_eq!(
+            request.committee_decision(&arbiters, 2),
+            SlashDecision::Undecided
+        );
+        request.record_vote(arbiters[1], true, 300).unwrap();
+        assert_eq!(
+            request.committee_decision(&arbiters, 2),
+            SlashDecision::Execute(300)
+        );
+    }
+
+    #[test]
+    fn committee_rejects_once_the_threshold_is_out_of_reach() {
+        let arbiters = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut request = slash_request_fixture();
+
+        request.record_vote(arbiters[0], false, 0).unwrap();
+        assert_eq!(
+            request.committee_decision(&arbiters, 2),
+            SlashDecision::Undecided
+        );
+        request.record_vote(arbiters[1], false, 0).unwrap();
+        assert_eq!(
+            request.committee_decision(&arbiters, 2),
+            SlashDecision::Reject
+        );
+    }
+
+    #[test]
+    fn votes_of_removed_arbiters_do_not_count() {
+        let removed = Pubkey::new_unique();
+        let arbiters = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut request = slash_request_fixture();
+
+        request.record_vote(removed, true, 100).unwrap();
+        request.record_vote(arbiters[0], true, 500).unwrap();
+        assert_eq!(request.committee_votes(&arbiters), (1, 0, 500));
+        assert_eq!(
+            request.committee_decision(&arbiters, 2),
+            SlashDecision::Undecided
+        );
+    }
+
+    #[test]
+    fn arbiters_vote_once_within_the_requested_amount() {
+        let arbiter = Pubkey::new_unique();
+        let mut request = slash_request_fixture();
+
+        assert_eq!(
+            request.record_vote(arbiter, true, 0).unwrap_err(),
+            Error::from(VaultError::InvalidSlashAmount)
+        );
+        assert_eq!(
+            request
+                .record_vote(arbiter, true, request.requested_amount + 1)
+                .unwrap_err(),
+            Error::from(VaultError::InvalidSlashAmount)
+        );
+        request.record_vote(arbiter, false, 0).unwrap();
+        assert_eq!(
+            request.record_vote(arbiter, true, 100).unwrap_err(),
+            Error::from(SignerError::ArbiterAlreadyVoted)
+        );
+    }
+
+    #[test]
+    fn epochs_run_back_to_back_from_the_lock_start() {
+        let vault = vault_fixture();
+        assert_eq!(
+            vault.epoch_end_at(0).unwrap(),
+            LOCK_START + WORK_EPOCH_DURATION
+        );
+        assert_eq!(
+            vault.commitment_deadline(1).unwrap(),
+            LOCK_START + 2 * WORK_EPOCH_DURATION + COMMITMENT_WINDOW
+        );
+        assert_eq!(
+            vault.epoch_end_at(u64::MAX).unwrap_err(),
+            Error::from(ArithmeticError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn only_ended_epochs_of_the_lock_can_be_committed() {
+        let vault = vault_fixture();
+        let first_end = LOCK_START + WORK_EPOCH_DURATION;
+        assert_eq!(
+            vault.require_epoch_ended(0, first_end - 1).unwrap_err(),
+            Error::from(ChallengeError::EpochNotEnded)
+        );
+        vault.require_epoch_ended(0, first_end).unwrap();
+        // The lock holds four epochs
+        vault
+            .require_epoch_ended(3, LOCK_START + LOCK_DURATION)
+            .unwrap();
+        assert_eq!(
+            vault
+                .require_epoch_ended(4, LOCK_START + 2 * LOCK_DURATION)
+                .unwrap_err(),
+            Error::from(ChallengeError::InvalidEpoch)
+        );
+    }
+
+    #[test]
+    fn challenge_slashes_are_capped_by_max_slash_bps() {
+        let mut vault = vault_fixture();
+        assert_eq!(vault.challenge_slash_allowance().unwrap(), 100);
+        vault.challenge_slashed = 60;
+        assert_eq!(vault.challenge_slash_allowance().unwrap(), 40);
+        vault.challenge_slashed = 100;
+        assert_eq!(vault.challenge_slash_allowance().unwrap(), 0);
+    }
+
+    #[test]
+    fn unanswered_challenge_fails_at_its_deadline() {
+        let mut challenge = Challenge {
+            vault: Pubkey::new_unique(),
+            commitment: Pubkey::new_unique(),
+            leaf_index: 0,
+            challenger: Pubkey::new_unique(),
+            slash_bps: 500,
+            claimants: Vec::new(),
+            insurance_bps: 0,
+            burn_bps: BASE_BPS,
+            status: ChallengeStatus::Open,
+            issued_at: 0,
+            deadline: 100,
+            answered_at: 0,
+            slash_request: Pubkey::default(),
+            bump: 255,
+        };
+        assert!(!challenge.is_failed(99));
+        assert!(challenge.is_failed(100));
+
+        challenge.status = ChallengeStatus::Answered;
+        assert!(!challenge.is_failed(100));
+        challenge.status = ChallengeStatus::Failed;
+        assert!(challenge.is_failed(0));
+        challenge.status = ChallengeStatus::Slashed;
+        assert!(!challenge.is_failed(100));
+    }
+}
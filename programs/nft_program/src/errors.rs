@@ -12,6 +12,24 @@ pub enum SignerError {
     InvalidAssetOwner,
 }
 
+#[error_code]
+pub enum AdminError {
+    #[msg("Signer must be the admin of the NFT program")]
+    UnauthorizedAdmin,
+    #[msg("Signer must be the pending admin of the NFT program")]
+    UnauthorizedPendingAdmin,
+    #[msg("Admin cannot be the default address")]
+    InvalidAdmin,
+}
+
+#[error_code]
+pub enum MarketplaceError {
+    #[msg("Signer must be the capital program authority or the admin")]
+    UnauthorizedPauseAuthority,
+    #[msg("The marketplace is paused")]
+    MarketplacePaused,
+}
+
 #[error_code]
 pub enum OfferError {
     #[msg("Mint does not match seller listed token mint")]
@@ -7,6 +7,27 @@ pub struct ProgramInitializedEvent {
     pub time_stamp: i64,
 }
 
+#[event]
+pub struct AdminTransferProposedEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub time_stamp: i64,
+}
+
+#[event]
+pub struct AdminTransferredEvent {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub time_stamp: i64,
+}
+
+#[event]
+pub struct MarketplacePauseUpdatedEvent {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub time_stamp: i64,
+}
+
 #[event]
 pub struct CollectionCreatedEvent {
     pub collection: Pubkey,
@@ -1,4 +1,7 @@
-use crate::{errors::*, state::Offer};
+use crate::{
+    errors::*,
+    state::{NFTConfig, Offer},
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -58,6 +61,13 @@ pub struct BuyPosition<'info> {
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    /// Appended last to keep the account order of existing clients
+    #[account(
+       seeds = [b"NFT_Config"],
+       bump = config.bump,
+       constraint = !config.is_paused() @ MarketplaceError::MarketplacePaused
+    )]
+    pub config: Account<'info, NFTConfig>,
 }
 
 impl<'info> BuyPosition<'info> {
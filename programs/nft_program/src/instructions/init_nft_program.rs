@@ -24,9 +24,12 @@ impl<'info> InitNFTProgram<'info> {
         capital_program: Pubkey,
     ) -> Result<()> {
         self.config.set_inner(NFTConfig {
-            capital_program: capital_program,
+            capital_program,
             authority: *self.authority.key,
             admin: *self.admin.key,
+            pending_admin: Pubkey::default(),
+            paused: false,
+            capital_paused: false,
             bump: bumps.config,
         });
         Ok(())
@@ -1,4 +1,7 @@
-use crate::{errors::*, state::Offer};
+use crate::{
+    errors::*,
+    state::{NFTConfig, Offer},
+};
 use anchor_lang::prelude::*;
 use mpl_core::{accounts::BaseAssetV1, instructions::TransferV1CpiBuilder, ID as MPL_CORE_ID};
 
@@ -25,6 +28,13 @@ pub struct ListPosition<'info> {
     )]
     pub mpl_core_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
+    /// Appended last to keep the account order of existing clients
+    #[account(
+       seeds = [b"NFT_Config"],
+       bump = config.bump,
+       constraint = !config.is_paused() @ MarketplaceError::MarketplacePaused
+    )]
+    pub config: Account<'info, NFTConfig>,
 }
 
 impl<'info> ListPosition<'info> {
@@ -4,7 +4,9 @@ pub mod create_vault_collection;
 pub mod init_nft_program;
 pub mod list_position;
 pub mod mint_positon;
+pub mod set_marketplace_pause;
 pub mod unlist_position;
+pub mod update_nft_program;
 
 pub use burn_nft::*;
 pub use buy_position::*;
@@ -12,4 +14,6 @@ pub use create_vault_collection::*;
 pub use init_nft_program::*;
 pub use list_position::*;
 pub use mint_positon::*;
+pub use set_marketplace_pause::*;
 pub use unlist_position::*;
+pub use update_nft_program::*;
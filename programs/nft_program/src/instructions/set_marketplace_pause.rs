@@ -0,0 +1,31 @@
+use crate::{errors::*, state::NFTConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMarketplacePause<'info> {
+    /// Either the capital program authority PDA or the admin
+    #[account(
+        constraint = authority.key() == config.authority
+            || authority.key() == config.admin @ MarketplaceError::UnauthorizedPauseAuthority
+    )]
+    pub authority: Signer<'info>,
+    #[account(
+      mut,
+      seeds = [b"NFT_Config"],
+      bump = config.bump
+    )]
+    pub config: Account<'info, NFTConfig>,
+}
+
+impl<'info> SetMarketplacePause<'info> {
+    /// The capital program and the admin each control their own flag, so the
+    /// admin cannot reopen the marketplace while the protocol is paused
+    pub fn set_pause(&mut self, paused: bool) -> Result<()> {
+        if self.authority.key() == self.config.authority {
+            self.config.capital_paused = paused;
+        } else {
+            self.config.paused = paused;
+        }
+        Ok(())
+    }
+}
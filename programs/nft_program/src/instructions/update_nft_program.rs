@@ -0,0 +1,43 @@
+use crate::{errors::*, state::NFTConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateNFTProgram<'info> {
+    #[account(address = config.admin @ AdminError::UnauthorizedAdmin)]
+    pub admin: Signer<'info>,
+    #[account(
+      mut,
+      seeds = [b"NFT_Config"],
+      bump = config.bump
+    )]
+    pub config: Account<'info, NFTConfig>,
+}
+
+impl<'info> UpdateNFTProgram<'info> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        require_keys_neq!(new_admin, Pubkey::default(), AdminError::InvalidAdmin);
+        self.config.pending_admin = new_admin;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptNFTAdmin<'info> {
+    #[account(address = config.pending_admin @ AdminError::UnauthorizedPendingAdmin)]
+    pub pending_admin: Signer<'info>,
+    #[account(
+      mut,
+      seeds = [b"NFT_Config"],
+      bump = config.bump
+    )]
+    pub config: Account<'info, NFTConfig>,
+}
+
+impl<'info> AcceptNFTAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<Pubkey> {
+        let old_admin = self.config.admin;
+        self.config.admin = *self.pending_admin.key;
+        self.config.pending_admin = Pubkey::default();
+        Ok(old_admin)
+    }
+}
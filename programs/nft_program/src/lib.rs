@@ -29,6 +29,42 @@ pub mod nft_program {
         Ok(())
     }
 
+    pub fn propose_admin_handler(ctx: Context<UpdateNFTProgram>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)?;
+        msg!("Admin transfer proposed");
+        emit!(AdminTransferProposedEvent {
+            admin: *ctx.accounts.admin.key,
+            pending_admin: new_admin,
+            time_stamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn accept_admin_handler(ctx: Context<AcceptNFTAdmin>) -> Result<()> {
+        let old_admin = ctx.accounts.accept_admin()?;
+        msg!("Admin transferred");
+        emit!(AdminTransferredEvent {
+            old_admin,
+            new_admin: *ctx.accounts.pending_admin.key,
+            time_stamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
+    pub fn set_marketplace_pause_handler(
+        ctx: Context<SetMarketplacePause>,
+        paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_pause(paused)?;
+        msg!("Marketplace paused: {}", paused);
+        emit!(MarketplacePauseUpdatedEvent {
+            authority: *ctx.accounts.authority.key,
+            paused,
+            time_stamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+
     pub fn create_vault_collection_handler(ctx: Context<CreateVaultCollection>) -> Result<()> {
         ctx.accounts.create_collection()?;
         msg!("Vault collection created");
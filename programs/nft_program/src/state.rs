@@ -15,5 +15,16 @@ pub struct NFTConfig {
     pub capital_program: Pubkey,
     pub authority: Pubkey,
     pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    // Set by the admin
+    pub paused: bool,
+    // Set by the capital program along with its protocol pause, only it can lift it
+    pub capital_paused: bool,
     pub bump: u8,
 }
+
+impl NFTConfig {
+    pub fn is_paused(&self) -> bool {
+        self.paused || self.capital_paused
+    }
+}
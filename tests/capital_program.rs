@@ -1,8 +1,19 @@
 mod setup;
 
+use setup::instructions::{DAY, LOCK_PHASE_DURATION};
 use setup::test_config::TestConfig;
 use setup::*;
-use solana_sdk::signer::Signer;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use zaals_finance_client::types::{ChallengeStatus, SlashShare, SlashStatus, VaultPhase};
+
+const DISPUTE_WINDOW: i64 = 2 * DAY;
+const WORK_EPOCH_DURATION: i64 = 7 * DAY;
+const COMMITMENT_WINDOW: i64 = DAY;
+const DECISION_WINDOW: i64 = 2 * DAY;
+const APPEAL_WINDOW: i64 = 7 * DAY;
+const CHALLENGE_RESPONSE_WINDOW: i64 = DAY;
+const SWEEP_GRACE_PERIOD: i64 = 180 * DAY;
 
 #[test]
 pub fn test_init_capital_program() {
@@ -19,4 +30,1228 @@ pub fn test_init_capital_program() {
             println!("capital program initialization failed with {:?}", e);
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_create_vault_rejects_lock_phase_above_max_lock_duration() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+
+    // init_capital_program configures a 365 day max_lock_duration
+    let terms = instructions::VaultTerms {
+        lock_phase_duration: 366 * DAY,
+        ..Default::default()
+    };
+    let (_, result) = instructions::create_vault_with(&mut test_config, &terms);
+    utils::assert_program_error(result, "LockPhaseTooLong");
+}
+
+#[test]
+pub fn test_reward_streams_cannot_use_the_lock_mint() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+
+    let terms = instructions::VaultTerms {
+        reward_in_lock_mint: true,
+        ..Default::default()
+    };
+    let (_, result) = instructions::create_vault_with(&mut test_config, &terms);
+    utils::assert_program_error(result, "InvalidRewardMint");
+
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let result = instructions::add_reward_stream(&mut test_config, &vault_setup, vault_setup.lock_mint);
+    utils::assert_program_error(result, "InvalidRewardMint");
+}
+
+#[test]
+pub fn test_fee_accounts_are_only_required_for_their_fee_share() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let distributor = test_config.node_operator.insecure_clone();
+
+    // Without a protocol fee the reward mint needs no fee accounts
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 100_000, None)
+        .expect("fee-less deposit failed");
+
+    let mut fee_terms = instructions::FeeTerms {
+        protocol_fee_bps: 1_000,
+        ..Default::default()
+    };
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+    let result = instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 100_000, None);
+    utils::assert_program_error(result, "TreasuryAccountRequired");
+
+    fee_terms.insurance_fee_bps = 5_000;
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+    let result = instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 100_000, None);
+    utils::assert_program_error(result, "InsuranceAccountRequired");
+
+    instructions::init_fee_accounts(&mut test_config, vault_setup.reward_mint).expect("fee account creation failed");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 100_000, None)
+        .expect("deposit with fee failed");
+
+    // 10% fee, half of it to the reward mint's insurance fund
+    let treasury_ata = utils::get_ata(&capital_accounts::get_treasury_pda(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &treasury_ata), 5_000);
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&vault_setup.reward_mint);
+    let insurance_fund_ata = utils::get_ata(&insurance_fund, &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &insurance_fund_ata), 5_000);
+    let vault_reward_ata = utils::get_ata(&vault_setup.vault, &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &vault_reward_ata), 190_000);
+}
+
+#[test]
+pub fn test_fee_funded_insurance_covers_slashed_principal_once_pooled() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let fee_terms = instructions::FeeTerms {
+        protocol_fee_bps: 1_000,
+        insurance_fee_bps: 5_000,
+        insurance_coverage_bps: 1_000,
+        ..Default::default()
+    };
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::init_fee_accounts(&mut test_config, vault_setup.reward_mint).expect("fee account creation failed");
+    instructions::init_fee_accounts(&mut test_config, vault_setup.lock_mint).expect("fee account creation failed");
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    // The insurance slice of the fee is collected in the reward mint
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let distributor = test_config.node_operator.insecure_clone();
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+    let reward_fund = capital_accounts::get_insurance_fund_data(&mut test_config.svm, &vault_setup.reward_mint);
+    assert_eq!(reward_fund.total_deposited, 50_000);
+
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+    let agent = test_config.agent.insecure_clone();
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000)
+        .expect("slash finalization failed");
+
+    // Coverage is paid in the lock mint, the admin pools the fee-funded
+    // insurance: withdraw it, convert it off-chain, deposit it into the lock
+    // mint's fund
+    instructions::withdraw_insurance(&mut test_config, vault_setup.reward_mint, 50_000)
+        .expect("insurance withdrawal failed");
+    let reward_fund = capital_accounts::get_insurance_fund_data(&mut test_config.svm, &vault_setup.reward_mint);
+    assert_eq!(reward_fund.total_withdrawn, 50_000);
+    let admin = test_config.admin.insecure_clone();
+    instructions::deposit_insurance(&mut test_config, &admin, vault_setup.lock_mint, 50_000)
+        .expect("insurance deposit failed");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + LOCK_PHASE_DURATION);
+    instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &[vault_setup.reward_mint])
+        .expect("reward claim failed");
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.lock_mint);
+    let balance_before = utils::get_token_balance(&test_config.svm, &holder_ata);
+    instructions::close_position(&mut test_config, &vault_setup, &position_setup, true)
+        .expect("position closing failed");
+
+    // 1_900_000 of slashed principal, plus the whole pooled fund of the 100_000 loss
+    let balance_after = utils::get_token_balance(&test_config.svm, &holder_ata);
+    assert_eq!(balance_after - balance_before, 1_950_000);
+    let lock_fund = capital_accounts::get_insurance_fund_data(&mut test_config.svm, &vault_setup.lock_mint);
+    assert_eq!(lock_fund.total_paid, 50_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.insurance_claimed, 50_000);
+}
+
+#[test]
+pub fn test_insurance_coverage_is_capped_by_coverage_bps() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let fee_terms = instructions::FeeTerms {
+        insurance_coverage_bps: 250,
+        ..Default::default()
+    };
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::init_fee_accounts(&mut test_config, vault_setup.lock_mint).expect("fee account creation failed");
+    let god = test_config.god.insecure_clone();
+    instructions::deposit_insurance(&mut test_config, &god, vault_setup.lock_mint, 1_000_000)
+        .expect("insurance deposit failed");
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + DISPUTE_WINDOW);
+    let agent = test_config.agent.insecure_clone();
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000)
+        .expect("slash finalization failed");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + LOCK_PHASE_DURATION);
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.lock_mint);
+    let balance_before = utils::get_token_balance(&test_config.svm, &holder_ata);
+    instructions::close_position(&mut test_config, &vault_setup, &position_setup, true)
+        .expect("position closing failed");
+
+    // The 100_000 loss is only covered up to 2.5% of the vault's 2_000_000
+    let balance_after = utils::get_token_balance(&test_config.svm, &holder_ata);
+    assert_eq!(balance_after - balance_before, 1_950_000);
+    let lock_fund = capital_accounts::get_insurance_fund_data(&mut test_config.svm, &vault_setup.lock_mint);
+    assert_eq!(lock_fund.total_paid, 50_000);
+}
+
+#[test]
+pub fn test_slash_request_executes_after_the_appeal_window() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Pending);
+    assert_eq!(request_data.requested_amount, 100_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Disputed);
+
+    // The node operator can still appeal, the agent has to wait
+    let agent = test_config.agent.insecure_clone();
+    let result = instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000);
+    utils::assert_program_error(result, "AppealWindowOpen");
+
+    utils::warp_to(&mut test_config.svm, request_data.created_at + DISPUTE_WINDOW);
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000)
+        .expect("slash finalization failed");
+
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Executed);
+    assert_eq!(request_data.executed_amount, 100_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+    assert_eq!(vault_data.capital_after_slashing, 1_900_000);
+    let escrow = utils::get_ata(&slash_request, &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &escrow), 100_000);
+}
+
+#[test]
+pub fn test_appealed_slash_request_is_decided_by_the_admin() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    instructions::appeal_slash_request(&mut test_config, &vault_setup, 0)
+        .expect("appeal failed");
+
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Appealed);
+    assert_eq!(request_data.appeal_evidence_hash, [9; 32]);
+
+    let result = instructions::appeal_slash_request(&mut test_config, &vault_setup, 0);
+    utils::assert_program_error(result, "SlashAlreadyAppealed");
+
+    // The agent lost the decision to the admin
+    let agent = test_config.agent.insecure_clone();
+    let result = instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000);
+    utils::assert_program_error(result, "UnauthorizedSlashResolver");
+
+    let admin = test_config.admin.insecure_clone();
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &admin, false, 0)
+        .expect("appeal resolution failed");
+
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Rejected);
+    assert_eq!(request_data.resolver, admin.pubkey());
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+    assert_eq!(vault_data.capital_after_slashing, 2_000_000);
+}
+
+#[test]
+pub fn test_appeal_window_closes_after_the_dispute_window() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + DISPUTE_WINDOW);
+    let result = instructions::appeal_slash_request(&mut test_config, &vault_setup, 0);
+    utils::assert_program_error(result, "AppealWindowClosed");
+}
+
+#[test]
+pub fn test_answered_and_failed_challenges() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    let leaves: Vec<[u8; 32]> = (1..=4).map(|index| [index; 32]).collect();
+    let (root, proofs) = utils::build_merkle_tree(&leaves);
+
+    // Epoch 0 can only be committed once it ended
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + DAY);
+    let result = instructions::commit_epoch(&mut test_config, &vault_setup, 0, root, 4);
+    utils::assert_program_error(result, "EpochNotEnded");
+
+    let epoch_end = vault_setup.lock_phase_start_at + WORK_EPOCH_DURATION;
+    utils::warp_to(&mut test_config.svm, epoch_end);
+    instructions::commit_epoch(&mut test_config, &vault_setup, 0, root, 4)
+        .expect("epoch commitment failed");
+    let commitment = capital_accounts::get_epoch_commitment_pda(&vault_setup.vault, 0);
+
+    // Answered with the right Merkle path
+    instructions::issue_challenge(&mut test_config, &vault_setup, 0, 1, 500)
+        .expect("challenge failed");
+    let answered = capital_accounts::get_challenge_pda(&commitment, 1);
+    instructions::respond_challenge(&mut test_config, &vault_setup, answered, commitment, leaves[1], proofs[1].clone())
+        .expect("challenge response failed");
+    let challenge_data = capital_accounts::get_challenge_data(&mut test_config.svm, &answered);
+    assert_eq!(challenge_data.status, ChallengeStatus::Answered);
+
+    // Left unanswered until the response deadline
+    instructions::issue_challenge(&mut test_config, &vault_setup, 0, 2, 500)
+        .expect("challenge failed");
+    let unanswered = capital_accounts::get_challenge_pda(&commitment, 2);
+    let result = instructions::slash_failed_challenge(&mut test_config, &vault_setup, unanswered, 0);
+    utils::assert_program_error(result, "ChallengeNotFailed");
+
+    utils::warp_to(&mut test_config.svm, epoch_end + CHALLENGE_RESPONSE_WINDOW);
+    instructions::slash_failed_challenge(&mut test_config, &vault_setup, unanswered, 0)
+        .expect("challenge slash failed");
+
+    let challenge_data = capital_accounts::get_challenge_data(&mut test_config.svm, &unanswered);
+    assert_eq!(challenge_data.status, ChallengeStatus::Slashed);
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    assert_eq!(challenge_data.slash_request, slash_request);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Executed);
+    assert_eq!(request_data.executed_amount, 100_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.challenge_slashed, 100_000);
+    assert_eq!(vault_data.capital_after_slashing, 1_900_000);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+
+    // A failed challenge is only slashed once
+    let result = instructions::slash_failed_challenge(&mut test_config, &vault_setup, unanswered, 1);
+    utils::assert_program_error(result, "ChallengeNotFailed");
+}
+
+#[test]
+pub fn test_challenge_slashes_stay_within_max_slash_bps() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    let leaves: Vec<[u8; 32]> = (1..=4).map(|index| [index; 32]).collect();
+    let (root, _) = utils::build_merkle_tree(&leaves);
+    let epoch_end = vault_setup.lock_phase_start_at + WORK_EPOCH_DURATION;
+    utils::warp_to(&mut test_config.svm, epoch_end);
+    instructions::commit_epoch(&mut test_config, &vault_setup, 0, root, 4)
+        .expect("epoch commitment failed");
+    let commitment = capital_accounts::get_epoch_commitment_pda(&vault_setup.vault, 0);
+
+    for leaf_index in 0..2 {
+        instructions::issue_challenge(&mut test_config, &vault_setup, 0, leaf_index, 600)
+            .expect("challenge failed");
+    }
+    utils::warp_to(&mut test_config.svm, epoch_end + CHALLENGE_RESPONSE_WINDOW);
+    for (sequence, leaf_index) in (0..2).enumerate() {
+        let challenge = capital_accounts::get_challenge_pda(&commitment, leaf_index);
+        instructions::slash_failed_challenge(&mut test_config, &vault_setup, challenge, sequence as u64)
+            .expect("challenge slash failed");
+    }
+
+    // 6% of 2_000_000, then only the 80_000 left of max_slash_bps instead of 6% of 1_880_000
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.challenge_slashed, 200_000);
+    assert_eq!(vault_data.capital_after_slashing, 1_800_000);
+
+    let result = instructions::issue_challenge(&mut test_config, &vault_setup, 0, 2, 500);
+    utils::assert_program_error(result, "ChallengeSlashLimitReached");
+}
+
+#[test]
+pub fn test_missed_epoch_commitment_is_slashable() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    let commitment_deadline = vault_setup.lock_phase_start_at + WORK_EPOCH_DURATION + COMMITMENT_WINDOW;
+    utils::warp_to(&mut test_config.svm, commitment_deadline - 1);
+    let result = instructions::challenge_missed_epoch(&mut test_config, &vault_setup, 0, 500);
+    utils::assert_program_error(result, "CommitmentWindowOpen");
+
+    utils::warp_to(&mut test_config.svm, commitment_deadline);
+    let result = instructions::commit_epoch(&mut test_config, &vault_setup, 0, [1; 32], 4);
+    utils::assert_program_error(result, "CommitmentWindowClosed");
+
+    instructions::challenge_missed_epoch(&mut test_config, &vault_setup, 0, 500)
+        .expect("missed epoch challenge failed");
+    let commitment = capital_accounts::get_epoch_commitment_pda(&vault_setup.vault, 0);
+    let challenge = capital_accounts::get_challenge_pda(&commitment, 0);
+    instructions::slash_failed_challenge(&mut test_config, &vault_setup, challenge, 0)
+        .expect("missed epoch slash failed");
+
+    let challenge_data = capital_accounts::get_challenge_data(&mut test_config.svm, &challenge);
+    assert_eq!(challenge_data.status, ChallengeStatus::Slashed);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.capital_after_slashing, 1_900_000);
+}
+
+#[test]
+pub fn test_early_unlock_returns_principal_minus_fee() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let kept = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let unlocked = instructions::open_position(&mut test_config, &vault_setup, 1_000_000);
+
+    // Nothing is locked during formation
+    let result = instructions::early_unlock_position(&mut test_config, &vault_setup, &unlocked);
+    utils::assert_program_error(result, "InvalidPhase");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.lock_mint);
+    let balance_before = utils::get_token_balance(&test_config.svm, &holder_ata);
+    instructions::early_unlock_position(&mut test_config, &vault_setup, &unlocked)
+        .expect("early unlock failed");
+
+    // 20% early unlock fee stays in the vault for the remaining holders
+    let balance_after = utils::get_token_balance(&test_config.svm, &holder_ata);
+    assert_eq!(balance_after - balance_before, 800_000);
+    assert!(capital_accounts::get_position_data(&mut test_config.svm, &unlocked.position).is_none());
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.total_capital_collected, 2_000_000);
+    assert_eq!(vault_data.capital_after_slashing, 2_200_000);
+
+    // The last position can't take the vault below min_cap
+    let result = instructions::early_unlock_position(&mut test_config, &vault_setup, &kept);
+    utils::assert_program_error(result, "VaultReachedMinCap");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + LOCK_PHASE_DURATION);
+    let result = instructions::early_unlock_position(&mut test_config, &vault_setup, &kept);
+    utils::assert_program_error(result, "InvalidPhase");
+}
+
+#[test]
+pub fn test_sweep_leaves_the_principal_of_open_positions() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let distributor = test_config.node_operator.insecure_clone();
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+
+    // Lock tokens sent to the vault by mistake are the only residue
+    let vault_lock_ata = utils::get_ata(&vault_setup.vault, &vault_setup.lock_mint);
+    let vault_lock_balance = utils::get_token_balance(&test_config.svm, &vault_lock_ata);
+    utils::create_ata(&mut test_config.svm, &vault_setup.vault, &vault_setup.lock_mint, vault_lock_balance + 5_000);
+
+    let matured_at = vault_setup.lock_phase_start_at + LOCK_PHASE_DURATION;
+    utils::warp_to(&mut test_config.svm, matured_at);
+    let result = instructions::sweep_vault(&mut test_config, &vault_setup);
+    utils::assert_program_error(result, "SweepGracePeriodActive");
+
+    utils::warp_to(&mut test_config.svm, matured_at + SWEEP_GRACE_PERIOD);
+    instructions::sweep_vault(&mut test_config, &vault_setup).expect("vault sweep failed");
+    let treasury = capital_accounts::get_treasury_pda();
+    let treasury_lock_ata = utils::get_ata(&treasury, &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &treasury_lock_ata), 5_000);
+    let treasury_reward_ata = utils::get_ata(&treasury, &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &treasury_reward_ata), 1_000_000);
+    assert!(capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault).swept);
+
+    // Unclaimed rewards are forfeited, the principal is still paid back
+    let result = instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &[vault_setup.reward_mint]);
+    utils::assert_program_error(result, "VaultSwept");
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.lock_mint);
+    let balance_before = utils::get_token_balance(&test_config.svm, &holder_ata);
+    instructions::close_position(&mut test_config, &vault_setup, &position_setup, false)
+        .expect("position closing failed");
+    let balance_after = utils::get_token_balance(&test_config.svm, &holder_ata);
+    assert_eq!(balance_after - balance_before, 2_000_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.capital_withdrawn, 2_000_000);
+}
+
+#[test]
+pub fn test_attestation_is_stored_with_the_attester_signature() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let attester = Keypair::new();
+    instructions::register_attester(&mut test_config, &attester.pubkey(), 9_000).expect("attester registration failed");
+
+    instructions::submit_attestation(&mut test_config, &vault_setup, &attester, 0, 8_500, 42)
+        .expect("attestation submission failed");
+    let attestation = capital_accounts::get_attestation_pda(&vault_setup.vault, &attester.pubkey(), 0);
+    let attestation_data = capital_accounts::get_attestation_data(&mut test_config.svm, &attestation);
+    assert_eq!(attestation_data.attester, attester.pubkey());
+    assert_eq!(attestation_data.uptime_bps, 8_500);
+    assert_eq!(attestation_data.work_units, 42);
+    assert!(attestation_data.failed);
+}
+
+#[test]
+pub fn test_attestation_rejects_a_signature_of_another_key() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let attester = Keypair::new();
+    instructions::register_attester(&mut test_config, &attester.pubkey(), 9_000).expect("attester registration failed");
+
+    // A valid ed25519 signature, but not from the registered attester
+    let impostor = Keypair::new();
+    let message = instructions::attestation_message(&vault_setup.vault, 0, 8_500, 42);
+    let inxs = [
+        utils::ed25519_instruction(&impostor, &message),
+        instructions::submit_attestation_inx(&test_config, &vault_setup, &attester.pubkey(), 0, 8_500, 42),
+    ];
+    let god = test_config.god.insecure_clone();
+    let result = utils::send_transaction(&mut test_config.svm, &inxs, &god.pubkey(), &[&god]);
+    utils::assert_program_error(result, "SignatureMismatch");
+}
+
+#[test]
+pub fn test_attestation_rejects_a_tampered_report() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let attester = Keypair::new();
+    instructions::register_attester(&mut test_config, &attester.pubkey(), 9_000).expect("attester registration failed");
+    let god = test_config.god.insecure_clone();
+
+    // Signed with a passing uptime, submitted with a failing one
+    let message = instructions::attestation_message(&vault_setup.vault, 0, 9_500, 42);
+    let inxs = [
+        utils::ed25519_instruction(&attester, &message),
+        instructions::submit_attestation_inx(&test_config, &vault_setup, &attester.pubkey(), 0, 8_000, 42),
+    ];
+    let result = utils::send_transaction(&mut test_config.svm, &inxs, &god.pubkey(), &[&god]);
+    utils::assert_program_error(result, "SignatureMismatch");
+
+    // Signed for epoch 1, submitted for epoch 0
+    let message = instructions::attestation_message(&vault_setup.vault, 1, 8_000, 42);
+    let inxs = [
+        utils::ed25519_instruction(&attester, &message),
+        instructions::submit_attestation_inx(&test_config, &vault_setup, &attester.pubkey(), 0, 8_000, 42),
+    ];
+    let result = utils::send_transaction(&mut test_config.svm, &inxs, &god.pubkey(), &[&god]);
+    utils::assert_program_error(result, "SignatureMismatch");
+}
+
+#[test]
+pub fn test_attestation_requires_the_signature_right_before_it() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let attester = Keypair::new();
+    instructions::register_attester(&mut test_config, &attester.pubkey(), 9_000).expect("attester registration failed");
+    let god = test_config.god.insecure_clone();
+    let message = instructions::attestation_message(&vault_setup.vault, 0, 8_500, 42);
+    let signature_inx = utils::ed25519_instruction(&attester, &message);
+    let submit_inx = instructions::submit_attestation_inx(&test_config, &vault_setup, &attester.pubkey(), 0, 8_500, 42);
+
+    let result = utils::send_transaction(&mut test_config.svm, std::slice::from_ref(&submit_inx), &god.pubkey(), &[&god]);
+    utils::assert_program_error(result, "InvalidSignatureInstruction");
+
+    let result = utils::send_transaction(&mut test_config.svm, &[submit_inx, signature_inx], &god.pubkey(), &[&god]);
+    utils::assert_program_error(result, "InvalidSignatureInstruction");
+}
+
+#[test]
+pub fn test_guardian_pauses_and_only_the_admin_unpauses() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let guardian = Keypair::new();
+    utils::fund(&mut test_config.svm, guardian.pubkey()).expect("guardian funding failed");
+    instructions::update_guardian(&mut test_config, &guardian.pubkey()).expect("guardian update failed");
+    let admin = test_config.admin.insecure_clone();
+    let distributor = test_config.node_operator.insecure_clone();
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+
+    instructions::set_vault_pause(&mut test_config, &vault_setup, &guardian, true).expect("vault pause failed");
+    let result = instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None);
+    utils::assert_program_error(result, "VaultPaused");
+    let result = instructions::set_vault_pause(&mut test_config, &vault_setup, &guardian, false);
+    utils::assert_program_error(result, "UnauthorizedPauseAuthority");
+    instructions::set_vault_pause(&mut test_config, &vault_setup, &admin, false).expect("vault unpause failed");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+
+    instructions::set_protocol_pause(&mut test_config, &guardian, true).expect("protocol pause failed");
+    let result = instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &[vault_setup.reward_mint]);
+    utils::assert_program_error(result, "ProtocolPaused");
+    let result = instructions::set_protocol_pause(&mut test_config, &guardian, false);
+    utils::assert_program_error(result, "UnauthorizedPauseAuthority");
+    instructions::set_protocol_pause(&mut test_config, &admin, false).expect("protocol unpause failed");
+    instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &[vault_setup.reward_mint])
+        .expect("reward claim failed");
+}
+
+#[test]
+pub fn test_failed_vault_refunds_stay_open_while_paused() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 500_000);
+    let admin = test_config.admin.insecure_clone();
+    instructions::set_protocol_pause(&mut test_config, &admin, true).expect("protocol pause failed");
+
+    // The vault missed min_cap, its capital providers get their deposit back
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.lock_mint);
+    let balance_before = utils::get_token_balance(&test_config.svm, &holder_ata);
+    instructions::close_position(&mut test_config, &vault_setup, &position_setup, false)
+        .expect("position closing failed");
+    let balance_after = utils::get_token_balance(&test_config.svm, &holder_ata);
+    assert_eq!(balance_after - balance_before, 500_000);
+}
+
+#[test]
+pub fn test_streamed_rewards_vest_linearly_next_to_instant_streams() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let god = test_config.god.pubkey();
+    let holder = test_config.capital_provider.pubkey();
+    let second_mint = utils::create_mint(&mut test_config.svm, &god, 0);
+    utils::create_ata(&mut test_config.svm, &holder, &second_mint, 0);
+    instructions::add_reward_stream(&mut test_config, &vault_setup, second_mint).expect("reward stream creation failed");
+
+    // The first stream vests over 10 days, the second one is claimable right away
+    let deposited_at = vault_setup.lock_phase_start_at;
+    utils::warp_to(&mut test_config.svm, deposited_at);
+    let distributor = test_config.node_operator.insecure_clone();
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, Some(10 * DAY))
+        .expect("reward deposit failed");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, second_mint, 500_000, None)
+        .expect("reward deposit failed");
+
+    // Both streams in one claim, the second one through remaining accounts
+    let reward_mints = [vault_setup.reward_mint, second_mint];
+    let holder_reward_ata = utils::get_ata(&holder, &vault_setup.reward_mint);
+    let holder_second_ata = utils::get_ata(&holder, &second_mint);
+    utils::warp_to(&mut test_config.svm, deposited_at + 5 * DAY);
+    instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &reward_mints)
+        .expect("reward claim failed");
+    assert_eq!(utils::get_token_balance(&test_config.svm, &holder_reward_ata), 400_000);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &holder_second_ata), 400_000);
+
+    utils::warp_to(&mut test_config.svm, deposited_at + 10 * DAY);
+    instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &reward_mints)
+        .expect("reward claim failed");
+    assert_eq!(utils::get_token_balance(&test_config.svm, &holder_reward_ata), 800_000);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &holder_second_ata), 400_000);
+}
+
+#[test]
+pub fn test_beneficiary_changes_rebase_the_operator_commission() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let first = test_config.beneficiary_1.insecure_clone();
+    let second = test_config.beneficiary_2.insecure_clone();
+    let distributor = test_config.node_operator.insecure_clone();
+    let reward_mints = [vault_setup.reward_mint];
+
+    // 500 of the operator's 2_000 bps go to the first beneficiary
+    instructions::add_beneficiary(&mut test_config, &vault_setup, &first, 500, None).expect("beneficiary addition failed");
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+
+    // Earnings so far stay with the old share, later deposits use the new one
+    instructions::resize_beneficiary(&mut test_config, &vault_setup, &first, 1_000).expect("beneficiary resize failed");
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.operator_commission_bps, 1_000);
+    assert_eq!(vault_data.total_beneficiary_bps, 1_000);
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+
+    // The share moves with its unclaimed 50_000 + 100_000
+    instructions::transfer_beneficiary(&mut test_config, &vault_setup, &first, &second.pubkey())
+        .expect("beneficiary transfer failed");
+    let first_account = capital_accounts::get_beneficiary_pda(&vault_setup.vault, &first.pubkey());
+    assert!(capital_accounts::get_beneficiary_data(&mut test_config.svm, &first_account).is_none());
+    instructions::claim_beneficiary_rewards(&mut test_config, &vault_setup, &second, &reward_mints)
+        .expect("beneficiary claim failed");
+    let second_ata = utils::get_ata(&second.pubkey(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &second_ata), 150_000);
+
+    // Removal pays out what vested and hands the share back to the operator
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+    instructions::remove_beneficiary(&mut test_config, &vault_setup, &second, &reward_mints)
+        .expect("beneficiary removal failed");
+    assert_eq!(utils::get_token_balance(&test_config.svm, &second_ata), 250_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.operator_commission_bps, 2_000);
+    assert_eq!(vault_data.total_beneficiary_bps, 0);
+    assert_eq!(vault_data.beneficiary_count, 0);
+
+    // 150_000 + 100_000 + 100_000 + 200_000
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+    instructions::claim_operator_commission(&mut test_config, &vault_setup, &reward_mints).expect("commission claim failed");
+    let operator_ata = utils::get_ata(&distributor.pubkey(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &operator_ata), 550_000);
+}
+
+#[test]
+pub fn test_vault_takes_more_than_five_beneficiaries() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let beneficiaries = [
+        test_config.beneficiary_1.insecure_clone(),
+        test_config.beneficiary_2.insecure_clone(),
+        test_config.beneficiary_3.insecure_clone(),
+        test_config.beneficiary_4.insecure_clone(),
+        test_config.beneficiary_5.insecure_clone(),
+        test_config.extra_beneficiary.insecure_clone(),
+    ];
+    for beneficiary in beneficiaries.iter() {
+        instructions::add_beneficiary(&mut test_config, &vault_setup, beneficiary, 300, None).expect("beneficiary addition failed");
+    }
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.beneficiary_count, 6);
+    assert_eq!(vault_data.total_beneficiary_bps, 1_800);
+    assert_eq!(vault_data.operator_commission_bps, 200);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let distributor = test_config.node_operator.insecure_clone();
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+    for beneficiary in beneficiaries.iter() {
+        instructions::claim_beneficiary_rewards(&mut test_config, &vault_setup, beneficiary, &[vault_setup.reward_mint])
+            .expect("beneficiary claim failed");
+        let beneficiary_ata = utils::get_ata(&beneficiary.pubkey(), &vault_setup.reward_mint);
+        assert_eq!(utils::get_token_balance(&test_config.svm, &beneficiary_ata), 30_000);
+    }
+
+    // Shares are still capped by the operator commission
+    let late_comer = Keypair::new();
+    let result = instructions::add_beneficiary(&mut test_config, &vault_setup, &late_comer, 300, None);
+    utils::assert_program_error(result, "BPSExceedsMaximum");
+}
+
+#[test]
+pub fn test_only_the_reward_distributor_and_the_agent_deposit() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let distributor = Keypair::new();
+    let stranger = Keypair::new();
+    utils::fund(&mut test_config.svm, distributor.pubkey()).expect("distributor funding failed");
+    utils::fund(&mut test_config.svm, stranger.pubkey()).expect("stranger funding failed");
+    let node_operator = test_config.node_operator.insecure_clone();
+    let agent = test_config.agent.insecure_clone();
+    let reward_mint = vault_setup.reward_mint;
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+
+    // The operator handed deposits over to a dedicated distributor
+    instructions::update_reward_distributor(&mut test_config, &vault_setup, &distributor.pubkey())
+        .expect("distributor update failed");
+    let result = instructions::deposit_rewards(&mut test_config, &vault_setup, &node_operator, reward_mint, 100_000, None);
+    utils::assert_program_error(result, "UnauthorizedDepositor");
+    let result = instructions::deposit_rewards(&mut test_config, &vault_setup, &stranger, reward_mint, 100_000, None);
+    utils::assert_program_error(result, "UnauthorizedDepositor");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, reward_mint, 100_000, None)
+        .expect("distributor deposit failed");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &agent, reward_mint, 100_000, None)
+        .expect("agent deposit failed");
+
+    // Without a distributor only the agent deposits
+    instructions::update_reward_distributor(&mut test_config, &vault_setup, &Pubkey::default())
+        .expect("distributor update failed");
+    let result = instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, reward_mint, 100_000, None);
+    utils::assert_program_error(result, "UnauthorizedDepositor");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &agent, reward_mint, 100_000, None)
+        .expect("agent deposit failed");
+
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.reward_streams[0].total_deposited, 300_000);
+}
+
+#[test]
+pub fn test_donations_are_split_fee_free_and_attributed() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let fee_terms = instructions::FeeTerms {
+        protocol_fee_bps: 1_000,
+        ..Default::default()
+    };
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+    let vault_setup = instructions::create_vault(&mut test_config);
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let donor = Keypair::new();
+    utils::fund(&mut test_config.svm, donor.pubkey()).expect("donor funding failed");
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+
+    let meta = instructions::donate_rewards(&mut test_config, &vault_setup, &donor, 200_000, 7, "community round")
+        .expect("donation failed");
+    let events = utils::emitted_events(&meta.logs, "RewardsDonatedEvent");
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(&event[0..32], vault_setup.vault.as_ref());
+    assert_eq!(&event[32..64], donor.pubkey().as_ref());
+    assert_eq!(u64::from_le_bytes(event[96..104].try_into().unwrap()), 200_000);
+    assert_eq!(u64::from_le_bytes(event[104..112].try_into().unwrap()), 7);
+    assert_eq!(u32::from_le_bytes(event[112..116].try_into().unwrap()), 15);
+    assert_eq!(&event[116..131], b"community round");
+
+    // No protocol fee, 80% to the investors and 20% to the operator
+    instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &[vault_setup.reward_mint])
+        .expect("reward claim failed");
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &holder_ata), 160_000);
+    instructions::claim_operator_commission(&mut test_config, &vault_setup, &[vault_setup.reward_mint])
+        .expect("commission claim failed");
+    let operator_ata = utils::get_ata(&test_config.node_operator.pubkey(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &operator_ata), 40_000);
+}
+
+#[test]
+pub fn test_protocol_fee_is_collected_and_withdrawn_by_the_admin() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let fee_terms = instructions::FeeTerms {
+        protocol_fee_bps: 1_000,
+        ..Default::default()
+    };
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::init_fee_accounts(&mut test_config, vault_setup.reward_mint).expect("fee account creation failed");
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let distributor = test_config.node_operator.insecure_clone();
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &distributor, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+
+    // The vault splits what is left after the 10% fee
+    let treasury_ata = utils::get_ata(&capital_accounts::get_treasury_pda(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &treasury_ata), 100_000);
+    instructions::claim_investor_rewards(&mut test_config, &vault_setup, &position_setup, &[vault_setup.reward_mint])
+        .expect("reward claim failed");
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &holder_ata), 720_000);
+
+    let result = instructions::withdraw_treasury(&mut test_config, &distributor, vault_setup.reward_mint, 100_000);
+    utils::assert_program_error(result, "UnauthorizedAdmin");
+    let admin = test_config.admin.insecure_clone();
+    instructions::withdraw_treasury(&mut test_config, &admin, vault_setup.reward_mint, 100_000)
+        .expect("treasury withdrawal failed");
+    let admin_ata = utils::get_ata(&admin.pubkey(), &vault_setup.reward_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &admin_ata), 100_000);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &treasury_ata), 0);
+}
+
+#[test]
+pub fn test_operator_claims_its_commission_on_every_stream_once() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let god = test_config.god.pubkey();
+    let node_operator = test_config.node_operator.pubkey();
+    let second_mint = utils::create_mint(&mut test_config.svm, &god, 0);
+    utils::create_ata(&mut test_config.svm, &node_operator, &second_mint, 0);
+    instructions::add_reward_stream(&mut test_config, &vault_setup, second_mint).expect("reward stream creation failed");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let agent = test_config.agent.insecure_clone();
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &agent, vault_setup.reward_mint, 1_000_000, None)
+        .expect("reward deposit failed");
+    instructions::deposit_rewards(&mut test_config, &vault_setup, &agent, second_mint, 500_000, None)
+        .expect("reward deposit failed");
+
+    // 2_000 bps of each stream
+    let reward_mints = [vault_setup.reward_mint, second_mint];
+    instructions::claim_operator_commission(&mut test_config, &vault_setup, &reward_mints).expect("commission claim failed");
+    let operator_reward_ata = utils::get_ata(&node_operator, &vault_setup.reward_mint);
+    let operator_second_ata = utils::get_ata(&node_operator, &second_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &operator_reward_ata), 200_000);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &operator_second_ata), 100_000);
+
+    let result = instructions::claim_operator_commission(&mut test_config, &vault_setup, &reward_mints);
+    utils::assert_program_error(result, "NoRewardsToClaim");
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.reward_streams[0].total_claimed, 200_000);
+    assert_eq!(vault_data.reward_streams[1].total_claimed, 100_000);
+}
+
+#[test]
+pub fn test_operator_bond_takes_the_first_loss_and_returns_the_rest() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let fee_terms = instructions::FeeTerms {
+        min_operator_bond_bps: 100,
+        ..Default::default()
+    };
+    instructions::update_fee_terms(&mut test_config, &fee_terms).expect("fee update failed");
+
+    // 1% of max_cap at least
+    let terms = instructions::VaultTerms {
+        operator_bond: 50_000,
+        ..Default::default()
+    };
+    let (_, result) = instructions::create_vault_with(&mut test_config, &terms);
+    utils::assert_program_error(result, "OperatorBondTooLow");
+    let terms = instructions::VaultTerms {
+        operator_bond: 150_000,
+        ..Default::default()
+    };
+    let (vault_setup, result) = instructions::create_vault_with(&mut test_config, &terms);
+    result.expect("vault creation failed");
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let agent = test_config.agent.insecure_clone();
+
+    // The bond covers the first slash on its own
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500).expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000)
+        .expect("slash finalization failed");
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    assert_eq!(capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request).bond_slashed, 100_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.operator_bond, 50_000);
+    assert_eq!(vault_data.capital_after_slashing, 2_000_000);
+
+    // The second one drains it and reaches the investors
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 1, 500).expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 1, &agent, true, 100_000)
+        .expect("slash finalization failed");
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.operator_bond, 0);
+    assert_eq!(vault_data.capital_after_slashing, 1_950_000);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + LOCK_PHASE_DURATION);
+    let holder_ata = utils::get_ata(&test_config.capital_provider.pubkey(), &vault_setup.lock_mint);
+    let balance_before = utils::get_token_balance(&test_config.svm, &holder_ata);
+    instructions::close_position(&mut test_config, &vault_setup, &position_setup, false)
+        .expect("position closing failed");
+    let balance_after = utils::get_token_balance(&test_config.svm, &holder_ata);
+    assert_eq!(balance_after - balance_before, 1_950_000);
+    instructions::close_vault(&mut test_config, &vault_setup).expect("vault closing failed");
+    assert!(test_config.svm.get_account(&vault_setup.vault).is_none());
+}
+
+#[test]
+pub fn test_unslashed_operator_bond_is_returned_on_close() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let terms = instructions::VaultTerms {
+        operator_bond: 150_000,
+        ..Default::default()
+    };
+    let (vault_setup, result) = instructions::create_vault_with(&mut test_config, &terms);
+    result.expect("vault creation failed");
+    let position_setup = instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at + LOCK_PHASE_DURATION);
+    instructions::close_position(&mut test_config, &vault_setup, &position_setup, false)
+        .expect("position closing failed");
+    let operator_ata = utils::get_ata(&test_config.node_operator.pubkey(), &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &operator_ata), 0);
+    instructions::close_vault(&mut test_config, &vault_setup).expect("vault closing failed");
+    assert_eq!(utils::get_token_balance(&test_config.svm, &operator_ata), 150_000);
+}
+
+#[test]
+pub fn test_slash_requests_keep_their_evidence_and_history() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let agent = test_config.agent.insecure_clone();
+
+    // Requests are numbered by the vault, the first one is 0
+    let result = instructions::create_slash_request(&mut test_config, &vault_setup, 1, 500);
+    utils::assert_program_error(result, "ConstraintSeeds");
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, false, 0)
+        .expect("slash rejection failed");
+
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 1, 250)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 1, &agent, true, 40_000)
+        .expect("slash finalization failed");
+
+    // Both requests stay on chain with what was alleged and what was done
+    let first = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let first = capital_accounts::get_slash_request_data(&mut test_config.svm, &first);
+    assert_eq!(first.sequence, 0);
+    assert_eq!(first.requester, agent.pubkey());
+    assert_eq!(first.evidence_hash, [7; 32]);
+    assert_eq!(first.evidence_uri, "ipfs://evidence");
+    assert_eq!(first.claimants[0].address, test_config.slash_claimant);
+    assert_eq!(first.slash_bps, 500);
+    assert_eq!(first.requested_amount, 100_000);
+    assert_eq!(first.status, SlashStatus::Rejected);
+    assert_eq!(first.resolver, agent.pubkey());
+    assert_eq!(first.executed_amount, 0);
+    assert!(first.resolved_at >= first.created_at + DISPUTE_WINDOW);
+
+    let second = capital_accounts::get_slash_request_pda(&vault_setup.vault, 1);
+    let second = capital_accounts::get_slash_request_data(&mut test_config.svm, &second);
+    assert_eq!(second.sequence, 1);
+    assert_eq!(second.slash_bps, 250);
+    assert_eq!(second.requested_amount, 50_000);
+    assert_eq!(second.status, SlashStatus::Executed);
+    assert_eq!(second.executed_amount, 40_000);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.slash_request_count, 2);
+    assert_eq!(vault_data.capital_after_slashing, 1_960_000);
+}
+
+#[test]
+pub fn test_anyone_dismisses_a_slash_request_left_undecided() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let caller = Keypair::new();
+    utils::fund(&mut test_config.svm, caller.pubkey()).expect("caller funding failed");
+
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW + DECISION_WINDOW - 1);
+    let result = instructions::dismiss_slash_request(&mut test_config, &vault_setup, 0, &caller);
+    utils::assert_program_error(result, "DisputeWindowNotExpired");
+
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW + DECISION_WINDOW);
+    instructions::dismiss_slash_request(&mut test_config, &vault_setup, 0, &caller)
+        .expect("slash dismissal failed");
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Dismissed);
+    assert_eq!(request_data.resolver, caller.pubkey());
+    assert_eq!(request_data.executed_amount, 0);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+    assert_eq!(vault_data.capital_after_slashing, 2_000_000);
+
+    // Too late for the agent, the vault is no longer disputed
+    let agent = test_config.agent.insecure_clone();
+    let result = instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000);
+    utils::assert_program_error(result, "NoActiveDispute");
+    let result = instructions::dismiss_slash_request(&mut test_config, &vault_setup, 0, &caller);
+    utils::assert_program_error(result, "NoActiveDispute");
+}
+
+#[test]
+pub fn test_appealed_slash_request_is_dismissed_after_the_appeal_window() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let caller = Keypair::new();
+    utils::fund(&mut test_config.svm, caller.pubkey()).expect("caller funding failed");
+
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    instructions::appeal_slash_request(&mut test_config, &vault_setup, 0)
+        .expect("appeal failed");
+
+    // The admin gets the appeal window on top of the dispute window
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW + DECISION_WINDOW);
+    let result = instructions::dismiss_slash_request(&mut test_config, &vault_setup, 0, &caller);
+    utils::assert_program_error(result, "DisputeWindowNotExpired");
+
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW + APPEAL_WINDOW);
+    instructions::dismiss_slash_request(&mut test_config, &vault_setup, 0, &caller)
+        .expect("slash dismissal failed");
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Dismissed);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+}
+
+#[test]
+pub fn test_slash_is_split_and_each_claimant_collects_its_share() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::init_fee_accounts(&mut test_config, vault_setup.lock_mint).expect("fee account creation failed");
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let first = test_config.beneficiary_1.insecure_clone();
+    let second = test_config.beneficiary_2.insecure_clone();
+    let stranger = test_config.beneficiary_3.insecure_clone();
+
+    let claimants = vec![
+        SlashShare { address: first.pubkey(), share_bps: 5_000 },
+        SlashShare { address: second.pubkey(), share_bps: 3_000 },
+    ];
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request_with(&mut test_config, &vault_setup, 0, 500, claimants, 1_000, 1_000)
+        .expect("slash request creation failed");
+    let result = instructions::claim_slash_payout(&mut test_config, &vault_setup, 0, &first);
+    utils::assert_program_error(result, "SlashNotExecuted");
+
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+    let agent = test_config.agent.insecure_clone();
+    instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000)
+        .expect("slash finalization failed");
+
+    // 10% insured, 10% burned, the claimants' 80% held in escrow
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.insurance_amount, 10_000);
+    assert_eq!(request_data.burned_amount, 10_000);
+    let lock_fund = capital_accounts::get_insurance_fund_data(&mut test_config.svm, &vault_setup.lock_mint);
+    assert_eq!(lock_fund.total_deposited, 10_000);
+    let vault_ata = utils::get_ata(&vault_setup.vault, &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &vault_ata), 1_900_000);
+    let escrow = utils::get_ata(&slash_request, &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &escrow), 80_000);
+
+    let result = instructions::claim_slash_payout(&mut test_config, &vault_setup, 0, &stranger);
+    utils::assert_program_error(result, "SlashClaimantNotFound");
+    instructions::claim_slash_payout(&mut test_config, &vault_setup, 0, &first).expect("payout claim failed");
+    let result = instructions::claim_slash_payout(&mut test_config, &vault_setup, 0, &first);
+    utils::assert_program_error(result, "SlashPayoutAlreadyClaimed");
+    instructions::claim_slash_payout(&mut test_config, &vault_setup, 0, &second).expect("payout claim failed");
+
+    let first_ata = utils::get_ata(&first.pubkey(), &vault_setup.lock_mint);
+    let second_ata = utils::get_ata(&second.pubkey(), &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &first_ata), 50_000);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &second_ata), 30_000);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &escrow), 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert!(request_data.claimants.iter().all(|claimant| claimant.claimed));
+}
+
+#[test]
+pub fn test_arbiter_committee_executes_the_slash_at_its_threshold() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let first = test_config.beneficiary_1.insecure_clone();
+    let second = test_config.beneficiary_2.insecure_clone();
+    let third = test_config.beneficiary_3.insecure_clone();
+    let fourth = test_config.beneficiary_4.insecure_clone();
+
+    let result = instructions::update_arbiters(&mut test_config, &[first.pubkey(), second.pubkey()], 3);
+    utils::assert_program_error(result, "InvalidArbiterSet");
+    let committee = [first.pubkey(), second.pubkey(), third.pubkey()];
+    instructions::update_arbiters(&mut test_config, &committee, 2).expect("arbiter update failed");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW);
+
+    // The agent hands the decision over to the committee
+    let agent = test_config.agent.insecure_clone();
+    let result = instructions::finalize_slash_request(&mut test_config, &vault_setup, 0, &agent, true, 100_000);
+    utils::assert_program_error(result, "ArbiterVoteRequired");
+    let result = instructions::vote_slash(&mut test_config, &vault_setup, 0, &fourth, true, 100_000);
+    utils::assert_program_error(result, "UnauthorizedArbiter");
+
+    instructions::vote_slash(&mut test_config, &vault_setup, 0, &first, true, 80_000).expect("vote failed");
+    let result = instructions::vote_slash(&mut test_config, &vault_setup, 0, &first, false, 0);
+    utils::assert_program_error(result, "ArbiterAlreadyVoted");
+
+    // The first arbiter leaves the committee, its approval no longer counts
+    let committee = [second.pubkey(), third.pubkey(), fourth.pubkey()];
+    instructions::update_arbiters(&mut test_config, &committee, 2).expect("arbiter update failed");
+    instructions::vote_slash(&mut test_config, &vault_setup, 0, &second, true, 60_000).expect("vote failed");
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Pending);
+    assert_eq!(request_data.approvals.len(), 2);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Disputed);
+
+    // The threshold is met, the smallest approved amount is executed
+    instructions::vote_slash(&mut test_config, &vault_setup, 0, &third, true, 90_000).expect("vote failed");
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Executed);
+    assert_eq!(request_data.executed_amount, 60_000);
+    assert_eq!(request_data.resolver, third.pubkey());
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+    assert_eq!(vault_data.capital_after_slashing, 1_940_000);
+    let escrow = utils::get_ata(&slash_request, &vault_setup.lock_mint);
+    assert_eq!(utils::get_token_balance(&test_config.svm, &escrow), 60_000);
+}
+
+#[test]
+pub fn test_arbiter_committee_rejects_once_the_threshold_is_out_of_reach() {
+    let mut test_config = TestConfig::new();
+    instructions::init_programs(&mut test_config);
+    let vault_setup = instructions::create_vault(&mut test_config);
+    instructions::open_position(&mut test_config, &vault_setup, 2_000_000);
+    let first = test_config.beneficiary_1.insecure_clone();
+    let second = test_config.beneficiary_2.insecure_clone();
+    let third = test_config.beneficiary_3.insecure_clone();
+    let committee = [first.pubkey(), second.pubkey(), third.pubkey()];
+    instructions::update_arbiters(&mut test_config, &committee, 2).expect("arbiter update failed");
+
+    utils::warp_to(&mut test_config.svm, vault_setup.lock_phase_start_at);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 0, 500)
+        .expect("slash request creation failed");
+
+    // Approvals wait for the appeal window, rejections don't
+    let result = instructions::vote_slash(&mut test_config, &vault_setup, 0, &first, true, 100_000);
+    utils::assert_program_error(result, "AppealWindowOpen");
+    instructions::vote_slash(&mut test_config, &vault_setup, 0, &first, false, 0).expect("vote failed");
+    instructions::vote_slash(&mut test_config, &vault_setup, 0, &second, false, 0).expect("vote failed");
+
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, 0);
+    let request_data = capital_accounts::get_slash_request_data(&mut test_config.svm, &slash_request);
+    assert_eq!(request_data.status, SlashStatus::Rejected);
+    let vault_data = capital_accounts::get_vault_data(&mut test_config.svm, &vault_setup.vault);
+    assert_eq!(vault_data.phase, VaultPhase::Active);
+    assert_eq!(vault_data.capital_after_slashing, 2_000_000);
+
+    // Votes close with the decision deadline, dismissal takes over
+    let slash_at = utils::now(&test_config.svm);
+    instructions::create_slash_request(&mut test_config, &vault_setup, 1, 500)
+        .expect("slash request creation failed");
+    utils::warp_to(&mut test_config.svm, slash_at + DISPUTE_WINDOW + DECISION_WINDOW);
+    let result = instructions::vote_slash(&mut test_config, &vault_setup, 1, &third, true, 100_000);
+    utils::assert_program_error(result, "SlashVotingClosed");
+}
@@ -1,12 +1,35 @@
 use litesvm::LiteSVM;
 use solana_sdk::pubkey::Pubkey;
-use zaals_finance_client::{accounts::AuthorityConfig, CAPITAL_PROGRAM_ID};
+use zaals_finance_client::{
+    accounts::{Attestation, AuthorityConfig, BeneficiaryAccount, Challenge, InsuranceFund, Position, SlashRequest, Vault},
+    pdas, CAPITAL_PROGRAM_ID,
+};
 
 pub fn get_authority_config_pda() -> Pubkey {
     let authority_config = Pubkey::try_find_program_address(&[b"Config"], &CAPITAL_PROGRAM_ID);
     authority_config.unwrap().0
 }
 
+#[allow(dead_code)]
+pub fn get_treasury_pda() -> Pubkey {
+    pdas::find_treasury_address().0
+}
+
+#[allow(dead_code)]
+pub fn get_insurance_fund_pda(mint: &Pubkey) -> Pubkey {
+    pdas::find_insurance_fund_address(mint).0
+}
+
+#[allow(dead_code)]
+pub fn get_operator_registry_pda(node_operator: &Pubkey) -> Pubkey {
+    pdas::find_operator_registry_address(node_operator).0
+}
+
+#[allow(dead_code)]
+pub fn get_vault_pda(node_operator: &Pubkey, vault_index: u64) -> Pubkey {
+    pdas::find_vault_address(node_operator, vault_index).0
+}
+
 #[allow(dead_code)]
 pub fn get_authority_config_pda_data(svm: &mut LiteSVM) -> AuthorityConfig {
     let authority_address = get_authority_config_pda();
@@ -15,3 +38,88 @@ pub fn get_authority_config_pda_data(svm: &mut LiteSVM) -> AuthorityConfig {
         .expect("Authority Config account not found");
     AuthorityConfig::from_bytes(&account.data).expect("Unable Deserialize data")
 }
+
+#[allow(dead_code)]
+pub fn get_slash_request_pda(vault: &Pubkey, sequence: u64) -> Pubkey {
+    pdas::find_slash_request_address(vault, sequence).0
+}
+
+#[allow(dead_code)]
+pub fn get_position_pda(asset: &Pubkey) -> Pubkey {
+    pdas::find_position_address(asset).0
+}
+
+#[allow(dead_code)]
+pub fn get_epoch_commitment_pda(vault: &Pubkey, epoch: u64) -> Pubkey {
+    pdas::find_epoch_commitment_address(vault, epoch).0
+}
+
+#[allow(dead_code)]
+pub fn get_challenge_pda(commitment: &Pubkey, leaf_index: u64) -> Pubkey {
+    pdas::find_challenge_address(commitment, leaf_index).0
+}
+
+#[allow(dead_code)]
+pub fn get_attester_pda(key: &Pubkey) -> Pubkey {
+    pdas::find_attester_address(key).0
+}
+
+#[allow(dead_code)]
+pub fn get_attestation_pda(vault: &Pubkey, attester: &Pubkey, epoch: u64) -> Pubkey {
+    pdas::find_attestation_address(vault, attester, epoch).0
+}
+
+#[allow(dead_code)]
+pub fn get_beneficiary_pda(vault: &Pubkey, beneficiary: &Pubkey) -> Pubkey {
+    pdas::find_beneficiary_address(vault, beneficiary).0
+}
+
+#[allow(dead_code)]
+pub fn get_vault_data(svm: &mut LiteSVM, vault: &Pubkey) -> Vault {
+    let account = svm.get_account(vault).expect("Vault account not found");
+    Vault::from_bytes(&account.data).expect("Unable Deserialize data")
+}
+
+#[allow(dead_code)]
+pub fn get_slash_request_data(svm: &mut LiteSVM, slash_request: &Pubkey) -> SlashRequest {
+    let account = svm
+        .get_account(slash_request)
+        .expect("Slash Request account not found");
+    SlashRequest::from_bytes(&account.data).expect("Unable Deserialize data")
+}
+
+#[allow(dead_code)]
+pub fn get_challenge_data(svm: &mut LiteSVM, challenge: &Pubkey) -> Challenge {
+    let account = svm
+        .get_account(challenge)
+        .expect("Challenge account not found");
+    Challenge::from_bytes(&account.data).expect("Unable Deserialize data")
+}
+
+#[allow(dead_code)]
+pub fn get_position_data(svm: &mut LiteSVM, position: &Pubkey) -> Option<Position> {
+    let account = svm.get_account(position)?;
+    Position::from_bytes(&account.data).ok()
+}
+
+#[allow(dead_code)]
+pub fn get_insurance_fund_data(svm: &mut LiteSVM, mint: &Pubkey) -> InsuranceFund {
+    let account = svm
+        .get_account(&get_insurance_fund_pda(mint))
+        .expect("Insurance Fund account not found");
+    InsuranceFund::from_bytes(&account.data).expect("Unable Deserialize data")
+}
+
+#[allow(dead_code)]
+pub fn get_attestation_data(svm: &mut LiteSVM, attestation: &Pubkey) -> Attestation {
+    let account = svm
+        .get_account(attestation)
+        .expect("Attestation account not found");
+    Attestation::from_bytes(&account.data).expect("Unable Deserialize data")
+}
+
+#[allow(dead_code)]
+pub fn get_beneficiary_data(svm: &mut LiteSVM, beneficiary_account: &Pubkey) -> Option<BeneficiaryAccount> {
+    let account = svm.get_account(beneficiary_account)?;
+    BeneficiaryAccount::from_bytes(&account.data).ok()
+}
@@ -2,3 +2,5 @@ pub const NFT_PROGRAM_KEY_PAIR: &str = "target/deploy/nft_program-keypair.json";
 pub const NFT_PROGRAM_SO_FILE: &str = "target/deploy/nft_program.so";
 pub const CAPITAL_PROGRAM_KEY_PAIR: &str = "target/deploy/capital_program-keypair.json";
 pub const CAPITAL_PROGRAM_SO_FILE: &str = "target/deploy/capital_program.so";
+pub const MPL_CORE_SO_FILE: &str = "tests/programs/core.so";
+pub const TOKEN_DECIMALS: u8 = 6;
@@ -1,11 +1,66 @@
-use crate::setup::{test_config::TestConfig, *};
+use crate::setup::{test_config::TestConfig, utils::*, *};
 use litesvm::types::TransactionResult;
-use solana_sdk::signature::Signer;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
 use zaals_finance_client::{
-    instructions::InitCapitalProgramHandlerBuilder,
+    instructions::{
+        AddBeneficiaryHandlerBuilder, AddRewardStreamHandlerBuilder, AppealSlashReqHandlerBuilder,
+        ChallengeMissedEpochHandlerBuilder, ClaimBeneficiaryRewardsHandlerBuilder,
+        ClaimInvestorRewardsHandlerBuilder, ClaimOperatorCommissionHandlerBuilder,
+        ClaimSlashPayoutHandlerBuilder, ClosePositionHandlerBuilder, CloseVaultHandlerBuilder,
+        CommitEpochHandlerBuilder, CreateSlasReqHandlerBuilder, CreateVaultHandlerBuilder,
+        DepositInsuranceHandlerBuilder, DepositRewardsHandlerBuilder, DismissSlashReqHandlerBuilder,
+        DonateRewardsHandlerBuilder, EarlyUnlockPositionHandlerBuilder,
+        FinalizeSlashReqHandlerBuilder, InitCapitalProgramHandlerBuilder,
+        InitFeeAccountsHandlerBuilder, IssueChallengeHandlerBuilder, OpenPositionHandlerBuilder,
+        RegisterAttesterHandlerBuilder, RemoveBeneficiaryHandlerBuilder,
+        ResizeBeneficiaryHandlerBuilder, RespondChallengeHandlerBuilder,
+        SetProtocolPauseHandlerBuilder, SetVaultPauseHandlerBuilder,
+        SlashFailedChallengeHandlerBuilder, SubmitAttestationHandlerBuilder,
+        SweepVaultHandlerBuilder, TransferBeneficiaryHandlerBuilder, UpdateArbitersHandlerBuilder,
+        UpdateGuardianHandlerBuilder, UpdateProgramConfigHandlerBuilder,
+        UpdateRewardDistributorHandlerBuilder, VoteSlashHandlerBuilder,
+        WithdrawInsuranceHandlerBuilder, WithdrawTreasuryHandlerBuilder,
+    },
     nft_program::instructions::InitNftProgramHandlerBuilder,
+    types::{BeneficiaryVesting, ChallengeSlashTerms, SlashShare},
 };
 
+#[allow(dead_code)]
+pub const DAY: i64 = 86400;
+#[allow(dead_code)]
+pub const LOCK_PHASE_DURATION: i64 = 35 * DAY;
+#[allow(dead_code)]
+pub const MIN_CAP: u64 = 1_000_000;
+#[allow(dead_code)]
+pub const MAX_CAP: u64 = 10_000_000;
+#[allow(dead_code)]
+pub const MAX_SLASH_BPS: u16 = 1_000;
+#[allow(dead_code)]
+pub const MIN_LOCK_DURATION: i64 = 31 * DAY;
+#[allow(dead_code)]
+pub const MAX_LOCK_DURATION: i64 = 365 * DAY;
+
+/// Addresses of a vault created by `create_vault`
+#[allow(dead_code)]
+pub struct VaultSetup {
+    pub vault: Pubkey,
+    pub collection: Pubkey,
+    pub lock_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub lock_phase_start_at: i64,
+}
+
+/// Addresses of a position opened by `open_position`
+#[allow(dead_code)]
+pub struct PositionSetup {
+    pub position: Pubkey,
+    pub asset: Pubkey,
+}
+
 #[allow(dead_code)]
 pub fn init_nft_program(test_config: &mut TestConfig) -> TransactionResult {
     let config_address = nft_accounts::get_nft_config_pda();
@@ -36,10 +91,9 @@ pub fn init_capital_program(test_config: &mut TestConfig) -> TransactionResult {
         .agent(test_config.agent.pubkey())
         .config(authority_config_address)
         .nft_config(nft_config_address)
-        .dispute_window(2 * 86400)
         .early_unlock_fee(2_000)
-        .max_lock_duration(365 * 86400)
-        .min_lock_duration(31 * 86400)
+        .max_lock_duration(MAX_LOCK_DURATION)
+        .min_lock_duration(MIN_LOCK_DURATION)
         .nft_program(test_config.nft_program_id)
         .instruction();
     utils::send_transaction(
@@ -53,5 +107,1270 @@ pub fn init_capital_program(test_config: &mut TestConfig) -> TransactionResult {
     )
 }
 
+/// Fee and insurance parameters set through update_program_config, all off
+/// after init_capital_program
+#[allow(dead_code)]
+#[derive(Default)]
+pub struct FeeTerms {
+    pub protocol_fee_bps: u16,
+    pub insurance_fee_bps: u16,
+    pub insurance_coverage_bps: u16,
+    pub min_operator_bond_bps: u16,
+}
+
+/// Admin's update of the fee terms, the lock durations keep their init values
+#[allow(dead_code)]
+pub fn update_fee_terms(test_config: &mut TestConfig, terms: &FeeTerms) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let inxs = UpdateProgramConfigHandlerBuilder::new()
+        .admin(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .early_unlock_fee(2_000)
+        .protocol_fee_bps(terms.protocol_fee_bps)
+        .min_operator_bond_bps(terms.min_operator_bond_bps)
+        .insurance_fee_bps(terms.insurance_fee_bps)
+        .insurance_coverage_bps(terms.insurance_coverage_bps)
+        .min_lock_duration(MIN_LOCK_DURATION)
+        .max_lock_duration(MAX_LOCK_DURATION)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// Admin's creation of the treasury token account and insurance fund of `mint`
+#[allow(dead_code)]
+pub fn init_fee_accounts(test_config: &mut TestConfig, mint: Pubkey) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let treasury = capital_accounts::get_treasury_pda();
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&mint);
+    let inxs = InitFeeAccountsHandlerBuilder::new()
+        .admin(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .mint(mint)
+        .treasury(treasury)
+        .treasury_ata(get_ata(&treasury, &mint))
+        .insurance_fund(insurance_fund)
+        .insurance_fund_ata(get_ata(&insurance_fund, &mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// Initializes both programs, every flow starts from here
+#[allow(dead_code)]
+pub fn init_programs(test_config: &mut TestConfig) {
+    init_nft_program(test_config).expect("nft program initialization failed");
+    init_capital_program(test_config).expect("capital program initialization failed");
+}
+
+/// Vault parameters individual tests vary, the rest is fixed by create_vault_with
+#[allow(dead_code)]
+pub struct VaultTerms {
+    pub lock_phase_duration: i64,
+    pub reward_in_lock_mint: bool,
+    // Posted from the node operator's lock token account, funded with exactly this much
+    pub operator_bond: u64,
+}
+
+impl Default for VaultTerms {
+    fn default() -> Self {
+        VaultTerms {
+            lock_phase_duration: LOCK_PHASE_DURATION,
+            reward_in_lock_mint: false,
+            operator_bond: 0,
+        }
+    }
+}
+
+/// Creates the node operator's first vault, the capital provider gets the
+/// whole locking token supply
+#[allow(dead_code)]
+pub fn create_vault(test_config: &mut TestConfig) -> VaultSetup {
+    let (vault_setup, result) = create_vault_with(test_config, &VaultTerms::default());
+    result.expect("vault creation failed");
+    vault_setup
+}
+
+/// Like create_vault, returns the creation result instead of expecting success
+#[allow(dead_code)]
+pub fn create_vault_with(
+    test_config: &mut TestConfig,
+    terms: &VaultTerms,
+) -> (VaultSetup, TransactionResult) {
+    let god = test_config.god.pubkey();
+    let node_operator = test_config.node_operator.pubkey();
+    let lock_mint = create_mint(&mut test_config.svm, &god, MAX_CAP);
+    let reward_mint = if terms.reward_in_lock_mint {
+        lock_mint
+    } else {
+        create_mint(&mut test_config.svm, &god, 0)
+    };
+    create_ata(&mut test_config.svm, &node_operator, &lock_mint, terms.operator_bond);
+    create_ata(
+        &mut test_config.svm,
+        &test_config.capital_provider.pubkey(),
+        &lock_mint,
+        MAX_CAP,
+    );
+
+    let vault = capital_accounts::get_vault_pda(&node_operator, 0);
+    let collection = Keypair::new();
+    let lock_phase_start_at = now(&test_config.svm) + 7 * DAY;
+    let inxs = CreateVaultHandlerBuilder::new()
+        .provider(node_operator)
+        .operator_registry(capital_accounts::get_operator_registry_pda(&node_operator))
+        .vault(vault)
+        .config_account(capital_accounts::get_authority_config_pda())
+        .nft_config(nft_accounts::get_nft_config_pda())
+        .reward_token_mint(reward_mint)
+        .lock_mint(lock_mint)
+        .vault_lock_ata(get_ata(&vault, &lock_mint))
+        .provider_lock_ata(get_ata(&node_operator, &lock_mint))
+        .nft_collection(collection.pubkey())
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .mpl_core_program(MPL_CORE_PROGRAM_ID)
+        .nft_marketplace(test_config.nft_program_id)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .min_cap(MIN_CAP)
+        .max_cap(MAX_CAP)
+        .min_lock_amount(100_000)
+        .operator_bond(terms.operator_bond)
+        .investor_bps(8_000)
+        .operator_commission_bps(2_000)
+        .max_slash_bps(MAX_SLASH_BPS)
+        .reward_distributor(node_operator)
+        .node_operator(node_operator)
+        .lock_phase_duration(terms.lock_phase_duration)
+        .lock_phase_start_time(lock_phase_start_at)
+        .instruction();
+    let result = utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone(), &collection],
+    );
+
+    let vault_setup = VaultSetup {
+        vault,
+        collection: collection.pubkey(),
+        lock_mint,
+        reward_mint,
+        lock_phase_start_at,
+    };
+    (vault_setup, result)
+}
+
+/// Locks `amount` of the capital provider's tokens in the vault
+#[allow(dead_code)]
+pub fn open_position(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    amount: u64,
+) -> PositionSetup {
+    let capital_provider = test_config.capital_provider.pubkey();
+    let asset = Keypair::new();
+    let position = capital_accounts::get_position_pda(&asset.pubkey());
+    let inxs = OpenPositionHandlerBuilder::new()
+        .capital_provider(capital_provider)
+        .asset(asset.pubkey())
+        .vault_collection(vault_setup.collection)
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .nft_config(nft_accounts::get_nft_config_pda())
+        .position(position)
+        .capital_provider_token_ata(get_ata(&capital_provider, &vault_setup.lock_mint))
+        .vault_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .locked_token_mint(vault_setup.lock_mint)
+        .token_program(TOKEN_PROGRAM_ID)
+        .mpl_core_program(MPL_CORE_PROGRAM_ID)
+        .nft_program(test_config.nft_program_id)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .amount(amount)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &capital_provider,
+        &[&test_config.capital_provider.insecure_clone(), &asset],
+    )
+    .expect("position opening failed");
+
+    PositionSetup {
+        position,
+        asset: asset.pubkey(),
+    }
+}
+
+/// Node operator's rotation of the vault's reward distributor
+#[allow(dead_code)]
+pub fn update_reward_distributor(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    new_distributor: &Pubkey,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = UpdateRewardDistributorHandlerBuilder::new()
+        .node_operator(node_operator)
+        .vault(vault_setup.vault)
+        .new_distributor(*new_distributor)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// `depositor` is funded with exactly `amount` of `reward_mint` and deposits
+/// it. The fee accounts are passed once init_fee_accounts created them
+#[allow(dead_code)]
+pub fn deposit_rewards(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    depositor: &Keypair,
+    reward_mint: Pubkey,
+    amount: u64,
+    stream_duration: Option<i64>,
+) -> TransactionResult {
+    let treasury = capital_accounts::get_treasury_pda();
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&reward_mint);
+    let fee_accounts = test_config.svm.get_account(&insurance_fund).is_some();
+    create_ata(&mut test_config.svm, &depositor.pubkey(), &reward_mint, amount);
+
+    let mut builder = DepositRewardsHandlerBuilder::new();
+    builder
+        .depositor(depositor.pubkey())
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .reward_token_mint(reward_mint)
+        .vault_reward_ata(get_ata(&vault_setup.vault, &reward_mint))
+        .treasury(treasury)
+        .treasury_reward_ata(fee_accounts.then(|| get_ata(&treasury, &reward_mint)))
+        .insurance_fund(fee_accounts.then_some(insurance_fund))
+        .insurance_fund_ata(fee_accounts.then(|| get_ata(&insurance_fund, &reward_mint)))
+        .depositor_reward_ata(get_ata(&depositor.pubkey(), &reward_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .amount(amount);
+    if let Some(stream_duration) = stream_duration {
+        builder.stream_duration(stream_duration);
+    }
+    let inxs = builder.instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &depositor.pubkey(),
+        &[&depositor.insecure_clone()],
+    )
+}
+
+/// Node operator's closing of an emptied vault, getting back what is left of its bond
+#[allow(dead_code)]
+pub fn close_vault(test_config: &mut TestConfig, vault_setup: &VaultSetup) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = CloseVaultHandlerBuilder::new()
+        .node_operator(node_operator)
+        .nft(vault_setup.collection)
+        .vault(vault_setup.vault)
+        .vault_reward_ata(get_ata(&vault_setup.vault, &vault_setup.reward_mint))
+        .vault_lock_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .node_operator_lock_ata(get_ata(&node_operator, &vault_setup.lock_mint))
+        .staking_token_mint(vault_setup.lock_mint)
+        .reward_token_mint(vault_setup.reward_mint)
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// `donor` is funded with exactly `amount` of the vault's reward mint and
+/// donates it for `campaign_id`
+#[allow(dead_code)]
+pub fn donate_rewards(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    donor: &Keypair,
+    amount: u64,
+    campaign_id: u64,
+    memo: &str,
+) -> TransactionResult {
+    let reward_mint = vault_setup.reward_mint;
+    create_ata(&mut test_config.svm, &donor.pubkey(), &reward_mint, amount);
+    let inxs = DonateRewardsHandlerBuilder::new()
+        .donor(donor.pubkey())
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .reward_token_mint(reward_mint)
+        .vault_reward_ata(get_ata(&vault_setup.vault, &reward_mint))
+        .donor_reward_ata(get_ata(&donor.pubkey(), &reward_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .amount(amount)
+        .campaign_id(campaign_id)
+        .memo(memo.to_string())
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &donor.pubkey(),
+        &[donor],
+    )
+}
+
+/// Capital provider's claim of the position's rewards, the first mint through
+/// the named accounts and the others as (mint, vault account, holder account)
+/// remaining accounts
+#[allow(dead_code)]
+pub fn claim_investor_rewards(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    position_setup: &PositionSetup,
+    reward_mints: &[Pubkey],
+) -> TransactionResult {
+    let holder = test_config.capital_provider.pubkey();
+    for mint in reward_mints {
+        if test_config.svm.get_account(&get_ata(&holder, mint)).is_none() {
+            create_ata(&mut test_config.svm, &holder, mint, 0);
+        }
+    }
+
+    let mut builder = ClaimInvestorRewardsHandlerBuilder::new();
+    builder
+        .holder(holder)
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .position(position_setup.position)
+        .asset(position_setup.asset)
+        .reward_mint(reward_mints[0])
+        .vault_ata(get_ata(&vault_setup.vault, &reward_mints[0]))
+        .holder_ata(get_ata(&holder, &reward_mints[0]))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .add_remaining_accounts(&reward_stream_accounts(&vault_setup.vault, &holder, &reward_mints[1..]));
+    let inxs = builder.instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &holder,
+        &[&test_config.capital_provider.insecure_clone()],
+    )
+}
+
+/// Node operator's claim of its commission on every stream of `reward_mints`
+#[allow(dead_code)]
+pub fn claim_operator_commission(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    reward_mints: &[Pubkey],
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = ClaimOperatorCommissionHandlerBuilder::new()
+        .node_operator(node_operator)
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .reward_mint(reward_mints[0])
+        .vault_ata(get_ata(&vault_setup.vault, &reward_mints[0]))
+        .node_operator_ata(get_ata(&node_operator, &reward_mints[0]))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .add_remaining_accounts(&reward_stream_accounts(&vault_setup.vault, &node_operator, &reward_mints[1..]))
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// Node operator's grant of `share_bps` of its commission to `beneficiary`, co-signed by it
+#[allow(dead_code)]
+pub fn add_beneficiary(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    beneficiary: &Keypair,
+    share_bps: u16,
+    vesting: Option<BeneficiaryVesting>,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let mut builder = AddBeneficiaryHandlerBuilder::new();
+    builder
+        .node_operator(node_operator)
+        .beneficiary(beneficiary.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .beneficiary_account(capital_accounts::get_beneficiary_pda(&vault_setup.vault, &beneficiary.pubkey()))
+        .system_program(SYSTEM_PROGRAM_ID)
+        .share_bps(share_bps);
+    if let Some(vesting) = vesting {
+        builder.vesting(vesting);
+    }
+    let inxs = builder.instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone(), beneficiary],
+    )
+}
+
+/// Node operator's change of `beneficiary`'s share, co-signed by it
+#[allow(dead_code)]
+pub fn resize_beneficiary(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    beneficiary: &Keypair,
+    share_bps: u16,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = ResizeBeneficiaryHandlerBuilder::new()
+        .node_operator(node_operator)
+        .beneficiary(beneficiary.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .beneficiary_account(capital_accounts::get_beneficiary_pda(&vault_setup.vault, &beneficiary.pubkey()))
+        .share_bps(share_bps)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone(), beneficiary],
+    )
+}
+
+/// Node operator's removal of `beneficiary`, paying it what vested on `reward_mints`
+#[allow(dead_code)]
+pub fn remove_beneficiary(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    beneficiary: &Keypair,
+    reward_mints: &[Pubkey],
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    for mint in reward_mints {
+        if test_config.svm.get_account(&get_ata(&beneficiary.pubkey(), mint)).is_none() {
+            create_ata(&mut test_config.svm, &beneficiary.pubkey(), mint, 0);
+        }
+    }
+    let inxs = RemoveBeneficiaryHandlerBuilder::new()
+        .node_operator(node_operator)
+        .beneficiary(beneficiary.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .beneficiary_account(capital_accounts::get_beneficiary_pda(&vault_setup.vault, &beneficiary.pubkey()))
+        .token_program(TOKEN_PROGRAM_ID)
+        .add_remaining_accounts(&reward_stream_accounts(&vault_setup.vault, &beneficiary.pubkey(), reward_mints))
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone(), beneficiary],
+    )
+}
+
+/// `beneficiary`'s hand over of its share and unclaimed rewards to `new_address`
+#[allow(dead_code)]
+pub fn transfer_beneficiary(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    beneficiary: &Keypair,
+    new_address: &Pubkey,
+) -> TransactionResult {
+    let inxs = TransferBeneficiaryHandlerBuilder::new()
+        .beneficiary(beneficiary.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .beneficiary_account(capital_accounts::get_beneficiary_pda(&vault_setup.vault, &beneficiary.pubkey()))
+        .new_beneficiary_account(capital_accounts::get_beneficiary_pda(&vault_setup.vault, new_address))
+        .system_program(SYSTEM_PROGRAM_ID)
+        .new_address(*new_address)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &beneficiary.pubkey(),
+        &[beneficiary],
+    )
+}
+
+/// `beneficiary`'s claim of what vested on every stream of `reward_mints`
+#[allow(dead_code)]
+pub fn claim_beneficiary_rewards(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    beneficiary: &Keypair,
+    reward_mints: &[Pubkey],
+) -> TransactionResult {
+    let inxs = ClaimBeneficiaryRewardsHandlerBuilder::new()
+        .beneficiary(beneficiary.pubkey())
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .beneficiary_account(capital_accounts::get_beneficiary_pda(&vault_setup.vault, &beneficiary.pubkey()))
+        .reward_mint(reward_mints[0])
+        .vault_ata(get_ata(&vault_setup.vault, &reward_mints[0]))
+        .beneficiary_ata(get_ata(&beneficiary.pubkey(), &reward_mints[0]))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .add_remaining_accounts(&reward_stream_accounts(&vault_setup.vault, &beneficiary.pubkey(), &reward_mints[1..]))
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &beneficiary.pubkey(),
+        &[beneficiary],
+    )
+}
+
+/// Capital provider's withdrawal of the position's principal, claiming
+/// insurance coverage from the lock mint's fund when `claim_insurance`
+#[allow(dead_code)]
+pub fn close_position(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    position_setup: &PositionSetup,
+    claim_insurance: bool,
+) -> TransactionResult {
+    let holder = test_config.capital_provider.pubkey();
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&vault_setup.lock_mint);
+    let inxs = ClosePositionHandlerBuilder::new()
+        .position_holder(holder)
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .position(position_setup.position)
+        .asset(position_setup.asset)
+        .collection(vault_setup.collection)
+        .lock_mint(vault_setup.lock_mint)
+        .vault_lock_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .capital_provider_lock_ata(get_ata(&holder, &vault_setup.lock_mint))
+        .insurance_fund(claim_insurance.then_some(insurance_fund))
+        .insurance_fund_ata(claim_insurance.then(|| get_ata(&insurance_fund, &vault_setup.lock_mint)))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .mpl_core_program(MPL_CORE_PROGRAM_ID)
+        .nft_program(test_config.nft_program_id)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &holder,
+        &[&test_config.capital_provider.insecure_clone()],
+    )
+}
+
+/// `depositor` is funded with exactly `amount` of `mint` and tops up its insurance fund
+#[allow(dead_code)]
+pub fn deposit_insurance(
+    test_config: &mut TestConfig,
+    depositor: &Keypair,
+    mint: Pubkey,
+    amount: u64,
+) -> TransactionResult {
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&mint);
+    create_ata(&mut test_config.svm, &depositor.pubkey(), &mint, amount);
+    let inxs = DepositInsuranceHandlerBuilder::new()
+        .depositor(depositor.pubkey())
+        .mint(mint)
+        .insurance_fund(insurance_fund)
+        .insurance_fund_ata(get_ata(&insurance_fund, &mint))
+        .depositor_ata(get_ata(&depositor.pubkey(), &mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .amount(amount)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &depositor.pubkey(),
+        &[&depositor.insecure_clone()],
+    )
+}
+
+/// Admin's withdrawal of `amount` from the insurance fund of `mint` into
+/// the admin's own token account
+#[allow(dead_code)]
+pub fn withdraw_insurance(test_config: &mut TestConfig, mint: Pubkey, amount: u64) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&mint);
+    let destination = get_ata(&admin, &mint);
+    if test_config.svm.get_account(&destination).is_none() {
+        create_ata(&mut test_config.svm, &admin, &mint, 0);
+    }
+    let inxs = WithdrawInsuranceHandlerBuilder::new()
+        .admin(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .mint(mint)
+        .insurance_fund(insurance_fund)
+        .insurance_fund_ata(get_ata(&insurance_fund, &mint))
+        .destination(destination)
+        .token_program(TOKEN_PROGRAM_ID)
+        .amount(amount)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// Admin's sweep of the vault's first reward stream and lock token residue to the treasury
+#[allow(dead_code)]
+pub fn sweep_vault(test_config: &mut TestConfig, vault_setup: &VaultSetup) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let treasury = capital_accounts::get_treasury_pda();
+    let inxs = SweepVaultHandlerBuilder::new()
+        .authority(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .treasury(treasury)
+        .reward_mint(vault_setup.reward_mint)
+        .vault_reward_ata(get_ata(&vault_setup.vault, &vault_setup.reward_mint))
+        .treasury_reward_ata(get_ata(&treasury, &vault_setup.reward_mint))
+        .lock_mint(vault_setup.lock_mint)
+        .vault_lock_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .treasury_lock_ata(get_ata(&treasury, &vault_setup.lock_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// `authority`'s withdrawal of collected protocol fees to its own token account
+#[allow(dead_code)]
+pub fn withdraw_treasury(
+    test_config: &mut TestConfig,
+    authority: &Keypair,
+    mint: Pubkey,
+    amount: u64,
+) -> TransactionResult {
+    let treasury = capital_accounts::get_treasury_pda();
+    let destination = get_ata(&authority.pubkey(), &mint);
+    if test_config.svm.get_account(&destination).is_none() {
+        create_ata(&mut test_config.svm, &authority.pubkey(), &mint, 0);
+    }
+    let inxs = WithdrawTreasuryHandlerBuilder::new()
+        .admin(authority.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .treasury(treasury)
+        .mint(mint)
+        .treasury_ata(get_ata(&treasury, &mint))
+        .destination(destination)
+        .token_program(TOKEN_PROGRAM_ID)
+        .amount(amount)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &authority.pubkey(),
+        &[authority],
+    )
+}
+
+/// Admin's appointment of a guardian allowed to pause
+#[allow(dead_code)]
+pub fn update_guardian(test_config: &mut TestConfig, new_guardian: &Pubkey) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let inxs = UpdateGuardianHandlerBuilder::new()
+        .admin(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .new_guardian(*new_guardian)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// Replaces the arbiter committee deciding slash requests
+#[allow(dead_code)]
+pub fn update_arbiters(
+    test_config: &mut TestConfig,
+    arbiters: &[Pubkey],
+    threshold: u8,
+) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let inxs = UpdateArbitersHandlerBuilder::new()
+        .admin(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .arbiters(arbiters.to_vec())
+        .threshold(threshold)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// Pauses or unpauses the whole protocol, `authority` pays the fee
+#[allow(dead_code)]
+pub fn set_protocol_pause(test_config: &mut TestConfig, authority: &Keypair, paused: bool) -> TransactionResult {
+    let inxs = SetProtocolPauseHandlerBuilder::new()
+        .authority(authority.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .nft_config(nft_accounts::get_nft_config_pda())
+        .nft_program(test_config.nft_program_id)
+        .paused(paused)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &authority.pubkey(),
+        &[&authority.insecure_clone()],
+    )
+}
+
+/// Pauses or unpauses one vault, `authority` pays the fee
+#[allow(dead_code)]
+pub fn set_vault_pause(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    authority: &Keypair,
+    paused: bool,
+) -> TransactionResult {
+    let inxs = SetVaultPauseHandlerBuilder::new()
+        .authority(authority.pubkey())
+        .config(capital_accounts::get_authority_config_pda())
+        .vault(vault_setup.vault)
+        .paused(paused)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &authority.pubkey(),
+        &[&authority.insecure_clone()],
+    )
+}
+
+/// Admin's registration of `key` as an attester, reports below `min_uptime_bps` fail
+#[allow(dead_code)]
+pub fn register_attester(test_config: &mut TestConfig, key: &Pubkey, min_uptime_bps: u16) -> TransactionResult {
+    let admin = test_config.admin.pubkey();
+    let inxs = RegisterAttesterHandlerBuilder::new()
+        .admin(admin)
+        .config(capital_accounts::get_authority_config_pda())
+        .attester(capital_accounts::get_attester_pda(key))
+        .system_program(SYSTEM_PROGRAM_ID)
+        .key(*key)
+        .min_uptime_bps(min_uptime_bps)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &admin,
+        &[&test_config.admin.insecure_clone()],
+    )
+}
+
+/// Message an attester signs for one epoch of a vault, as built by Attestation::message
+#[allow(dead_code)]
+pub fn attestation_message(vault: &Pubkey, epoch: u64, uptime_bps: u16, work_units: u64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(50);
+    message.extend_from_slice(vault.as_ref());
+    message.extend_from_slice(&epoch.to_le_bytes());
+    message.extend_from_slice(&uptime_bps.to_le_bytes());
+    message.extend_from_slice(&work_units.to_le_bytes());
+    message
+}
+
+/// submit_attestation instruction for a report of `attester`, relayed and paid by god
+#[allow(dead_code)]
+pub fn submit_attestation_inx(
+    test_config: &TestConfig,
+    vault_setup: &VaultSetup,
+    attester: &Pubkey,
+    epoch: u64,
+    uptime_bps: u16,
+    work_units: u64,
+) -> Instruction {
+    SubmitAttestationHandlerBuilder::new()
+        .payer(test_config.god.pubkey())
+        .attester(capital_accounts::get_attester_pda(attester))
+        .vault(vault_setup.vault)
+        .attestation(capital_accounts::get_attestation_pda(&vault_setup.vault, attester, epoch))
+        .epoch(epoch)
+        .uptime_bps(uptime_bps)
+        .work_units(work_units)
+        .instruction()
+}
+
+/// Relays a report signed by `attester`, its ed25519 verification right before it
+#[allow(dead_code)]
+pub fn submit_attestation(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    attester: &Keypair,
+    epoch: u64,
+    uptime_bps: u16,
+    work_units: u64,
+) -> TransactionResult {
+    let message = attestation_message(&vault_setup.vault, epoch, uptime_bps, work_units);
+    let inxs = [
+        utils::ed25519_instruction(attester, &message),
+        submit_attestation_inx(test_config, vault_setup, &attester.pubkey(), epoch, uptime_bps, work_units),
+    ];
+    let payer = test_config.god.pubkey();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &inxs,
+        &payer,
+        &[&test_config.god.insecure_clone()],
+    )
+}
+
+/// Node operator's registration of another reward mint on the vault
+#[allow(dead_code)]
+pub fn add_reward_stream(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    reward_mint: Pubkey,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = AddRewardStreamHandlerBuilder::new()
+        .node_operator(node_operator)
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .reward_mint(reward_mint)
+        .vault_reward_ata(get_ata(&vault_setup.vault, &reward_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// Agent's slash request of `slash_bps`, all paid to the test slash claimant
+#[allow(dead_code)]
+pub fn create_slash_request(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+    slash_bps: u16,
+) -> TransactionResult {
+    let claimants = slash_claimants(test_config);
+    create_slash_request_with(test_config, vault_setup, sequence, slash_bps, claimants, 0, 0)
+}
+
+/// Agent's slash request of `slash_bps`, split between `claimants`, the
+/// insurance fund and the burn
+#[allow(dead_code)]
+pub fn create_slash_request_with(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+    slash_bps: u16,
+    claimants: Vec<SlashShare>,
+    insurance_bps: u16,
+    burn_bps: u16,
+) -> TransactionResult {
+    let agent = test_config.agent.pubkey();
+    let inxs = CreateSlasReqHandlerBuilder::new()
+        .agent(agent)
+        .vault(vault_setup.vault)
+        .slash_request(capital_accounts::get_slash_request_pda(
+            &vault_setup.vault,
+            sequence,
+        ))
+        .config(capital_accounts::get_authority_config_pda())
+        .system_program(SYSTEM_PROGRAM_ID)
+        .slash_bps(slash_bps)
+        .claimants(claimants)
+        .insurance_bps(insurance_bps)
+        .burn_bps(burn_bps)
+        .evidence_hash([7; 32])
+        .evidence_uri("ipfs://evidence".to_string())
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &agent,
+        &[&test_config.agent.insecure_clone()],
+    )
+}
+
+#[allow(dead_code)]
+pub fn appeal_slash_request(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = AppealSlashReqHandlerBuilder::new()
+        .node_operator(node_operator)
+        .vault(vault_setup.vault)
+        .slash_request(capital_accounts::get_slash_request_pda(
+            &vault_setup.vault,
+            sequence,
+        ))
+        .config(capital_accounts::get_authority_config_pda())
+        .evidence_hash([9; 32])
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// `caller` dismisses a slash request left undecided past its deadline
+#[allow(dead_code)]
+pub fn dismiss_slash_request(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+    caller: &Keypair,
+) -> TransactionResult {
+    let inxs = DismissSlashReqHandlerBuilder::new()
+        .caller(caller.pubkey())
+        .vault(vault_setup.vault)
+        .slash_request(capital_accounts::get_slash_request_pda(
+            &vault_setup.vault,
+            sequence,
+        ))
+        .config(capital_accounts::get_authority_config_pda())
+        .instruction();
+    utils::send_transaction(&mut test_config.svm, &[inxs], &caller.pubkey(), &[caller])
+}
+
+/// `resolver` decides the slash request, executing `amount` on approval
+#[allow(dead_code)]
+pub fn finalize_slash_request(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+    resolver: &Keypair,
+    decision: bool,
+    amount: u64,
+) -> TransactionResult {
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, sequence);
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&vault_setup.lock_mint);
+    let fee_accounts = test_config.svm.get_account(&insurance_fund).is_some();
+    let inxs = FinalizeSlashReqHandlerBuilder::new()
+        .resolver(resolver.pubkey())
+        .vault(vault_setup.vault)
+        .slash_request(slash_request)
+        .config(capital_accounts::get_authority_config_pda())
+        .locking_token_mint(vault_setup.lock_mint)
+        .vault_token_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .slash_escrow_ata(get_ata(&slash_request, &vault_setup.lock_mint))
+        .insurance_fund(fee_accounts.then_some(insurance_fund))
+        .insurance_fund_ata(fee_accounts.then(|| get_ata(&insurance_fund, &vault_setup.lock_mint)))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .decision(decision)
+        .amount(amount)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &resolver.pubkey(),
+        &[resolver],
+    )
+}
+
+/// `claimant` collects its share of an executed slash request
+#[allow(dead_code)]
+pub fn claim_slash_payout(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+    claimant: &Keypair,
+) -> TransactionResult {
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, sequence);
+    let inxs = ClaimSlashPayoutHandlerBuilder::new()
+        .claimant(claimant.pubkey())
+        .vault(vault_setup.vault)
+        .slash_request(slash_request)
+        .locking_token_mint(vault_setup.lock_mint)
+        .slash_escrow_ata(get_ata(&slash_request, &vault_setup.lock_mint))
+        .claimant_ata(get_ata(&claimant.pubkey(), &vault_setup.lock_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(&mut test_config.svm, &[inxs], &claimant.pubkey(), &[claimant])
+}
+
+/// `arbiter` votes on the slash request, approving `amount` of it or rejecting it
+#[allow(dead_code)]
+pub fn vote_slash(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    sequence: u64,
+    arbiter: &Keypair,
+    approve: bool,
+    amount: u64,
+) -> TransactionResult {
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, sequence);
+    let insurance_fund = capital_accounts::get_insurance_fund_pda(&vault_setup.lock_mint);
+    let fee_accounts = test_config.svm.get_account(&insurance_fund).is_some();
+    let inxs = VoteSlashHandlerBuilder::new()
+        .arbiter(arbiter.pubkey())
+        .vault(vault_setup.vault)
+        .slash_request(slash_request)
+        .config(capital_accounts::get_authority_config_pda())
+        .locking_token_mint(vault_setup.lock_mint)
+        .vault_token_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .slash_escrow_ata(get_ata(&slash_request, &vault_setup.lock_mint))
+        .insurance_fund(fee_accounts.then_some(insurance_fund))
+        .insurance_fund_ata(fee_accounts.then(|| get_ata(&insurance_fund, &vault_setup.lock_mint)))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .approve(approve)
+        .amount(amount)
+        .instruction();
+    utils::send_transaction(&mut test_config.svm, &[inxs], &arbiter.pubkey(), &[arbiter])
+}
+
+#[allow(dead_code)]
+pub fn commit_epoch(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    leaf_count: u64,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = CommitEpochHandlerBuilder::new()
+        .node_operator(node_operator)
+        .vault(vault_setup.vault)
+        .commitment(capital_accounts::get_epoch_commitment_pda(
+            &vault_setup.vault,
+            epoch,
+        ))
+        .system_program(SYSTEM_PROGRAM_ID)
+        .epoch(epoch)
+        .merkle_root(merkle_root)
+        .leaf_count(leaf_count)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// Agent's challenge of one leaf of the epoch's commitment
 #[allow(dead_code)]
-pub fn create_vault(test_config: &mut TestConfig) {}
+pub fn issue_challenge(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    epoch: u64,
+    leaf_index: u64,
+    slash_bps: u16,
+) -> TransactionResult {
+    let agent = test_config.agent.pubkey();
+    let commitment = capital_accounts::get_epoch_commitment_pda(&vault_setup.vault, epoch);
+    let inxs = IssueChallengeHandlerBuilder::new()
+        .agent(agent)
+        .vault(vault_setup.vault)
+        .commitment(commitment)
+        .challenge(capital_accounts::get_challenge_pda(&commitment, leaf_index))
+        .config(capital_accounts::get_authority_config_pda())
+        .system_program(SYSTEM_PROGRAM_ID)
+        .leaf_index(leaf_index)
+        .terms(challenge_terms(test_config, slash_bps))
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &agent,
+        &[&test_config.agent.insecure_clone()],
+    )
+}
+
+/// Agent's challenge of an epoch the node operator never committed
+#[allow(dead_code)]
+pub fn challenge_missed_epoch(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    epoch: u64,
+    slash_bps: u16,
+) -> TransactionResult {
+    let agent = test_config.agent.pubkey();
+    let commitment = capital_accounts::get_epoch_commitment_pda(&vault_setup.vault, epoch);
+    let inxs = ChallengeMissedEpochHandlerBuilder::new()
+        .agent(agent)
+        .vault(vault_setup.vault)
+        .commitment(commitment)
+        .challenge(capital_accounts::get_challenge_pda(&commitment, 0))
+        .config(capital_accounts::get_authority_config_pda())
+        .system_program(SYSTEM_PROGRAM_ID)
+        .epoch(epoch)
+        .terms(challenge_terms(test_config, slash_bps))
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &agent,
+        &[&test_config.agent.insecure_clone()],
+    )
+}
+
+#[allow(dead_code)]
+pub fn respond_challenge(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    challenge: Pubkey,
+    commitment: Pubkey,
+    leaf: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> TransactionResult {
+    let node_operator = test_config.node_operator.pubkey();
+    let inxs = RespondChallengeHandlerBuilder::new()
+        .node_operator(node_operator)
+        .vault(vault_setup.vault)
+        .commitment(commitment)
+        .challenge(challenge)
+        .leaf(leaf)
+        .proof(proof)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &node_operator,
+        &[&test_config.node_operator.insecure_clone()],
+    )
+}
+
+/// Anyone executes the slash of a failed challenge, here the god account
+#[allow(dead_code)]
+pub fn slash_failed_challenge(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    challenge: Pubkey,
+    sequence: u64,
+) -> TransactionResult {
+    let caller = test_config.god.pubkey();
+    let slash_request = capital_accounts::get_slash_request_pda(&vault_setup.vault, sequence);
+    let inxs = SlashFailedChallengeHandlerBuilder::new()
+        .caller(caller)
+        .vault(vault_setup.vault)
+        .challenge(challenge)
+        .slash_request(slash_request)
+        .config(capital_accounts::get_authority_config_pda())
+        .locking_token_mint(vault_setup.lock_mint)
+        .vault_token_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .slash_escrow_ata(get_ata(&slash_request, &vault_setup.lock_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &caller,
+        &[&test_config.god.insecure_clone()],
+    )
+}
+
+#[allow(dead_code)]
+pub fn early_unlock_position(
+    test_config: &mut TestConfig,
+    vault_setup: &VaultSetup,
+    position_setup: &PositionSetup,
+) -> TransactionResult {
+    let holder = test_config.capital_provider.pubkey();
+    let inxs = EarlyUnlockPositionHandlerBuilder::new()
+        .position_holder(holder)
+        .vault(vault_setup.vault)
+        .config(capital_accounts::get_authority_config_pda())
+        .position(position_setup.position)
+        .asset(position_setup.asset)
+        .collection(vault_setup.collection)
+        .lock_mint(vault_setup.lock_mint)
+        .vault_lock_ata(get_ata(&vault_setup.vault, &vault_setup.lock_mint))
+        .capital_provider_lock_ata(get_ata(&holder, &vault_setup.lock_mint))
+        .reward_mint(vault_setup.reward_mint)
+        .vault_reward_ata(get_ata(&vault_setup.vault, &vault_setup.reward_mint))
+        .holder_reward_ata(get_ata(&holder, &vault_setup.reward_mint))
+        .token_program(TOKEN_PROGRAM_ID)
+        .associated_token_program(ASSOCIATED_TOKEN_PROGRAM_ID)
+        .mpl_core_program(MPL_CORE_PROGRAM_ID)
+        .nft_program(test_config.nft_program_id)
+        .system_program(SYSTEM_PROGRAM_ID)
+        .instruction();
+    utils::send_transaction(
+        &mut test_config.svm,
+        &[inxs],
+        &holder,
+        &[&test_config.capital_provider.insecure_clone()],
+    )
+}
+
+#[allow(dead_code)]
+fn slash_claimants(test_config: &TestConfig) -> Vec<SlashShare> {
+    vec![SlashShare {
+        address: test_config.slash_claimant,
+        share_bps: 10_000,
+    }]
+}
+
+fn challenge_terms(test_config: &TestConfig, slash_bps: u16) -> ChallengeSlashTerms {
+    ChallengeSlashTerms {
+        slash_bps,
+        claimants: slash_claimants(test_config),
+        insurance_bps: 0,
+        burn_bps: 0,
+    }
+}
+
+/// (mint, vault account, recipient account) triples of further reward streams
+#[allow(dead_code)]
+fn reward_stream_accounts(vault: &Pubkey, recipient: &Pubkey, mints: &[Pubkey]) -> Vec<AccountMeta> {
+    mints
+        .iter()
+        .flat_map(|mint| {
+            [
+                AccountMeta::new_readonly(*mint, false),
+                AccountMeta::new(get_ata(vault, mint), false),
+                AccountMeta::new(get_ata(recipient, mint), false),
+            ]
+        })
+        .collect()
+}
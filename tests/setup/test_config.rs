@@ -50,7 +50,14 @@ impl TestConfig {
         utils::fund(&mut svm, node_operator.pubkey()).expect("airdrop failed");
         utils::fund(&mut svm, capital_provider.pubkey()).expect("airdrop failed");
         utils::fund(&mut svm, god.pubkey()).expect("airdrop failed");
+        utils::fund(&mut svm, beneficiary_1.pubkey()).expect("airdrop failed");
+        utils::fund(&mut svm, beneficiary_2.pubkey()).expect("airdrop failed");
+        utils::fund(&mut svm, beneficiary_3.pubkey()).expect("airdrop failed");
+        utils::fund(&mut svm, beneficiary_4.pubkey()).expect("airdrop failed");
+        utils::fund(&mut svm, beneficiary_5.pubkey()).expect("airdrop failed");
+        utils::fund(&mut svm, extra_beneficiary.pubkey()).expect("airdrop failed");
 
+        utils::deploy_mpl_core(&mut svm).expect("mpl_core deployment failed");
         utils::deploy_nft_program(&mut svm).expect("nft_program deployment failed");
         utils::deploy_capital_program(&mut svm).expect("capital_program deployment failed");
 
@@ -1,16 +1,32 @@
 use crate::constants::{NFT_PROGRAM_KEY_PAIR, NFT_PROGRAM_SO_FILE};
-use crate::setup::constants::{CAPITAL_PROGRAM_KEY_PAIR, CAPITAL_PROGRAM_SO_FILE};
+use crate::setup::constants::{
+    CAPITAL_PROGRAM_KEY_PAIR, CAPITAL_PROGRAM_SO_FILE, MPL_CORE_SO_FILE, TOKEN_DECIMALS,
+};
 use litesvm::LiteSVM;
 use litesvm::{error::LiteSVMError, types::TransactionResult};
 use solana_sdk::{
+    account::Account,
     clock::Clock,
+    ed25519_program,
+    hash::hashv,
     instruction::Instruction,
     native_token::LAMPORTS_PER_SOL,
+    pubkey,
     pubkey::Pubkey,
     signature::{read_keypair_file, Keypair, Signer},
     transaction::Transaction,
 };
 
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const MPL_CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+#[allow(dead_code)]
+pub const SYSTEM_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+
+const MINT_LEN: usize = 82;
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
 pub fn deploy_nft_program(svm: &mut LiteSVM) -> Result<(), LiteSVMError> {
     let program_keypair =
         read_keypair_file(NFT_PROGRAM_KEY_PAIR).expect("Failed to read keypair file");
@@ -27,6 +43,10 @@ pub fn deploy_capital_program(svm: &mut LiteSVM) -> Result<(), LiteSVMError> {
     svm.add_program_from_file(program_id, CAPITAL_PROGRAM_SO_FILE)
 }
 
+pub fn deploy_mpl_core(svm: &mut LiteSVM) -> Result<(), LiteSVMError> {
+    svm.add_program_from_file(MPL_CORE_PROGRAM_ID, MPL_CORE_SO_FILE)
+}
+
 pub fn fund(svm: &mut LiteSVM, claimant: Pubkey) -> TransactionResult {
     svm.airdrop(&claimant, 100 * LAMPORTS_PER_SOL)
 }
@@ -48,3 +68,180 @@ pub fn send_transaction(
 
     result
 }
+
+/// ed25519 program instruction verifying `signer`'s signature over `message`,
+/// with the public key, signature and message inside its own data
+#[allow(dead_code)]
+pub fn ed25519_instruction(signer: &Keypair, message: &[u8]) -> Instruction {
+    const DATA_START: u16 = 16;
+    let public_key_offset = DATA_START;
+    let signature_offset = public_key_offset + 32;
+    let message_offset = signature_offset + 64;
+    let offsets = [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ];
+
+    let mut data = vec![1, 0];
+    for offset in offsets {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signer.sign_message(message).as_ref());
+    data.extend_from_slice(message);
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[allow(dead_code)]
+pub fn now(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+/// Moves the cluster clock to `unix_timestamp`
+#[allow(dead_code)]
+pub fn warp_to(svm: &mut LiteSVM, unix_timestamp: i64) {
+    let mut clock: Clock = svm.get_sysvar();
+    clock.unix_timestamp = unix_timestamp;
+    svm.set_sysvar(&clock);
+}
+
+/// Writes an initialized SPL token mint with `supply` already issued
+#[allow(dead_code)]
+pub fn create_mint(svm: &mut LiteSVM, authority: &Pubkey, supply: u64) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    let mut data = vec![0; MINT_LEN];
+    data[0..4].copy_from_slice(&1u32.to_le_bytes());
+    data[4..36].copy_from_slice(authority.as_ref());
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[44] = TOKEN_DECIMALS;
+    data[45] = 1;
+    set_token_program_account(svm, mint, data);
+    mint
+}
+
+#[allow(dead_code)]
+pub fn get_ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Writes `owner`'s associated token account of `mint` holding `amount`
+#[allow(dead_code)]
+pub fn create_ata(svm: &mut LiteSVM, owner: &Pubkey, mint: &Pubkey, amount: u64) -> Pubkey {
+    let ata = get_ata(owner, mint);
+    let mut data = vec![0; TOKEN_ACCOUNT_LEN];
+    data[0..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data[108] = 1;
+    set_token_program_account(svm, ata, data);
+    ata
+}
+
+#[allow(dead_code)]
+pub fn get_token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let account = svm
+        .get_account(token_account)
+        .expect("Token account not found");
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+#[allow(dead_code)]
+fn set_token_program_account(svm: &mut LiteSVM, address: Pubkey, data: Vec<u8>) {
+    let account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(address, account)
+        .expect("Unable to write token account");
+}
+
+/// Fails unless the transaction failed with the program error `error_name`
+#[allow(dead_code)]
+pub fn assert_program_error(result: TransactionResult, error_name: &str) {
+    match result {
+        Ok(meta) => panic!("Expected {error_name}, transaction succeeded: {:?}", meta.logs),
+        Err(e) => {
+            let expected = format!("Error Code: {error_name}.");
+            assert!(
+                e.meta.logs.iter().any(|log| log.contains(&expected)),
+                "Expected {error_name}, got {:?}",
+                e.meta.logs
+            );
+        }
+    }
+}
+
+/// Data of the anchor events named `event_name` in a transaction's logs,
+/// without their discriminator
+#[allow(dead_code)]
+pub fn emitted_events(logs: &[String], event_name: &str) -> Vec<Vec<u8>> {
+    let discriminator = hashv(&[format!("event:{event_name}").as_bytes()]).to_bytes();
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(decode_base64)
+        .filter_map(|data| data.strip_prefix(&discriminator[..8]).map(<[u8]>::to_vec))
+        .collect()
+}
+
+fn decode_base64(encoded: &str) -> Vec<u8> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut data = Vec::with_capacity(encoded.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for byte in encoded.bytes().take_while(|byte| *byte != b'=') {
+        let value = ALPHABET
+            .iter()
+            .position(|c| *c == byte)
+            .expect("Invalid base64 event data");
+        buffer = (buffer << 6 | value as u32) & 0xffff;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            data.push((buffer >> bits) as u8);
+        }
+    }
+    data
+}
+
+/// Root and per-leaf proofs of an epoch work tree, hashed like the program
+/// does and padded with zero leaves
+#[allow(dead_code)]
+pub fn build_merkle_tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let mut level: Vec<[u8; 32]> = leaves
+        .iter()
+        .map(|leaf| hashv(&[&[0], leaf]).to_bytes())
+        .collect();
+    level.resize(
+        leaves.len().next_power_of_two(),
+        hashv(&[&[0], &[0; 32]]).to_bytes(),
+    );
+
+    let mut proofs = vec![Vec::new(); leaves.len()];
+    let mut positions: Vec<usize> = (0..leaves.len()).collect();
+    while level.len() > 1 {
+        for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+            proof.push(level[*position ^ 1]);
+            *position /= 2;
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| hashv(&[&[1], &pair[0], &pair[1]]).to_bytes())
+            .collect();
+    }
+    (level[0], proofs)
+}